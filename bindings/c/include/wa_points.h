@@ -0,0 +1,95 @@
+#ifndef WA_POINTS_H
+#define WA_POINTS_H
+
+/* Generated by cbindgen from bindings/c/src/lib.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Input for `wa_score`. Pointer fields may be NULL where noted.
+ */
+typedef struct WaScoreInput {
+  /**
+   * "men" or "women"
+   */
+  const char *gender;
+  /**
+   * Event name as listed by `wa_event_name`, e.g. "100m"
+   */
+  const char *event;
+  /**
+   * Seconds for time-based events, meters for field events
+   */
+  double performance;
+  /**
+   * Wind in m/s, or NULL for no wind information
+   */
+  const double *wind_speed;
+  /**
+   * Net downhill in m/km for road events, or NULL
+   */
+  const double *net_downhill;
+  /**
+   * Competition category (e.g. "A"), or NULL to skip placement scoring
+   */
+  const char *competition_category;
+  int place;
+  /**
+   * "final", "semifinal", "heat", "qualification" or "other"; NULL means
+   * "final"
+   */
+  const char *round;
+  int size_of_final;
+  bool qualified_to_final;
+} WaScoreInput;
+
+/**
+ * Output of `wa_score`.
+ */
+typedef struct WaScoreOutput {
+  double points;
+  bool beyond_table_range;
+} WaScoreOutput;
+
+/**
+ * Calculates the World Athletics score for a performance.
+ *
+ * # Safety
+ * `input` and `output` must be valid pointers, and every non-NULL string in `input`
+ * must be NUL-terminated.
+ */
+int wa_score(const struct WaScoreInput *input, struct WaScoreOutput *output);
+
+/**
+ * Calculates the performance (seconds or meters) required to score `points` in an event.
+ *
+ * # Safety
+ * `gender` and `event` must be valid NUL-terminated strings and `performance` a valid pointer.
+ */
+int wa_performance_for_score(const char *gender,
+                             const char *event,
+                             double points,
+                             double *performance);
+
+/**
+ * The number of supported events.
+ */
+size_t wa_event_count(void);
+
+/**
+ * The name of the event at `index`, or NULL if out of range.
+ * The returned string is static and must not be freed.
+ */
+const char *wa_event_name(size_t index);
+
+/**
+ * The message for the last error on this thread, or NULL if there was none.
+ * The string is valid until the next failing call on the same thread.
+ */
+const char *wa_last_error(void);
+
+#endif /* WA_POINTS_H */
@@ -0,0 +1,286 @@
+//! C interface to the scoring engine.
+//!
+//! Every fallible function returns `0` on success and `-1` on failure, in which case
+//! `wa_last_error()` describes what went wrong. Strings are NUL-terminated UTF-8.
+use std::cell::RefCell;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::sync::OnceLock;
+
+use wa_points_core::models::*;
+use wa_points_core::scoring_logic::calculator::calculate_world_athletics_score;
+use wa_points_core::scoring_logic::coefficients::{self, calculate_result_score};
+use wa_points_core::scoring_logic::placement_score::{calculate_placement_score, RoundType};
+
+/// Input for `wa_score`. Pointer fields may be NULL where noted.
+#[repr(C)]
+pub struct WaScoreInput {
+    /// "men" or "women"
+    pub gender: *const c_char,
+    /// Event name as listed by `wa_event_name`, e.g. "100m"
+    pub event: *const c_char,
+    /// Seconds for time-based events, meters for field events
+    pub performance: f64,
+    /// Wind in m/s, or NULL for no wind information
+    pub wind_speed: *const f64,
+    /// Net downhill in m/km for road events, or NULL
+    pub net_downhill: *const f64,
+    /// Competition category (e.g. "A"), or NULL to skip placement scoring
+    pub competition_category: *const c_char,
+    pub place: c_int,
+    /// "final", "semifinal", "heat", "qualification" or "other"; NULL means
+    /// "final"
+    pub round: *const c_char,
+    pub size_of_final: c_int,
+    pub qualified_to_final: bool,
+}
+
+/// Output of `wa_score`.
+#[repr(C)]
+pub struct WaScoreOutput {
+    pub points: f64,
+    pub beyond_table_range: bool,
+}
+
+const WA_OK: c_int = 0;
+const WA_ERROR: c_int = -1;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+static EVENT_NAMES: OnceLock<Vec<CString>> = OnceLock::new();
+
+fn set_last_error(message: String) {
+    let message = CString::new(message).unwrap_or_else(|_| c"Invalid error message".into());
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// # Safety
+/// `ptr` must be NULL or a valid NUL-terminated string.
+unsafe fn read_str<'a>(ptr: *const c_char, field: &str) -> Result<Option<&'a str>, String> {
+    if ptr.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(Some)
+        .map_err(|_| format!("{} is not valid UTF-8", field))
+}
+
+/// # Safety
+/// `ptr` must be NULL or a valid NUL-terminated string.
+unsafe fn read_required_str<'a>(ptr: *const c_char, field: &str) -> Result<&'a str, String> {
+    read_str(ptr, field)?.ok_or_else(|| format!("{} must not be NULL", field))
+}
+
+fn parse_gender(gender: &str) -> Result<Gender, String> {
+    Gender::from_string(&gender.to_lowercase()).ok_or_else(|| format!("Unknown gender: {}", gender))
+}
+
+fn parse_event(event: &str) -> Result<Event, String> {
+    event.parse()
+}
+
+fn parse_round(round: Option<&str>) -> Result<RoundType, String> {
+    match round.map(str::to_lowercase).as_deref() {
+        None | Some("final") => Ok(RoundType::Final),
+        Some("semifinal") => Ok(RoundType::SemiFinal),
+        Some("heat") => Ok(RoundType::Heat),
+        Some("qualification") => Ok(RoundType::Qualification),
+        Some("other") => Ok(RoundType::Other),
+        Some(other) => Err(format!("Unknown round: {}", other)),
+    }
+}
+
+/// # Safety
+/// See `wa_score`.
+unsafe fn score(input: &WaScoreInput) -> Result<WaScoreOutput, String> {
+    let placement_info = match read_str(input.competition_category, "competition_category")? {
+        Some(category) => Some(PlacementInfo {
+            competition_category: CompetitionCategory::from_string(category)
+                .ok_or_else(|| format!("Unknown competition category: {}", category))?,
+            place: input.place,
+            round: parse_round(read_str(input.round, "round")?)?,
+            size_of_final: input.size_of_final,
+            qualified_to_final: input.qualified_to_final,
+            tied_with: 0,
+            main_event: false,
+        }),
+        None => None,
+    };
+    let event = parse_event(read_required_str(input.event, "event")?)?;
+    let score_input = WorldAthleticsScoreInput {
+        gender: parse_gender(read_required_str(input.gender, "gender")?)?,
+        performance: Performance::for_event(&event, input.performance),
+        event,
+        wind_speed: input.wind_speed.as_ref().copied(),
+        net_downhill: input.net_downhill.as_ref().copied(),
+        venue_altitude: None,
+        placement_info,
+        table_edition: None,
+    };
+    let output = calculate_world_athletics_score(
+        score_input,
+        calculate_result_score,
+        calculate_placement_score,
+    )?;
+    Ok(WaScoreOutput {
+        points: output.points,
+        beyond_table_range: output.beyond_table_range,
+    })
+}
+
+/// Calculates the World Athletics score for a performance.
+///
+/// # Safety
+/// `input` and `output` must be valid pointers, and every non-NULL string in `input`
+/// must be NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn wa_score(input: *const WaScoreInput, output: *mut WaScoreOutput) -> c_int {
+    if input.is_null() || output.is_null() {
+        set_last_error("input and output must not be NULL".to_string());
+        return WA_ERROR;
+    }
+    match score(&*input) {
+        Ok(result) => {
+            *output = result;
+            WA_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            WA_ERROR
+        }
+    }
+}
+
+/// Calculates the performance (seconds or meters) required to score `points` in an event.
+///
+/// # Safety
+/// `gender` and `event` must be valid NUL-terminated strings and `performance` a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn wa_performance_for_score(
+    gender: *const c_char,
+    event: *const c_char,
+    points: f64,
+    performance: *mut f64,
+) -> c_int {
+    if performance.is_null() {
+        set_last_error("performance must not be NULL".to_string());
+        return WA_ERROR;
+    }
+    let result = (|| {
+        let gender = parse_gender(read_required_str(gender, "gender")?)?;
+        let event = parse_event(read_required_str(event, "event")?)?;
+        coefficients::performance_for_score(points, gender, &event)
+    })();
+    match result {
+        Ok(value) => {
+            *performance = value;
+            WA_OK
+        }
+        Err(e) => {
+            set_last_error(e);
+            WA_ERROR
+        }
+    }
+}
+
+/// The number of supported events.
+#[no_mangle]
+pub extern "C" fn wa_event_count() -> usize {
+    event_names().len()
+}
+
+/// The name of the event at `index`, or NULL if out of range.
+/// The returned string is static and must not be freed.
+#[no_mangle]
+pub extern "C" fn wa_event_name(index: usize) -> *const c_char {
+    event_names()
+        .get(index)
+        .map_or(std::ptr::null(), |name| name.as_ptr())
+}
+
+/// The message for the last error on this thread, or NULL if there was none.
+/// The string is valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn wa_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+fn event_names() -> &'static [CString] {
+    EVENT_NAMES.get_or_init(|| {
+        Event::all_variants()
+            .iter()
+            .filter_map(|event| CString::new(event.to_string()).ok())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(event: &CStr, performance: f64) -> WaScoreInput {
+        WaScoreInput {
+            gender: c"men".as_ptr(),
+            event: event.as_ptr(),
+            performance,
+            wind_speed: std::ptr::null(),
+            net_downhill: std::ptr::null(),
+            competition_category: std::ptr::null(),
+            place: 0,
+            round: std::ptr::null(),
+            size_of_final: 8,
+            qualified_to_final: false,
+        }
+    }
+
+    #[test]
+    fn test_score() {
+        let mut output = WaScoreOutput {
+            points: 0.0,
+            beyond_table_range: false,
+        };
+        let input = input(c"5000m", 840.0);
+        assert_eq!(unsafe { wa_score(&input, &mut output) }, WA_OK);
+        assert_eq!(output.points, 1000.0);
+        assert!(!output.beyond_table_range);
+    }
+
+    #[test]
+    fn test_score_error_sets_last_error() {
+        let mut output = WaScoreOutput {
+            points: 0.0,
+            beyond_table_range: false,
+        };
+        let input = input(c"Egg and Spoon", 10.0);
+        assert_eq!(unsafe { wa_score(&input, &mut output) }, WA_ERROR);
+        let message = unsafe { CStr::from_ptr(wa_last_error()) };
+        assert!(message.to_str().unwrap().contains("Unknown event"));
+    }
+
+    #[test]
+    fn test_performance_for_score() {
+        let mut performance = 0.0;
+        let status = unsafe {
+            wa_performance_for_score(c"men".as_ptr(), c"5000m".as_ptr(), 1000.0, &mut performance)
+        };
+        assert_eq!(status, WA_OK);
+        assert!((performance - 840.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_event_listing() {
+        assert_eq!(wa_event_count(), Event::all_variants().len());
+        let first = unsafe { CStr::from_ptr(wa_event_name(0)) };
+        assert_eq!(
+            first.to_str().unwrap(),
+            Event::all_variants()[0].to_string()
+        );
+        assert!(wa_event_name(wa_event_count()).is_null());
+    }
+}
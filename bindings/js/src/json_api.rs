@@ -0,0 +1,139 @@
+//! JSON facade for pages that embed the scoring engine without the TypeScript wrapper.
+//!
+//! `calculateScore` takes a `ScoreInput` from `ts/index.ts` as a JSON string and returns
+//! the `ScoreBreakdown` as JSON:
+//!
+//! ```json
+//! {"gender": "men", "event": "100m", "performance": 9.58, "windSpeed": 0.9,
+//!  "placement": {"category": "OW", "place": 1}}
+//! ```
+use crate::{score, ScoreBreakdown};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct JsonPlacement {
+    category: String,
+    place: i32,
+    round: Option<String>,
+    size_of_final: Option<i32>,
+    qualified_to_final: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct JsonScoreInput {
+    gender: String,
+    event: String,
+    performance: f64,
+    wind_speed: Option<f64>,
+    net_downhill: Option<f64>,
+    placement: Option<JsonPlacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonScoreBreakdown {
+    points: f64,
+    result_score: f64,
+    wind_adjustment: f64,
+    downhill_adjustment: f64,
+    placement_score: i32,
+    beyond_table_range: bool,
+}
+
+impl From<ScoreBreakdown> for JsonScoreBreakdown {
+    fn from(breakdown: ScoreBreakdown) -> Self {
+        JsonScoreBreakdown {
+            points: breakdown.points,
+            result_score: breakdown.result_score,
+            wind_adjustment: breakdown.wind_adjustment,
+            downhill_adjustment: breakdown.downhill_adjustment,
+            placement_score: breakdown.placement_score,
+            beyond_table_range: breakdown.beyond_table_range,
+        }
+    }
+}
+
+/// Why a `calculateScore` call failed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// The input isn't valid JSON or doesn't have the `ScoreInput` shape
+    InvalidInput(String),
+    /// The input was read but couldn't be scored, e.g. an unknown event
+    Scoring(String),
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::InvalidInput(e) => write!(f, "Invalid score input: {}", e),
+            ApiError::Scoring(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<ApiError> for JsError {
+    fn from(error: ApiError) -> Self {
+        JsError::new(&error.to_string())
+    }
+}
+
+fn calculate_score_json(input_json: &str) -> Result<String, ApiError> {
+    let input: JsonScoreInput =
+        serde_json::from_str(input_json).map_err(|e| ApiError::InvalidInput(e.to_string()))?;
+    let placement = input.placement;
+    let breakdown = score(
+        &input.gender,
+        &input.event,
+        input.performance,
+        input.wind_speed,
+        input.net_downhill,
+        placement.as_ref().map(|p| p.category.clone()),
+        placement.as_ref().map(|p| p.place),
+        placement.as_ref().and_then(|p| p.round.clone()),
+        placement.as_ref().and_then(|p| p.size_of_final),
+        placement.as_ref().and_then(|p| p.qualified_to_final),
+    )
+    .map_err(ApiError::Scoring)?;
+    serde_json::to_string(&JsonScoreBreakdown::from(breakdown))
+        .map_err(|e| ApiError::Scoring(e.to_string()))
+}
+
+/// Scores a `ScoreInput` given as JSON and returns the `ScoreBreakdown` as JSON.
+#[wasm_bindgen(js_name = calculateScore)]
+pub fn calculate_score(input_json: &str) -> Result<String, JsError> {
+    calculate_score_json(input_json).map_err(JsError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_calculate_score_json() {
+        let output: Value = serde_json::from_str(
+            &calculate_score_json(
+                r#"{"gender": "men", "event": "100m", "performance": 10.0, "windSpeed": -1.0,
+                    "placement": {"category": "A", "place": 1}}"#,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(output["windAdjustment"], 6.0);
+        assert_eq!(output["placementScore"], 140);
+        assert_eq!(output["beyondTableRange"], false);
+
+        assert!(matches!(
+            calculate_score_json(r#"{"gender": "men", "event": "100m"}"#),
+            Err(ApiError::InvalidInput(_))
+        ));
+        assert_eq!(
+            calculate_score_json(r#"{"gender": "men", "event": "Sack race", "performance": 10}"#),
+            Err(ApiError::Scoring("Unknown event: Sack race".to_string()))
+        );
+    }
+}
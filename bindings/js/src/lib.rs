@@ -0,0 +1,193 @@
+//! wasm-bindgen exports backing the `wa-points` npm package.
+//!
+//! The raw exports take positional arguments; `ts/index.ts` wraps them in an
+//! object-based API with proper types for events, genders and the score breakdown.
+//! `json_api` offers the same scoring with JSON in and out, for use without the wrapper.
+use wa_points_core::models::*;
+use wa_points_core::scoring_logic::calculator::calculate_world_athletics_score;
+use wa_points_core::scoring_logic::coefficients::{self, calculate_result_score};
+use wa_points_core::scoring_logic::placement_score::{calculate_placement_score, RoundType};
+use wasm_bindgen::prelude::*;
+
+pub mod json_api;
+
+/// The components of a calculated score.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreBreakdown {
+    pub points: f64,
+    #[wasm_bindgen(js_name = resultScore)]
+    pub result_score: f64,
+    #[wasm_bindgen(js_name = windAdjustment)]
+    pub wind_adjustment: f64,
+    #[wasm_bindgen(js_name = downhillAdjustment)]
+    pub downhill_adjustment: f64,
+    #[wasm_bindgen(js_name = placementScore)]
+    pub placement_score: i32,
+    #[wasm_bindgen(js_name = beyondTableRange)]
+    pub beyond_table_range: bool,
+}
+
+impl From<WorldAthleticsScoreOutput> for ScoreBreakdown {
+    fn from(output: WorldAthleticsScoreOutput) -> Self {
+        ScoreBreakdown {
+            points: output.points,
+            result_score: output.result_score,
+            wind_adjustment: output.wind_adjustment,
+            downhill_adjustment: output.downhill_adjustment,
+            placement_score: output.placement_score,
+            beyond_table_range: output.beyond_table_range,
+        }
+    }
+}
+
+fn parse_gender(gender: &str) -> Result<Gender, String> {
+    Gender::from_string(gender).ok_or_else(|| format!("Unknown gender: {}", gender))
+}
+
+fn parse_event(event: &str) -> Result<Event, String> {
+    event.parse()
+}
+
+fn parse_round(round: Option<&str>) -> Result<RoundType, String> {
+    match round {
+        None | Some("final") => Ok(RoundType::Final),
+        Some("semifinal") => Ok(RoundType::SemiFinal),
+        Some("heat") => Ok(RoundType::Heat),
+        Some("qualification") => Ok(RoundType::Qualification),
+        Some("other") => Ok(RoundType::Other),
+        Some(other) => Err(format!("Unknown round: {}", other)),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn score(
+    gender: &str,
+    event: &str,
+    performance: f64,
+    wind_speed: Option<f64>,
+    net_downhill: Option<f64>,
+    competition_category: Option<String>,
+    place: Option<i32>,
+    round: Option<String>,
+    size_of_final: Option<i32>,
+    qualified_to_final: Option<bool>,
+) -> Result<ScoreBreakdown, String> {
+    let placement_info = match (competition_category, place) {
+        (Some(category), Some(place)) => Some(PlacementInfo {
+            competition_category: CompetitionCategory::from_string(&category)
+                .ok_or_else(|| format!("Unknown competition category: {}", category))?,
+            place,
+            round: parse_round(round.as_deref())?,
+            size_of_final: size_of_final.unwrap_or(8),
+            qualified_to_final: qualified_to_final.unwrap_or(false),
+            tied_with: 0,
+            main_event: false,
+        }),
+        _ => None,
+    };
+    let event = parse_event(event)?;
+    let input = WorldAthleticsScoreInput {
+        gender: parse_gender(gender)?,
+        performance: Performance::for_event(&event, performance),
+        event,
+        wind_speed,
+        net_downhill,
+        venue_altitude: None,
+        placement_info,
+        table_edition: None,
+    };
+    calculate_world_athletics_score(input, calculate_result_score, calculate_placement_score)
+        .map(ScoreBreakdown::from)
+}
+
+/// Calculates the score for a performance. Use the `score` wrapper from `ts/index.ts`.
+#[wasm_bindgen(js_name = scoreRaw)]
+#[allow(clippy::too_many_arguments)]
+pub fn score_raw(
+    gender: &str,
+    event: &str,
+    performance: f64,
+    wind_speed: Option<f64>,
+    net_downhill: Option<f64>,
+    competition_category: Option<String>,
+    place: Option<i32>,
+    round: Option<String>,
+    size_of_final: Option<i32>,
+    qualified_to_final: Option<bool>,
+) -> Result<ScoreBreakdown, JsError> {
+    score(
+        gender,
+        event,
+        performance,
+        wind_speed,
+        net_downhill,
+        competition_category,
+        place,
+        round,
+        size_of_final,
+        qualified_to_final,
+    )
+    .map_err(|e| JsError::new(&e))
+}
+
+/// The performance (seconds or meters) required to score `points` in an event.
+#[wasm_bindgen(js_name = performanceForScore)]
+pub fn performance_for_score(gender: &str, event: &str, points: f64) -> Result<f64, JsError> {
+    parse_gender(gender)
+        .and_then(|gender| Ok((gender, parse_event(event)?)))
+        .and_then(|(gender, event)| coefficients::performance_for_score(points, gender, &event))
+        .map_err(|e| JsError::new(&e))
+}
+
+/// The names of all supported events.
+#[wasm_bindgen(js_name = listEvents)]
+pub fn list_events() -> Vec<String> {
+    Event::all_variants()
+        .iter()
+        .map(|e| e.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        let breakdown = score(
+            "men",
+            "100m",
+            10.0,
+            Some(-1.0),
+            None,
+            Some("A".to_string()),
+            Some(1),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert_eq!(breakdown.wind_adjustment, 6.0);
+        assert_eq!(breakdown.placement_score, 140);
+        assert_eq!(
+            breakdown.points,
+            breakdown.result_score + breakdown.wind_adjustment + 140.0
+        );
+
+        assert!(score("men", "100m", 10.0, None, None, None, None, None, None, None).is_ok());
+        assert!(score("both", "100m", 10.0, None, None, None, None, None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_typescript_event_union_is_complete() {
+        let typescript = include_str!("../ts/index.ts");
+        for event in list_events() {
+            assert!(
+                typescript.contains(&format!("\"{}\"", event)),
+                "Event {} is missing from the TypeScript `Event` type",
+                event
+            );
+        }
+    }
+}
@@ -0,0 +1,188 @@
+import {
+  calculateScore as rawCalculateScore,
+  listEvents as rawListEvents,
+  performanceForScore as rawPerformanceForScore,
+  scoreRaw,
+} from "../pkg/wa_points_js";
+
+export type Gender = "men" | "women";
+
+export type Event =
+  | "50m"
+  | "55m"
+  | "60m"
+  | "100m"
+  | "200m"
+  | "300m"
+  | "400m"
+  | "500m"
+  | "600m"
+  | "800m"
+  | "1000m"
+  | "1500m"
+  | "2000m"
+  | "3000m"
+  | "5000m"
+  | "10000m"
+  | "50m Hurdle"
+  | "55m Hurdle"
+  | "60m Hurdle"
+  | "100m Hurdle"
+  | "110m Hurdle"
+  | "300m Hurdle"
+  | "400m Hurdle"
+  | "2000m SC"
+  | "3000m SC"
+  | "4x100m"
+  | "4x200m"
+  | "4x400m"
+  | "4x400mix"
+  | "Long Jump"
+  | "Triple Jump"
+  | "High Jump"
+  | "Pole Vault"
+  | "Shot Put"
+  | "Discus Throw"
+  | "Hammer Throw"
+  | "Javelin Throw"
+  | "200m short track"
+  | "300m short track"
+  | "400m short track"
+  | "500m short track"
+  | "600m short track"
+  | "800m short track"
+  | "1000m short track"
+  | "1500m short track"
+  | "2000m short track"
+  | "3000m short track"
+  | "5000m short track"
+  | "Mile short track"
+  | "2 Miles short track"
+  | "4x100m short track"
+  | "4x200m short track"
+  | "4x400m short track"
+  | "4x400mix short track"
+  | "Dec."
+  | "Hept."
+  | "Hept. short track"
+  | "Pent. short track"
+  | "Road 5 km"
+  | "Road 10 km"
+  | "Road 15 km"
+  | "Road 20 km"
+  | "Road 25 km"
+  | "Road 30 km"
+  | "Road HM"
+  | "Road Marathon"
+  | "Road 10 Miles"
+  | "Road Mile"
+  | "Road 5km Walk"
+  | "Road 10km Walk"
+  | "Road 15km Walk"
+  | "Road 20km Walk"
+  | "Road 30km Walk"
+  | "Road 35km Walk"
+  | "Road 50km Walk"
+  | "3000m Walk"
+  | "5000m Walk"
+  | "10,000m Walk"
+  | "15,000m Walk"
+  | "20,000m Walk"
+  | "30,000m Walk"
+  | "35,000m Walk"
+  | "50,000m Walk"
+  | "XC Short Course"
+  | "XC 8km"
+  | "XC 10km"
+  | "XC Championship";
+
+export type CompetitionCategory =
+  | "OW"
+  | "DF"
+  | "GW"
+  | "GL"
+  | "A"
+  | "B"
+  | "C"
+  | "D"
+  | "E"
+  | "F";
+
+export type Round = "final" | "semifinal" | "heat" | "qualification" | "other";
+
+export interface Placement {
+  category: CompetitionCategory;
+  place: number;
+  round?: Round;
+  /** Only used for semifinals; defaults to 8 */
+  sizeOfFinal?: number;
+  /** Qualified for the next round from a semifinal, heat or qualification round */
+  qualifiedToFinal?: boolean;
+}
+
+export interface ScoreInput {
+  gender: Gender;
+  event: Event;
+  /** Seconds for time-based events, meters for field events */
+  performance: number;
+  /** Wind in m/s; omit for no wind information */
+  windSpeed?: number;
+  /** Net downhill in m/km for road events */
+  netDownhill?: number;
+  placement?: Placement;
+}
+
+export interface ScoreBreakdown {
+  points: number;
+  resultScore: number;
+  windAdjustment: number;
+  downhillAdjustment: number;
+  placementScore: number;
+  beyondTableRange: boolean;
+}
+
+/** Calculates the World Athletics score for a performance. Throws on unknown events. */
+export function score(input: ScoreInput): ScoreBreakdown {
+  const raw = scoreRaw(
+    input.gender,
+    input.event,
+    input.performance,
+    input.windSpeed,
+    input.netDownhill,
+    input.placement?.category,
+    input.placement?.place,
+    input.placement?.round,
+    input.placement?.sizeOfFinal,
+    input.placement?.qualifiedToFinal,
+  );
+  try {
+    return {
+      points: raw.points,
+      resultScore: raw.resultScore,
+      windAdjustment: raw.windAdjustment,
+      downhillAdjustment: raw.downhillAdjustment,
+      placementScore: raw.placementScore,
+      beyondTableRange: raw.beyondTableRange,
+    };
+  } finally {
+    raw.free();
+  }
+}
+
+/** The performance (seconds or meters) required to score `points`. */
+export function performanceForScore(gender: Gender, event: Event, points: number): number {
+  return rawPerformanceForScore(gender, event, points);
+}
+
+/** All supported events. */
+export function listEvents(): Event[] {
+  return rawListEvents() as Event[];
+}
+
+/**
+ * Scores a `ScoreInput` given as a JSON string and returns the `ScoreBreakdown` as JSON,
+ * for callers that already hold JSON. Throws on malformed input or unknown events.
+ */
+export function calculateScore(inputJson: string): string {
+  return rawCalculateScore(inputJson);
+}
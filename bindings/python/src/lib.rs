@@ -0,0 +1,137 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use wa_points_core::models::*;
+use wa_points_core::scoring_logic::calculator::calculate_world_athletics_score;
+use wa_points_core::scoring_logic::coefficients::{self, calculate_result_score};
+use wa_points_core::scoring_logic::placement_score::{calculate_placement_score, RoundType};
+
+fn parse_gender(gender: &str) -> PyResult<Gender> {
+    Gender::from_string(&gender.to_lowercase())
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown gender: {}", gender)))
+}
+
+fn parse_event(event: &str) -> PyResult<Event> {
+    event.parse::<Event>().map_err(PyValueError::new_err)
+}
+
+fn parse_round(round: &str) -> PyResult<RoundType> {
+    match round.to_lowercase().as_str() {
+        "final" => Ok(RoundType::Final),
+        "semifinal" => Ok(RoundType::SemiFinal),
+        "heat" => Ok(RoundType::Heat),
+        "qualification" => Ok(RoundType::Qualification),
+        "other" => Ok(RoundType::Other),
+        _ => Err(PyValueError::new_err(format!("Unknown round: {}", round))),
+    }
+}
+
+/// Calculate the World Athletics score for a performance.
+///
+/// `performance` is in seconds for time-based events and meters for field events.
+/// Placement points are only added when both `category` and `place` are given.
+/// Returns a dict with `points` and `beyond_table_range`.
+#[pyfunction]
+#[pyo3(signature = (
+    gender,
+    event,
+    performance,
+    wind_speed=None,
+    net_downhill=None,
+    category=None,
+    place=None,
+    round="final",
+    size_of_final=8,
+    qualified_to_final=false,
+))]
+#[allow(clippy::too_many_arguments)]
+fn score(
+    py: Python<'_>,
+    gender: &str,
+    event: &str,
+    performance: f64,
+    wind_speed: Option<f64>,
+    net_downhill: Option<f64>,
+    category: Option<&str>,
+    place: Option<i32>,
+    round: &str,
+    size_of_final: i32,
+    qualified_to_final: bool,
+) -> PyResult<PyObject> {
+    let placement_info = match (category, place) {
+        (Some(category), Some(place)) => Some(PlacementInfo {
+            competition_category: CompetitionCategory::from_string(category).ok_or_else(|| {
+                PyValueError::new_err(format!("Unknown competition category: {}", category))
+            })?,
+            place,
+            round: parse_round(round)?,
+            size_of_final,
+            qualified_to_final,
+            tied_with: 0,
+            main_event: false,
+        }),
+        _ => None,
+    };
+    let event = parse_event(event)?;
+    let input = WorldAthleticsScoreInput {
+        gender: parse_gender(gender)?,
+        performance: Performance::for_event(&event, performance),
+        event,
+        wind_speed,
+        net_downhill,
+        venue_altitude: None,
+        placement_info,
+        table_edition: None,
+    };
+    let output =
+        calculate_world_athletics_score(input, calculate_result_score, calculate_placement_score)
+            .map_err(PyValueError::new_err)?;
+
+    let result = pyo3::types::PyDict::new(py);
+    result.set_item("points", output.points)?;
+    result.set_item("beyond_table_range", output.beyond_table_range)?;
+    Ok(result.into_any().unbind())
+}
+
+/// The performance (seconds or meters) required to score `points` in an event.
+#[pyfunction]
+fn performance_for_score(gender: &str, event: &str, points: f64) -> PyResult<f64> {
+    coefficients::performance_for_score(points, parse_gender(gender)?, &parse_event(event)?)
+        .map_err(PyValueError::new_err)
+}
+
+/// Generate the points table for an event as a list of `(points, performance)` tuples.
+#[pyfunction]
+#[pyo3(signature = (gender, event, step=1))]
+fn score_table(gender: &str, event: &str, step: usize) -> PyResult<Vec<(u32, f64)>> {
+    let rows = coefficients::score_table(parse_gender(gender)?, &parse_event(event)?, step)
+        .map_err(PyValueError::new_err)?;
+    Ok(rows
+        .into_iter()
+        .map(|row| (row.points, row.performance))
+        .collect())
+}
+
+/// Parse a time string (ss.mmm, mm:ss.mmm or hh:mm:ss.mmm) into seconds.
+#[pyfunction]
+fn parse_time(time: &str) -> PyResult<f64> {
+    Event::parse_time_to_seconds(time).map_err(PyValueError::new_err)
+}
+
+/// The names of all supported events, as accepted by the other functions.
+#[pyfunction]
+fn events() -> Vec<String> {
+    Event::all_variants()
+        .iter()
+        .map(|e| e.to_string())
+        .collect()
+}
+
+#[pymodule]
+fn wa_points(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(score, m)?)?;
+    m.add_function(wrap_pyfunction!(performance_for_score, m)?)?;
+    m.add_function(wrap_pyfunction!(score_table, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_time, m)?)?;
+    m.add_function(wrap_pyfunction!(events, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Generates the bundled scoring tables as static Rust arrays from the JSON in `data/`, so
+//! the library neither embeds nor parses the JSON at runtime. The arrays are included by
+//! `scoring_logic::bundled`:
+//!
+//! - `COEFFICIENT_EDITIONS`: every `world_athletics_constants_<edition>.json`, as
+//!   `(edition, [CoefficientRow])`
+//! - `PLACEMENT_SCORES`: `track_and_field_placement_scores.json`, as
+//!   `(table, category, place, points)`
+//! - `ESTIMATED_PLACEMENT_SCORES`: `estimated_placement_scores.json`, in the same form
+use serde_json::{Map, Value};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const COEFFICIENTS_PREFIX: &str = "world_athletics_constants_";
+const PLACEMENT_FILE: &str = "track_and_field_placement_scores.json";
+const ESTIMATED_PLACEMENT_FILE: &str = "estimated_placement_scores.json";
+
+fn read_object(path: &Path) -> Map<String, Value> {
+    println!("cargo:rerun-if-changed={}", path.display());
+    let json = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+    match serde_json::from_str(&json) {
+        Ok(Value::Object(object)) => object,
+        Ok(_) => panic!("{} isn't a JSON object", path.display()),
+        Err(e) => panic!("Failed to parse {}: {}", path.display(), e),
+    }
+}
+
+fn as_object<'a>(value: &'a Value, what: &str) -> &'a Map<String, Value> {
+    value
+        .as_object()
+        .unwrap_or_else(|| panic!("{} isn't a JSON object", what))
+}
+
+/// The coefficient editions in `data/`, oldest first
+fn editions(data: &Path) -> Vec<(String, PathBuf)> {
+    let mut editions: Vec<(String, PathBuf)> = fs::read_dir(data)
+        .expect("Failed to list data/")
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let edition = name
+                .strip_prefix(COEFFICIENTS_PREFIX)?
+                .strip_suffix(".json")?
+                .to_string();
+            Some((edition, path))
+        })
+        .collect();
+    editions.sort();
+    editions
+}
+
+fn write_coefficients(out: &mut String, data: &Path) {
+    out.push_str("pub static COEFFICIENT_EDITIONS: &[(&str, &[CoefficientRow])] = &[\n");
+    for (edition, path) in editions(data) {
+        let tables = read_object(&path);
+        writeln!(out, "    ({:?}, &[", edition).unwrap();
+        for (gender, events) in &tables {
+            for (event, coefficients) in as_object(events, gender) {
+                let values: Vec<f64> = coefficients
+                    .as_array()
+                    .and_then(|values| values.iter().map(Value::as_f64).collect())
+                    .filter(|values: &Vec<f64>| values.len() == 3)
+                    .unwrap_or_else(|| panic!("{} {} needs three coefficients", gender, event));
+                writeln!(
+                    out,
+                    "        ({:?}, {:?}, [{:?}, {:?}, {:?}]),",
+                    gender, event, values[0], values[1], values[2]
+                )
+                .unwrap();
+            }
+        }
+        out.push_str("    ]),\n");
+    }
+    out.push_str("];\n");
+}
+
+/// Writes a placing points file as the `name` array. A top-level "note" is skipped.
+fn write_placement(out: &mut String, path: &Path, name: &str) {
+    let tables = read_object(path);
+    writeln!(out, "pub static {}: &[(&str, &str, i32, i32)] = &[", name).unwrap();
+    for (table, categories) in tables.iter().filter(|(table, _)| *table != "note") {
+        for (category, places) in as_object(categories, table) {
+            for (place, points) in as_object(places, category) {
+                let place: i32 = place
+                    .parse()
+                    .unwrap_or_else(|_| panic!("{} {}: invalid place {}", table, category, place));
+                let points = points
+                    .as_i64()
+                    .unwrap_or_else(|| panic!("{} {} {}: invalid points", table, category, place));
+                writeln!(
+                    out,
+                    "    ({:?}, {:?}, {}, {}),",
+                    table, category, place, points
+                )
+                .unwrap();
+            }
+        }
+    }
+    out.push_str("];\n");
+}
+
+fn main() {
+    let data = Path::new("data");
+    println!("cargo:rerun-if-changed=data");
+    let mut out = String::from("// Generated by build.rs from data/. Do not edit.\n\n");
+    write_coefficients(&mut out, data);
+    out.push('\n');
+    write_placement(&mut out, &data.join(PLACEMENT_FILE), "PLACEMENT_SCORES");
+    out.push('\n');
+    write_placement(
+        &mut out,
+        &data.join(ESTIMATED_PLACEMENT_FILE),
+        "ESTIMATED_PLACEMENT_SCORES",
+    );
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    fs::write(out_dir.join("bundled_tables.rs"), out).expect("Failed to write bundled tables");
+}
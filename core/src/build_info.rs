@@ -0,0 +1,120 @@
+//! A build's version, table edition and data checksums, so deployments and embedding pages
+//! can check they are computing against the expected scoring tables.
+//!
+//! The scoring API server answers `/healthz` with this as JSON, and the web app summarises
+//! it in the footer.
+use crate::scoring_logic::coefficients::TABLE_VERSION;
+use serde::Serialize;
+
+/// 64-bit FNV-1a hash, computed at compile time for the bundled data files
+pub const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
+    }
+    hash
+}
+
+/// The bundled data files and their checksums
+const DATA_FILES: &[(&str, u64)] = &[
+    (
+        "world_athletics_constants_2025.json",
+        fnv1a(include_bytes!(
+            "../data/world_athletics_constants_2025.json"
+        )),
+    ),
+    (
+        "track_and_field_placement_scores.json",
+        fnv1a(include_bytes!(
+            "../data/track_and_field_placement_scores.json"
+        )),
+    ),
+    (
+        "estimated_coefficients.json",
+        fnv1a(include_bytes!("../data/estimated_coefficients.json")),
+    ),
+    (
+        "estimated_placement_scores.json",
+        fnv1a(include_bytes!("../data/estimated_placement_scores.json")),
+    ),
+    (
+        "combined_events_coefficients.json",
+        fnv1a(include_bytes!("../data/combined_events_coefficients.json")),
+    ),
+    (
+        "season_lists.json",
+        fnv1a(include_bytes!("../data/season_lists.json")),
+    ),
+    (
+        "ranking_calendar.json",
+        fnv1a(include_bytes!("../data/ranking_calendar.json")),
+    ),
+    (
+        "entry_standards.json",
+        fnv1a(include_bytes!("../data/entry_standards.json")),
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DataChecksum {
+    pub file: &'static str,
+    /// FNV-1a 64-bit hash of the file, as 16 hex digits
+    pub fnv1a: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BuildInfo {
+    pub status: &'static str,
+    pub version: &'static str,
+    pub table_edition: &'static str,
+    pub data: Vec<DataChecksum>,
+}
+
+impl BuildInfo {
+    /// A short checksum over every data file, for display
+    pub fn data_fingerprint(&self) -> String {
+        let combined: String = self.data.iter().map(|d| d.fnv1a.as_str()).collect();
+        format!("{:016x}", fnv1a(combined.as_bytes()))[..8].to_string()
+    }
+}
+
+/// The build info for a program at `version`, e.g. its `CARGO_PKG_VERSION`
+pub fn build_info(version: &'static str) -> BuildInfo {
+    BuildInfo {
+        status: "ok",
+        version,
+        table_edition: TABLE_VERSION,
+        data: DATA_FILES
+            .iter()
+            .map(|(file, hash)| DataChecksum {
+                file,
+                fnv1a: format!("{:016x}", hash),
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a() {
+        // Reference values for FNV-1a 64
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn test_build_info() {
+        let info = build_info("1.2.3");
+        assert_eq!(info.version, "1.2.3");
+        assert_eq!(info.table_edition, TABLE_VERSION);
+        assert_eq!(info.data.len(), DATA_FILES.len());
+        assert!(info.data.iter().all(|d| d.fnv1a.len() == 16));
+        assert_eq!(info.data_fingerprint().len(), 8);
+    }
+}
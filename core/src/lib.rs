@@ -0,0 +1,7 @@
+//! The World Athletics scoring math on its own: the events and score inputs (`models`) and
+//! the scoring tables, calculator and related tools (`scoring_logic`), without the web app.
+//! The app, the C, JavaScript and Python bindings, and anything else that scores
+//! performances build on this crate.
+pub mod build_info;
+pub mod models;
+pub mod scoring_logic;
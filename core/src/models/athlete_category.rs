@@ -0,0 +1,307 @@
+use super::performance::{
+    CombinedEvent, Event, Gender, RaceWalkingEvent, RoadRunningEvent, TrackAndFieldEvent,
+};
+
+/// The first age of each masters age group. The last group includes everyone older.
+pub const MASTERS_AGE_GROUPS: [u32; 10] = [35, 40, 45, 50, 55, 60, 65, 70, 75, 80];
+
+/// The category an athlete competes in. Scoring always uses the senior tables; the category
+/// narrows the events to those contested in it and is noted alongside the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AthleteCategory {
+    /// Under 18 (youth)
+    U18,
+    /// Under 20 (junior)
+    U20,
+    #[default]
+    Senior,
+    /// A masters age group, by its first age (e.g. 45 for M45/W45)
+    Masters(u32),
+}
+
+impl AthleteCategory {
+    pub fn all() -> Vec<AthleteCategory> {
+        [
+            AthleteCategory::U18,
+            AthleteCategory::U20,
+            AthleteCategory::Senior,
+        ]
+        .into_iter()
+        .chain(MASTERS_AGE_GROUPS.into_iter().map(AthleteCategory::Masters))
+        .collect()
+    }
+
+    /// The category for an athlete's age: U18 and U20 for the under 18s and 20s, the masters
+    /// age group from 35, senior in between
+    pub fn from_age(age: u32) -> AthleteCategory {
+        if age < 18 {
+            return AthleteCategory::U18;
+        }
+        if age < 20 {
+            return AthleteCategory::U20;
+        }
+        MASTERS_AGE_GROUPS
+            .into_iter()
+            .rev()
+            .find(|&group| age >= group)
+            .map(AthleteCategory::Masters)
+            .unwrap_or(AthleteCategory::Senior)
+    }
+
+    /// The category's name, e.g. "U20", "Senior", "M45" or "W80+"
+    pub fn label(&self, gender: Gender) -> String {
+        match self {
+            AthleteCategory::U18 => "U18".to_string(),
+            AthleteCategory::U20 => "U20".to_string(),
+            AthleteCategory::Senior => "Senior".to_string(),
+            AthleteCategory::Masters(age) => {
+                let letter = match gender {
+                    Gender::Men => "M",
+                    Gender::Women => "W",
+                };
+                let open_ended = MASTERS_AGE_GROUPS.last() == Some(age);
+                format!("{}{}{}", letter, age, if open_ended { "+" } else { "" })
+            }
+        }
+    }
+
+    /// A short name for share links and form values
+    pub fn code(&self) -> String {
+        match self {
+            AthleteCategory::U18 => "u18".to_string(),
+            AthleteCategory::U20 => "u20".to_string(),
+            AthleteCategory::Senior => "senior".to_string(),
+            AthleteCategory::Masters(age) => age.to_string(),
+        }
+    }
+
+    pub fn from_code(s: &str) -> Option<AthleteCategory> {
+        AthleteCategory::all()
+            .into_iter()
+            .find(|category| category.code() == s)
+    }
+
+    /// Whether an event is contested in this category. Masters events follow the World
+    /// Masters Athletics championship programmes, including the age limits on the senior
+    /// hurdle and steeplechase events (older age groups race lower or shorter variants).
+    /// U20 and U18 athletes don't race the longest road and walk distances, and U18s run
+    /// the 2000m steeplechase rather than the 3000m.
+    pub fn contests(&self, gender: Gender, event: &Event) -> bool {
+        use TrackAndFieldEvent as T;
+        let age = match self {
+            AthleteCategory::Senior => return true,
+            AthleteCategory::U18 | AthleteCategory::U20 => {
+                let u18 = *self == AthleteCategory::U18;
+                return match event {
+                    Event::TrackAndField(T::M3000mSC | T::M10000) => !u18,
+                    Event::TrackAndField(T::M2000mSC) => u18,
+                    Event::RoadRunning(event) => match event {
+                        RoadRunningEvent::Road5km
+                        | RoadRunningEvent::Road10km
+                        | RoadRunningEvent::RoadMile => true,
+                        RoadRunningEvent::Road15km
+                        | RoadRunningEvent::Road10Miles
+                        | RoadRunningEvent::Road20km
+                        | RoadRunningEvent::RoadHM => !u18,
+                        _ => false,
+                    },
+                    Event::RaceWalking(event) => match event {
+                        RaceWalkingEvent::M3000mW
+                        | RaceWalkingEvent::M5000mW
+                        | RaceWalkingEvent::M10000mW
+                        | RaceWalkingEvent::Road5kmW
+                        | RaceWalkingEvent::Road10kmW => true,
+                        RaceWalkingEvent::Road15kmW
+                        | RaceWalkingEvent::Road20kmW
+                        | RaceWalkingEvent::M15000mW
+                        | RaceWalkingEvent::M20000mW => !u18,
+                        _ => false,
+                    },
+                    _ => true,
+                };
+            }
+            AthleteCategory::Masters(age) => *age,
+        };
+        match event {
+            Event::TrackAndField(event) => match event {
+                T::M60
+                | T::M100
+                | T::M200
+                | T::M400
+                | T::M800
+                | T::M1500
+                | T::M3000
+                | T::M5000
+                | T::M10000
+                | T::M60H
+                | T::M4x100m
+                | T::M4x400m
+                | T::M200mSh
+                | T::M400mSh
+                | T::M800mSh
+                | T::M1500mSh
+                | T::M3000mSh
+                | T::M4x200mSh
+                | T::LJ
+                | T::TJ
+                | T::HJ
+                | T::PV
+                | T::SP
+                | T::DT
+                | T::HT
+                | T::JT => true,
+                T::M110H => gender == Gender::Men && age < 50,
+                T::M100H => gender == Gender::Women && age < 40,
+                T::M400H => match gender {
+                    Gender::Men => age < 60,
+                    Gender::Women => age < 50,
+                },
+                T::M300H => match gender {
+                    Gender::Men => age >= 60,
+                    Gender::Women => age >= 50,
+                },
+                T::M3000mSC => gender == Gender::Men && age < 60,
+                T::M2000mSC => gender == Gender::Women || age >= 60,
+                _ => false,
+            },
+            Event::CombinedEvents(event) => match event {
+                CombinedEvent::Dec => gender == Gender::Men,
+                CombinedEvent::Hept => gender == Gender::Women,
+                CombinedEvent::PentSh => true,
+                CombinedEvent::HeptSh => false,
+            },
+            Event::RoadRunning(event) => matches!(
+                event,
+                RoadRunningEvent::Road10km
+                    | RoadRunningEvent::RoadHM
+                    | RoadRunningEvent::RoadMarathon
+            ),
+            Event::RaceWalking(event) => matches!(
+                event,
+                RaceWalkingEvent::M3000mW
+                    | RaceWalkingEvent::M5000mW
+                    | RaceWalkingEvent::Road10kmW
+                    | RaceWalkingEvent::Road20kmW
+            ),
+            Event::CrossCountry(_) => true,
+        }
+    }
+
+    /// The implements or hurdle heights a score assumes for an event, where the category's
+    /// differ from the senior ones
+    pub fn specification(&self, gender: Gender, event: &Event) -> Option<&'static str> {
+        use TrackAndFieldEvent as T;
+        let (u18, men) = match self {
+            AthleteCategory::U18 => (true, gender == Gender::Men),
+            AthleteCategory::U20 => (false, gender == Gender::Men),
+            _ => return None,
+        };
+        let spec = match (event, men, u18) {
+            (Event::TrackAndField(T::M110H), true, false) => "110m hurdles at 0.991m",
+            (Event::TrackAndField(T::M110H), true, true) => "110m hurdles at 0.914m",
+            (Event::TrackAndField(T::M100H), false, true) => "100m hurdles at 0.762m",
+            (Event::TrackAndField(T::M400H), true, true) => "400m hurdles at 0.838m",
+            (Event::TrackAndField(T::M300H), true, true) => "300m hurdles at 0.838m",
+            (Event::TrackAndField(T::M2000mSC), true, true) => "barriers at 0.914m",
+            (Event::TrackAndField(T::SP), true, false) => "a 6kg shot",
+            (Event::TrackAndField(T::SP), true, true) => "a 5kg shot",
+            (Event::TrackAndField(T::SP), false, true) => "a 3kg shot",
+            (Event::TrackAndField(T::DT), true, false) => "a 1.75kg discus",
+            (Event::TrackAndField(T::DT), true, true) => "a 1.5kg discus",
+            (Event::TrackAndField(T::HT), true, false) => "a 6kg hammer",
+            (Event::TrackAndField(T::HT), true, true) => "a 5kg hammer",
+            (Event::TrackAndField(T::HT), false, true) => "a 3kg hammer",
+            (Event::TrackAndField(T::JT), true, true) => "a 700g javelin",
+            (Event::TrackAndField(T::JT), false, true) => "a 500g javelin",
+            (Event::CombinedEvents(_), _, _) if men || u18 => {
+                "the category's implements and hurdle heights"
+            }
+            _ => return None,
+        };
+        Some(spec)
+    }
+
+    /// The note shown with a score in this category, `None` for seniors
+    pub fn note(&self, gender: Gender, event: &Event) -> Option<String> {
+        if *self == AthleteCategory::Senior {
+            return None;
+        }
+        let kind = match self {
+            AthleteCategory::Masters(_) => "age group",
+            _ => "category",
+        };
+        let mut note = format!(
+            "{} {}. Points use the senior scoring tables.",
+            self.label(gender),
+            kind
+        );
+        if let Some(spec) = self.specification(gender, event) {
+            note.push_str(&format!(" Assumes {}.", spec));
+        }
+        Some(note)
+    }
+
+    /// The events a gender contests in this category, in the usual order
+    pub fn events(&self, gender: Gender) -> Vec<Event> {
+        Event::all_variants()
+            .into_iter()
+            .filter(|event| event.is_available_for(gender, None) && self.contests(gender, event))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_masters_categories() {
+        assert_eq!(AthleteCategory::from_age(16), AthleteCategory::U18);
+        assert_eq!(AthleteCategory::from_age(19), AthleteCategory::U20);
+        assert_eq!(AthleteCategory::from_age(34), AthleteCategory::Senior);
+        assert_eq!(AthleteCategory::from_age(47), AthleteCategory::Masters(45));
+        assert_eq!(AthleteCategory::from_age(91), AthleteCategory::Masters(80));
+        assert_eq!(AthleteCategory::Masters(45).label(Gender::Men), "M45");
+        assert_eq!(AthleteCategory::Masters(80).label(Gender::Women), "W80+");
+        for category in AthleteCategory::all() {
+            assert_eq!(AthleteCategory::from_code(&category.code()), Some(category));
+        }
+
+        let senior = AthleteCategory::Senior;
+        let men = senior.events(Gender::Men);
+        assert!(men.contains(&Event::CombinedEvents(CombinedEvent::Dec)));
+        assert!(!men.contains(&Event::CombinedEvents(CombinedEvent::Hept)));
+        let hurdles = Event::TrackAndField(TrackAndFieldEvent::M110H);
+        assert!(AthleteCategory::Masters(45).contests(Gender::Men, &hurdles));
+        assert!(!AthleteCategory::Masters(50).contests(Gender::Men, &hurdles));
+        let steeple = Event::TrackAndField(TrackAndFieldEvent::M2000mSC);
+        assert!(AthleteCategory::Masters(60).contests(Gender::Men, &steeple));
+        assert!(!AthleteCategory::Masters(55).contests(Gender::Men, &steeple));
+        let mile = Event::RoadRunning(RoadRunningEvent::RoadMile);
+        assert!(!AthleteCategory::Masters(35).contests(Gender::Women, &mile));
+    }
+
+    #[test]
+    fn test_junior_categories() {
+        let steeple = Event::TrackAndField(TrackAndFieldEvent::M3000mSC);
+        assert!(AthleteCategory::U20.contests(Gender::Men, &steeple));
+        assert!(!AthleteCategory::U18.contests(Gender::Men, &steeple));
+        let marathon = Event::RoadRunning(RoadRunningEvent::RoadMarathon);
+        assert!(!AthleteCategory::U20.contests(Gender::Women, &marathon));
+
+        let shot = Event::TrackAndField(TrackAndFieldEvent::SP);
+        assert_eq!(
+            AthleteCategory::U20.specification(Gender::Men, &shot),
+            Some("a 6kg shot")
+        );
+        // U20 women throw the senior implements
+        assert_eq!(
+            AthleteCategory::U20.specification(Gender::Women, &shot),
+            None
+        );
+        let note = AthleteCategory::U18.note(Gender::Women, &shot).unwrap();
+        assert!(note.starts_with("U18 category"));
+        assert!(note.contains("3kg"));
+        assert_eq!(AthleteCategory::Senior.note(Gender::Men, &shot), None);
+    }
+}
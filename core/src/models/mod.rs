@@ -0,0 +1,6 @@
+pub mod athlete_category;
+pub mod performance;
+pub mod units;
+pub use athlete_category::*;
+pub use performance::*;
+pub use units::*;
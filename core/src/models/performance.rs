@@ -0,0 +1,1457 @@
+use crate::scoring_logic::placement_score::{PlacementScoreEventGroup, RoundType};
+use crate::scoring_logic::track_size::is_short_track_event;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+// src/models/performance.rs
+/// Represents events typically categorized under Track & Field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Default)]
+pub enum TrackAndFieldEvent {
+    // Sprints/Middle Distance/Long Distance
+    M50,
+    M55,
+    M60,
+    #[default]
+    M100,
+    M200,
+    M300,
+    M400,
+    M500,
+    M600,
+    M800,
+    M1000,
+    M1500,
+    M2000,
+    M3000,
+    M5000,
+    M10000,
+    // Hurdles
+    M50H,
+    M55H,
+    M60H,
+    M100H,
+    M110H,
+    M300H,
+    M400H,
+    // Steeplechase
+    M2000mSC,
+    M3000mSC,
+    // Relays
+    M4x100m,
+    M4x200m,
+    M4x400m,
+    M4x400mix,
+    // Field Events
+    LJ,
+    TJ,
+    HJ,
+    PV,
+    SP,
+    DT,
+    HT,
+    JT,
+    // Indoor/Short Track specific events (often denoted by 'sh' in JSON)
+    // M50mSh,
+    // M55mSh,
+    // M60mSh,
+    M200mSh,
+    M300mSh,
+    M400mSh,
+    M500mSh,
+    M600mSh,
+    M800mSh,
+    M1000mSh,
+    M1500mSh,
+    M2000mSh,
+    M3000mSh,
+    M5000mSh,
+    MileSh,
+    M2MilesSh, // Mile and 2 Miles on short track
+    M4x100mSh,
+    M4x200mSh,
+    M4x400mSh,
+    M4x400mixSh,
+}
+
+/// Represents Combined Events.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Default)]
+pub enum CombinedEvent {
+    #[default]
+    Dec, // Decathlon
+    Hept,   // Heptathlon
+    HeptSh, // Heptathlon (short track/indoor component)
+    PentSh, // Pentathlon (short track/indoor component)
+}
+
+/// Represents Road Running Events.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Default)]
+pub enum RoadRunningEvent {
+    Road5km,
+    Road10km,
+    Road15km,
+    Road20km,
+    Road25km,
+    Road30km,
+    RoadHM,
+    #[default]
+    RoadMarathon,
+    Road10Miles,
+    RoadMile,
+}
+
+/// Represents Race Walking Events.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Default)]
+pub enum RaceWalkingEvent {
+    Road5kmW,
+    Road10kmW,
+    Road15kmW,
+    Road20kmW,
+    Road30kmW,
+    #[default]
+    Road35kmW,
+    Road50kmW,
+    M3000mW,
+    M5000mW,
+    M10000mW,
+    M15000mW,
+    M20000mW,
+    M30000mW,
+    M35000mW,
+    M50000mW, // Track walks
+}
+
+/// Represents Cross Country Events. Cross country has no scoring tables: results are scored
+/// on placing alone, with the `cross_country_finals` placing points.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EnumIter, Default)]
+pub enum CrossCountryEvent {
+    /// Short course races of around 4km
+    XcShortCourse,
+    Xc8km,
+    Xc10km,
+    /// The senior race at a cross country championship
+    #[default]
+    XcChampionship,
+}
+
+/// A combined enum for all supported events, categorized by World Athletics sections.
+/// This will be used in the `WorldAthleticsScoreInput` to specify the event.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    TrackAndField(TrackAndFieldEvent),
+    CombinedEvents(CombinedEvent),
+    RoadRunning(RoadRunningEvent),
+    RaceWalking(RaceWalkingEvent),
+    CrossCountry(CrossCountryEvent),
+}
+
+/// Broad event groups for ranking athletes across similar events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+pub enum EventGroup {
+    Track,
+    Field,
+    CombinedEvents,
+    RoadRunning,
+    RaceWalking,
+    CrossCountry,
+}
+
+impl fmt::Display for EventGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventGroup::Track => write!(f, "Track"),
+            EventGroup::Field => write!(f, "Field"),
+            EventGroup::CombinedEvents => write!(f, "Combined Events"),
+            EventGroup::RoadRunning => write!(f, "Road Running"),
+            EventGroup::RaceWalking => write!(f, "Race Walking"),
+            EventGroup::CrossCountry => write!(f, "Cross Country"),
+        }
+    }
+}
+
+/// Finer event categories for grouping the event list, e.g. in the event select
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+pub enum EventCategory {
+    Sprints,
+    MiddleDistance,
+    Hurdles,
+    Jumps,
+    Throws,
+    Relays,
+    Road,
+    RaceWalks,
+    ShortTrack,
+    Combined,
+    CrossCountry,
+}
+
+impl fmt::Display for EventCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventCategory::Sprints => write!(f, "Sprints"),
+            EventCategory::MiddleDistance => write!(f, "Middle & Long Distance"),
+            EventCategory::Hurdles => write!(f, "Hurdles & Steeplechase"),
+            EventCategory::Jumps => write!(f, "Jumps"),
+            EventCategory::Throws => write!(f, "Throws"),
+            EventCategory::Relays => write!(f, "Relays"),
+            EventCategory::Road => write!(f, "Road"),
+            EventCategory::RaceWalks => write!(f, "Race Walks"),
+            EventCategory::ShortTrack => write!(f, "Short Track"),
+            EventCategory::Combined => write!(f, "Combined Events"),
+            EventCategory::CrossCountry => write!(f, "Cross Country"),
+        }
+    }
+}
+
+/// Where an event is contested: outdoors, or on a short (indoor) track
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Venue {
+    Outdoor,
+    ShortTrack,
+}
+
+impl fmt::Display for Venue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Venue::Outdoor => write!(f, "Outdoor"),
+            Venue::ShortTrack => write!(f, "Short Track"),
+        }
+    }
+}
+
+impl Default for Event {
+    fn default() -> Self {
+        Event::TrackAndField(TrackAndFieldEvent::M100)
+    }
+}
+
+impl Event {
+    pub fn all_variants() -> Vec<Event> {
+        let mut events = Vec::new();
+        for track_and_field_event in TrackAndFieldEvent::iter() {
+            events.push(Event::TrackAndField(track_and_field_event));
+        }
+        for combined_event in CombinedEvent::iter() {
+            events.push(Event::CombinedEvents(combined_event));
+        }
+        for road_running_event in RoadRunningEvent::iter() {
+            events.push(Event::RoadRunning(road_running_event));
+        }
+        for race_walking_event in RaceWalkingEvent::iter() {
+            events.push(Event::RaceWalking(race_walking_event));
+        }
+        for cross_country_event in CrossCountryEvent::iter() {
+            events.push(Event::CrossCountry(cross_country_event));
+        }
+        events
+    }
+
+    // Convert from string back to enum (for form handling)
+    pub fn from_string(s: &str) -> Option<Event> {
+        event_lookup().display_names.get(s).cloned()
+    }
+
+    /// A short, stable code for the event, e.g. "100m", "LJ" or "HM". Codes don't change when
+    /// display names do, so they suit URLs, command lines and files.
+    pub fn code(&self) -> &'static str {
+        use TrackAndFieldEvent as T;
+        match self {
+            Event::TrackAndField(e) => match e {
+                T::M50 => "50m",
+                T::M55 => "55m",
+                T::M60 => "60m",
+                T::M100 => "100m",
+                T::M200 => "200m",
+                T::M300 => "300m",
+                T::M400 => "400m",
+                T::M500 => "500m",
+                T::M600 => "600m",
+                T::M800 => "800m",
+                T::M1000 => "1000m",
+                T::M1500 => "1500m",
+                T::M2000 => "2000m",
+                T::M3000 => "3000m",
+                T::M5000 => "5000m",
+                T::M10000 => "10000m",
+                T::M50H => "50mH",
+                T::M55H => "55mH",
+                T::M60H => "60mH",
+                T::M100H => "100mH",
+                T::M110H => "110mH",
+                T::M300H => "300mH",
+                T::M400H => "400mH",
+                T::M2000mSC => "2000mSC",
+                T::M3000mSC => "3000mSC",
+                T::M4x100m => "4x100m",
+                T::M4x200m => "4x200m",
+                T::M4x400m => "4x400m",
+                T::M4x400mix => "4x400mX",
+                T::LJ => "LJ",
+                T::TJ => "TJ",
+                T::HJ => "HJ",
+                T::PV => "PV",
+                T::SP => "SP",
+                T::DT => "DT",
+                T::HT => "HT",
+                T::JT => "JT",
+                T::M200mSh => "200mSh",
+                T::M300mSh => "300mSh",
+                T::M400mSh => "400mSh",
+                T::M500mSh => "500mSh",
+                T::M600mSh => "600mSh",
+                T::M800mSh => "800mSh",
+                T::M1000mSh => "1000mSh",
+                T::M1500mSh => "1500mSh",
+                T::M2000mSh => "2000mSh",
+                T::M3000mSh => "3000mSh",
+                T::M5000mSh => "5000mSh",
+                T::MileSh => "MileSh",
+                T::M2MilesSh => "2MilesSh",
+                T::M4x100mSh => "4x100mSh",
+                T::M4x200mSh => "4x200mSh",
+                T::M4x400mSh => "4x400mSh",
+                T::M4x400mixSh => "4x400mXSh",
+            },
+            Event::CombinedEvents(e) => match e {
+                CombinedEvent::Dec => "DEC",
+                CombinedEvent::Hept => "HEP",
+                CombinedEvent::HeptSh => "HEPSh",
+                CombinedEvent::PentSh => "PENSh",
+            },
+            Event::RoadRunning(e) => match e {
+                RoadRunningEvent::Road5km => "5km",
+                RoadRunningEvent::Road10km => "10km",
+                RoadRunningEvent::Road15km => "15km",
+                RoadRunningEvent::Road20km => "20km",
+                RoadRunningEvent::Road25km => "25km",
+                RoadRunningEvent::Road30km => "30km",
+                RoadRunningEvent::RoadHM => "HM",
+                RoadRunningEvent::RoadMarathon => "MAR",
+                RoadRunningEvent::Road10Miles => "10Miles",
+                RoadRunningEvent::RoadMile => "RoadMile",
+            },
+            Event::RaceWalking(e) => match e {
+                RaceWalkingEvent::Road5kmW => "5kmW",
+                RaceWalkingEvent::Road10kmW => "10kmW",
+                RaceWalkingEvent::Road15kmW => "15kmW",
+                RaceWalkingEvent::Road20kmW => "20kmW",
+                RaceWalkingEvent::Road30kmW => "30kmW",
+                RaceWalkingEvent::Road35kmW => "35kmW",
+                RaceWalkingEvent::Road50kmW => "50kmW",
+                RaceWalkingEvent::M3000mW => "3000mW",
+                RaceWalkingEvent::M5000mW => "5000mW",
+                RaceWalkingEvent::M10000mW => "10000mW",
+                RaceWalkingEvent::M15000mW => "15000mW",
+                RaceWalkingEvent::M20000mW => "20000mW",
+                RaceWalkingEvent::M30000mW => "30000mW",
+                RaceWalkingEvent::M35000mW => "35000mW",
+                RaceWalkingEvent::M50000mW => "50000mW",
+            },
+            Event::CrossCountry(e) => match e {
+                CrossCountryEvent::XcShortCourse => "XCShort",
+                CrossCountryEvent::Xc8km => "XC8km",
+                CrossCountryEvent::Xc10km => "XC10km",
+                CrossCountryEvent::XcChampionship => "XC",
+            },
+        }
+    }
+
+    /// Whether the event is contested by (and has scoring tables for) a gender, at a venue or
+    /// (for `None`) at any venue. The 110m hurdles, decathlon and short track heptathlon are
+    /// men's events; the 100m hurdles, heptathlon and short track pentathlon are women's.
+    pub fn is_available_for(&self, gender: Gender, venue: Option<Venue>) -> bool {
+        let for_gender = match self {
+            Event::TrackAndField(TrackAndFieldEvent::M110H)
+            | Event::CombinedEvents(CombinedEvent::Dec)
+            | Event::CombinedEvents(CombinedEvent::HeptSh) => gender == Gender::Men,
+            Event::TrackAndField(TrackAndFieldEvent::M100H)
+            | Event::CombinedEvents(CombinedEvent::Hept)
+            | Event::CombinedEvents(CombinedEvent::PentSh) => gender == Gender::Women,
+            _ => true,
+        };
+        for_gender && venue.is_none_or(|venue| self.venues().contains(&venue))
+    }
+
+    /// The venues the event is contested at. Short track events are only run indoors, and the
+    /// sprints, hurdles, jumps, shot put and shorter walks without a short track variant are
+    /// contested at both.
+    pub fn venues(&self) -> Vec<Venue> {
+        use TrackAndFieldEvent as T;
+        match self {
+            _ if is_short_track_event(self) => vec![Venue::ShortTrack],
+            Event::CombinedEvents(CombinedEvent::HeptSh | CombinedEvent::PentSh) => {
+                vec![Venue::ShortTrack]
+            }
+            Event::TrackAndField(
+                T::M50
+                | T::M55
+                | T::M60
+                | T::M50H
+                | T::M55H
+                | T::M60H
+                | T::LJ
+                | T::TJ
+                | T::HJ
+                | T::PV
+                | T::SP,
+            )
+            | Event::RaceWalking(RaceWalkingEvent::M3000mW | RaceWalkingEvent::M5000mW) => {
+                vec![Venue::Outdoor, Venue::ShortTrack]
+            }
+            _ => vec![Venue::Outdoor],
+        }
+    }
+
+    /// The event's category in the grouped event list
+    pub fn category(&self) -> EventCategory {
+        use TrackAndFieldEvent as T;
+        match self {
+            Event::TrackAndField(e) => match e {
+                T::M50 | T::M55 | T::M60 | T::M100 | T::M200 | T::M300 | T::M400 => {
+                    EventCategory::Sprints
+                }
+                T::M500
+                | T::M600
+                | T::M800
+                | T::M1000
+                | T::M1500
+                | T::M2000
+                | T::M3000
+                | T::M5000
+                | T::M10000 => EventCategory::MiddleDistance,
+                T::M50H
+                | T::M55H
+                | T::M60H
+                | T::M100H
+                | T::M110H
+                | T::M300H
+                | T::M400H
+                | T::M2000mSC
+                | T::M3000mSC => EventCategory::Hurdles,
+                T::M4x100m | T::M4x200m | T::M4x400m | T::M4x400mix => EventCategory::Relays,
+                T::LJ | T::TJ | T::HJ | T::PV => EventCategory::Jumps,
+                T::SP | T::DT | T::HT | T::JT => EventCategory::Throws,
+                T::M200mSh
+                | T::M300mSh
+                | T::M400mSh
+                | T::M500mSh
+                | T::M600mSh
+                | T::M800mSh
+                | T::M1000mSh
+                | T::M1500mSh
+                | T::M2000mSh
+                | T::M3000mSh
+                | T::M5000mSh
+                | T::MileSh
+                | T::M2MilesSh
+                | T::M4x100mSh
+                | T::M4x200mSh
+                | T::M4x400mSh
+                | T::M4x400mixSh => EventCategory::ShortTrack,
+            },
+            Event::CombinedEvents(_) => EventCategory::Combined,
+            Event::RoadRunning(_) => EventCategory::Road,
+            Event::RaceWalking(_) => EventCategory::RaceWalks,
+            Event::CrossCountry(_) => EventCategory::CrossCountry,
+        }
+    }
+
+    pub fn event_group(&self) -> EventGroup {
+        match self {
+            Event::TrackAndField(_) => match self.performance_type() {
+                PerformanceType::Time => EventGroup::Track,
+                PerformanceType::Distance | PerformanceType::Points => EventGroup::Field,
+            },
+            Event::CombinedEvents(_) => EventGroup::CombinedEvents,
+            Event::RoadRunning(_) => EventGroup::RoadRunning,
+            Event::RaceWalking(_) => EventGroup::RaceWalking,
+            Event::CrossCountry(_) => EventGroup::CrossCountry,
+        }
+    }
+
+    /// Determines whether this event is measured by time or distance
+    pub fn performance_type(&self) -> PerformanceType {
+        match self {
+            // Field events are measured in meters/distance
+            Event::TrackAndField(TrackAndFieldEvent::LJ)
+            | Event::TrackAndField(TrackAndFieldEvent::TJ)
+            | Event::TrackAndField(TrackAndFieldEvent::HJ)
+            | Event::TrackAndField(TrackAndFieldEvent::PV)
+            | Event::TrackAndField(TrackAndFieldEvent::SP)
+            | Event::TrackAndField(TrackAndFieldEvent::DT)
+            | Event::TrackAndField(TrackAndFieldEvent::HT)
+            | Event::TrackAndField(TrackAndFieldEvent::JT) => PerformanceType::Distance,
+
+            // Combined events are scored on their points total
+            Event::CombinedEvents(_) => PerformanceType::Points,
+
+            // All other events are time-based
+            _ => PerformanceType::Time,
+        }
+    }
+
+    /// The race distance in meters for running and walking events, or `None` for field events,
+    /// combined events and cross country (where course lengths vary).
+    pub fn distance_meters(&self) -> Option<f64> {
+        use TrackAndFieldEvent as T;
+        let meters = match self {
+            Event::TrackAndField(e) => match e {
+                T::M50 | T::M50H => 50.0,
+                T::M55 | T::M55H => 55.0,
+                T::M60 | T::M60H => 60.0,
+                T::M100 | T::M100H => 100.0,
+                T::M110H => 110.0,
+                T::M200 | T::M200mSh => 200.0,
+                T::M300 | T::M300H | T::M300mSh => 300.0,
+                T::M400 | T::M400H | T::M400mSh | T::M4x100m | T::M4x100mSh => 400.0,
+                T::M500 | T::M500mSh => 500.0,
+                T::M600 | T::M600mSh => 600.0,
+                T::M800 | T::M800mSh | T::M4x200m | T::M4x200mSh => 800.0,
+                T::M1000 | T::M1000mSh => 1000.0,
+                T::M1500 | T::M1500mSh => 1500.0,
+                T::M4x400m | T::M4x400mix | T::M4x400mSh | T::M4x400mixSh => 1600.0,
+                T::MileSh => 1609.344,
+                T::M2000 | T::M2000mSh | T::M2000mSC => 2000.0,
+                T::M3000 | T::M3000mSh | T::M3000mSC => 3000.0,
+                T::M2MilesSh => 3218.688,
+                T::M5000 | T::M5000mSh => 5000.0,
+                T::M10000 => 10000.0,
+                T::LJ | T::TJ | T::HJ | T::PV | T::SP | T::DT | T::HT | T::JT => return None,
+            },
+            Event::RoadRunning(e) => match e {
+                RoadRunningEvent::Road5km => 5000.0,
+                RoadRunningEvent::Road10km => 10000.0,
+                RoadRunningEvent::Road15km => 15000.0,
+                RoadRunningEvent::Road20km => 20000.0,
+                RoadRunningEvent::Road25km => 25000.0,
+                RoadRunningEvent::Road30km => 30000.0,
+                RoadRunningEvent::RoadHM => 21097.5,
+                RoadRunningEvent::RoadMarathon => 42195.0,
+                RoadRunningEvent::Road10Miles => 16093.44,
+                RoadRunningEvent::RoadMile => 1609.344,
+            },
+            Event::RaceWalking(e) => match e {
+                RaceWalkingEvent::M3000mW => 3000.0,
+                RaceWalkingEvent::Road5kmW | RaceWalkingEvent::M5000mW => 5000.0,
+                RaceWalkingEvent::Road10kmW | RaceWalkingEvent::M10000mW => 10000.0,
+                RaceWalkingEvent::Road15kmW | RaceWalkingEvent::M15000mW => 15000.0,
+                RaceWalkingEvent::Road20kmW | RaceWalkingEvent::M20000mW => 20000.0,
+                RaceWalkingEvent::Road30kmW | RaceWalkingEvent::M30000mW => 30000.0,
+                RaceWalkingEvent::Road35kmW | RaceWalkingEvent::M35000mW => 35000.0,
+                RaceWalkingEvent::Road50kmW | RaceWalkingEvent::M50000mW => 50000.0,
+            },
+            Event::CombinedEvents(_) | Event::CrossCountry(_) => return None,
+        };
+        Some(meters)
+    }
+
+    /// The half marathon, 25km and 30km score placing points on the road running table when
+    /// they're the competition's main event, and on a smaller table when held alongside a
+    /// marathon
+    pub fn placement_depends_on_main_event(&self) -> bool {
+        matches!(
+            self,
+            Event::RoadRunning(
+                RoadRunningEvent::RoadHM | RoadRunningEvent::Road25km | RoadRunningEvent::Road30km
+            )
+        )
+    }
+
+    /// The placing table group, taking the half marathon, 25km and 30km as races held
+    /// alongside a marathon
+    pub fn to_placement_score_event_group(&self) -> PlacementScoreEventGroup {
+        self.to_placement_score_event_group_for(false)
+    }
+
+    /// The placing table group, for a race that was or wasn't the competition's main event
+    pub fn to_placement_score_event_group_for(&self, main_event: bool) -> PlacementScoreEventGroup {
+        match self {
+            _ if main_event && self.placement_depends_on_main_event() => {
+                PlacementScoreEventGroup::RoadRunning
+            }
+            Event::TrackAndField(TrackAndFieldEvent::M5000)
+            | Event::TrackAndField(TrackAndFieldEvent::M3000mSC) => {
+                PlacementScoreEventGroup::Distance5000m3000mSC
+            }
+
+            Event::TrackAndField(TrackAndFieldEvent::M10000) => {
+                PlacementScoreEventGroup::Distance10000m
+            }
+            Event::RoadRunning(RoadRunningEvent::Road10km) => PlacementScoreEventGroup::Road10km,
+            Event::RoadRunning(RoadRunningEvent::RoadMarathon) => {
+                PlacementScoreEventGroup::RoadMarathon
+            }
+            Event::RoadRunning(RoadRunningEvent::RoadHM)
+            | Event::RoadRunning(RoadRunningEvent::Road30km)
+            | Event::RoadRunning(RoadRunningEvent::Road25km) => {
+                PlacementScoreEventGroup::HalfMarathon
+            }
+            Event::RaceWalking(RaceWalkingEvent::M20000mW)
+            | Event::RaceWalking(RaceWalkingEvent::Road20kmW)
+            | Event::RaceWalking(RaceWalkingEvent::Road5kmW)
+            | Event::RaceWalking(RaceWalkingEvent::Road10kmW)
+            | Event::RaceWalking(RaceWalkingEvent::Road15kmW)
+            | Event::RaceWalking(RaceWalkingEvent::M3000mW)
+            | Event::RaceWalking(RaceWalkingEvent::M5000mW)
+            | Event::RaceWalking(RaceWalkingEvent::M10000mW)
+            | Event::RaceWalking(RaceWalkingEvent::M15000mW) => {
+                PlacementScoreEventGroup::RaceWalking20Km
+            }
+            Event::RaceWalking(RaceWalkingEvent::M35000mW)
+            | Event::RaceWalking(RaceWalkingEvent::Road35kmW) => {
+                PlacementScoreEventGroup::RaceWalking35Km
+            }
+            Event::RaceWalking(_) => PlacementScoreEventGroup::RaceWalking35KmSimilar,
+            Event::TrackAndField(_) => PlacementScoreEventGroup::TrackAndField,
+            Event::CombinedEvents(_) => PlacementScoreEventGroup::CombinedEvent,
+            Event::RoadRunning(_) => PlacementScoreEventGroup::RoadRunning,
+            Event::CrossCountry(_) => PlacementScoreEventGroup::CrossCountry,
+        }
+    }
+}
+
+impl fmt::Display for Event {
+    /// Converts the Event enum variant into its string representation
+    /// which matches the keys in your JSON constants table.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Event::TrackAndField(e) => match e {
+                TrackAndFieldEvent::M50 => "50m",
+                TrackAndFieldEvent::M55 => "55m",
+                TrackAndFieldEvent::M60 => "60m",
+                TrackAndFieldEvent::M100 => "100m",
+                TrackAndFieldEvent::M200 => "200m",
+                TrackAndFieldEvent::M300 => "300m",
+                TrackAndFieldEvent::M400 => "400m",
+                TrackAndFieldEvent::M500 => "500m",
+                TrackAndFieldEvent::M600 => "600m",
+                TrackAndFieldEvent::M800 => "800m",
+                TrackAndFieldEvent::M1000 => "1000m",
+                TrackAndFieldEvent::M1500 => "1500m",
+                TrackAndFieldEvent::M2000 => "2000m",
+                TrackAndFieldEvent::M3000 => "3000m",
+                TrackAndFieldEvent::M5000 => "5000m",
+                TrackAndFieldEvent::M10000 => "10000m",
+                TrackAndFieldEvent::M50H => "50m Hurdle",
+                TrackAndFieldEvent::M55H => "55m Hurdle",
+                TrackAndFieldEvent::M60H => "60m Hurdle",
+                TrackAndFieldEvent::M100H => "100m Hurdle", // Women's 100mH
+                TrackAndFieldEvent::M110H => "110m Hurdle", // Men's 110mH
+                TrackAndFieldEvent::M300H => "300m Hurdle",
+                TrackAndFieldEvent::M400H => "400m Hurdle",
+                TrackAndFieldEvent::M2000mSC => "2000m SC",
+                TrackAndFieldEvent::M3000mSC => "3000m SC",
+                TrackAndFieldEvent::M4x100m => "4x100m",
+                TrackAndFieldEvent::M4x200m => "4x200m",
+                TrackAndFieldEvent::M4x400m => "4x400m",
+                TrackAndFieldEvent::M4x400mix => "4x400mix",
+                TrackAndFieldEvent::LJ => "Long Jump",
+                TrackAndFieldEvent::TJ => "Triple Jump",
+                TrackAndFieldEvent::HJ => "High Jump",
+                TrackAndFieldEvent::PV => "Pole Vault",
+                TrackAndFieldEvent::SP => "Shot Put",
+                TrackAndFieldEvent::DT => "Discus Throw",
+                TrackAndFieldEvent::HT => "Hammer Throw",
+                TrackAndFieldEvent::JT => "Javelin Throw",
+                // TrackAndFieldEvent::M50mSh => "50m short track",
+                // TrackAndFieldEvent::M55mSh => "55m short track",
+                // TrackAndFieldEvent::M60mSh => "60m short track",
+                TrackAndFieldEvent::M200mSh => "200m short track",
+                TrackAndFieldEvent::M300mSh => "300m short track",
+                TrackAndFieldEvent::M400mSh => "400m short track",
+                TrackAndFieldEvent::M500mSh => "500m short track",
+                TrackAndFieldEvent::M600mSh => "600m short track",
+                TrackAndFieldEvent::M800mSh => "800m short track",
+                TrackAndFieldEvent::M1000mSh => "1000m short track",
+                TrackAndFieldEvent::M1500mSh => "1500m short track",
+                TrackAndFieldEvent::M2000mSh => "2000m short track",
+                TrackAndFieldEvent::M3000mSh => "3000m short track",
+                TrackAndFieldEvent::M5000mSh => "5000m short track",
+                TrackAndFieldEvent::MileSh => "Mile short track",
+                TrackAndFieldEvent::M2MilesSh => "2 Miles short track",
+                TrackAndFieldEvent::M4x100mSh => "4x100m short track",
+                TrackAndFieldEvent::M4x200mSh => "4x200m short track",
+                TrackAndFieldEvent::M4x400mSh => "4x400m short track",
+                TrackAndFieldEvent::M4x400mixSh => "4x400mix short track",
+            },
+            Event::CombinedEvents(e) => match e {
+                CombinedEvent::Dec => "Dec.",
+                CombinedEvent::HeptSh => "Hept. short track",
+                CombinedEvent::PentSh => "Pent. short track",
+                CombinedEvent::Hept => "Hept.",
+            },
+            Event::RoadRunning(e) => match e {
+                RoadRunningEvent::Road5km => "Road 5 km",
+                RoadRunningEvent::Road10km => "Road 10 km",
+                RoadRunningEvent::Road15km => "Road 15 km",
+                RoadRunningEvent::Road20km => "Road 20 km",
+                RoadRunningEvent::Road25km => "Road 25 km",
+                RoadRunningEvent::Road30km => "Road 30 km",
+                RoadRunningEvent::RoadHM => "Road HM",
+                RoadRunningEvent::RoadMarathon => "Road Marathon",
+                RoadRunningEvent::Road10Miles => "Road 10 Miles",
+                RoadRunningEvent::RoadMile => "Road Mile",
+            },
+            Event::RaceWalking(e) => match e {
+                RaceWalkingEvent::Road5kmW => "Road 5km Walk",
+                RaceWalkingEvent::Road10kmW => "Road 10km Walk",
+                RaceWalkingEvent::Road15kmW => "Road 15km Walk",
+                RaceWalkingEvent::Road20kmW => "Road 20km Walk",
+                RaceWalkingEvent::Road30kmW => "Road 30km Walk",
+                RaceWalkingEvent::Road35kmW => "Road 35km Walk",
+                RaceWalkingEvent::Road50kmW => "Road 50km Walk",
+                RaceWalkingEvent::M3000mW => "3000m Walk",
+                RaceWalkingEvent::M5000mW => "5000m Walk",
+                RaceWalkingEvent::M10000mW => "10,000m Walk",
+                RaceWalkingEvent::M15000mW => "15,000m Walk",
+                RaceWalkingEvent::M20000mW => "20,000m Walk",
+                RaceWalkingEvent::M30000mW => "30,000m Walk",
+                RaceWalkingEvent::M35000mW => "35,000m Walk",
+                RaceWalkingEvent::M50000mW => "50,000m Walk",
+            },
+            Event::CrossCountry(e) => match e {
+                CrossCountryEvent::XcShortCourse => "XC Short Course",
+                CrossCountryEvent::Xc8km => "XC 8km",
+                CrossCountryEvent::Xc10km => "XC 10km",
+                CrossCountryEvent::XcChampionship => "XC Championship",
+            },
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Common names for events beyond their display names and codes
+const EVENT_ALIASES: &[(&str, &str)] = &[
+    ("110m hurdles", "110mH"),
+    ("100m hurdles", "100mH"),
+    ("300m hurdles", "300mH"),
+    ("400m hurdles", "400mH"),
+    ("steeplechase", "3000mSC"),
+    ("3000m steeplechase", "3000mSC"),
+    ("2000m steeplechase", "2000mSC"),
+    ("long jump", "LJ"),
+    ("triple jump", "TJ"),
+    ("high jump", "HJ"),
+    ("pole vault", "PV"),
+    ("shot put", "SP"),
+    ("shot", "SP"),
+    ("discus", "DT"),
+    ("discus throw", "DT"),
+    ("hammer", "HT"),
+    ("hammer throw", "HT"),
+    ("javelin", "JT"),
+    ("javelin throw", "JT"),
+    ("decathlon", "DEC"),
+    ("heptathlon", "HEP"),
+    ("half marathon", "HM"),
+    ("marathon", "MAR"),
+    ("10k", "10km"),
+    ("5k", "5km"),
+];
+
+/// Events by display name, and by normalized display name, code and alias
+struct EventLookup {
+    display_names: HashMap<String, Event>,
+    names: HashMap<String, Event>,
+}
+
+/// Lowercase with spaces removed, so "Long Jump", "long jump" and "LongJump" match
+fn normalize_event_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn event_lookup() -> &'static EventLookup {
+    static EVENT_LOOKUP: OnceLock<EventLookup> = OnceLock::new();
+    EVENT_LOOKUP.get_or_init(|| {
+        let mut display_names = HashMap::new();
+        let mut names = HashMap::new();
+        for event in Event::all_variants() {
+            display_names.insert(event.to_string(), event.clone());
+            names.insert(normalize_event_name(&event.to_string()), event.clone());
+            names.insert(normalize_event_name(event.code()), event);
+        }
+        for (alias, code) in EVENT_ALIASES {
+            if let Some(event) = names.get(&normalize_event_name(code)).cloned() {
+                names.insert(normalize_event_name(alias), event);
+            }
+        }
+        EventLookup {
+            display_names,
+            names,
+        }
+    })
+}
+
+/// Events serialize as their stable code (e.g. "LJ"), and deserialize from anything `FromStr`
+/// accepts
+impl Serialize for Event {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for Event {
+    type Err = String;
+
+    /// Parses a display name ("Long Jump"), code ("LJ") or common alias ("long jump"),
+    /// ignoring case and spaces
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        event_lookup()
+            .names
+            .get(&normalize_event_name(s))
+            .cloned()
+            .ok_or_else(|| format!("Unknown event: {}", s.trim()))
+    }
+}
+
+/// Enum to represent the type of performance measurement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceType {
+    /// Time-based events (running, hurdles, etc.) measured in seconds
+    Time,
+    /// Distance/height-based field events measured in meters
+    Distance,
+    /// Combined events, measured by their points total
+    Points,
+}
+
+/// A mark in the unit its event is measured in
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Performance {
+    Seconds(f64),
+    Meters(f64),
+    Points(u32),
+}
+
+impl Performance {
+    /// A mark of `value` in the unit `event` is measured in. Points totals are rounded.
+    pub fn for_event(event: &Event, value: f64) -> Performance {
+        match event.performance_type() {
+            PerformanceType::Time => Performance::Seconds(value),
+            PerformanceType::Distance => Performance::Meters(value),
+            PerformanceType::Points => Performance::Points(value.round().max(0.0) as u32),
+        }
+    }
+
+    /// Parses a written mark for an event: a time, a distance in meters or feet and inches,
+    /// or a points total
+    pub fn parse(event: &Event, mark: &str) -> Result<Performance, String> {
+        match event.performance_type() {
+            PerformanceType::Time => Event::parse_time_to_seconds(mark).map(Performance::Seconds),
+            PerformanceType::Distance => {
+                super::units::parse_distance_mark(mark).map(Performance::Meters)
+            }
+            PerformanceType::Points => mark
+                .trim()
+                .parse::<u32>()
+                .map(Performance::Points)
+                .map_err(|_| format!("Invalid points total: {}", mark.trim())),
+        }
+    }
+
+    pub fn performance_type(&self) -> PerformanceType {
+        match self {
+            Performance::Seconds(_) => PerformanceType::Time,
+            Performance::Meters(_) => PerformanceType::Distance,
+            Performance::Points(_) => PerformanceType::Points,
+        }
+    }
+
+    /// The mark as a number of seconds, meters or points
+    pub fn value(&self) -> f64 {
+        match self {
+            Performance::Seconds(value) | Performance::Meters(value) => *value,
+            Performance::Points(points) => *points as f64,
+        }
+    }
+
+    /// The mark's value for scoring `event`, or an error if the event is measured in
+    /// another unit (e.g. a distance entered for a timed event)
+    pub fn value_for(&self, event: &Event) -> Result<f64, String> {
+        if self.performance_type() != event.performance_type() {
+            return Err(format!("{} is not a valid mark for {}", self, event));
+        }
+        let value = self.value();
+        if !value.is_finite() || value < 0.0 {
+            return Err(format!("Invalid mark: {}", value));
+        }
+        Ok(value)
+    }
+}
+
+impl fmt::Display for Performance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Performance::Seconds(seconds) => {
+                write!(f, "{}", Event::seconds_to_time_string(*seconds))
+            }
+            Performance::Meters(meters) => write!(f, "{:.2}m", meters),
+            Performance::Points(points) => write!(f, "{} pts", points),
+        }
+    }
+}
+
+/// Enum to represent gender for clearer function signatures and data access.
+/// Serialized as "men"/"women"; the capitalized names written by older versions still load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, Serialize, Deserialize)] // Added Copy for easier use in arguments
+#[serde(rename_all = "lowercase")]
+pub enum Gender {
+    #[serde(alias = "Men")]
+    Men,
+    #[serde(alias = "Women")]
+    Women,
+}
+
+impl Gender {
+    pub fn from_string(s: &str) -> Option<Gender> {
+        Gender::iter().find(|variant| variant.to_string() == s)
+    }
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Gender::Men => write!(f, "men"),
+            Gender::Women => write!(f, "women"),
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, EnumIter)]
+pub enum CompetitionCategory {
+    #[default]
+    /// Other competitions
+    F,
+    /// International Matches
+    E,
+    /// World Athletics Continental Tour Challenger series
+    D,
+    /// World Athletics Continental Tour Bronze Meetings
+    C,
+    /// World Athletics Continental Tour Silver Meetings
+    B,
+    /// Major Games and Gold Meetings
+    A,
+    /// Area Senior Outdoor Championships
+    GL,
+    /// Minor Championships
+    GW,
+    /// Diamond League Finals*
+    DF,
+    /// Worlds and Olympics
+    OW,
+}
+
+impl CompetitionCategory {
+    pub fn from_string(s: &str) -> Option<CompetitionCategory> {
+        CompetitionCategory::iter().find(|variant| variant.to_string() == s)
+    }
+}
+
+impl fmt::Display for CompetitionCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompetitionCategory::F => write!(f, "F"),
+            CompetitionCategory::E => write!(f, "E"),
+            CompetitionCategory::D => write!(f, "D"),
+            CompetitionCategory::C => write!(f, "C"),
+            CompetitionCategory::B => write!(f, "B"),
+            CompetitionCategory::A => write!(f, "A"),
+            CompetitionCategory::GL => write!(f, "GL"),
+            CompetitionCategory::GW => write!(f, "GW"),
+            CompetitionCategory::DF => write!(f, "DF"),
+            CompetitionCategory::OW => write!(f, "OW"),
+        }
+    }
+}
+
+fn default_size_of_final() -> i32 {
+    8
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacementInfo {
+    pub competition_category: CompetitionCategory,
+    pub place: i32,
+    pub round: RoundType,
+    /// The size of the final impacts how the prelim is scored
+    #[serde(default = "default_size_of_final")]
+    pub size_of_final: i32,
+    #[serde(default)]
+    pub qualified_to_final: bool,
+    /// How many other athletes share the place, e.g. 2 for a three-way tie for 3rd
+    #[serde(default)]
+    pub tied_with: i32,
+    /// Whether the race was the competition's main event rather than a race held alongside
+    /// a marathon. Only the half marathon, 25km and 30km are scored differently.
+    #[serde(default)]
+    pub main_event: bool,
+}
+/// Represents the input data required to calculate a World Athletics Score.
+/// The optional fields may be left out when deserializing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldAthleticsScoreInput {
+    pub gender: Gender,
+    pub event: Event,
+    pub performance: Performance,
+    /// For events affected by wind (e.g., sprints, jumps)
+    pub wind_speed: Option<f64>,
+    /// For road running events, net elevation drop in m/km (if > 1.0 m/km)
+    pub net_downhill: Option<f64>,
+    /// Venue altitude in meters above sea level, for events that benefit from altitude
+    pub venue_altitude: Option<f64>,
+    pub placement_info: Option<PlacementInfo>,
+    /// The scoring-table edition to score against, e.g. "2025". Left out, the scorers' own
+    /// tables are used.
+    pub table_edition: Option<String>,
+}
+
+/// The output of a World Athletics Score calculation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorldAthleticsScoreOutput {
+    /// The final score: result score plus wind/downhill adjustments and placing score
+    pub points: f64,
+    /// The score from the scoring tables for the performance alone
+    pub result_score: f64,
+    /// Points added (headwind) or deducted (tailwind, NWI) for wind-affected events
+    pub wind_adjustment: f64,
+    /// Points deducted for net downhill road courses
+    pub downhill_adjustment: f64,
+    /// Points earned for the placing in the competition
+    pub placement_score: i32,
+    /// The performance was beyond the top of the official tables, so the result score
+    /// was clamped to the table ceiling rather than extrapolated
+    pub beyond_table_range: bool,
+    /// Some of the points use estimates rather than the official tables: the result score,
+    /// the placing points or both
+    pub estimated: bool,
+    /// The result score uses estimated coefficients, as the official tables don't include
+    /// the event (see `coefficients::estimated_coefficients`)
+    pub result_estimated: bool,
+    /// The placing points are estimated, as the official tables don't score the round
+    /// (see `placement_score::estimated_placement_scores`)
+    pub placement_estimated: bool,
+    /// The mark was set at altitude ("A"). This doesn't change the points.
+    pub altitude_assisted: bool,
+}
+
+/// Utility functions for time parsing and conversion
+impl Event {
+    /// Parse time string in various formats (hh:mm:ss.mmm, mm:ss.mmm, ss.mmm) to seconds
+    pub fn parse_time_to_seconds(time_str: &str) -> Result<f64, String> {
+        let time_str = time_str.trim();
+
+        // Split by colons to determine format
+        let parts: Vec<&str> = time_str.split(':').collect();
+
+        match parts.len() {
+            // Format: ss.mmm or ss
+            1 => parts[0]
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid seconds format: {}", time_str)),
+            // Format: mm:ss.mmm or mm:ss
+            2 => {
+                let minutes = parts[0]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid minutes: {}", parts[0]))?;
+                let seconds = parts[1]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid seconds: {}", parts[1]))?;
+                Ok(minutes * 60.0 + seconds)
+            }
+            // Format: hh:mm:ss.mmm or hh:mm:ss
+            3 => {
+                let hours = parts[0]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid hours: {}", parts[0]))?;
+                let minutes = parts[1]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid minutes: {}", parts[1]))?;
+                let seconds = parts[2]
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid seconds: {}", parts[2]))?;
+                Ok(hours * 3600.0 + minutes * 60.0 + seconds)
+            }
+            _ => Err(format!(
+                "Invalid time format: {}. Expected formats: ss.mmm, mm:ss.mmm, or hh:mm:ss.mmm",
+                time_str
+            )),
+        }
+    }
+
+    /// Convert seconds back to time string format (mm:ss.mmm or hh:mm:ss.mmm)
+    pub fn seconds_to_time_string(seconds: f64) -> String {
+        if seconds < 3600.0 {
+            // Less than an hour, use mm:ss.mmm format
+            let minutes = (seconds / 60.0).floor();
+            let remaining_seconds = seconds - (minutes * 60.0);
+            format!("{:02.0}:{:06.3}", minutes, remaining_seconds)
+        } else {
+            // Hour or more, use hh:mm:ss.mmm format
+            let hours = (seconds / 3600.0).floor();
+            let remaining_minutes = ((seconds - (hours * 3600.0)) / 60.0).floor();
+            let remaining_seconds = seconds - (hours * 3600.0) - (remaining_minutes * 60.0);
+            format!(
+                "{:02.0}:{:02.0}:{:06.3}",
+                hours, remaining_minutes, remaining_seconds
+            )
+        }
+    }
+
+    /// Format a performance in the event's standard unit for display
+    pub fn format_performance(&self, performance: f64) -> String {
+        Performance::for_event(self, performance).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_parse_time_to_seconds() {
+        // Test seconds only
+        assert!((Event::parse_time_to_seconds("10.50").unwrap() - 10.50).abs() < 0.001);
+        assert!((Event::parse_time_to_seconds("9.58").unwrap() - 9.58).abs() < 0.001);
+
+        // Test mm:ss format
+        assert!((Event::parse_time_to_seconds("1:30.25").unwrap() - 90.25).abs() < 0.001);
+        assert!((Event::parse_time_to_seconds("3:45.67").unwrap() - 225.67).abs() < 0.001);
+
+        // Test hh:mm:ss format
+        assert!((Event::parse_time_to_seconds("2:15:30.50").unwrap() - 8130.50).abs() < 0.001);
+        assert!((Event::parse_time_to_seconds("1:00:00.00").unwrap() - 3600.00).abs() < 0.001);
+
+        // Test error cases
+        assert!(Event::parse_time_to_seconds("invalid").is_err());
+        assert!(Event::parse_time_to_seconds("1:2:3:4").is_err());
+        assert!(Event::parse_time_to_seconds("").is_err());
+    }
+
+    #[test]
+    fn test_distance_meters() {
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M400H).distance_meters(),
+            Some(400.0)
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M300H).distance_meters(),
+            Some(300.0)
+        );
+        assert_eq!(
+            Event::RoadRunning(RoadRunningEvent::RoadMarathon).distance_meters(),
+            Some(42195.0)
+        );
+        assert_eq!(
+            Event::RaceWalking(RaceWalkingEvent::M20000mW).distance_meters(),
+            Some(20000.0)
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::LJ).distance_meters(),
+            None
+        );
+        assert_eq!(
+            Event::CombinedEvents(CombinedEvent::Dec).distance_meters(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_event_group() {
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M400H).event_group(),
+            EventGroup::Track
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::LJ).event_group(),
+            EventGroup::Field
+        );
+        assert_eq!(
+            Event::CombinedEvents(CombinedEvent::Dec).event_group(),
+            EventGroup::CombinedEvents
+        );
+        assert_eq!(
+            Event::RoadRunning(RoadRunningEvent::RoadMarathon).event_group(),
+            EventGroup::RoadRunning
+        );
+        assert_eq!(
+            Event::RaceWalking(RaceWalkingEvent::M10000mW).to_placement_score_event_group(),
+            PlacementScoreEventGroup::RaceWalking20Km
+        );
+    }
+
+    #[test]
+    fn test_category() {
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M400).category(),
+            EventCategory::Sprints
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M3000mSC).category(),
+            EventCategory::Hurdles
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M4x400mixSh).category(),
+            EventCategory::ShortTrack
+        );
+        assert_eq!(
+            Event::RaceWalking(RaceWalkingEvent::M5000mW).category(),
+            EventCategory::RaceWalks
+        );
+    }
+
+    #[test]
+    fn test_event_codes_and_from_str() {
+        let mut codes = std::collections::HashSet::new();
+        for event in Event::all_variants() {
+            assert!(
+                codes.insert(event.code()),
+                "Duplicate code {}",
+                event.code()
+            );
+            assert_eq!(event.code().parse::<Event>(), Ok(event.clone()));
+            assert_eq!(event.to_string().parse::<Event>(), Ok(event.clone()));
+            assert_eq!(Event::from_string(&event.to_string()), Some(event.clone()));
+        }
+        assert_eq!(
+            "long jump".parse::<Event>(),
+            Ok(Event::TrackAndField(TrackAndFieldEvent::LJ))
+        );
+        assert_eq!(
+            " half marathon ".parse::<Event>(),
+            Ok(Event::RoadRunning(RoadRunningEvent::RoadHM))
+        );
+        assert_eq!(
+            "road 10 KM".parse::<Event>(),
+            Ok(Event::RoadRunning(RoadRunningEvent::Road10km))
+        );
+        assert!("100 yards".parse::<Event>().is_err());
+        // from_string stays exact, for values the app wrote itself
+        assert_eq!(Event::from_string("long jump"), None);
+    }
+
+    #[test]
+    fn test_serde() {
+        let input = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100H),
+            performance: Performance::Seconds(12.5),
+            wind_speed: Some(-0.3),
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::GL,
+                place: 2,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        };
+        let json = serde_json::to_value(&input).unwrap();
+        assert_eq!(json["gender"], "women");
+        assert_eq!(json["event"], "100mH");
+        assert_eq!(json["performance"]["seconds"], 12.5);
+        assert_eq!(json["placement_info"]["competition_category"], "GL");
+        let restored: WorldAthleticsScoreInput = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.event, input.event);
+        assert_eq!(restored.performance, input.performance);
+        assert_eq!(restored.wind_speed, input.wind_speed);
+
+        // Optional fields can be left out, and events and genders take any accepted name
+        let minimal: WorldAthleticsScoreInput = serde_json::from_str(
+            r#"{"gender": "Men", "event": "Long Jump", "performance": {"meters": 8.1},
+                "placement_info": {"competition_category": "A", "place": 1, "round": "Final"}}"#,
+        )
+        .unwrap();
+        assert_eq!(minimal.gender, Gender::Men);
+        assert_eq!(minimal.event, Event::TrackAndField(TrackAndFieldEvent::LJ));
+        assert_eq!(minimal.placement_info.unwrap().size_of_final, 8);
+        assert!(serde_json::from_str::<Event>(r#""100 yards""#).is_err());
+    }
+
+    #[test]
+    fn test_performance() {
+        let sprint = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let long_jump = Event::TrackAndField(TrackAndFieldEvent::LJ);
+        let decathlon = Event::CombinedEvents(CombinedEvent::Dec);
+        assert_eq!(
+            Performance::parse(&sprint, "10.50"),
+            Ok(Performance::Seconds(10.5))
+        );
+        assert_eq!(
+            Performance::parse(&long_jump, "8.95m"),
+            Ok(Performance::Meters(8.95))
+        );
+        assert_eq!(
+            Performance::parse(&decathlon, "8500"),
+            Ok(Performance::Points(8500))
+        );
+        assert!(Performance::parse(&decathlon, "2:30:00").is_err());
+        assert_eq!(
+            Performance::for_event(&decathlon, 8499.6),
+            Performance::Points(8500)
+        );
+
+        // A mark is only scored through its own event's unit
+        assert_eq!(Performance::Seconds(10.5).value_for(&sprint), Ok(10.5));
+        assert!(Performance::Meters(8.95).value_for(&sprint).is_err());
+        assert!(Performance::Seconds(8500.0).value_for(&decathlon).is_err());
+        assert!(Performance::Meters(f64::NAN).value_for(&long_jump).is_err());
+
+        assert_eq!(Performance::Meters(8.95).to_string(), "8.95m");
+        assert_eq!(Performance::Points(8500).to_string(), "8500 pts");
+    }
+
+    #[test]
+    fn test_seconds_to_time_string() {
+        // Test less than an hour
+        assert_eq!(Event::seconds_to_time_string(10.50), "00:10.500");
+        assert_eq!(Event::seconds_to_time_string(90.25), "01:30.250");
+        assert_eq!(Event::seconds_to_time_string(225.67), "03:45.670");
+
+        // Test an hour or more
+        assert_eq!(Event::seconds_to_time_string(3600.0), "01:00:00.000");
+        assert_eq!(Event::seconds_to_time_string(8130.50), "02:15:30.500");
+    }
+
+    #[test]
+    fn test_performance_type() {
+        // Test field events return Distance
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::LJ).performance_type(),
+            PerformanceType::Distance
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::SP).performance_type(),
+            PerformanceType::Distance
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::HJ).performance_type(),
+            PerformanceType::Distance
+        );
+
+        // Test track events return Time
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M100).performance_type(),
+            PerformanceType::Time
+        );
+        assert_eq!(
+            Event::TrackAndField(TrackAndFieldEvent::M400H).performance_type(),
+            PerformanceType::Time
+        );
+        assert_eq!(
+            Event::RoadRunning(RoadRunningEvent::RoadMarathon).performance_type(),
+            PerformanceType::Time
+        );
+    }
+
+    /// The official constants, with the estimated coefficients for the events they lack
+    fn constants_with_estimates() -> Value {
+        let mut json_data: Value = serde_json::from_str(include_str!(
+            "../../data/world_athletics_constants_2025.json"
+        ))
+        .unwrap();
+        let estimates: Value =
+            serde_json::from_str(include_str!("../../data/estimated_coefficients.json")).unwrap();
+        for key in ["men", "women"] {
+            let events = json_data[key].as_object_mut().unwrap();
+            for (name, coefficients) in estimates[key].as_object().unwrap() {
+                assert!(
+                    !events.contains_key(name),
+                    "{} is in the official constants and the estimates",
+                    name
+                );
+                events.insert(name.clone(), coefficients.clone());
+            }
+        }
+        json_data
+    }
+
+    #[test]
+    fn test_is_available_for_matches_json() {
+        let json_data = constants_with_estimates();
+        for event in Event::all_variants() {
+            if matches!(event, Event::CrossCountry(_)) {
+                continue;
+            }
+            for (gender, key) in [(Gender::Men, "men"), (Gender::Women, "women")] {
+                assert_eq!(
+                    event.is_available_for(gender, None),
+                    json_data[key].get(event.to_string()).is_some(),
+                    "{} {}",
+                    gender,
+                    event
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_enum_events_must_exist_in_json() {
+        // This test ensures ALL events defined in enums exist in the JSON constants, or
+        // failing that in the estimated coefficients
+        let json_data = constants_with_estimates();
+
+        let men_events = json_data["men"]
+            .as_object()
+            .expect("Men's section not found");
+        let women_events = json_data["women"]
+            .as_object()
+            .expect("Women's section not found");
+
+        // Get all enum events and check each one
+        let all_events = Event::all_variants();
+        let mut missing_events = Vec::new();
+
+        for event in all_events {
+            let event_string = event.to_string();
+
+            // Skip cross country events as they might be placeholders
+            if matches!(event, Event::CrossCountry(_)) {
+                continue;
+            }
+
+            let should_be_in_men = event.is_available_for(Gender::Men, None);
+            let should_be_in_women = event.is_available_for(Gender::Women, None);
+
+            let in_men = men_events.contains_key(&event_string);
+            let in_women = women_events.contains_key(&event_string);
+
+            if should_be_in_men && !in_men {
+                missing_events.push(format!("Missing from men's constants: {}", event_string));
+            }
+            if should_be_in_women && !in_women {
+                missing_events.push(format!("Missing from women's constants: {}", event_string));
+            }
+        }
+
+        // Fail the test if any events are missing
+        if !missing_events.is_empty() {
+            panic!(
+                "The following events are defined in enums but missing from JSON constants:\n{}\n\
+                All enum events must have corresponding entries in world_athletics_constants_2025.json \
+                or estimated_coefficients.json",
+                missing_events.join("\n")
+            );
+        }
+    }
+}
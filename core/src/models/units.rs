@@ -0,0 +1,266 @@
+// src/models/units.rs
+//! Unit preferences and conversions for displaying marks, paces and temperatures, and for
+//! reading field-event marks entered in feet and inches. Scoring always works in the official
+//! units (meters, seconds, m/km); these only affect how values are entered and shown.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use strum_macros::EnumIter;
+
+const METERS_PER_FOOT: f64 = 0.3048;
+const METERS_PER_MILE: f64 = 1609.344;
+const FEET_PER_MILE: f64 = 5280.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum DistanceUnit {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum PaceUnit {
+    #[default]
+    PerKilometer,
+    PerMile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+/// The user's preferred units, applied across every input and display
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnitPreferences {
+    pub distance: DistanceUnit,
+    pub pace: PaceUnit,
+    pub temperature: TemperatureUnit,
+}
+
+impl fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DistanceUnit::Metric => write!(f, "Metric"),
+            DistanceUnit::Imperial => write!(f, "Imperial"),
+        }
+    }
+}
+
+impl PaceUnit {
+    /// The distance a pace is given over
+    pub fn meters(self) -> f64 {
+        match self {
+            PaceUnit::PerKilometer => 1000.0,
+            PaceUnit::PerMile => METERS_PER_MILE,
+        }
+    }
+}
+
+impl fmt::Display for PaceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaceUnit::PerKilometer => write!(f, "min/km"),
+            PaceUnit::PerMile => write!(f, "min/mile"),
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemperatureUnit::Celsius => write!(f, "°C"),
+            TemperatureUnit::Fahrenheit => write!(f, "°F"),
+        }
+    }
+}
+
+/// Formats a field-event mark, e.g. `8.95 m` or `29' 4.4"`.
+pub fn format_distance(meters: f64, unit: DistanceUnit) -> String {
+    match unit {
+        DistanceUnit::Metric => format!("{:.2} m", meters),
+        DistanceUnit::Imperial => {
+            let total_inches = (meters / METERS_PER_FOOT * 12.0 * 10.0).round() / 10.0;
+            let feet = (total_inches / 12.0).floor();
+            let inches = total_inches - feet * 12.0;
+            format!("{:.0}' {:.1}\"", feet, inches)
+        }
+    }
+}
+
+/// Parses a field-event mark in meters (`8.95`, `8.95m`) or feet and inches (`26' 4.5"`,
+/// `26-04.5`, `26ft 4.5in`, `80'`), returning meters. Feet and inches are recognized by
+/// their markers, so a bare number is always meters.
+pub fn parse_distance_mark(mark: &str) -> Result<f64, String> {
+    let mark = mark.trim();
+    let invalid = || {
+        format!(
+            "Invalid distance: {}. Enter meters (8.95) or feet and inches (29' 4.5\")",
+            mark
+        )
+    };
+    let imperial = mark.contains(['\'', '"', '-']) || mark.contains("ft") || mark.contains("in");
+    if !imperial {
+        let meters = mark
+            .strip_suffix('m')
+            .unwrap_or(mark)
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| invalid())?;
+        return if meters.is_finite() && meters >= 0.0 {
+            Ok(meters)
+        } else {
+            Err(invalid())
+        };
+    }
+    let normalized = mark.replace("ft", "'").replace("in", "\"");
+    let (feet, inches) = normalized.split_once(['\'', '-']).ok_or_else(invalid)?;
+    let feet = feet.trim().parse::<f64>().map_err(|_| invalid())?;
+    let inches = inches.trim().trim_end_matches('"').trim();
+    let inches = if inches.is_empty() {
+        0.0
+    } else {
+        inches.parse::<f64>().map_err(|_| invalid())?
+    };
+    if !feet.is_finite() || !inches.is_finite() || feet < 0.0 || !(0.0..12.0).contains(&inches) {
+        return Err(invalid());
+    }
+    let meters = (feet * 12.0 + inches) / 12.0 * METERS_PER_FOOT;
+    if meters.is_finite() {
+        Ok(meters)
+    } else {
+        Err(invalid())
+    }
+}
+
+/// Formats a race distance, e.g. `10 km` or `6.21 mi`.
+pub fn format_race_distance(meters: f64, unit: DistanceUnit) -> String {
+    match unit {
+        DistanceUnit::Metric if meters >= 1000.0 => {
+            format!("{} km", (meters / 1000.0 * 1000.0).round() / 1000.0)
+        }
+        DistanceUnit::Metric => format!("{} m", meters),
+        DistanceUnit::Imperial => format!("{:.2} mi", meters / METERS_PER_MILE),
+    }
+}
+
+/// Formats the average pace for a race, e.g. `2:58 /km`.
+pub fn format_pace(seconds: f64, meters: f64, unit: PaceUnit) -> String {
+    let pace = (seconds / meters * unit.meters()).round();
+    let suffix = match unit {
+        PaceUnit::PerKilometer => "/km",
+        PaceUnit::PerMile => "/mile",
+    };
+    format!("{}:{:02} {}", (pace / 60.0).floor(), pace % 60.0, suffix)
+}
+
+/// Formats a net downhill gradient, given in the official m/km.
+pub fn format_gradient(meters_per_km: f64, unit: DistanceUnit) -> String {
+    match unit {
+        DistanceUnit::Metric => format!("{:.1} m/km", meters_per_km),
+        DistanceUnit::Imperial => {
+            // m/km is a ratio, so ft/mile is the same ratio times feet per mile
+            format!("{:.1} ft/mile", meters_per_km / 1000.0 * FEET_PER_MILE)
+        }
+    }
+}
+
+pub fn format_temperature(celsius: f64, unit: TemperatureUnit) -> String {
+    match unit {
+        TemperatureUnit::Celsius => format!("{:.0}°C", celsius),
+        TemperatureUnit::Fahrenheit => format!("{:.0}°F", celsius * 9.0 / 5.0 + 32.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_distance() {
+        assert_eq!(format_distance(8.95, DistanceUnit::Metric), "8.95 m");
+        assert_eq!(format_distance(8.95, DistanceUnit::Imperial), "29' 4.4\"");
+        assert_eq!(format_distance(2.00, DistanceUnit::Imperial), "6' 6.7\"");
+    }
+
+    #[test]
+    fn test_parse_distance_mark() {
+        let close = |mark: &str, meters: f64| {
+            let parsed = parse_distance_mark(mark).unwrap();
+            assert!(
+                (parsed - meters).abs() < 0.001,
+                "{} parsed as {}",
+                mark,
+                parsed
+            );
+        };
+        close("8.95", 8.95);
+        close("8.95m", 8.95);
+        close("29' 4.4\"", 8.95096);
+        close("29'4.4", 8.95096);
+        close("29-04.4", 8.95096);
+        close("29ft 4.4in", 8.95096);
+        close("80'", 24.384);
+        assert!(parse_distance_mark("").is_err());
+        assert!(parse_distance_mark("29' 13\"").is_err());
+        assert!(parse_distance_mark("-8.95").is_err());
+        assert!(parse_distance_mark("eight").is_err());
+        assert!(parse_distance_mark("1e308'").is_err());
+    }
+
+    #[test]
+    fn test_format_race_distance() {
+        assert_eq!(format_race_distance(10000.0, DistanceUnit::Metric), "10 km");
+        assert_eq!(
+            format_race_distance(21097.5, DistanceUnit::Metric),
+            "21.098 km"
+        );
+        assert_eq!(
+            format_race_distance(10000.0, DistanceUnit::Imperial),
+            "6.21 mi"
+        );
+    }
+
+    #[test]
+    fn test_format_pace() {
+        // 2:00:00 marathon
+        assert_eq!(
+            format_pace(7200.0, 42195.0, PaceUnit::PerKilometer),
+            "2:51 /km"
+        );
+        assert_eq!(
+            format_pace(7200.0, 42195.0, PaceUnit::PerMile),
+            "4:35 /mile"
+        );
+    }
+
+    #[test]
+    fn test_format_gradient_and_temperature() {
+        assert_eq!(format_gradient(1.0, DistanceUnit::Metric), "1.0 m/km");
+        assert_eq!(format_gradient(1.0, DistanceUnit::Imperial), "5.3 ft/mile");
+        assert_eq!(
+            format_temperature(20.0, TemperatureUnit::Fahrenheit),
+            "68°F"
+        );
+    }
+
+    #[test]
+    fn test_unit_preferences_serde() {
+        let preferences = UnitPreferences {
+            distance: DistanceUnit::Imperial,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&preferences).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UnitPreferences>(&json).unwrap(),
+            preferences
+        );
+        // Missing fields fall back to the defaults
+        assert_eq!(
+            serde_json::from_str::<UnitPreferences>("{}").unwrap(),
+            UnitPreferences::default()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! Venue altitude. Marks set at 1000 m or higher are annotated "A" in World Athletics
+//! statistics, and the thinner air helps sprints, hurdles and horizontal jumps.
+//!
+//! The World Athletics Ranking Rules don't modify the result score for altitude (unlike wind
+//! and downhill courses), so an altitude-assisted mark scores the same points; the calculator
+//! flags it alongside the score instead.
+use crate::models::{Event, TrackAndFieldEvent};
+
+/// Venues at or above this altitude (in meters) are "altitude" venues
+pub const ALTITUDE_THRESHOLD_M: f64 = 1000.0;
+
+/// Determines if an event benefits from altitude: sprints and hurdles up to 400m, their
+/// relays and the horizontal jumps
+pub fn is_altitude_affected_event(event: &Event) -> bool {
+    use TrackAndFieldEvent as T;
+    matches!(
+        event,
+        Event::TrackAndField(
+            T::M50
+                | T::M55
+                | T::M60
+                | T::M100
+                | T::M200
+                | T::M300
+                | T::M400
+                | T::M50H
+                | T::M55H
+                | T::M60H
+                | T::M100H
+                | T::M110H
+                | T::M300H
+                | T::M400H
+                | T::M4x100m
+                | T::M4x200m
+                | T::M4x400m
+                | T::M4x400mix
+                | T::M200mSh
+                | T::M300mSh
+                | T::M400mSh
+                | T::M4x100mSh
+                | T::M4x200mSh
+                | T::M4x400mSh
+                | T::M4x400mixSh
+                | T::LJ
+                | T::TJ
+        )
+    )
+}
+
+/// Whether a mark in `event` at a venue `venue_altitude` meters above sea level is
+/// altitude-assisted ("A")
+pub fn is_altitude_assisted(event: &Event, venue_altitude: Option<f64>) -> bool {
+    is_altitude_affected_event(event)
+        && venue_altitude.is_some_and(|altitude| altitude >= ALTITUDE_THRESHOLD_M)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RoadRunningEvent;
+
+    #[test]
+    fn test_is_altitude_assisted() {
+        let sprint = Event::TrackAndField(TrackAndFieldEvent::M100);
+        assert!(is_altitude_assisted(&sprint, Some(1850.0)));
+        assert!(is_altitude_assisted(&sprint, Some(ALTITUDE_THRESHOLD_M)));
+        assert!(!is_altitude_assisted(&sprint, Some(999.0)));
+        assert!(!is_altitude_assisted(&sprint, None));
+
+        let distance = Event::TrackAndField(TrackAndFieldEvent::M5000);
+        assert!(!is_altitude_assisted(&distance, Some(2240.0)));
+        let road = Event::RoadRunning(RoadRunningEvent::Road10km);
+        assert!(!is_altitude_assisted(&road, Some(2240.0)));
+    }
+}
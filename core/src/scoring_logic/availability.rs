@@ -0,0 +1,95 @@
+//! Which genders and venues every event can be scored for, as a matrix for the UI and
+//! external consumers. The genders come from the coefficients tables themselves rather than
+//! from `Event::is_available_for`, so the matrix follows whatever tables are loaded.
+use super::calculator::is_placement_only_event;
+use super::provider::ScoringTableProvider;
+use crate::models::{Event, Gender, Venue};
+use serde::Serialize;
+use strum::IntoEnumIterator;
+
+/// One event's row of the availability matrix
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventAvailability {
+    /// The event's display name, e.g. "100m"
+    pub event: String,
+    pub code: &'static str,
+    /// The genders with scoring tables for the event
+    pub genders: Vec<Gender>,
+    pub venues: Vec<Venue>,
+}
+
+/// The availability of every event, in the usual event order. Events scored on placing
+/// alone have no coefficients, so they count as available to every gender.
+pub fn availability_matrix<P: ScoringTableProvider + ?Sized>(
+    provider: &P,
+) -> Vec<EventAvailability> {
+    Event::all_variants()
+        .into_iter()
+        .map(|event| {
+            let name = event.to_string();
+            EventAvailability {
+                genders: Gender::iter()
+                    .filter(|gender| {
+                        is_placement_only_event(&event)
+                            || provider.get_coefficients(*gender, &event).is_some()
+                    })
+                    .collect(),
+                venues: event.venues(),
+                code: event.code(),
+                event: name,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CrossCountryEvent, TrackAndFieldEvent};
+    use crate::scoring_logic::coefficients::TABLE_VERSION;
+    use crate::scoring_logic::engine::ScoringEngine;
+
+    #[test]
+    fn test_availability_matrix_matches_is_available_for() {
+        let engine = ScoringEngine::bundled(TABLE_VERSION).unwrap();
+        let matrix = availability_matrix(&engine);
+        assert_eq!(matrix.len(), Event::all_variants().len());
+        for (event, row) in Event::all_variants().iter().zip(&matrix) {
+            for gender in Gender::iter() {
+                assert_eq!(
+                    row.genders.contains(&gender),
+                    event.is_available_for(gender, None),
+                    "{} {}",
+                    gender,
+                    event
+                );
+            }
+        }
+
+        let row = |event: Event| {
+            matrix
+                .iter()
+                .find(|row| row.event == event.to_string())
+                .unwrap()
+                .clone()
+        };
+        assert_eq!(
+            row(Event::TrackAndField(TrackAndFieldEvent::M110H)).genders,
+            [Gender::Men]
+        );
+        assert_eq!(
+            row(Event::TrackAndField(TrackAndFieldEvent::M400mSh)).venues,
+            [Venue::ShortTrack]
+        );
+        assert_eq!(
+            row(Event::TrackAndField(TrackAndFieldEvent::M60)).venues,
+            [Venue::Outdoor, Venue::ShortTrack]
+        );
+        assert_eq!(
+            row(Event::CrossCountry(CrossCountryEvent::Xc10km)).genders,
+            [Gender::Men, Gender::Women]
+        );
+        assert!(!Event::TrackAndField(TrackAndFieldEvent::DT)
+            .is_available_for(Gender::Women, Some(Venue::ShortTrack)));
+    }
+}
@@ -0,0 +1,6 @@
+//! The bundled scoring tables, generated from the JSON in `data/` by `build.rs`
+
+/// One event's coefficients: (gender, event name, coefficients)
+pub type CoefficientRow = (&'static str, &'static str, [f64; 3]);
+
+include!(concat!(env!("OUT_DIR"), "/bundled_tables.rs"));
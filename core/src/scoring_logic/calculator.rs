@@ -0,0 +1,1042 @@
+// src/scoring_logic/calculator.rs
+use crate::models::{
+    CompetitionCategory, Event, Gender, PlacementInfo, TrackAndFieldEvent,
+    WorldAthleticsScoreInput, WorldAthleticsScoreOutput,
+};
+use strum::IntoEnumIterator;
+
+use super::altitude::is_altitude_assisted;
+use super::coefficients::{self, CoefficientsTable, ResultScoreError};
+use super::engine;
+use super::placement_score::{
+    self, scored_place, PlacementCalculator, PlacementScoreCalcInput, MAX_TABLE_PLACE,
+};
+use super::provider::ScoringTableProvider;
+
+/// Determines if an event is a road running event
+pub fn is_road_running_event(event: &Event) -> bool {
+    matches!(event, Event::RoadRunning(_))
+}
+
+/// Determines if an event is scored on placing alone. Cross country has no scoring tables,
+/// so the mark isn't scored and the points are the placing score.
+pub fn is_placement_only_event(event: &Event) -> bool {
+    matches!(event, Event::CrossCountry(_))
+}
+
+/// Determines if an event is affected by wind for scoring modifications.
+/// The wind modification applies in the following events:
+/// 100m, 200m, 100m Hurdles, 110mHurdles, Long Jump, Triple Jump
+pub fn is_wind_affected_event(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::TrackAndField(TrackAndFieldEvent::M100)
+        | Event::TrackAndField(TrackAndFieldEvent::M200)
+        | Event::TrackAndField(TrackAndFieldEvent::M100H) // Women's hurdles
+        | Event::TrackAndField(TrackAndFieldEvent::M110H) // Men's hurdles
+        | Event::TrackAndField(TrackAndFieldEvent::LJ)
+        | Event::TrackAndField(TrackAndFieldEvent::TJ)
+    )
+}
+
+/// Calculates the wind adjustment points based on wind speed.
+///
+/// Rules:
+/// - 1 m/s wind is equivalent to 6 points.
+/// - For wind readings in between those identified in the table, the allocation of points is ±0.6 points for every ±0.1 m/s.
+/// - Tailwind (positive wind speed): No modification between 0 m/s and +2.0 m/s.
+///   Deduction starts from +2.1 m/s, while the calculation of the points to be deducted still starts from 0.0 m/s.
+/// - Headwind (negative wind speed): Adds points.
+/// - No Wind Information (NWI): Deduct 30 points from the Result Score.
+///
+/// # Arguments
+/// * `wind_speed` - An `Option<f64>` representing the wind speed in m/s.
+///
+/// # Returns
+/// The points to be added or deducted due to wind.
+pub(crate) fn calculate_wind_adjustment(wind_speed: Option<f64>) -> f64 {
+    const POINTS_PER_M_S: f64 = 6.0;
+    const NWI_PENALTY: f64 = -30.0;
+    const TAILWIND_THRESHOLD: f64 = 2.0; // No deduction up to +2.0 m/s
+
+    match wind_speed {
+        Some(wind_value) => {
+            if wind_value > 0.0 {
+                // Tailwind
+                if wind_value > TAILWIND_THRESHOLD {
+                    // For tailwind > +2.0 m/s, deduction applies.
+                    // The rule "calculation of the points to be deducted still starts from 0.0 m/s"
+                    // implies a linear deduction from 0.0 m/s, but only applied if wind > 2.0.
+                    // E.g., +2.5 m/s -> -(2.5 * 6.0) = -15.0 pts
+                    -(wind_value * POINTS_PER_M_S)
+                } else {
+                    // No deduction for tailwind <= +2.0 m/s
+                    0.0
+                }
+            } else {
+                // Headwind (negative wind_value) or exactly 0.0 m/s
+                // Headwind adds points. E.g., -1.0 m/s -> -(-1.0 * 6.0) = +6.0 pts
+                // 0.0 m/s -> 0.0 pts
+                -wind_value * POINTS_PER_M_S
+            }
+        }
+        None => NWI_PENALTY, // No Wind Information (NWI)
+    }
+}
+
+/// Calculates the downhill adjustment points based on net elevation drop for road running events.
+///
+/// Rules:
+/// - No deduction if the net drop is within the allowed 1 m/km.
+/// - A net drop of 1 m/km of the race distance is equivalent to 6 points deduction.
+/// - For each additional 0.1 m/km drop, an additional 0.6 points are deducted.
+///
+/// # Arguments
+/// * `net_downhill` - An `Option<f64>` representing the net elevation drop in m/km.
+///
+/// # Returns
+/// The points to be deducted due to downhill course.
+pub(crate) fn calculate_downhill_adjustment(net_downhill: Option<f64>) -> f64 {
+    const POINTS_PER_M_KM: f64 = 6.0;
+    const POINTS_PER_0_1_M_KM: f64 = 0.6;
+    const THRESHOLD: f64 = 1.0; // No deduction below 1 m/km
+
+    match net_downhill {
+        Some(drop) => {
+            if drop <= THRESHOLD {
+                // No deduction for drops within allowed limit
+                0.0
+            } else {
+                // Calculate excess drop above threshold
+                let excess = drop - THRESHOLD;
+                // Convert to 0.1 m/km units and calculate deduction
+                let deduction_base = POINTS_PER_M_KM; // 6 points for the first 1 m/km over threshold
+                let deduction_additional = (excess * 10.0) * POINTS_PER_0_1_M_KM; // 0.6 points per 0.1 m/km
+                -(deduction_base + deduction_additional)
+            }
+        }
+        None => 0.0, // No adjustment if no drop specified
+    }
+}
+
+/// Scores a performance (in seconds, meters or points) against the result score tables.
+/// Implemented for closures and functions taking the performance, gender and event, and
+/// for anything that needs state, such as its own set of tables.
+pub trait ResultScorer {
+    fn result_score(&self, performance: f64, gender: Gender, event: &Event) -> Result<f64, String>;
+
+    /// Whether the performance is better than the top of the tables, so its score was
+    /// clamped to `MAX_RESULT_SCORE`. Closures and functions are taken to score against the
+    /// app's engine, as `coefficients::calculate_result_score` does.
+    fn is_beyond_table_range(&self, performance: f64, gender: Gender, event: &Event) -> bool {
+        coefficients::is_beyond_table_range(performance, gender, event)
+    }
+
+    /// Whether the event is scored with estimated coefficients rather than the official
+    /// tables. Closures and functions are taken to score against the app's engine.
+    fn is_estimated(&self, gender: Gender, event: &Event) -> bool {
+        coefficients::is_estimated(gender, event)
+    }
+
+    /// The edition of the tables scored against, if known. Closures and functions are
+    /// taken to score against the app's engine.
+    fn table_edition(&self) -> Option<String> {
+        engine::table_edition()
+    }
+}
+
+impl<F: Fn(f64, Gender, &Event) -> Result<f64, String>> ResultScorer for F {
+    fn result_score(&self, performance: f64, gender: Gender, event: &Event) -> Result<f64, String> {
+        self(performance, gender, event)
+    }
+}
+
+impl ResultScorer for &CoefficientsTable {
+    fn result_score(&self, performance: f64, gender: Gender, event: &Event) -> Result<f64, String> {
+        self.calculate_result_score(performance, gender, event)
+            .map_err(String::from)
+    }
+
+    fn is_beyond_table_range(&self, performance: f64, gender: Gender, event: &Event) -> bool {
+        CoefficientsTable::is_beyond_table_range(self, performance, gender, event)
+    }
+
+    fn is_estimated(&self, gender: Gender, event: &Event) -> bool {
+        CoefficientsTable::is_estimated(self, gender, event)
+    }
+
+    fn table_edition(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Looks up the placing points for a place in a competition. `None` scores no placing
+/// points. Implemented for closures and functions like `ResultScorer`.
+pub trait PlacementScorer {
+    fn placement_score(&self, input: PlacementScoreCalcInput) -> Option<i32>;
+
+    /// Whether the placing points come from estimates rather than the official tables.
+    /// Closures and functions are taken to score against the app's engine.
+    fn is_estimated(&self, input: &PlacementScoreCalcInput) -> bool {
+        placement_score::is_estimated_placement(input)
+    }
+}
+
+impl<F: Fn(PlacementScoreCalcInput) -> Option<i32>> PlacementScorer for F {
+    fn placement_score(&self, input: PlacementScoreCalcInput) -> Option<i32> {
+        self(input)
+    }
+}
+
+impl PlacementScorer for &PlacementCalculator {
+    fn placement_score(&self, input: PlacementScoreCalcInput) -> Option<i32> {
+        self.calculate_placement_score(input)
+    }
+
+    fn is_estimated(&self, input: &PlacementScoreCalcInput) -> bool {
+        PlacementCalculator::is_estimated(self, input)
+    }
+}
+
+/// The lookup for one place of a placing
+fn placement_input(
+    event: &Event,
+    placement_info: &PlacementInfo,
+    place: i32,
+) -> PlacementScoreCalcInput {
+    PlacementScoreCalcInput {
+        event: event.clone(),
+        competition_category: placement_info.competition_category,
+        round_type: placement_info.round,
+        place,
+        qualified_to_final: placement_info.qualified_to_final,
+        size_of_final: placement_info.size_of_final,
+        main_event: placement_info.main_event,
+    }
+}
+
+/// The placing points for a placing. Athletes tied for a place share the points of the
+/// places they fill: e.g. two athletes joint 3rd each get the average of the points for 3rd
+/// and 4th, rounded to the nearest point. Places past the end of the tables score nothing,
+/// so they aren't looked up.
+pub fn shared_placement_score(
+    event: &Event,
+    placement_info: &PlacementInfo,
+    placement_scorer: &impl PlacementScorer,
+) -> i32 {
+    let tied_with = placement_info.tied_with.max(0);
+    let last_place = placement_info
+        .place
+        .saturating_add(tied_with)
+        .min(MAX_TABLE_PLACE);
+    let places = placement_info.place..=last_place;
+    let total: i32 = places
+        .map(|place| {
+            placement_scorer
+                .placement_score(placement_input(event, placement_info, place))
+                .unwrap_or(0)
+        })
+        .sum();
+    (total as f64 / (tied_with as f64 + 1.0)).round() as i32
+}
+
+/// Checks a placing is one the tables can score: a place from 1st, and fewer athletes
+/// tied on it than the tables have places.
+pub fn check_placement_info(placement_info: &PlacementInfo) -> Result<(), String> {
+    if placement_info.place < 1 {
+        return Err(format!("Invalid place: {}", placement_info.place));
+    }
+    if !(0..MAX_TABLE_PLACE).contains(&placement_info.tied_with) {
+        return Err(format!(
+            "Invalid number of tied athletes: {} (must be 0 to {})",
+            placement_info.tied_with,
+            MAX_TABLE_PLACE - 1
+        ));
+    }
+    Ok(())
+}
+
+/// Calculates the World Athletics Score for a given performance.
+///
+/// The result score comes from `result_scorer` and the placing points from
+/// `placement_scorer`, so tests and integrators can substitute their own tables.
+///
+/// # Arguments
+/// * `input` - A `WorldAthleticsScoreInput` struct containing all necessary performance details.
+/// * `result_scorer` - Scores the performance, e.g. `coefficients::calculate_result_score`.
+/// * `placement_scorer` - Scores the placing, e.g. `placement_score::calculate_placement_score`.
+///
+/// An input naming a `table_edition` is an error unless the scorers are that edition's
+/// tables; score it against `engine::bundled_engine` to use another bundled edition.
+///
+/// # Returns
+/// A `Result` containing either a `WorldAthleticsScoreOutput` with the calculated points
+/// or a `String` error message if coefficients are not found. A performance better than the
+/// one scoring `MAX_RESULT_SCORE` is flagged as `beyond_table_range`; one scoring exactly
+/// `MAX_RESULT_SCORE` is still inside the tables.
+pub fn calculate_world_athletics_score(
+    input: WorldAthleticsScoreInput,
+    result_scorer: impl ResultScorer,
+    placement_scorer: impl PlacementScorer,
+) -> Result<WorldAthleticsScoreOutput, String> {
+    check_table_edition(&input, result_scorer.table_edition())?;
+    score_performance(input, result_scorer, placement_scorer)
+}
+
+/// Checks an input naming a `table_edition` is scored against that edition's tables
+fn check_table_edition(
+    input: &WorldAthleticsScoreInput,
+    tables_edition: Option<String>,
+) -> Result<(), String> {
+    match (&input.table_edition, tables_edition) {
+        (Some(edition), Some(tables_edition)) if *edition != tables_edition => Err(format!(
+            "The input asks for the {} edition, but the scoring tables are the {} edition",
+            edition, tables_edition
+        )),
+        (Some(edition), None) => Err(format!(
+            "The input asks for the {} edition, but the scoring tables' edition isn't known",
+            edition
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn score_performance(
+    input: WorldAthleticsScoreInput,
+    result_scorer: impl ResultScorer,
+    placement_scorer: impl PlacementScorer,
+) -> Result<WorldAthleticsScoreOutput, String> {
+    log::info!("Calculating score for input: {:?}", input);
+
+    // Events a gender doesn't contest have no tables for it
+    if !input.event.is_available_for(input.gender, None) {
+        return Err(format!(
+            "{} isn't contested by {}",
+            input.event, input.gender
+        ));
+    }
+
+    if let Some(placement_info) = &input.placement_info {
+        check_placement_info(placement_info)?;
+    }
+
+    // Placement-only events need a place, and their mark isn't scored
+    let placement_only = is_placement_only_event(&input.event);
+    if placement_only && input.placement_info.is_none() {
+        return Err(format!(
+            "{} is scored on placing only; enter a place",
+            input.event
+        ));
+    }
+
+    // The performance must be in the unit the event is measured in
+    let performance = input.performance.value_for(&input.event)?;
+    let result_score = if placement_only {
+        0.0
+    } else {
+        result_scorer.result_score(performance, input.gender, &input.event)?
+    };
+    let beyond_table_range = !placement_only
+        && result_scorer.is_beyond_table_range(performance, input.gender, &input.event);
+    let result_estimated =
+        !placement_only && result_scorer.is_estimated(input.gender, &input.event);
+
+    // Modify result score due to wind for some track events
+    // The wind modification applies in the following events:
+    let wind_adjustment = if is_wind_affected_event(&input.event) {
+        calculate_wind_adjustment(input.wind_speed)
+    } else {
+        0.0
+    };
+
+    // Apply downhill adjustment for road running events
+    let downhill_adjustment = if is_road_running_event(&input.event) {
+        calculate_downhill_adjustment(input.net_downhill)
+    } else {
+        0.0
+    };
+
+    // Altitude is flagged but, per the ranking rules, doesn't modify the score
+    let altitude_assisted = is_altitude_assisted(&input.event, input.venue_altitude);
+
+    let placing_score = input
+        .placement_info
+        .as_ref()
+        .map(|placement_info| {
+            shared_placement_score(&input.event, placement_info, &placement_scorer)
+        })
+        .unwrap_or(0);
+    let placement_estimated = placing_score != 0
+        && input.placement_info.as_ref().is_some_and(|placement_info| {
+            placement_scorer.is_estimated(&placement_input(
+                &input.event,
+                placement_info,
+                placement_info.place,
+            ))
+        });
+    log::debug!(
+        "result score = {} and placement score = {}",
+        result_score,
+        placing_score
+    );
+    let points = result_score + wind_adjustment + downhill_adjustment + (placing_score as f64);
+
+    Ok(WorldAthleticsScoreOutput {
+        points,
+        result_score,
+        wind_adjustment,
+        downhill_adjustment,
+        placement_score: placing_score,
+        beyond_table_range,
+        estimated: result_estimated || placement_estimated,
+        result_estimated,
+        placement_estimated,
+        altitude_assisted,
+    })
+}
+
+/// Scores the input's performance and place at every competition category, from the
+/// highest (OW) down to F, to show how much the meet's category matters.
+///
+/// # Returns
+/// An error if the input has no placing, or if the performance can't be scored.
+pub fn scores_by_category<R: ResultScorer + Copy, P: PlacementScorer + Copy>(
+    input: &WorldAthleticsScoreInput,
+    result_scorer: R,
+    placement_scorer: P,
+) -> Result<Vec<(CompetitionCategory, WorldAthleticsScoreOutput)>, String> {
+    let placement_info = input
+        .placement_info
+        .as_ref()
+        .ok_or("A place is needed to compare competition categories")?;
+    CompetitionCategory::iter()
+        .rev()
+        .map(|competition_category| {
+            let mut input = input.clone();
+            input.placement_info = Some(PlacementInfo {
+                competition_category,
+                ..placement_info.clone()
+            });
+            calculate_world_athletics_score(input, result_scorer, placement_scorer)
+                .map(|output| (competition_category, output))
+        })
+        .collect()
+}
+
+/// Winds the sensitivity table covers, in m/s: −4.0 to +4.0 in 0.5 steps
+fn wind_steps() -> impl Iterator<Item = f64> {
+    (-8..=8).map(|step| step as f64 * 0.5)
+}
+
+/// Scores the input at every wind in `wind_steps`, keeping everything else the same.
+///
+/// # Returns
+/// An error if the event isn't wind-affected, or if the performance can't be scored.
+pub fn scores_by_wind<R: ResultScorer + Copy, P: PlacementScorer + Copy>(
+    input: &WorldAthleticsScoreInput,
+    result_scorer: R,
+    placement_scorer: P,
+) -> Result<Vec<(f64, WorldAthleticsScoreOutput)>, String> {
+    if !is_wind_affected_event(&input.event) {
+        return Err(format!("{} isn't affected by wind", input.event));
+    }
+    wind_steps()
+        .map(|wind_speed| {
+            let mut input = input.clone();
+            input.wind_speed = Some(wind_speed);
+            calculate_world_athletics_score(input, result_scorer, placement_scorer)
+                .map(|output| (wind_speed, output))
+        })
+        .collect()
+}
+
+/// Calculates the World Athletics Score against the tables from `provider`, e.g.
+/// `BundledTables` or a federation's own tables. Otherwise the same as
+/// `calculate_world_athletics_score`; an input naming a `table_edition` must name the
+/// provider's.
+pub fn calculate_score_with_provider<P: ScoringTableProvider + ?Sized>(
+    input: WorldAthleticsScoreInput,
+    provider: &P,
+) -> Result<WorldAthleticsScoreOutput, String> {
+    check_table_edition(&input, provider.table_edition())?;
+    score_performance(
+        input,
+        |performance, gender, event: &Event| {
+            let event_name = event.to_string();
+            provider
+                .get_coefficients(gender, event)
+                .ok_or_else(|| ResultScoreError::CoefficientsNotFound {
+                    gender,
+                    event_name: event_name.clone(),
+                })
+                .and_then(|coefficients| {
+                    coefficients.checked_result_score(performance, &event_name)
+                })
+                .map_err(String::from)
+        },
+        |placement: PlacementScoreCalcInput| {
+            provider
+                .get_placement_table(
+                    &placement.event,
+                    placement.competition_category,
+                    placement.round_type,
+                    placement.size_of_final,
+                    placement.main_event,
+                )?
+                .get(&scored_place(&placement))
+                .copied()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; // Import everything from the parent module
+    use crate::models::*;
+    use crate::scoring_logic::coefficients::{MAX_RESULT_SCORE, TABLE_VERSION};
+    use crate::scoring_logic::engine::ScoringEngine;
+    use crate::scoring_logic::placement_score::RoundType;
+    use assert_approx_eq::assert_approx_eq;
+
+    // --- Mock function for results score calculator ---
+    /// A mock implementation of `result_score_calculator` for testing.
+    /// It simulates the calculation of World Athletics points based on a performance result.
+    /// It will always return the performance
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        _event: &Event,
+    ) -> Result<f64, String> {
+        Ok(performance)
+    }
+    // --- Mock function for placement_score_calculator ---
+    /// A mock implementation of `placement_score_calculator` for testing.
+    /// It returns a fixed score based on the placement.
+    /// This is a simplified mock for testing purposes.
+    /// # Arguments
+    /// * `input` - A `PlacementScoreCalcInput` struct containing placement details.
+    /// # Returns
+    /// An `Option<i32>` representing the placement score.
+    /// This mock simply returns a fixed score based on the place.
+    /// If the place is 1, it returns 100 points; otherwise, it returns 0.
+    fn mock_placement_score_calculator(input: PlacementScoreCalcInput) -> Option<i32> {
+        // For simplicity, let's say 1st place gets 100 points, others get 0.
+        if input.place == 1 {
+            Some(100)
+        } else {
+            Some(0)
+        }
+    }
+
+    /// Tests the `calculate_wind_adjustment` helper function.
+    #[test]
+    fn test_calculate_wind_adjustment() {
+        // Test cases for tailwind (positive wind_value)
+        assert_eq!(calculate_wind_adjustment(Some(0.0)), 0.0); // At 0.0 m/s
+        assert_eq!(calculate_wind_adjustment(Some(1.0)), 0.0); // +1.0 m/s (no deduction <= 2.0)
+        assert_eq!(calculate_wind_adjustment(Some(1.9)), 0.0); // +1.9 m/s (no deduction <= 2.0)
+        assert_eq!(calculate_wind_adjustment(Some(2.0)), 0.0); // +2.0 m/s (no deduction <= 2.0)
+        assert_approx_eq!(calculate_wind_adjustment(Some(2.1)), -12.6); // +2.1 m/s (2.1 * 6 = 12.6, deducted)
+        assert_approx_eq!(calculate_wind_adjustment(Some(2.5)), -15.0); // +2.5 m/s (2.5 * 6 = 15.0, deducted)
+        assert_approx_eq!(calculate_wind_adjustment(Some(3.0)), -18.0); // +3.0 m/s (matches table)
+        assert_approx_eq!(calculate_wind_adjustment(Some(4.0)), -24.0); // +4.0 m/s (matches table)
+
+        // Test cases for headwind (negative wind_value)
+        assert_eq!(calculate_wind_adjustment(Some(-0.0)), 0.0); // Exactly 0.0 m/s
+        assert_approx_eq!(calculate_wind_adjustment(Some(-0.1)), 0.6); // -0.1 m/s (+0.6 pts)
+        assert_approx_eq!(calculate_wind_adjustment(Some(-0.5)), 3.0); // -0.5 m/s (+3.0 pts)
+        assert_approx_eq!(calculate_wind_adjustment(Some(-1.0)), 6.0); // -1.0 m/s (matches table)
+        assert_approx_eq!(calculate_wind_adjustment(Some(-1.5)), 9.0); // -1.5 m/s (+9.0 pts)
+        assert_approx_eq!(calculate_wind_adjustment(Some(-2.0)), 12.0); // -2.0 m/s (matches table)
+        assert_approx_eq!(calculate_wind_adjustment(Some(-3.0)), 18.0); // -3.0 m/s (matches table)
+        assert_approx_eq!(calculate_wind_adjustment(Some(-4.0)), 24.0); // -4.0 m/s (matches table)
+
+        // Test case for No Wind Information (NWI)
+        assert_eq!(calculate_wind_adjustment(None), -30.0);
+    }
+
+    /// Tests the `calculate_downhill_adjustment` helper function.
+    #[test]
+    fn test_calculate_downhill_adjustment() {
+        // Test cases for downhill courses
+        assert_eq!(calculate_downhill_adjustment(None), 0.0); // No downhill data
+        assert_eq!(calculate_downhill_adjustment(Some(0.0)), 0.0); // Flat course
+        assert_eq!(calculate_downhill_adjustment(Some(0.5)), 0.0); // 0.5 m/km (within allowed)
+        assert_eq!(calculate_downhill_adjustment(Some(1.0)), 0.0); // 1.0 m/km (exactly allowed)
+
+        // Beyond allowed limit:
+        assert_approx_eq!(calculate_downhill_adjustment(Some(1.1)), -6.6); // 1.1 m/km: -6 - (0.1*10*0.6) = -6.6
+        assert_approx_eq!(calculate_downhill_adjustment(Some(1.2)), -7.2); // 1.2 m/km: -6 - (0.2*10*0.6) = -7.2
+        assert_approx_eq!(calculate_downhill_adjustment(Some(1.5)), -9.0); // 1.5 m/km: -6 - (0.5*10*0.6) = -9.0
+        assert_approx_eq!(calculate_downhill_adjustment(Some(2.0)), -12.0); // 2.0 m/km: -6 - (1*10*0.6) = -12.0
+        assert_approx_eq!(calculate_downhill_adjustment(Some(3.0)), -18.0); // 3.0 m/km: -6 - (2*10*0.6) = -18.0
+    }
+
+    /// Tests the end-to-end `calculate_world_athletics_score` function using a mock coefficient fetcher.
+    #[test]
+    fn test_calculate_world_athletics_score() {
+        // No need to call load_coefficients() here, as we are mocking the dependency.
+
+        // Test case 1: Men's 100m
+        let input1 = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            performance: Performance::Seconds(10.50), // Example: 10.50 seconds
+            wind_speed: Some(0.0),
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let expected_points1 = 10.50; // 10.50
+        let output1 = calculate_world_athletics_score(
+            input1,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for men's 100m");
+        assert_eq!(output1.points, expected_points1);
+
+        // Test case 2: Women's Long Jump (LJ)
+        let input2 = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::LJ),
+            performance: Performance::Meters(6.50), // Example: 6.50 meters
+            wind_speed: Some(0.0),                  // with no wind we will apply a penalty
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let expected_points2 = 6.5;
+        let output2 = calculate_world_athletics_score(
+            input2,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for women's LJ");
+        assert_eq!(output2.points, expected_points2);
+
+        // Test case 4: Men's 5000m (using a value that would be in seconds)
+        let input4 = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M5000),
+            performance: Performance::Seconds(840.0), // 14 minutes (840 seconds)
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let expected_points4 = 840.0;
+        let output4 = calculate_world_athletics_score(
+            input4,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for men's 5000m");
+        assert_eq!(output4.points, expected_points4);
+
+        // Test case 5: Men's 35km Race Walk. Use a winning position in the final. This should add 100 points.
+        let input5 = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::RaceWalking(RaceWalkingEvent::Road35kmW),
+            performance: Performance::Seconds(9415.0), // Example: 2:36:55
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::A,
+                round: RoundType::Final,
+                place: 1,
+                qualified_to_final: true,
+                tied_with: 0,
+                main_event: false,
+                size_of_final: 12,
+            }),
+            table_edition: None,
+        };
+        let expected_points5 = 9415.0 + 100.0; // 9415.0 + 100 points for placement
+        let output5 = calculate_world_athletics_score(
+            input5,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for men's 35km Race Walk");
+        assert_eq!(output5.points, expected_points5);
+        assert_eq!(output5.result_score, 9415.0);
+        assert_eq!(output5.placement_score, 100);
+
+        // Test case 6: Womens LJ with a -3.0 m/s headwind
+        let input6 = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::LJ),
+            performance: Performance::Meters(6.50), // Example: 6.50 meters
+            wind_speed: Some(-3.0),                 // -3.0 m/s headwind
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let expected_points6 = 6.50 + 18.0; // 6.50 performance + 18.0 points for headwind adjustment
+        let output6 = calculate_world_athletics_score(
+            input6,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for women's LJ with headwind");
+        assert_eq!(output6.points, expected_points6);
+        assert_eq!(output6.wind_adjustment, 18.0);
+
+        // Test case 7: Road Marathon with a downhill course (1.5 m/km drop)
+        let input7 = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::RoadRunning(RoadRunningEvent::RoadMarathon),
+            performance: Performance::Seconds(7200.0), // Example: 2:00:00
+            wind_speed: None,
+            net_downhill: Some(1.5), // 1.5 m/km drop (exceeds the 1.0 m/km allowance)
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let expected_points7 = 7200.0 - 9.0; // 7200.0 - 9.0 points for downhill adjustment
+        let output7 = calculate_world_athletics_score(
+            input7,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for men's Road Marathon with downhill course");
+        assert_eq!(output7.points, expected_points7);
+        assert_eq!(output7.downhill_adjustment, -9.0);
+
+        // Test case 8: Road 10km with a significant downhill course (2.5 m/km drop)
+        let input8 = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::RoadRunning(RoadRunningEvent::Road10km),
+            performance: Performance::Seconds(1800.0), // Example: 30:00
+            wind_speed: None,
+            net_downhill: Some(2.5), // 2.5 m/km drop
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let expected_points8 = 1800.0 - 15.0; // 1800.0 - 15.0 points for downhill adjustment
+        let output8 = calculate_world_athletics_score(
+            input8,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .expect("Calculation failed for women's Road 10km with downhill course");
+        assert_eq!(output8.points, expected_points8);
+    }
+
+    /// Tests that only a performance better than the one scoring the table ceiling is flagged
+    /// as beyond the table range, and that one scoring exactly the ceiling isn't.
+    #[test]
+    fn test_beyond_table_range_flag() {
+        let engine = ScoringEngine::bundled(TABLE_VERSION).unwrap();
+        let m100 = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let ceiling = engine
+            .coefficients()
+            .max_performance(Gender::Men, &m100)
+            .unwrap();
+        let input = |performance| WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: m100.clone(),
+            performance: Performance::Seconds(performance),
+            wind_speed: Some(0.0),
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+
+        let at_ceiling = engine.score(input(ceiling)).unwrap();
+        assert_eq!(at_ceiling.result_score, MAX_RESULT_SCORE);
+        assert!(!at_ceiling.beyond_table_range);
+
+        let beyond = engine.score(input(ceiling - 0.01)).unwrap();
+        assert_eq!(beyond.result_score, MAX_RESULT_SCORE);
+        assert!(beyond.beyond_table_range);
+
+        // Functions score against the app's engine, which is the same bundled edition
+        let scored = |performance| {
+            calculate_world_athletics_score(
+                input(performance),
+                coefficients::calculate_result_score,
+                mock_placement_score_calculator,
+            )
+            .unwrap()
+        };
+        assert!(!scored(ceiling).beyond_table_range);
+        assert!(scored(ceiling - 0.01).beyond_table_range);
+        assert!(!scored(10.5).beyond_table_range);
+    }
+
+    #[test]
+    fn test_altitude_is_flagged_without_changing_points() {
+        let input = |venue_altitude| WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M200),
+            performance: Performance::Seconds(1000.0),
+            wind_speed: Some(0.0),
+            net_downhill: None,
+            venue_altitude,
+            placement_info: None,
+            table_edition: None,
+        };
+        let at_sea_level = calculate_world_athletics_score(
+            input(None),
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .unwrap();
+        let at_altitude = calculate_world_athletics_score(
+            input(Some(2240.0)),
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .unwrap();
+        assert!(!at_sea_level.altitude_assisted);
+        assert!(at_altitude.altitude_assisted);
+        assert_eq!(at_altitude.points, at_sea_level.points);
+    }
+
+    /// Scores every result as its mark and counts the placings it was asked for
+    #[derive(Default)]
+    struct CountingPlacementScorer {
+        calls: std::cell::Cell<u32>,
+    }
+
+    impl PlacementScorer for &CountingPlacementScorer {
+        fn placement_score(&self, input: PlacementScoreCalcInput) -> Option<i32> {
+            self.calls.set(self.calls.get() + 1);
+            Some(100 - input.place * 10)
+        }
+    }
+
+    #[test]
+    fn test_scorer_traits() {
+        let bonus = 5.0;
+        let placement_scorer = CountingPlacementScorer::default();
+        let input = |place| WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::M800),
+            performance: Performance::Seconds(120.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::B,
+                place,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        };
+        // Closures can capture state, and scorers can be any type implementing the traits
+        let scorer = |performance: f64, _: Gender, _: &Event| Ok(performance + bonus);
+        let first = calculate_world_athletics_score(input(1), scorer, &placement_scorer);
+        let third = calculate_world_athletics_score(input(3), scorer, &placement_scorer);
+        assert_eq!(first.unwrap().points, 125.0 + 90.0);
+        assert_eq!(third.unwrap().points, 125.0 + 70.0);
+        assert_eq!(placement_scorer.calls.get(), 2);
+
+        // A mark in the wrong unit is never passed to the result scorer
+        let mut meters = input(1);
+        meters.performance = Performance::Meters(120.0);
+        assert!(calculate_world_athletics_score(meters, scorer, &placement_scorer).is_err());
+    }
+
+    #[test]
+    fn test_tied_places_share_placing_points() {
+        let placement_scorer = CountingPlacementScorer::default();
+        let input = |place, tied_with| WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::HT),
+            performance: Performance::Meters(78.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::A,
+                place,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with,
+                main_event: false,
+            }),
+            table_edition: None,
+        };
+        let score = |place, tied_with| {
+            calculate_world_athletics_score(
+                input(place, tied_with),
+                mock_result_score_calculator,
+                &placement_scorer,
+            )
+            .unwrap()
+            .placement_score
+        };
+        assert_eq!(score(3, 0), 70);
+        // Joint 3rd of two shares the points for 3rd and 4th
+        assert_eq!(score(3, 1), 65);
+        // Three-way tie: (70 + 60 + 50) / 3
+        assert_eq!(score(3, 2), 60);
+        assert_eq!(placement_scorer.calls.get(), 6);
+
+        // A tie running past the end of the tables only looks up the places they cover
+        let huge_tie = PlacementInfo {
+            tied_with: i32::MAX,
+            ..input(3, 0).placement_info.unwrap()
+        };
+        let placing = shared_placement_score(&input(3, 0).event, &huge_tie, &&placement_scorer);
+        assert_eq!(placing, 0);
+        assert_eq!(
+            placement_scorer.calls.get(),
+            6 + (MAX_TABLE_PLACE - 3 + 1) as u32
+        );
+        let tied_at_end = PlacementInfo {
+            place: i32::MAX,
+            tied_with: i32::MAX,
+            ..huge_tie
+        };
+        assert_eq!(
+            shared_placement_score(&input(3, 0).event, &tied_at_end, &&placement_scorer),
+            0
+        );
+        let error = calculate_world_athletics_score(
+            input(3, i32::MAX),
+            mock_result_score_calculator,
+            &placement_scorer,
+        )
+        .unwrap_err();
+        assert!(error.contains("tied"));
+        assert!(calculate_world_athletics_score(
+            input(0, 0),
+            mock_result_score_calculator,
+            &placement_scorer
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_cross_country_is_scored_on_placing() {
+        let mut input = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::CrossCountry(CrossCountryEvent::XcChampionship),
+            performance: Performance::Seconds(1800.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        assert!(calculate_world_athletics_score(
+            input.clone(),
+            mock_result_score_calculator,
+            mock_placement_score_calculator
+        )
+        .is_err());
+
+        input.placement_info = Some(PlacementInfo {
+            competition_category: CompetitionCategory::GW,
+            place: 1,
+            round: RoundType::Final,
+            size_of_final: 8,
+            qualified_to_final: false,
+            tied_with: 0,
+            main_event: false,
+        });
+        let output = calculate_world_athletics_score(
+            input,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .unwrap();
+        assert_eq!(output.result_score, 0.0);
+        assert_eq!(output.points, 100.0);
+    }
+
+    #[test]
+    fn test_scores_by_category() {
+        let mut input = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M1500),
+            performance: Performance::Seconds(1200.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let placement_scorer = |input: PlacementScoreCalcInput| match input.competition_category {
+            CompetitionCategory::OW => Some(100),
+            CompetitionCategory::A => Some(50),
+            _ => None,
+        };
+        assert!(
+            scores_by_category(&input, mock_result_score_calculator, placement_scorer).is_err()
+        );
+
+        input.placement_info = Some(PlacementInfo {
+            competition_category: CompetitionCategory::F,
+            place: 1,
+            round: RoundType::Final,
+            size_of_final: 8,
+            qualified_to_final: false,
+            tied_with: 0,
+            main_event: false,
+        });
+        let scores =
+            scores_by_category(&input, mock_result_score_calculator, placement_scorer).unwrap();
+        assert_eq!(scores.len(), 10);
+        assert_eq!(scores[0].0, CompetitionCategory::OW);
+        assert_eq!(scores[0].1.points, 1300.0);
+        assert_eq!(scores[9].0, CompetitionCategory::F);
+        assert_eq!(scores[9].1.points, 1200.0);
+        let a = scores
+            .iter()
+            .find(|(category, _)| *category == CompetitionCategory::A)
+            .unwrap();
+        assert_eq!(a.1.placement_score, 50);
+    }
+
+    #[test]
+    fn test_scores_by_wind() {
+        let mut input = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            performance: Performance::Seconds(1100.0),
+            wind_speed: Some(1.0),
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let scores = scores_by_wind(
+            &input,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .unwrap();
+        assert_eq!(scores.len(), 17);
+        assert_eq!(scores[0].0, -4.0);
+        assert_approx_eq!(scores[0].1.points, 1124.0);
+        assert_eq!(scores[8].0, 0.0);
+        assert_approx_eq!(scores[8].1.points, 1100.0);
+        assert_approx_eq!(scores[12].1.points, 1100.0); // +2.0 m/s
+        assert_approx_eq!(scores[16].1.points, 1076.0);
+
+        input.event = Event::TrackAndField(TrackAndFieldEvent::M400);
+        assert!(scores_by_wind(
+            &input,
+            mock_result_score_calculator,
+            mock_placement_score_calculator
+        )
+        .is_err());
+    }
+}
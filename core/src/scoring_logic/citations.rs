@@ -0,0 +1,192 @@
+//! Rule references for each component of a score breakdown, so a score can be audited
+//! against the regulations it was calculated from.
+use crate::models::WorldAthleticsScoreOutput;
+
+/// A reference to the rule behind a score component
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleCitation {
+    /// Stable identifier, used in exports
+    pub id: &'static str,
+    /// Where the rule is published
+    pub citation: &'static str,
+    /// What the rule says, in a sentence
+    pub summary: &'static str,
+}
+
+pub const RESULT_SCORE: RuleCitation = RuleCitation {
+    id: "WA-SCORING-TABLES",
+    citation: "World Athletics Scoring Tables of Athletics",
+    summary: "The result score is read from the scoring tables for the event, gender and mark.",
+};
+
+pub const ESTIMATED_RESULT_SCORE: RuleCitation = RuleCitation {
+    id: "ESTIMATED-COEFFICIENTS",
+    citation: "Estimated coefficients, not part of the World Athletics Scoring Tables",
+    summary: "The scoring tables don't include the event, so the result score comes from an official event's curve with the times scaled.",
+};
+
+pub const ESTIMATED_PLACING_SCORE: RuleCitation = RuleCitation {
+    id: "ESTIMATED-PLACING-POINTS",
+    citation: "Estimated placing points, not part of the World Athletics placing tables",
+    summary: "The placing tables don't score the round, so the placing points are estimates.",
+};
+
+pub const TABLE_CEILING: RuleCitation = RuleCitation {
+    id: "WA-SCORING-TABLES-CEILING",
+    citation: "World Athletics Scoring Tables of Athletics",
+    summary: "The tables stop at 1400 points; marks beyond them score the table maximum.",
+};
+
+pub const WIND_MODIFICATION: RuleCitation = RuleCitation {
+    id: "WA-RANKINGS-WIND",
+    citation: "World Athletics World Rankings Rules, wind modification table",
+    summary: "1 m/s of wind is worth 6 points. Headwind adds points, tailwind over +2.0 m/s deducts them from 0.0 m/s, and no wind information deducts 30 points.",
+};
+
+pub const DOWNHILL_MODIFICATION: RuleCitation = RuleCitation {
+    id: "WA-RANKINGS-DOWNHILL",
+    citation: "World Athletics World Rankings Rules, road course net drop modification",
+    summary: "Road courses dropping more than 1 m/km lose 6 points, plus 0.6 points for every further 0.1 m/km.",
+};
+
+pub const PLACING_SCORE: RuleCitation = RuleCitation {
+    id: "WA-RANKINGS-PLACING",
+    citation: "World Athletics World Rankings Rules, placing score tables",
+    summary: "Points for the place achieved, by competition category, round and size of the final.",
+};
+
+/// One line of a score breakdown with the rule it comes from
+#[derive(Debug, Clone, PartialEq)]
+pub struct BreakdownComponent {
+    pub label: &'static str,
+    pub points: f64,
+    pub citation: RuleCitation,
+}
+
+/// The components that make up the score, each with its rule citation. The result score
+/// is always listed; adjustments only when they changed the score.
+pub fn score_breakdown(score: &WorldAthleticsScoreOutput) -> Vec<BreakdownComponent> {
+    let mut components = vec![if score.result_estimated {
+        BreakdownComponent {
+            label: "Result score (estimated)",
+            points: score.result_score,
+            citation: ESTIMATED_RESULT_SCORE,
+        }
+    } else {
+        BreakdownComponent {
+            label: "Result score",
+            points: score.result_score,
+            citation: RESULT_SCORE,
+        }
+    }];
+    if score.beyond_table_range {
+        components.push(BreakdownComponent {
+            label: "Capped at table maximum",
+            points: 0.0,
+            citation: TABLE_CEILING,
+        });
+    }
+    if score.wind_adjustment != 0.0 {
+        components.push(BreakdownComponent {
+            label: "Wind adjustment",
+            points: score.wind_adjustment,
+            citation: WIND_MODIFICATION,
+        });
+    }
+    if score.downhill_adjustment != 0.0 {
+        components.push(BreakdownComponent {
+            label: "Downhill adjustment",
+            points: score.downhill_adjustment,
+            citation: DOWNHILL_MODIFICATION,
+        });
+    }
+    if score.placement_score != 0 {
+        components.push(if score.placement_estimated {
+            BreakdownComponent {
+                label: "Placing score (estimated)",
+                points: score.placement_score as f64,
+                citation: ESTIMATED_PLACING_SCORE,
+            }
+        } else {
+            BreakdownComponent {
+                label: "Placing score",
+                points: score.placement_score as f64,
+                citation: PLACING_SCORE,
+            }
+        });
+    }
+    components
+}
+
+/// The breakdown as CSV rows with a header, one row per component plus the total
+pub fn breakdown_rows(score: &WorldAthleticsScoreOutput) -> Vec<Vec<String>> {
+    let mut rows = vec![vec![
+        "Component".to_string(),
+        "Points".to_string(),
+        "Rule".to_string(),
+        "Citation".to_string(),
+    ]];
+    rows.extend(score_breakdown(score).into_iter().map(|component| {
+        vec![
+            component.label.to_string(),
+            component.points.to_string(),
+            component.citation.id.to_string(),
+            component.citation.citation.to_string(),
+        ]
+    }));
+    rows.push(vec![
+        "Total".to_string(),
+        score.points.to_string(),
+        String::new(),
+        String::new(),
+    ]);
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_breakdown_cites_each_adjustment() {
+        let score = WorldAthleticsScoreOutput {
+            points: 1140.0,
+            result_score: 1150.0,
+            wind_adjustment: -30.0,
+            downhill_adjustment: 0.0,
+            placement_score: 20,
+            beyond_table_range: false,
+            estimated: false,
+            result_estimated: false,
+            placement_estimated: false,
+            altitude_assisted: false,
+        };
+        let breakdown = score_breakdown(&score);
+        let ids: Vec<&str> = breakdown.iter().map(|c| c.citation.id).collect();
+        assert_eq!(
+            ids,
+            vec![RESULT_SCORE.id, WIND_MODIFICATION.id, PLACING_SCORE.id]
+        );
+
+        let rows = breakdown_rows(&score);
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[2][2], WIND_MODIFICATION.id);
+        assert_eq!(rows[4], vec!["Total", "1140", "", ""]);
+
+        let estimated = score_breakdown(&WorldAthleticsScoreOutput {
+            estimated: true,
+            result_estimated: true,
+            ..score.clone()
+        });
+        assert_eq!(estimated[0].citation, ESTIMATED_RESULT_SCORE);
+        assert_eq!(estimated[2].citation, PLACING_SCORE);
+
+        let estimated_placing = score_breakdown(&WorldAthleticsScoreOutput {
+            estimated: true,
+            placement_estimated: true,
+            ..score
+        });
+        assert_eq!(estimated_placing[0].citation, RESULT_SCORE);
+        assert_eq!(estimated_placing[2].citation, ESTIMATED_PLACING_SCORE);
+    }
+}
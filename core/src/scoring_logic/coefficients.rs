@@ -0,0 +1,784 @@
+// src/scoring_logic/data_tables.rs
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+
+use super::bundled::COEFFICIENT_EDITIONS;
+use super::engine::with_scoring_engine;
+use crate::models::{Event, Gender};
+
+/// The highest result score covered by the official scoring tables.
+/// Performances beyond this are clamped rather than extrapolated along the quadratic.
+pub const MAX_RESULT_SCORE: f64 = 1400.0;
+
+/// The edition of the scoring tables used by default
+pub const TABLE_VERSION: &str = "2025";
+
+/// The names of the bundled table editions, oldest first, e.g. for a selector. Adding an
+/// edition means adding its `world_athletics_constants_<edition>.json` data file.
+pub fn table_editions() -> Vec<String> {
+    COEFFICIENT_EDITIONS
+        .iter()
+        .map(|(edition, _)| edition.to_string())
+        .collect()
+}
+
+/// Whether the named edition's tables are bundled
+pub fn is_bundled_edition(edition: &str) -> bool {
+    COEFFICIENT_EDITIONS
+        .iter()
+        .any(|(name, _)| *name == edition)
+}
+
+/// The coefficients of a bundled edition
+pub fn edition_coefficients(edition: &str) -> Result<CoefficientsTable, String> {
+    let (_, rows) = COEFFICIENT_EDITIONS
+        .iter()
+        .find(|(name, _)| *name == edition)
+        .ok_or_else(|| format!("Scoring tables for the {} edition are not bundled", edition))?;
+    Ok(CoefficientsTable::from_rows(rows))
+}
+
+/// Estimated coefficients for events the official tables don't include, from
+/// `estimated_coefficients.json`. They're kept apart from the official editions and only
+/// fill gaps (see `CoefficientsTable::with_estimates`).
+pub fn estimated_coefficients() -> &'static CoefficientsTable {
+    static ESTIMATES: OnceLock<CoefficientsTable> = OnceLock::new();
+    ESTIMATES.get_or_init(|| {
+        serde_json::from_str(include_str!("../../data/estimated_coefficients.json"))
+            .expect("estimated_coefficients.json is valid")
+    })
+}
+
+// This struct now represents the three coefficients in the array
+#[derive(Debug, Deserialize, Clone)]
+pub struct Coefficients {
+    // These fields will be populated from the array elements
+    pub conversion_factor: f64,
+    pub result_shift: f64,
+    pub point_shift: f64,
+}
+
+// A helper struct to correctly deserialize the [f64, f64, f64] array
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)] // Allows deserializing from multiple types, here specifically from an array
+pub enum RawCoefficients {
+    Array([f64; 3]), // For when it's an array of 3 floats
+}
+
+// Implement conversion from RawCoefficients to Coefficients
+impl From<RawCoefficients> for Coefficients {
+    fn from(raw: RawCoefficients) -> Self {
+        match raw {
+            RawCoefficients::Array([cf, rs, ps]) => Coefficients {
+                conversion_factor: cf,
+                result_shift: rs,
+                point_shift: ps,
+            },
+        }
+    }
+}
+
+/// The performances an event's tables cover, in its standard unit. Performances better than
+/// `best` are clamped to `MAX_RESULT_SCORE`; below `worst` (the 0 point mark) the quadratic
+/// turns back up and produces meaningless scores, so they are rejected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerformanceRange {
+    /// The performance scoring `MAX_RESULT_SCORE`
+    pub best: f64,
+    /// The performance scoring 0 points
+    pub worst: f64,
+}
+
+impl PerformanceRange {
+    /// Whether a performance is a valid mark no worse than the bottom of the tables.
+    /// Performances better than `best` are covered (they clamp).
+    pub fn covers(&self, performance: f64) -> bool {
+        if !performance.is_finite() || performance <= 0.0 {
+            return false;
+        }
+        if self.best < self.worst {
+            performance <= self.worst
+        } else {
+            performance >= self.worst
+        }
+    }
+
+    /// The range in ascending order, e.g. (9.4, 17.3) for a time or (2.5, 8.9) for a distance
+    pub fn ascending(&self) -> (f64, f64) {
+        (self.best.min(self.worst), self.best.max(self.worst))
+    }
+}
+
+/// Why a result score couldn't be calculated
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResultScoreError {
+    CoefficientsNotFound {
+        gender: Gender,
+        event_name: String,
+    },
+    /// The performance is worse than the tables cover, or isn't a valid mark at all
+    OutOfRange {
+        event_name: String,
+        performance: f64,
+        range: PerformanceRange,
+    },
+}
+
+impl fmt::Display for ResultScoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResultScoreError::CoefficientsNotFound { gender, event_name } => write!(
+                f,
+                "Coefficients not found for gender {} and event: {}",
+                gender, event_name,
+            ),
+            ResultScoreError::OutOfRange {
+                event_name,
+                performance,
+                range,
+            } => {
+                let (low, high) = range.ascending();
+                write!(
+                    f,
+                    "{} is outside the range the {} scoring tables cover ({:.2} to {:.2})",
+                    performance, event_name, low, high,
+                )
+            }
+        }
+    }
+}
+
+impl From<ResultScoreError> for String {
+    fn from(error: ResultScoreError) -> Self {
+        error.to_string()
+    }
+}
+
+impl Coefficients {
+    /// Calculates the result score for a performance.
+    ///
+    /// The formula is: `points = floor(conversionFactor * (result + resultShift)^2 + pointShift)`
+    ///
+    /// Performances beyond the top of the tables are clamped to `MAX_RESULT_SCORE`.
+    pub fn result_score(&self, result: f64) -> f64 {
+        if self.is_beyond_table_range(result) {
+            return MAX_RESULT_SCORE;
+        }
+        // coefficients[0] * x * x + coefficients[1] * x + coefficients[2]
+        let raw_points = self.conversion_factor * result * result
+            + self.result_shift * result
+            + self.point_shift;
+        raw_points.round()
+    }
+
+    /// `result_score`, rejecting performances outside `valid_range`
+    pub fn checked_result_score(
+        &self,
+        result: f64,
+        event_name: &str,
+    ) -> Result<f64, ResultScoreError> {
+        if !self.valid_range().covers(result) {
+            return Err(ResultScoreError::OutOfRange {
+                event_name: event_name.to_string(),
+                performance: result,
+                range: self.valid_range(),
+            });
+        }
+        Ok(self.result_score(result))
+    }
+
+    /// Calculates the performance required to achieve a given result score.
+    /// This is the inverse of `result_score`, solving the quadratic for the result.
+    ///
+    /// Time-based events have a negative `result_shift` and score on the falling side of the
+    /// quadratic, so the lower root is used. Distance and points events use the upper root.
+    /// Returns `None` if the score is unreachable.
+    pub fn performance_for_score(&self, points: f64) -> Option<f64> {
+        if self.conversion_factor == 0.0 {
+            // A linear table (only seen in custom tables)
+            return (self.result_shift != 0.0)
+                .then(|| (points - self.point_shift) / self.result_shift);
+        }
+        let discriminant = self.result_shift * self.result_shift
+            - 4.0 * self.conversion_factor * (self.point_shift - points);
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root_offset = discriminant.sqrt();
+        let performance = if self.result_shift < 0.0 {
+            (-self.result_shift - root_offset) / (2.0 * self.conversion_factor)
+        } else {
+            (-self.result_shift + root_offset) / (2.0 * self.conversion_factor)
+        };
+        Some(performance)
+    }
+
+    /// The performances the tables cover. Where the quadratic never falls to 0 points the
+    /// worst bound is its turning point.
+    pub fn valid_range(&self) -> PerformanceRange {
+        let vertex = -self.result_shift / (2.0 * self.conversion_factor);
+        PerformanceRange {
+            best: self
+                .performance_for_score(MAX_RESULT_SCORE)
+                .unwrap_or(vertex),
+            worst: self.performance_for_score(0.0).unwrap_or(vertex),
+        }
+    }
+
+    /// Determines whether a performance is better than the top of the official tables.
+    pub fn is_beyond_table_range(&self, result: f64) -> bool {
+        let Some(bound) = self.performance_for_score(MAX_RESULT_SCORE) else {
+            return false;
+        };
+        if self.result_shift < 0.0 {
+            result < bound
+        } else {
+            result > bound
+        }
+    }
+}
+
+/// A single row of a generated points table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreTableRow {
+    pub points: u32,
+    /// The performance in the event's standard unit (seconds, meters or points)
+    pub performance: f64,
+}
+
+// Represents the coefficients for a single gender (e.g., "men" or "women") as they appear
+// in the JSON, keyed by event name
+#[derive(Debug, Deserialize, Clone)]
+pub struct GenderCoefficients {
+    #[serde(flatten)] // This tells Serde to put all top-level keys into the HashMap
+    pub events: HashMap<String, RawCoefficients>,
+}
+
+// The top-level structure of your JSON
+#[derive(Debug, Deserialize, Clone)]
+struct RawCoefficientsTable {
+    men: GenderCoefficients,
+    women: GenderCoefficients,
+}
+
+/// The coefficients of every event in the tables, keyed by gender and event. The JSON's
+/// event names are matched to events when the tables are loaded; names that match no event
+/// are listed in `unmatched_keys` rather than silently never being looked up.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(from = "RawCoefficientsTable")]
+pub struct CoefficientsTable {
+    events: HashMap<(Gender, Event), Coefficients>,
+    unmatched_keys: Vec<String>,
+    /// Entries filled in from estimates rather than the tables themselves
+    estimated: HashSet<(Gender, Event)>,
+}
+
+impl From<RawCoefficientsTable> for CoefficientsTable {
+    fn from(raw: RawCoefficientsTable) -> Self {
+        let entries = [(Gender::Men, raw.men), (Gender::Women, raw.women)]
+            .into_iter()
+            .flat_map(|(gender, coefficients)| {
+                coefficients
+                    .events
+                    .into_iter()
+                    .map(move |(name, raw)| (gender.to_string(), name, raw.into()))
+            });
+        CoefficientsTable::from_entries(entries)
+    }
+}
+
+impl CoefficientsTable {
+    /// Matches each `(gender, event name, coefficients)` entry to a gender and event
+    fn from_entries(entries: impl IntoIterator<Item = (String, String, Coefficients)>) -> Self {
+        let mut events = HashMap::new();
+        let mut unmatched_keys = Vec::new();
+        for (gender, name, coefficients) in entries {
+            match (Gender::from_string(&gender), Event::from_string(&name)) {
+                (Some(gender), Some(event)) => {
+                    events.insert((gender, event), coefficients);
+                }
+                _ => unmatched_keys.push(format!("{}: {}", gender, name)),
+            }
+        }
+        unmatched_keys.sort();
+        CoefficientsTable {
+            events,
+            unmatched_keys,
+            estimated: HashSet::new(),
+        }
+    }
+
+    /// Tables from `(gender, event name, [conversion factor, result shift, point shift])`
+    /// rows, as `build.rs` generates them for the bundled editions
+    pub fn from_rows(rows: &[(&str, &str, [f64; 3])]) -> Self {
+        CoefficientsTable::from_entries(rows.iter().map(|(gender, name, coefficients)| {
+            (
+                gender.to_string(),
+                name.to_string(),
+                RawCoefficients::Array(*coefficients).into(),
+            )
+        }))
+    }
+
+    /// Retrieves the coefficients for a specific event and gender.
+    /// Returns `None` if the event or gender is not found.
+    pub fn get_coefficients(&self, gender: Gender, event: &Event) -> Option<Coefficients> {
+        self.events.get(&(gender, event.clone())).cloned()
+    }
+
+    /// These tables, with `estimates` filling in the events they don't have. Those entries
+    /// are reported by `is_estimated`, so scores from them can be marked as estimates.
+    pub fn with_estimates(mut self, estimates: &CoefficientsTable) -> Self {
+        for (key, coefficients) in &estimates.events {
+            if !self.events.contains_key(key) {
+                self.events.insert(key.clone(), coefficients.clone());
+                self.estimated.insert(key.clone());
+            }
+        }
+        self
+    }
+
+    /// Whether the event's coefficients are an estimate rather than from the tables
+    pub fn is_estimated(&self, gender: Gender, event: &Event) -> bool {
+        self.estimated.contains(&(gender, event.clone()))
+    }
+
+    /// The JSON keys that matched no event, as "gender: name", e.g. "men: Road 100 km"
+    pub fn unmatched_keys(&self) -> &[String] {
+        &self.unmatched_keys
+    }
+
+    /// Calculates the performance required to achieve a given result score.
+    /// See `Coefficients::performance_for_score`.
+    /// Returns `None` if the event or gender is not found or the score is unreachable.
+    pub fn performance_for_score(&self, points: f64, gender: Gender, event: &Event) -> Option<f64> {
+        self.get_coefficients(gender, event)?
+            .performance_for_score(points)
+    }
+
+    /// Retrieves the performance that scores `MAX_RESULT_SCORE` for an event and gender,
+    /// i.e. the upper bound of the official tables.
+    pub fn max_performance(&self, gender: Gender, event: &Event) -> Option<f64> {
+        self.performance_for_score(MAX_RESULT_SCORE, gender, event)
+    }
+
+    /// Generates the points table for an event, from `step` points up to `MAX_RESULT_SCORE`.
+    pub fn score_table(
+        &self,
+        gender: Gender,
+        event: &Event,
+        step: usize,
+    ) -> Result<Vec<ScoreTableRow>, String> {
+        if step == 0 {
+            return Err("Table step must be at least 1 point".to_string());
+        }
+        (step..=MAX_RESULT_SCORE as usize)
+            .step_by(step)
+            .map(|points| {
+                self.performance_for_score(points as f64, gender, event)
+                    .map(|performance| ScoreTableRow {
+                        points: points as u32,
+                        performance,
+                    })
+                    .ok_or_else(|| {
+                        format!(
+                            "Coefficients not found for gender {} and event: {}",
+                            gender, event,
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Determines whether a performance is better than the top of the official tables.
+    pub fn is_beyond_table_range(&self, result: f64, gender: Gender, event: &Event) -> bool {
+        self.get_coefficients(gender, event)
+            .is_some_and(|c| c.is_beyond_table_range(result))
+    }
+
+    /// Calculates the points based on a result and the event-specific coefficients.
+    /// See `Coefficients::result_score`.
+    ///
+    /// # Arguments
+    /// * `result` - The performance result in the standard unit (e.g., seconds for track, meters for field).
+    /// * 'gender' - The gender of the competitor
+    /// * 'event' - The event
+    /// # Errors
+    /// `OutOfRange` for performances worse than the tables cover (see `PerformanceRange`)
+    pub fn calculate_result_score(
+        &self,
+        result: f64,
+        gender: Gender,
+        event: &Event,
+    ) -> Result<f64, ResultScoreError> {
+        let event_name = event.to_string();
+        let coefficients = self.get_coefficients(gender, event).ok_or_else(|| {
+            ResultScoreError::CoefficientsNotFound {
+                gender,
+                event_name: event_name.clone(),
+            }
+        })?;
+        coefficients.checked_result_score(result, &event_name)
+    }
+
+    /// The performances the tables cover for an event, or `None` if it isn't in the tables
+    pub fn valid_range(&self, gender: Gender, event: &Event) -> Option<PerformanceRange> {
+        self.get_coefficients(gender, event)
+            .map(|coefficients| coefficients.valid_range())
+    }
+}
+
+pub fn calculate_result_score(result: f64, gender: Gender, event: &Event) -> Result<f64, String> {
+    with_coefficients(|coefficients| {
+        coefficients
+            .calculate_result_score(result, gender, event)
+            .map_err(String::from)
+    })
+}
+
+/// Whether a performance is better than the top of the app engine's tables
+pub fn is_beyond_table_range(result: f64, gender: Gender, event: &Event) -> bool {
+    with_coefficients(|coefficients| Ok(coefficients.is_beyond_table_range(result, gender, event)))
+        .unwrap_or(false)
+}
+
+/// Whether the app engine scores the event with estimated coefficients
+pub fn is_estimated(gender: Gender, event: &Event) -> bool {
+    with_coefficients(|coefficients| Ok(coefficients.is_estimated(gender, event))).unwrap_or(false)
+}
+
+pub fn valid_range(gender: Gender, event: &Event) -> Result<PerformanceRange, String> {
+    with_coefficients(|coefficients| {
+        coefficients.valid_range(gender, event).ok_or_else(|| {
+            format!(
+                "Coefficients not found for gender {} and event: {}",
+                gender, event,
+            )
+        })
+    })
+}
+
+pub fn get_coefficients(gender: Gender, event: &Event) -> Result<Coefficients, String> {
+    with_coefficients(|coefficients| {
+        coefficients.get_coefficients(gender, event).ok_or_else(|| {
+            format!(
+                "Coefficients not found for gender {} and event: {}",
+                gender, event,
+            )
+        })
+    })
+}
+
+pub fn performance_for_score(points: f64, gender: Gender, event: &Event) -> Result<f64, String> {
+    with_coefficients(|coefficients| {
+        coefficients
+            .performance_for_score(points, gender, event)
+            .ok_or_else(|| {
+                format!(
+                    "No {} performance scores {} points for gender {}",
+                    event, points, gender,
+                )
+            })
+    })
+}
+
+pub fn score_table(
+    gender: Gender,
+    event: &Event,
+    step: usize,
+) -> Result<Vec<ScoreTableRow>, String> {
+    with_coefficients(|coefficients| coefficients.score_table(gender, event, step))
+}
+
+/// Runs `f` against the loaded engine's coefficients
+fn with_coefficients<T>(
+    f: impl FnOnce(&CoefficientsTable) -> Result<T, String>,
+) -> Result<T, String> {
+    with_scoring_engine(|engine| f(engine.coefficients()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_approx_eq::assert_approx_eq;
+
+    /// An event by its code, e.g. "LJ"
+    fn event(code: &str) -> Event {
+        code.parse().unwrap()
+    }
+
+    // A minimal JSON string for testing parsing without relying on the file system
+    const TEST_JSON_DATA: &str = r#"{
+        "men": {
+            "100m": [24.642211664166098, -837.7135408530303, 7119.3125116789015],
+            "Long Jump": [1.931092872960562, 186.73134733641928, -479.70640445759636],
+            "5000m": [0.002777997945427213,  -8.000608112196687,5760.418712362531]
+        },
+        "women": {
+            "100m": [9.927426450685289, -436.6751262119069, 4802.020943877404],
+            "High Jump": [39.557908744493034, 831.3655724464043, -601.5063267494843],
+            "Long Jump": [1.958114032649064, 193.69548254413166,-233.98988652729167]
+        }
+    }"#;
+
+    /// Tests the direct parsing of a JSON string into the CoefficientsTable struct.
+    #[test]
+    fn test_json_parsing_direct() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        // Test men's 100m
+        let men_100m_coefficients = table
+            .events
+            .get(&(Gender::Men, event("100m")))
+            .expect("Men's 100m coefficients not found");
+        assert_approx_eq!(men_100m_coefficients.conversion_factor, 24.642211664166098);
+        assert_approx_eq!(men_100m_coefficients.result_shift, -837.7135408530303);
+        assert_approx_eq!(men_100m_coefficients.point_shift, 7119.3125116789015);
+
+        // Test women's HJ
+        let women_hj_coefficients = table
+            .events
+            .get(&(Gender::Women, event("HJ")))
+            .expect("Women's HJ coefficients not found");
+        assert_approx_eq!(women_hj_coefficients.conversion_factor, 39.557908744493034);
+        assert_approx_eq!(women_hj_coefficients.result_shift, 831.3655724464043);
+        assert_approx_eq!(women_hj_coefficients.point_shift, -601.5063267494843);
+
+        // Test an event missing from the tables
+        assert!(!table.events.contains_key(&(Gender::Men, event("HJ"))));
+        assert!(table.unmatched_keys().is_empty());
+    }
+
+    #[test]
+    fn test_unmatched_keys_are_reported() {
+        let table: CoefficientsTable = serde_json::from_str(
+            r#"{"men": {"100m": [24.6, -837.7, 7119.3], "100 metres": [24.6, -837.7, 7119.3]},
+                "women": {"Road 100 km": [0.0, -1.0, 100.0]}}"#,
+        )
+        .unwrap();
+        assert!(table
+            .get_coefficients(Gender::Men, &event("100m"))
+            .is_some());
+        assert_eq!(
+            table.unmatched_keys(),
+            ["men: 100 metres", "women: Road 100 km"]
+        );
+        assert!(matches!(
+            table.calculate_result_score(10.0, Gender::Men, &event("LJ")),
+            Err(ResultScoreError::CoefficientsNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_get_coefficients_function_integration() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        // Test retrieving men's LJ
+        let men_lj_coefficients = table
+            .get_coefficients(Gender::Men, &event("LJ"))
+            .expect("Failed to get men's LJ coefficients");
+        assert_approx_eq!(men_lj_coefficients.conversion_factor, 1.931092872960562);
+        assert_approx_eq!(men_lj_coefficients.result_shift, 186.73134733641928);
+        assert_approx_eq!(men_lj_coefficients.point_shift, -479.7064044575963);
+
+        // Test retrieving women's 100m
+        let women_100m_coefficients = table
+            .get_coefficients(Gender::Women, &event("100m"))
+            .expect("Failed to get women's 100m coefficients");
+        assert_approx_eq!(women_100m_coefficients.conversion_factor, 9.927426450685289);
+        assert_approx_eq!(women_100m_coefficients.result_shift, -436.6751262119069);
+        assert_approx_eq!(women_100m_coefficients.point_shift, 4802.020943877404);
+
+        // Test a non-existent event for a specific gender
+        assert!(table.get_coefficients(Gender::Men, &event("HJ")).is_none());
+        assert!(table
+            .get_coefficients(Gender::Women, &event("5000m"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_calculate_placement_score() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        // A Men's 100m result of 10.5 seconds should yield 1040.0 points
+        let points = table.calculate_result_score(10.5, Gender::Men, &event("100m"));
+        assert!(points.is_ok());
+        let points = points.unwrap();
+        assert_approx_eq!(points, 1040.0);
+
+        // A womens long jump of 6.5 meters should result in 1108.0 points
+        let points = table.calculate_result_score(6.5, Gender::Women, &event("LJ"));
+        assert!(points.is_ok());
+        let points = points.unwrap();
+        assert_approx_eq!(points, 1108.0);
+
+        // Test with a non-existent event
+        let points = table.calculate_result_score(10.0, Gender::Men, &event("HJ"));
+        assert!(points.is_err());
+
+        // Test with a 5k value of 14 minutes (840 seconds) that should yield 1000.0 points
+        let points = table.calculate_result_score(840.0, Gender::Men, &event("5000m"));
+        assert!(points.is_ok());
+        let points = points.unwrap();
+        assert_approx_eq!(points, 1000.0);
+    }
+
+    #[test]
+    fn test_max_performance() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        // Time events are bounded from below, distance events from above
+        let men_100m = table.max_performance(Gender::Men, &event("100m")).unwrap();
+        assert!(men_100m > 9.0 && men_100m < 10.0);
+        let women_lj = table.max_performance(Gender::Women, &event("LJ")).unwrap();
+        assert!(women_lj > 7.0 && women_lj < 8.5);
+
+        // Both bounds score exactly the table ceiling
+        assert_approx_eq!(
+            table
+                .calculate_result_score(men_100m, Gender::Men, &event("100m"))
+                .unwrap(),
+            MAX_RESULT_SCORE
+        );
+        assert_approx_eq!(
+            table
+                .calculate_result_score(women_lj, Gender::Women, &event("LJ"))
+                .unwrap(),
+            MAX_RESULT_SCORE
+        );
+
+        assert!(table.max_performance(Gender::Men, &event("HJ")).is_none());
+    }
+
+    #[test]
+    fn test_performance_for_score_roundtrip() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        // A 14:00 5000m scores 1000 points, so 1000 points should map back to ~840s
+        let performance = table
+            .performance_for_score(1000.0, Gender::Men, &event("5000m"))
+            .unwrap();
+        assert!((performance - 840.0).abs() < 0.5);
+
+        let performance = table
+            .performance_for_score(1108.0, Gender::Women, &event("LJ"))
+            .unwrap();
+        assert_approx_eq!(
+            table
+                .calculate_result_score(performance, Gender::Women, &event("LJ"))
+                .unwrap(),
+            1108.0
+        );
+
+        assert!(table
+            .performance_for_score(1000.0, Gender::Men, &event("HJ"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_score_table() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        let rows = table.score_table(Gender::Men, &event("100m"), 100).unwrap();
+        assert_eq!(rows.len(), 14);
+        assert_eq!(rows[0].points, 100);
+        assert_eq!(rows[13].points, 1400);
+        // Faster times score more points
+        assert!(rows.windows(2).all(|w| w[0].performance > w[1].performance));
+
+        assert!(table.score_table(Gender::Men, &event("100m"), 0).is_err());
+        assert!(table.score_table(Gender::Men, &event("HJ"), 1).is_err());
+    }
+
+    #[test]
+    fn test_super_world_record_is_clamped() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        // A 9.00 100m would extrapolate well past 1400 points
+        assert!(table.is_beyond_table_range(9.0, Gender::Men, &event("100m")));
+        assert_approx_eq!(
+            table
+                .calculate_result_score(9.0, Gender::Men, &event("100m"))
+                .unwrap(),
+            MAX_RESULT_SCORE
+        );
+
+        // A 9.00m women's long jump likewise
+        assert!(table.is_beyond_table_range(9.0, Gender::Women, &event("LJ")));
+        assert_approx_eq!(
+            table
+                .calculate_result_score(9.0, Gender::Women, &event("LJ"))
+                .unwrap(),
+            MAX_RESULT_SCORE
+        );
+
+        // Ordinary performances are untouched
+        assert!(!table.is_beyond_table_range(10.5, Gender::Men, &event("100m")));
+        assert!(!table.is_beyond_table_range(6.5, Gender::Women, &event("LJ")));
+    }
+
+    #[test]
+    fn test_bundled_editions_parse() {
+        assert!(is_bundled_edition(TABLE_VERSION));
+        assert!(!is_bundled_edition("1999"));
+        for edition in table_editions() {
+            let table = edition_coefficients(&edition).unwrap();
+            assert!(table
+                .get_coefficients(Gender::Men, &event("100m"))
+                .is_some());
+            // The only keys without an event are events the calculator doesn't offer
+            for key in table.unmatched_keys() {
+                let (_, name) = key.split_once(": ").unwrap();
+                assert!(
+                    ["Mile", "2 Miles", "Road 3km Walk", "Road 100 km"].contains(&name),
+                    "{}",
+                    key
+                );
+            }
+        }
+        assert!(edition_coefficients("1999").is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_performances_are_rejected() {
+        let table: CoefficientsTable =
+            serde_json::from_str(TEST_JSON_DATA).expect("Failed to parse test JSON data");
+
+        let range = table.valid_range(Gender::Men, &event("100m")).unwrap();
+        let (low, high) = range.ascending();
+        assert!(low > 9.0 && low < 10.0);
+        assert!(high > 14.0 && high < 20.0);
+        assert!(table
+            .calculate_result_score(high - 0.01, Gender::Men, &event("100m"))
+            .is_ok());
+        let error = table
+            .calculate_result_score(high + 1.0, Gender::Men, &event("100m"))
+            .unwrap_err();
+        assert!(matches!(error, ResultScoreError::OutOfRange { .. }));
+        assert!(error.to_string().contains("100m"));
+
+        // Distances are bounded from below
+        assert!(table
+            .calculate_result_score(1.0, Gender::Women, &event("LJ"))
+            .is_err());
+        assert!(table
+            .calculate_result_score(-5.0, Gender::Women, &event("LJ"))
+            .is_err());
+        assert!(table
+            .calculate_result_score(f64::NAN, Gender::Men, &event("100m"))
+            .is_err());
+        assert!(matches!(
+            table.calculate_result_score(10.0, Gender::Men, &event("HJ")),
+            Err(ResultScoreError::CoefficientsNotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,201 @@
+//! Combined events scoring: points for each discipline of a decathlon, heptathlon or
+//! pentathlon from the official formulas, summed to the competition total.
+//!
+//! Track events score `a·(b − T)^c` with `T` in seconds, jumps `a·(M − b)^c` with `M` in
+//! centimetres and throws `a·(M − b)^c` with `M` in metres. Points are rounded down, and a
+//! mark outside the formula's range scores 0. The coefficients are bundled in
+//! `data/combined_events_coefficients.json`, keyed by gender and combined event name.
+use crate::models::{Event, Gender};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DisciplineKind {
+    Track,
+    Jump,
+    Throw,
+}
+
+/// One discipline of a combined event with its scoring coefficients
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Discipline {
+    /// The discipline's event name, e.g. "100m" or "Long Jump"
+    pub discipline: String,
+    pub kind: DisciplineKind,
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl Discipline {
+    /// Points for a mark in seconds (track) or metres (jumps and throws)
+    pub fn points(&self, mark: f64) -> i32 {
+        let base = match self.kind {
+            DisciplineKind::Track => self.b - mark,
+            DisciplineKind::Jump => mark * 100.0 - self.b,
+            DisciplineKind::Throw => mark - self.b,
+        };
+        if !base.is_finite() || base <= 0.0 {
+            return 0;
+        }
+        // Rounded to avoid float error (e.g. 7.8 * 100 = 779.999...) before flooring
+        let base = (base * 1e6).round() / 1e6;
+        (self.a * base.powf(self.c)).floor() as i32
+    }
+
+    /// Parses a written mark: a time for track disciplines, metres otherwise
+    pub fn parse_mark(&self, mark: &str) -> Result<f64, String> {
+        match self.kind {
+            DisciplineKind::Track => Event::parse_time_to_seconds(mark),
+            DisciplineKind::Jump | DisciplineKind::Throw => mark
+                .trim()
+                .trim_end_matches('m')
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid distance: {}", mark)),
+        }
+    }
+}
+
+/// Bundled combined events coefficients, keyed by gender and combined event name
+#[derive(Debug, Default, Deserialize)]
+pub struct CombinedEventsTables {
+    men: HashMap<String, Vec<Discipline>>,
+    women: HashMap<String, Vec<Discipline>>,
+}
+
+impl CombinedEventsTables {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse combined events coefficients: {}", e))
+    }
+
+    /// The disciplines of a combined event in competition order, if it is bundled
+    pub fn disciplines(&self, gender: Gender, event: &Event) -> Option<&[Discipline]> {
+        let tables = match gender {
+            Gender::Men => &self.men,
+            Gender::Women => &self.women,
+        };
+        tables.get(&event.to_string()).map(Vec::as_slice)
+    }
+}
+
+static COMBINED_EVENTS_TABLES: OnceLock<CombinedEventsTables> = OnceLock::new();
+
+/// The bundled combined events coefficients, parsed on first use
+pub fn combined_events_tables() -> &'static CombinedEventsTables {
+    COMBINED_EVENTS_TABLES.get_or_init(|| {
+        CombinedEventsTables::from_json(include_str!(
+            "../../data/combined_events_coefficients.json"
+        ))
+        .unwrap_or_else(|e| {
+            log::error!("{}", e);
+            CombinedEventsTables::default()
+        })
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisciplineScore {
+    pub discipline: String,
+    /// `None` for a discipline without a valid mark (DNF, NM, not yet contested)
+    pub mark: Option<f64>,
+    pub points: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombinedEventsScore {
+    pub disciplines: Vec<DisciplineScore>,
+    pub total: i32,
+}
+
+/// Scores each discipline's mark and sums the total. `marks` are in competition order;
+/// missing marks score 0.
+pub fn score_combined_event(
+    disciplines: &[Discipline],
+    marks: &[Option<f64>],
+) -> Result<CombinedEventsScore, String> {
+    if marks.len() != disciplines.len() {
+        return Err(format!(
+            "Expected {} marks, got {}",
+            disciplines.len(),
+            marks.len()
+        ));
+    }
+    let disciplines: Vec<DisciplineScore> = disciplines
+        .iter()
+        .zip(marks)
+        .map(|(discipline, mark)| DisciplineScore {
+            discipline: discipline.discipline.clone(),
+            mark: *mark,
+            points: mark.map(|mark| discipline.points(mark)).unwrap_or(0),
+        })
+        .collect();
+    let total = disciplines.iter().map(|d| d.points).sum();
+    Ok(CombinedEventsScore { disciplines, total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CombinedEvent;
+
+    fn tables() -> CombinedEventsTables {
+        CombinedEventsTables::from_json(include_str!(
+            "../../data/combined_events_coefficients.json"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_discipline_points() {
+        let tables = tables();
+        let decathlon = tables
+            .disciplines(Gender::Men, &Event::CombinedEvents(CombinedEvent::Dec))
+            .unwrap();
+        assert_eq!(decathlon.len(), 10);
+        // Reference values from the combined events scoring tables
+        assert_eq!(decathlon[0].points(10.00), 1096);
+        assert_eq!(decathlon[1].points(7.80), 1010);
+        assert_eq!(decathlon[2].points(16.00), 851);
+        assert_eq!(decathlon[3].points(2.10), 896);
+        assert_eq!(decathlon[9].points(233.79), 1000);
+        assert_eq!(decathlon[0].points(19.0), 0);
+
+        let heptathlon = tables
+            .disciplines(Gender::Women, &Event::CombinedEvents(CombinedEvent::Hept))
+            .unwrap();
+        let marks = [
+            "13.85", "1.82", "17.07", "23.80", "6.48", "57.18", "2:07.63",
+        ];
+        let points: Vec<i32> = heptathlon
+            .iter()
+            .zip(marks)
+            .map(|(d, mark)| d.points(d.parse_mark(mark).unwrap()))
+            .collect();
+        assert_eq!(points, vec![1000, 1003, 1000, 1000, 1001, 1000, 1000]);
+    }
+
+    #[test]
+    fn test_score_combined_event() {
+        let tables = tables();
+        let pentathlon = tables
+            .disciplines(Gender::Women, &Event::CombinedEvents(CombinedEvent::PentSh))
+            .unwrap();
+        let score = score_combined_event(
+            pentathlon,
+            &[Some(8.20), Some(1.82), None, Some(6.48), Some(127.63)],
+        )
+        .unwrap();
+        assert_eq!(score.disciplines[2].points, 0);
+        assert_eq!(
+            score.total,
+            score.disciplines.iter().map(|d| d.points).sum::<i32>()
+        );
+        assert!(score_combined_event(pentathlon, &[Some(8.20)]).is_err());
+        assert!(tables
+            .disciplines(Gender::Men, &Event::CombinedEvents(CombinedEvent::Hept))
+            .is_none());
+    }
+}
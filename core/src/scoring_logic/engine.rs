@@ -0,0 +1,382 @@
+//! The scoring engine: the result score coefficients and the placing points tables of one
+//! edition, loaded and replaced together.
+//!
+//! An engine can be built and passed around explicitly (`ScoringEngine::bundled`,
+//! `ScoringEngine::from_json`), or used as the app's engine, which the free functions in
+//! `coefficients` and `placement_score` score against. The app's engine is the bundled
+//! current edition until `replace_scoring_engine` swaps in other tables, so there's nothing
+//! to load at startup. The bundled tables are generated at build time, and the placement
+//! tables are only indexed on first use, since many calculations never include a placing.
+//! Each bundled edition has its own engine (`bundled_engine`) to score inputs naming that
+//! edition against; other tables refuse them.
+use super::bundled::{COEFFICIENT_EDITIONS, PLACEMENT_SCORES};
+use super::calculator::{calculate_world_athletics_score, PlacementScorer, ResultScorer};
+use super::coefficients::{
+    edition_coefficients, estimated_coefficients, Coefficients, CoefficientsTable, TABLE_VERSION,
+};
+use super::placement_score::{
+    estimated_placement_scores, PlacementCalculator, PlacementScoreCalcInput, RoundType,
+};
+use super::provider::ScoringTableProvider;
+use crate::models::{
+    CompetitionCategory, Event, Gender, WorldAthleticsScoreInput, WorldAthleticsScoreOutput,
+};
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+pub struct ScoringEngine {
+    /// The edition of the tables, if known
+    edition: Option<String>,
+    coefficients: CoefficientsTable,
+    placement: OnceLock<PlacementCalculator>,
+}
+
+impl ScoringEngine {
+    pub fn new(coefficients: CoefficientsTable, placement: PlacementCalculator) -> Self {
+        ScoringEngine {
+            edition: None,
+            coefficients,
+            placement: OnceLock::from(placement),
+        }
+    }
+
+    /// The bundled tables of an edition, e.g. `TABLE_VERSION`, with the estimated
+    /// coefficients for events the edition doesn't include
+    pub fn bundled(edition: &str) -> Result<Self, String> {
+        Ok(ScoringEngine {
+            edition: Some(edition.to_string()),
+            coefficients: edition_coefficients(edition)?.with_estimates(estimated_coefficients()),
+            placement: OnceLock::new(),
+        })
+    }
+
+    /// Tables read from JSON in the same format as the bundled data files
+    pub fn from_json(coefficients_json: &str, placement_json: &str) -> Result<Self, String> {
+        Ok(ScoringEngine::new(
+            serde_json::from_str(coefficients_json)
+                .map_err(|e| format!("Invalid coefficients: {}", e))?,
+            PlacementCalculator::new(placement_json)
+                .map_err(|e| format!("Invalid placement tables: {}", e))?,
+        ))
+    }
+
+    /// These tables with the estimated coefficients and placing points filling in the
+    /// events and rounds they don't have
+    pub fn with_estimates(self) -> Self {
+        let placement = match self.placement.into_inner() {
+            Some(placement) => {
+                OnceLock::from(placement.with_estimates(estimated_placement_scores()))
+            }
+            // The bundled tables get their estimates when they're indexed
+            None => OnceLock::new(),
+        };
+        ScoringEngine {
+            edition: self.edition,
+            coefficients: self.coefficients.with_estimates(estimated_coefficients()),
+            placement,
+        }
+    }
+
+    /// These tables labelled as the named edition, e.g. fetched copies of a bundled one
+    pub fn with_edition(self, edition: &str) -> Self {
+        ScoringEngine {
+            edition: Some(edition.to_string()),
+            ..self
+        }
+    }
+
+    pub fn edition(&self) -> Option<&str> {
+        self.edition.as_deref()
+    }
+
+    pub fn coefficients(&self) -> &CoefficientsTable {
+        &self.coefficients
+    }
+
+    /// The placing points tables, indexing the bundled ones with their estimates if this is
+    /// their first use
+    pub fn placement(&self) -> Result<&PlacementCalculator, String> {
+        if let Some(placement) = self.placement.get() {
+            return Ok(placement);
+        }
+        let built = PlacementCalculator::from_rows(PLACEMENT_SCORES)
+            .map_err(|e| format!("Invalid placement tables: {}", e))?
+            .with_estimates(estimated_placement_scores());
+        Ok(self.placement.get_or_init(|| built))
+    }
+
+    /// Scores a performance against this engine's tables
+    pub fn score(
+        &self,
+        input: WorldAthleticsScoreInput,
+    ) -> Result<WorldAthleticsScoreOutput, String> {
+        calculate_world_athletics_score(input, self, self)
+    }
+}
+
+impl ResultScorer for &ScoringEngine {
+    fn result_score(&self, performance: f64, gender: Gender, event: &Event) -> Result<f64, String> {
+        self.coefficients
+            .calculate_result_score(performance, gender, event)
+            .map_err(String::from)
+    }
+
+    fn is_beyond_table_range(&self, performance: f64, gender: Gender, event: &Event) -> bool {
+        self.coefficients()
+            .is_beyond_table_range(performance, gender, event)
+    }
+
+    fn is_estimated(&self, gender: Gender, event: &Event) -> bool {
+        self.coefficients().is_estimated(gender, event)
+    }
+
+    fn table_edition(&self) -> Option<String> {
+        self.edition.clone()
+    }
+}
+
+impl PlacementScorer for &ScoringEngine {
+    fn placement_score(&self, input: PlacementScoreCalcInput) -> Option<i32> {
+        self.placement().ok()?.calculate_placement_score(input)
+    }
+
+    fn is_estimated(&self, input: &PlacementScoreCalcInput) -> bool {
+        self.placement()
+            .is_ok_and(|placement| placement.is_estimated(input))
+    }
+}
+
+impl ScoringTableProvider for ScoringEngine {
+    fn get_coefficients(&self, gender: Gender, event: &Event) -> Option<Coefficients> {
+        self.coefficients.get_coefficients(gender, event)
+    }
+
+    fn get_placement_table(
+        &self,
+        event: &Event,
+        competition_category: CompetitionCategory,
+        round_type: RoundType,
+        size_of_final: i32,
+        main_event: bool,
+    ) -> Option<HashMap<i32, i32>> {
+        self.placement()
+            .ok()?
+            .placement_table(
+                event,
+                competition_category,
+                round_type,
+                size_of_final,
+                main_event,
+            )
+            .cloned()
+    }
+
+    fn table_edition(&self) -> Option<String> {
+        self.edition.clone()
+    }
+}
+
+// The app's engine when it has been replaced, e.g. by newer tables fetched at runtime (the
+// web app's `remote_tables`). Until then the bundled current edition is used.
+static SCORING_ENGINE: RwLock<Option<ScoringEngine>> = RwLock::new(None);
+
+/// A bundled edition's engine, built on first use
+pub fn bundled_engine(edition: &str) -> Result<&'static ScoringEngine, String> {
+    static BUNDLED: OnceLock<HashMap<&'static str, OnceLock<ScoringEngine>>> = OnceLock::new();
+    let editions = BUNDLED.get_or_init(|| {
+        COEFFICIENT_EDITIONS
+            .iter()
+            .map(|(edition, _)| (*edition, OnceLock::new()))
+            .collect()
+    });
+    let cell = editions
+        .get(edition)
+        .ok_or_else(|| format!("Scoring tables for the {} edition are not bundled", edition))?;
+    if let Some(engine) = cell.get() {
+        return Ok(engine);
+    }
+    let engine = ScoringEngine::bundled(edition)?;
+    Ok(cell.get_or_init(|| engine))
+}
+
+/// Runs `f` against the app's engine
+pub fn with_scoring_engine<T>(
+    f: impl FnOnce(&ScoringEngine) -> Result<T, String>,
+) -> Result<T, String> {
+    let engine = SCORING_ENGINE
+        .read()
+        .map_err(|_| "Scoring engine lock poisoned".to_string())?;
+    match engine.as_ref() {
+        Some(engine) => f(engine),
+        None => f(bundled_engine(TABLE_VERSION)?),
+    }
+}
+
+/// The edition of the app's engine, if known
+pub fn table_edition() -> Option<String> {
+    with_scoring_engine(|engine| Ok(engine.edition().map(String::from)))
+        .ok()
+        .flatten()
+}
+
+/// Scores against the app's engine, or, when the input names another edition, against
+/// that bundled edition's engine
+pub fn score_in_edition(
+    input: WorldAthleticsScoreInput,
+) -> Result<WorldAthleticsScoreOutput, String> {
+    match &input.table_edition {
+        Some(edition) if Some(edition) != table_edition().as_ref() => {
+            bundled_engine(edition)?.score(input)
+        }
+        _ => with_scoring_engine(|engine| engine.score(input)),
+    }
+}
+
+/// Replaces the app's engine, e.g. with updated tables fetched at runtime
+pub fn replace_scoring_engine(engine: ScoringEngine) -> Result<(), String> {
+    *SCORING_ENGINE
+        .write()
+        .map_err(|_| "Scoring engine lock poisoned".to_string())? = Some(engine);
+    Ok(())
+}
+
+/// Indexes the app engine's placement tables if they haven't been already
+pub fn load_placement_tables() -> Result<(), String> {
+    with_scoring_engine(|engine| engine.placement().map(|_| ()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Performance, PlacementInfo, TrackAndFieldEvent};
+    use crate::scoring_logic::calculator::calculate_score_with_provider;
+
+    #[test]
+    fn test_scoring_engine() {
+        let engine = ScoringEngine::bundled(TABLE_VERSION).unwrap();
+        assert!(ScoringEngine::bundled("1985").is_err());
+        let input = WorldAthleticsScoreInput {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::M400),
+            performance: Performance::Seconds(50.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::GL,
+                place: 1,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        };
+        let output = engine.score(input.clone()).unwrap();
+        assert!(output.result_score > 1000.0);
+        assert!(output.placement_score > 0);
+
+        // Tables read from JSON score the same
+        let from_json = ScoringEngine::from_json(
+            include_str!("../../data/world_athletics_constants_2025.json"),
+            include_str!("../../data/track_and_field_placement_scores.json"),
+        )
+        .unwrap();
+        assert_eq!(from_json.score(input).unwrap(), output);
+        assert!(ScoringEngine::from_json("{}", "{}").is_err());
+    }
+
+    #[test]
+    fn test_scoring_by_edition() {
+        let input = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            performance: Performance::Seconds(10.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        let edition = |edition: &str| WorldAthleticsScoreInput {
+            table_edition: Some(edition.to_string()),
+            ..input.clone()
+        };
+        // Another edition with its own 100m coefficients
+        let other = ScoringEngine::from_json(
+            r#"{"men": {"100m": [25.0, -850.0, 7250.0]}, "women": {}}"#,
+            include_str!("../../data/track_and_field_placement_scores.json"),
+        )
+        .unwrap()
+        .with_edition("2022");
+        let current = bundled_engine(TABLE_VERSION).unwrap();
+        assert_eq!(current.edition(), Some(TABLE_VERSION));
+
+        // The same mark scores differently in each edition
+        let scored_other =
+            calculate_world_athletics_score(edition("2022"), &other, &other).unwrap();
+        let scored_current = current.score(edition(TABLE_VERSION)).unwrap();
+        assert_ne!(scored_other.points, scored_current.points);
+        assert_eq!(scored_current, current.score(input.clone()).unwrap());
+
+        // Inputs naming another edition aren't quietly scored against other tables
+        assert!(calculate_world_athletics_score(edition(TABLE_VERSION), &other, &other).is_err());
+        assert!(calculate_score_with_provider(edition(TABLE_VERSION), &other).is_err());
+        assert_eq!(
+            calculate_score_with_provider(edition("2022"), &other).unwrap(),
+            scored_other
+        );
+        let table = current.coefficients();
+        assert!(calculate_world_athletics_score(edition(TABLE_VERSION), table, current).is_err());
+        assert!(
+            calculate_world_athletics_score(input.clone(), |_, _, _: &Event| Ok(1.0), current)
+                .is_ok()
+        );
+
+        // Left out, the scorers' own tables are used
+        assert_eq!(
+            calculate_world_athletics_score(input.clone(), &other, &other).unwrap(),
+            scored_other
+        );
+        assert!(calculate_world_athletics_score(edition("1999"), &other, &other).is_err());
+        assert_eq!(
+            score_in_edition(edition(TABLE_VERSION)).unwrap(),
+            scored_current
+        );
+        assert!(score_in_edition(edition("1999")).is_err());
+    }
+
+    #[test]
+    fn test_estimated_events() {
+        let hurdles = Event::TrackAndField(TrackAndFieldEvent::M300H);
+        // The estimates stay out of the official edition and only fill its gaps
+        assert!(edition_coefficients(TABLE_VERSION)
+            .unwrap()
+            .get_coefficients(Gender::Men, &hurdles)
+            .is_none());
+        let engine = ScoringEngine::bundled(TABLE_VERSION).unwrap();
+        assert!(engine.coefficients().is_estimated(Gender::Men, &hurdles));
+        let four_hundred = Event::TrackAndField(TrackAndFieldEvent::M400H);
+        assert!(!engine
+            .coefficients()
+            .is_estimated(Gender::Men, &four_hundred));
+
+        let input = |event: &Event| WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: event.clone(),
+            performance: Performance::Seconds(38.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        };
+        assert!(engine.score(input(&hurdles)).unwrap().estimated);
+        let official = engine.score(WorldAthleticsScoreInput {
+            performance: Performance::Seconds(50.0),
+            ..input(&four_hundred)
+        });
+        assert!(!official.unwrap().estimated);
+    }
+}
@@ -0,0 +1,181 @@
+//! Championship entry standards, bundled from `data/entry_standards.json`:
+//!
+//! ```json
+//! {
+//!   "championships": [
+//!     { "name": "World Championships", "men": { "100m": "10.00" }, "women": { "Long Jump": "6.86" } }
+//!   ]
+//! }
+//! ```
+//!
+//! Events are keyed by their display names and marks are written as they would be entered
+//! in the calculator.
+use crate::models::{Event, Gender, Performance, PerformanceType};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+#[derive(Debug, Deserialize)]
+struct ChampionshipJson {
+    name: String,
+    #[serde(default)]
+    men: HashMap<String, String>,
+    #[serde(default)]
+    women: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntryStandardsJson {
+    championships: Vec<ChampionshipJson>,
+}
+
+/// A championship's entry standard in one event
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntryStandard {
+    pub championship: String,
+    pub gender: Gender,
+    pub event: Event,
+    /// The standard in seconds, meters or points
+    pub mark: f64,
+}
+
+#[derive(Debug, Default)]
+pub struct EntryStandards {
+    standards: Vec<EntryStandard>,
+}
+
+impl EntryStandards {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let parsed: EntryStandardsJson = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse entry standards JSON: {}", e))?;
+        let mut standards = Vec::new();
+        for championship in parsed.championships {
+            for (gender, marks) in [
+                (Gender::Men, championship.men),
+                (Gender::Women, championship.women),
+            ] {
+                for (event_name, mark) in marks {
+                    let event = Event::from_string(&event_name).ok_or_else(|| {
+                        format!("Unknown event {} in {}", event_name, championship.name)
+                    })?;
+                    let mark = Performance::parse(&event, &mark)
+                        .map_err(|e| format!("{} {} {}: {}", championship.name, gender, event, e))?
+                        .value();
+                    standards.push(EntryStandard {
+                        championship: championship.name.clone(),
+                        gender,
+                        event,
+                        mark,
+                    });
+                }
+            }
+        }
+        Ok(EntryStandards { standards })
+    }
+
+    /// Every bundled standard for an event, in the order the championships are listed
+    pub fn standards(&self, gender: Gender, event: &Event) -> Vec<&EntryStandard> {
+        self.standards
+            .iter()
+            .filter(|standard| standard.gender == gender && &standard.event == event)
+            .collect()
+    }
+}
+
+static ENTRY_STANDARDS: OnceLock<EntryStandards> = OnceLock::new();
+
+/// The bundled entry standards, parsed on first use
+pub fn entry_standards() -> &'static EntryStandards {
+    ENTRY_STANDARDS.get_or_init(|| {
+        EntryStandards::from_json(include_str!("../../data/entry_standards.json")).unwrap_or_else(
+            |e| {
+                log::error!("{}", e);
+                EntryStandards::default()
+            },
+        )
+    })
+}
+
+/// How a performance compares with an entry standard
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardComparison {
+    pub standard: EntryStandard,
+    pub met: bool,
+    /// How far the performance is inside (positive) or outside (negative) the standard,
+    /// in seconds, meters or points
+    pub margin: f64,
+    /// Result score of the performance minus that of the standard, when both can be scored
+    pub points_margin: Option<f64>,
+}
+
+/// Compares a performance with a standard. Points use the result score alone, without
+/// wind, downhill or placing adjustments.
+pub fn compare_to_standard(
+    standard: &EntryStandard,
+    performance: f64,
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+) -> StandardComparison {
+    let margin = match standard.event.performance_type() {
+        PerformanceType::Time => standard.mark - performance,
+        PerformanceType::Distance | PerformanceType::Points => performance - standard.mark,
+    };
+    let score = |mark| result_score_calculator(mark, standard.gender, &standard.event).ok();
+    StandardComparison {
+        standard: standard.clone(),
+        met: margin >= 0.0,
+        margin,
+        points_margin: score(performance)
+            .zip(score(standard.mark))
+            .map(|(score, standard_score)| score - standard_score),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TrackAndFieldEvent;
+
+    fn mock_result_score_calculator(mark: f64, _: Gender, event: &Event) -> Result<f64, String> {
+        match event {
+            Event::TrackAndField(TrackAndFieldEvent::M100) => Ok(2000.0 - mark * 100.0),
+            _ => Err(format!("No coefficients for {}", event)),
+        }
+    }
+
+    #[test]
+    fn test_entry_standards() {
+        let standards = EntryStandards::from_json(
+            r#"{"championships": [
+                {"name": "Worlds", "men": {"100m": "10.00", "Long Jump": "8.27"}},
+                {"name": "Olympics", "men": {"100m": "10.00"}, "women": {"100m": "11.07"}}
+            ]}"#,
+        )
+        .unwrap();
+        let sprint = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let found = standards.standards(Gender::Men, &sprint);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].championship, "Worlds");
+
+        let comparison = compare_to_standard(found[0], 10.05, mock_result_score_calculator);
+        assert!(!comparison.met);
+        assert!((comparison.margin + 0.05).abs() < 1e-9);
+        assert!((comparison.points_margin.unwrap() + 5.0).abs() < 1e-9);
+
+        let long_jump = Event::TrackAndField(TrackAndFieldEvent::LJ);
+        let standard = standards.standards(Gender::Men, &long_jump)[0];
+        let comparison = compare_to_standard(standard, 8.30, mock_result_score_calculator);
+        assert!(comparison.met);
+        assert!((comparison.margin - 0.03).abs() < 1e-9);
+        assert_eq!(comparison.points_margin, None);
+
+        assert!(EntryStandards::from_json(
+            r#"{"championships": [{"name": "Worlds", "men": {"100m": "fast"}}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_bundled_standards_parse() {
+        assert!(EntryStandards::from_json(include_str!("../../data/entry_standards.json")).is_ok());
+    }
+}
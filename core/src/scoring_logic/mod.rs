@@ -0,0 +1,23 @@
+pub mod altitude;
+pub mod availability;
+mod bundled;
+pub mod calculator;
+pub mod citations;
+pub mod coefficients;
+pub mod combined_events;
+pub mod engine;
+pub mod entry_standards;
+pub mod pace;
+pub mod placement_score;
+pub mod points_table;
+pub mod provider;
+pub mod race_predictor;
+pub mod ranking_calendar;
+pub mod ranking_score;
+pub mod relay;
+pub mod season_lists;
+pub mod selection;
+pub mod spreadsheet;
+pub mod standards;
+pub mod target;
+pub mod track_size;
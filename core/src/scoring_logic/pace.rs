@@ -0,0 +1,153 @@
+//! Paces and even splits for road races, and the reverse: the finish time (and so the points)
+//! of a race run at a steady pace.
+use super::calculator::is_road_running_event;
+use crate::models::{Event, Gender, PaceUnit};
+
+/// The distance of a road race, or `None` for other events
+pub fn road_race_meters(event: &Event) -> Option<f64> {
+    is_road_running_event(event)
+        .then(|| event.distance_meters())
+        .flatten()
+}
+
+/// The elapsed time at a point of the race
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    pub meters: f64,
+    /// Seconds
+    pub elapsed: f64,
+}
+
+/// The splits of an evenly paced race at every `interval` meters, and at the finish
+pub fn even_splits(seconds: f64, meters: f64, interval: f64) -> Vec<Split> {
+    if seconds <= 0.0 || meters <= 0.0 || interval <= 0.0 {
+        return Vec::new();
+    }
+    let whole_intervals = (meters / interval).ceil() as usize;
+    (1..=whole_intervals)
+        .map(|n| (n as f64 * interval).min(meters))
+        .map(|at| Split {
+            meters: at,
+            elapsed: seconds * at / meters,
+        })
+        .collect()
+}
+
+/// Parses a pace such as "2:58", in seconds per unit
+pub fn parse_pace(pace: &str) -> Result<f64, String> {
+    let seconds = Event::parse_time_to_seconds(pace.trim())?;
+    if seconds > 0.0 {
+        Ok(seconds)
+    } else {
+        Err(format!("Invalid pace: {}", pace))
+    }
+}
+
+/// A race time written to the whole second, as road marks are, e.g. "27:15" or "2:02:16"
+pub fn format_race_time(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{}:{:02}", minutes, seconds)
+    }
+}
+
+/// The finish time of a road race run at a steady pace, with its result score
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaceTarget {
+    /// Seconds, to the whole second
+    pub time: f64,
+    pub points: Result<f64, String>,
+}
+
+/// The finish time and points of a road race run at `pace` seconds per unit
+pub fn pace_target(
+    gender: Gender,
+    event: &Event,
+    pace: f64,
+    unit: PaceUnit,
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+) -> Result<PaceTarget, String> {
+    let meters = road_race_meters(event)
+        .ok_or_else(|| format!("{} isn't a road race over a set distance", event))?;
+    if pace <= 0.0 {
+        return Err(format!("Invalid pace: {}", pace));
+    }
+    let time = (pace * meters / unit.meters()).round();
+    Ok(PaceTarget {
+        time,
+        points: result_score_calculator(time, gender, event),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RoadRunningEvent, TrackAndFieldEvent};
+
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        event: &Event,
+    ) -> Result<f64, String> {
+        match event {
+            Event::RoadRunning(RoadRunningEvent::Road10km) => Ok(3000.0 - performance),
+            _ => Err(format!("No table for {}", event)),
+        }
+    }
+
+    #[test]
+    fn test_pace_target_and_splits() {
+        let ten_k = Event::RoadRunning(RoadRunningEvent::Road10km);
+        assert_eq!(road_race_meters(&ten_k), Some(10000.0));
+        assert_eq!(
+            road_race_meters(&Event::TrackAndField(TrackAndFieldEvent::M10000)),
+            None
+        );
+
+        // 2:45 /km is 27:30 for 10 km
+        let target = pace_target(
+            Gender::Men,
+            &ten_k,
+            parse_pace("2:45").unwrap(),
+            PaceUnit::PerKilometer,
+            mock_result_score_calculator,
+        )
+        .unwrap();
+        assert_eq!(target.time, 1650.0);
+        assert_eq!(format_race_time(target.time), "27:30");
+        assert_eq!(target.points, Ok(1350.0));
+        assert_eq!(format_race_time(7336.0), "2:02:16");
+
+        let splits = even_splits(1650.0, 10000.0, 5000.0);
+        assert_eq!(
+            splits,
+            vec![
+                Split {
+                    meters: 5000.0,
+                    elapsed: 825.0
+                },
+                Split {
+                    meters: 10000.0,
+                    elapsed: 1650.0
+                },
+            ]
+        );
+        // The last split is the finish, short of a whole interval
+        let half = even_splits(3600.0, 21097.5, 5000.0);
+        assert_eq!(half.len(), 5);
+        assert_eq!(half[4].meters, 21097.5);
+
+        assert!(parse_pace("0:00").is_err());
+        assert!(pace_target(
+            Gender::Men,
+            &Event::TrackAndField(TrackAndFieldEvent::M10000),
+            165.0,
+            PaceUnit::PerKilometer,
+            mock_result_score_calculator
+        )
+        .is_err());
+    }
+}
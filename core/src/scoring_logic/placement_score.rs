@@ -0,0 +1,911 @@
+use super::bundled::ESTIMATED_PLACEMENT_SCORES;
+use super::engine::with_scoring_engine;
+use crate::models::{CompetitionCategory, Event, EventCategory};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::OnceLock;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+pub enum PlacementScoreEventGroup {
+    TrackAndField,        // Standard track & field events
+    Distance5000m3000mSC, // 5000m and 3000mSC
+    Distance10000m,       // 10,000m
+    Road10km,             // 10km Road Race
+    CombinedEvent,
+    RoadMarathon,
+    HalfMarathon,
+    RoadRunning,
+    RaceWalking20Km,
+    RaceWalking35Km,
+    RaceWalking35KmSimilar,
+    CrossCountry,
+}
+
+impl fmt::Display for PlacementScoreEventGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PlacementScoreEventGroup::TrackAndField => "Track & field",
+            PlacementScoreEventGroup::Distance5000m3000mSC => "5000m & 3000m steeplechase",
+            PlacementScoreEventGroup::Distance10000m => "10,000m",
+            PlacementScoreEventGroup::Road10km => "10km road",
+            PlacementScoreEventGroup::CombinedEvent => "Combined events",
+            PlacementScoreEventGroup::RoadMarathon => "Marathon",
+            PlacementScoreEventGroup::HalfMarathon => "Half marathon, 25km & 30km",
+            PlacementScoreEventGroup::RoadRunning => "Other road races",
+            PlacementScoreEventGroup::RaceWalking20Km => "20km race walk & shorter",
+            PlacementScoreEventGroup::RaceWalking35Km => "35km race walk",
+            PlacementScoreEventGroup::RaceWalking35KmSimilar => "30km & 50km race walks",
+            PlacementScoreEventGroup::CrossCountry => "Cross country",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl PlacementScoreEventGroup {
+    /// The events scored with the group's placing tables
+    pub fn events(&self) -> Vec<Event> {
+        Event::all_variants()
+            .into_iter()
+            .filter(|event| event.to_placement_score_event_group() == *self)
+            .collect()
+    }
+
+    /// The rounds any of the group's events have, in `RoundType` order
+    pub fn rounds(&self) -> Vec<RoundType> {
+        let events = self.events();
+        RoundType::iter()
+            .filter(|round| {
+                events
+                    .iter()
+                    .any(|event| rounds_for_event(event).contains(round))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, EnumIter)]
+pub enum RoundType {
+    Final,
+    SemiFinal,
+    /// A first round of a track event
+    Heat,
+    /// The qualifying round of a field event
+    Qualification,
+    Other,
+}
+
+impl fmt::Display for RoundType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoundType::Final => write!(f, "Final"),
+            RoundType::SemiFinal => write!(f, "Semifinal"),
+            RoundType::Heat => write!(f, "Heat"),
+            RoundType::Qualification => write!(f, "Qualification"),
+            RoundType::Other => write!(f, "Other"),
+        }
+    }
+}
+
+/// The rounds placing points can be scored for in an event. Jumps and throws have a
+/// qualification round instead of heats and semi-finals, and events outside the track
+/// (road, walks, combined events, cross country) are scored on the final alone.
+pub fn rounds_for_event(event: &Event) -> Vec<RoundType> {
+    use RoundType::*;
+    match (event.to_placement_score_event_group(), event.category()) {
+        (PlacementScoreEventGroup::TrackAndField, EventCategory::Jumps | EventCategory::Throws) => {
+            vec![Final, Qualification, Other]
+        }
+        (
+            PlacementScoreEventGroup::TrackAndField
+            | PlacementScoreEventGroup::Distance5000m3000mSC,
+            _,
+        ) => vec![Final, SemiFinal, Heat, Other],
+        _ => vec![Final, Other],
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+struct PlacementScoreData {
+    track_field_final: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    track_field_semi_max9: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    track_field_semi_10plus: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    distance_5000m_3000m_sc_final: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    distance_5000m_3000m_sc_semi_max9: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    distance_5000m_3000m_sc_semi_10plus: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    distance_10000m_final: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    road_10km_final: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    combined_events: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    road_marathon: HashMap<CompetitionCategory, HashMap<i32, i32>>, //TODO: figure out downhill course points
+    half_marathon_similar_event: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    road_running_event_group: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    race_walking_20km: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    race_walking_35km: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    race_walking_30km_50km: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    cross_country_finals: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    // The published tables don't score heats or qualification rounds; estimates can fill
+    // these in (see `estimated_placement_scores`)
+    #[serde(default)]
+    track_field_heat: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+    #[serde(default)]
+    track_field_qualification: HashMap<CompetitionCategory, HashMap<i32, i32>>,
+}
+
+impl PlacementScoreData {
+    /// The table with a JSON key, e.g. "track_field_final"
+    fn table(&self, name: &str) -> Option<&HashMap<CompetitionCategory, HashMap<i32, i32>>> {
+        let table = match name {
+            "track_field_final" => &self.track_field_final,
+            "track_field_semi_max9" => &self.track_field_semi_max9,
+            "track_field_semi_10plus" => &self.track_field_semi_10plus,
+            "distance_5000m_3000m_sc_final" => &self.distance_5000m_3000m_sc_final,
+            "distance_5000m_3000m_sc_semi_max9" => &self.distance_5000m_3000m_sc_semi_max9,
+            "distance_5000m_3000m_sc_semi_10plus" => &self.distance_5000m_3000m_sc_semi_10plus,
+            "distance_10000m_final" => &self.distance_10000m_final,
+            "road_10km_final" => &self.road_10km_final,
+            "combined_events" => &self.combined_events,
+            "road_marathon" => &self.road_marathon,
+            "half_marathon_similar_event" => &self.half_marathon_similar_event,
+            "road_running_event_group" => &self.road_running_event_group,
+            "race_walking_20km" => &self.race_walking_20km,
+            "race_walking_35km" => &self.race_walking_35km,
+            "race_walking_30km_50km" => &self.race_walking_30km_50km,
+            "cross_country_finals" => &self.cross_country_finals,
+            "track_field_heat" => &self.track_field_heat,
+            "track_field_qualification" => &self.track_field_qualification,
+            _ => return None,
+        };
+        Some(table)
+    }
+
+    fn table_mut(
+        &mut self,
+        name: &str,
+    ) -> Option<&mut HashMap<CompetitionCategory, HashMap<i32, i32>>> {
+        let table = match name {
+            "track_field_final" => &mut self.track_field_final,
+            "track_field_semi_max9" => &mut self.track_field_semi_max9,
+            "track_field_semi_10plus" => &mut self.track_field_semi_10plus,
+            "distance_5000m_3000m_sc_final" => &mut self.distance_5000m_3000m_sc_final,
+            "distance_5000m_3000m_sc_semi_max9" => &mut self.distance_5000m_3000m_sc_semi_max9,
+            "distance_5000m_3000m_sc_semi_10plus" => &mut self.distance_5000m_3000m_sc_semi_10plus,
+            "distance_10000m_final" => &mut self.distance_10000m_final,
+            "road_10km_final" => &mut self.road_10km_final,
+            "combined_events" => &mut self.combined_events,
+            "road_marathon" => &mut self.road_marathon,
+            "half_marathon_similar_event" => &mut self.half_marathon_similar_event,
+            "road_running_event_group" => &mut self.road_running_event_group,
+            "race_walking_20km" => &mut self.race_walking_20km,
+            "race_walking_35km" => &mut self.race_walking_35km,
+            "race_walking_30km_50km" => &mut self.race_walking_30km_50km,
+            "cross_country_finals" => &mut self.cross_country_finals,
+            "track_field_heat" => &mut self.track_field_heat,
+            "track_field_qualification" => &mut self.track_field_qualification,
+            _ => return None,
+        };
+        Some(table)
+    }
+}
+
+/// The JSON keys of the placing tables
+const TABLE_NAMES: &[&str] = &[
+    "track_field_final",
+    "track_field_semi_max9",
+    "track_field_semi_10plus",
+    "distance_5000m_3000m_sc_final",
+    "distance_5000m_3000m_sc_semi_max9",
+    "distance_5000m_3000m_sc_semi_10plus",
+    "distance_10000m_final",
+    "road_10km_final",
+    "combined_events",
+    "road_marathon",
+    "half_marathon_similar_event",
+    "road_running_event_group",
+    "race_walking_20km",
+    "race_walking_35km",
+    "race_walking_30km_50km",
+    "cross_country_finals",
+    "track_field_heat",
+    "track_field_qualification",
+];
+
+pub struct PlacementCalculator {
+    data: PlacementScoreData,
+    /// Tables filled in from estimates rather than the official tables
+    estimated: HashSet<&'static str>,
+}
+
+pub struct PlacementScoreCalcInput {
+    pub event: Event,
+    pub competition_category: CompetitionCategory,
+    pub round_type: RoundType,
+    pub place: i32,
+    pub qualified_to_final: bool,
+    pub size_of_final: i32,
+    /// See `PlacementInfo::main_event`
+    pub main_event: bool,
+}
+
+impl PlacementCalculator {
+    pub fn new(json_data: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data: PlacementScoreData = serde_json::from_str(json_data)?;
+        Ok(PlacementCalculator {
+            data,
+            estimated: HashSet::new(),
+        })
+    }
+
+    /// Tables from `(table, category, place, points)` rows, as `build.rs` generates them
+    /// for the bundled tables. Tables are named by their JSON keys, e.g. "track_field_final".
+    pub fn from_rows(rows: &[(&str, &str, i32, i32)]) -> Result<Self, String> {
+        let mut data = PlacementScoreData::default();
+        for &(table, category, place, points) in rows {
+            let competition_category = CompetitionCategory::from_string(category)
+                .ok_or_else(|| format!("Unknown competition category: {}", category))?;
+            data.table_mut(table)
+                .ok_or_else(|| format!("Unknown placement table: {}", table))?
+                .entry(competition_category)
+                .or_default()
+                .insert(place, points);
+        }
+        Ok(PlacementCalculator {
+            data,
+            estimated: HashSet::new(),
+        })
+    }
+
+    /// These tables with any they leave empty filled in from `estimates`, which are then
+    /// reported by `is_estimated`
+    pub fn with_estimates(mut self, estimates: &PlacementCalculator) -> Self {
+        for &name in TABLE_NAMES {
+            let estimate = estimates.data.table(name).filter(|table| !table.is_empty());
+            if let (Some(estimate), Some(table)) = (estimate, self.data.table_mut(name)) {
+                if table.is_empty() {
+                    *table = estimate.clone();
+                    self.estimated.insert(name);
+                }
+            }
+        }
+        self
+    }
+
+    /// Whether the placing points for the input come from estimates
+    pub fn is_estimated(&self, input: &PlacementScoreCalcInput) -> bool {
+        table_name(
+            &input.event,
+            input.round_type,
+            input.size_of_final,
+            input.main_event,
+        )
+        .is_some_and(|name| self.estimated.contains(name))
+    }
+
+    pub fn calculate_placement_score(&self, input: PlacementScoreCalcInput) -> Option<i32> {
+        self.placement_table(
+            &input.event,
+            input.competition_category,
+            input.round_type,
+            input.size_of_final,
+            input.main_event,
+        )?
+        .get(&scored_place(&input))
+        .copied()
+    }
+
+    /// The placing points by place that apply to an event, category and round. Semi-finals
+    /// use different tables depending on whether the final has more than 9 places. Heats and
+    /// qualification rounds only score at the highest categories, and rounds the event
+    /// doesn't have (see `rounds_for_event`) have no table. The half marathon, 25km and 30km
+    /// have a different table when they're the competition's main event.
+    pub fn placement_table(
+        &self,
+        event: &Event,
+        competition_category: CompetitionCategory,
+        round_type: RoundType,
+        size_of_final: i32,
+        main_event: bool,
+    ) -> Option<&HashMap<i32, i32>> {
+        let name = table_name(event, round_type, size_of_final, main_event)?;
+        self.data.table(name)?.get(&competition_category)
+    }
+}
+
+/// The JSON key of the placing table for an event and round, or `None` for rounds without
+/// placing points
+fn table_name(
+    event: &Event,
+    round_type: RoundType,
+    size_of_final: i32,
+    main_event: bool,
+) -> Option<&'static str> {
+    if !rounds_for_event(event).contains(&round_type) {
+        return None;
+    }
+    let group = event.to_placement_score_event_group_for(main_event);
+    let name = match (group, round_type) {
+        (PlacementScoreEventGroup::TrackAndField, RoundType::Final) => "track_field_final",
+        (PlacementScoreEventGroup::TrackAndField, RoundType::SemiFinal) => {
+            // check to see which semifinal table to use
+            if size_of_final <= 9 {
+                "track_field_semi_max9"
+            } else {
+                "track_field_semi_10plus"
+            }
+        }
+        (PlacementScoreEventGroup::Distance5000m3000mSC, RoundType::Final) => {
+            "distance_5000m_3000m_sc_final"
+        }
+        (PlacementScoreEventGroup::Distance5000m3000mSC, RoundType::SemiFinal) => {
+            // check to see which semifinal table to use
+            if size_of_final <= 9 {
+                "distance_5000m_3000m_sc_semi_max9"
+            } else {
+                "distance_5000m_3000m_sc_semi_10plus"
+            }
+        }
+        (PlacementScoreEventGroup::Distance10000m, RoundType::Final) => "distance_10000m_final",
+        (PlacementScoreEventGroup::Road10km, RoundType::Final) => "road_10km_final",
+        (PlacementScoreEventGroup::CombinedEvent, RoundType::Final) => "combined_events",
+        (PlacementScoreEventGroup::RoadMarathon, RoundType::Final) => "road_marathon",
+        (PlacementScoreEventGroup::HalfMarathon, RoundType::Final) => "half_marathon_similar_event",
+        (PlacementScoreEventGroup::RoadRunning, RoundType::Final) => "road_running_event_group",
+        (PlacementScoreEventGroup::RaceWalking20Km, RoundType::Final) => "race_walking_20km",
+        (PlacementScoreEventGroup::RaceWalking35Km, RoundType::Final) => "race_walking_35km",
+        (PlacementScoreEventGroup::RaceWalking35KmSimilar, RoundType::Final) => {
+            "race_walking_30km_50km"
+        }
+        (PlacementScoreEventGroup::CrossCountry, RoundType::Final) => "cross_country_finals",
+        (
+            PlacementScoreEventGroup::TrackAndField
+            | PlacementScoreEventGroup::Distance5000m3000mSC,
+            RoundType::Heat,
+        ) => "track_field_heat",
+        (PlacementScoreEventGroup::TrackAndField, RoundType::Qualification) => {
+            "track_field_qualification"
+        }
+        (_, RoundType::SemiFinal | RoundType::Heat | RoundType::Qualification) => return None,
+        (_, RoundType::Other) => return None,
+    };
+    Some(name)
+}
+
+/// The place the placing points are looked up for. If the athlete qualifies for the next round
+/// from a semi-final, heat or qualification round, they get the same points as all other
+/// qualified athletes (1st place).
+pub fn scored_place(input: &PlacementScoreCalcInput) -> i32 {
+    if input.qualified_to_final
+        && matches!(
+            input.round_type,
+            RoundType::SemiFinal | RoundType::Heat | RoundType::Qualification
+        )
+    {
+        1
+    } else {
+        input.place
+    }
+}
+
+/// Estimated placing points for rounds the official tables don't score, from
+/// `estimated_placement_scores.json`. They're kept apart from the official tables and only
+/// fill gaps (see `PlacementCalculator::with_estimates`).
+pub fn estimated_placement_scores() -> &'static PlacementCalculator {
+    static ESTIMATES: OnceLock<PlacementCalculator> = OnceLock::new();
+    ESTIMATES.get_or_init(|| {
+        PlacementCalculator::from_rows(ESTIMATED_PLACEMENT_SCORES)
+            .expect("estimated_placement_scores.json is valid")
+    })
+}
+
+/// Whether the placing points for the input come from estimates in the loaded engine
+pub fn is_estimated_placement(input: &PlacementScoreCalcInput) -> bool {
+    with_scoring_engine(|engine| Ok(engine.placement()?.is_estimated(input))).unwrap_or(false)
+}
+
+/// Calculate placement score for given parameters against the loaded engine's tables
+/// Returns None if no score is available for the given combination
+pub fn calculate_placement_score(input: PlacementScoreCalcInput) -> Option<i32> {
+    with_scoring_engine(|engine| Ok(engine.placement()?.calculate_placement_score(input)))
+        .ok()
+        .flatten()
+}
+
+/// The lowest place covered by any placement table (cross country, Area Championships)
+pub const MAX_TABLE_PLACE: i32 = 160;
+
+/// Placing points for every place the tables cover for an event, category and round,
+/// as `(place, points)` pairs in finishing order. Places without points are skipped.
+pub fn placement_points_by_place(
+    event: &Event,
+    competition_category: CompetitionCategory,
+    round_type: RoundType,
+    size_of_final: i32,
+    main_event: bool,
+    placement_score_calculator: impl Fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Vec<(i32, i32)> {
+    (1..=MAX_TABLE_PLACE)
+        .filter_map(|place| {
+            placement_score_calculator(PlacementScoreCalcInput {
+                event: event.clone(),
+                competition_category,
+                round_type,
+                place,
+                qualified_to_final: false,
+                size_of_final,
+                main_event,
+            })
+            .map(|points| (place, points))
+        })
+        .collect()
+}
+
+/// Placing points for every category and place of an event group's tables in one round
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlacementGrid {
+    /// The categories with any placing points, highest first
+    pub categories: Vec<CompetitionCategory>,
+    /// Every place with points in any category, with its points in each of `categories`
+    pub rows: Vec<(i32, Vec<Option<i32>>)>,
+}
+
+/// The placing tables of an event group for one round as a category × place grid. Empty if
+/// none of the group's events have the round.
+pub fn placement_grid(
+    group: PlacementScoreEventGroup,
+    round_type: RoundType,
+    size_of_final: i32,
+    placement_score_calculator: impl Fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> PlacementGrid {
+    let Some(event) = group
+        .events()
+        .into_iter()
+        .find(|event| rounds_for_event(event).contains(&round_type))
+    else {
+        return PlacementGrid::default();
+    };
+    let columns: Vec<(CompetitionCategory, HashMap<i32, i32>)> = CompetitionCategory::iter()
+        .rev()
+        .map(|category| {
+            let points = placement_points_by_place(
+                &event,
+                category,
+                round_type,
+                size_of_final,
+                false,
+                &placement_score_calculator,
+            );
+            (category, points.into_iter().collect::<HashMap<_, _>>())
+        })
+        .filter(|(_, points)| !points.is_empty())
+        .collect();
+    let mut places: Vec<i32> = columns
+        .iter()
+        .flat_map(|(_, points)| points.keys().copied())
+        .collect();
+    places.sort_unstable();
+    places.dedup();
+    PlacementGrid {
+        categories: columns.iter().map(|(category, _)| *category).collect(),
+        rows: places
+            .into_iter()
+            .map(|place| {
+                let points = columns
+                    .iter()
+                    .map(|(_, points)| points.get(&place).copied())
+                    .collect();
+                (place, points)
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{RoadRunningEvent, TrackAndFieldEvent};
+
+    fn get_test_json() -> &'static str {
+        r#"{
+            "track_field_final": {
+                "OW": {
+                    "1": 375,
+                    "2": 330,
+                    "3": 300,
+                    "4": 270,
+                    "5": 250,
+                    "6": 230,
+                    "7": 215,
+                    "8": 200,
+                    "9": 130,
+                    "10": 120,
+                    "11": 110,
+                    "12": 100,
+                    "13": 95,
+                    "14": 90,
+                    "15": 85,
+                    "16": 80
+                },
+                "DF": {
+                    "1": 240,
+                    "2": 210,
+                    "3": 185,
+                    "4": 170,
+                    "5": 155,
+                    "6": 145,
+                    "7": 135,
+                    "8": 125,
+                    "9": 90,
+                    "10": 80,
+                    "11": 70,
+                    "12": 60
+                },
+                "F": {
+                    "1": 15,
+                    "2": 10,
+                    "3": 5
+                }
+            },
+            "track_field_semi_max9": {
+                "OW": {
+                    "1": 140,
+                    "9": 130,
+                    "10": 120,
+                    "11": 110,
+                    "12": 100,
+                    "13": 85,
+                    "14": 80,
+                    "15": 75,
+                    "16": 70
+                },
+                "DF": {
+                    "1": 95,
+                    "9": 90,
+                    "10": 80,
+                    "11": 70,
+                    "12": 60
+                }
+            },
+            "track_field_semi_10plus": {
+                "DF": {
+                    "1": 90,
+                    "11": 85,
+                    "12": 60
+                    }
+                },
+            "distance_5000m_3000m_sc_final": {
+                "OW": {
+                    "1": 305,
+                    "2": 270,
+                    "3": 240
+                }
+            },
+            "distance_5000m_3000m_sc_semi_max9": {},
+            "distance_5000m_3000m_sc_semi_10plus": {},
+            "distance_10000m_final": {
+                "OW": {
+                    "1": 280,
+                    "2": 250,
+                    "3": 225
+                }
+            },
+            "road_10km_final": {
+                "OW": {
+                    "1": 95,
+                    "2": 85,
+                    "3": 75
+                }
+            },
+            "combined_events":{},
+            "road_marathon":{},
+            "half_marathon_similar_event":{},
+            "road_running_event_group": {},
+            "race_walking_20km": {},
+            "race_walking_35km":{} ,
+            "race_walking_30km_50km": {},
+            "cross_country_finals": {}
+        }"#
+    }
+
+    #[test]
+    fn test_calculator_initialization() {
+        let json_data = get_test_json();
+        let calculator = PlacementCalculator::new(json_data).unwrap();
+
+        // Test track field final score
+        assert_eq!(
+            calculator.calculate_placement_score(PlacementScoreCalcInput {
+                event: Event::TrackAndField(TrackAndFieldEvent::M100),
+                competition_category: CompetitionCategory::OW,
+                round_type: RoundType::Final,
+                place: 1,
+                qualified_to_final: true,
+                size_of_final: 8,
+                main_event: false,
+            }),
+            Some(375)
+        );
+        // Test a random placement score
+        assert_eq!(
+            calculator.calculate_placement_score(PlacementScoreCalcInput {
+                event: Event::RoadRunning(RoadRunningEvent::Road10km),
+                competition_category: CompetitionCategory::OW,
+                round_type: RoundType::Final,
+                place: 3,
+                qualified_to_final: true,
+                size_of_final: 32,
+                main_event: false,
+            }),
+            Some(75)
+        );
+        // Test a semifinal score that does not advance to the final
+        assert_eq!(
+            calculator.calculate_placement_score(PlacementScoreCalcInput {
+                event: Event::TrackAndField(TrackAndFieldEvent::M100),
+                competition_category: CompetitionCategory::DF,
+                round_type: RoundType::SemiFinal,
+                place: 11,
+                qualified_to_final: false,
+                size_of_final: 10,
+                main_event: false,
+            }),
+            Some(85)
+        );
+        // Test a semifinal score where the athlete advances to the final
+        assert_eq!(
+            calculator.calculate_placement_score(PlacementScoreCalcInput {
+                event: Event::TrackAndField(TrackAndFieldEvent::M100),
+                competition_category: CompetitionCategory::DF,
+                round_type: RoundType::SemiFinal,
+                place: 11,
+                qualified_to_final: true,
+                size_of_final: 11,
+                main_event: false,
+            }),
+            Some(90)
+        );
+        // Test a semifinal score where the athlete advances to the final in an 8-person final
+        assert_eq!(
+            calculator.calculate_placement_score(PlacementScoreCalcInput {
+                event: Event::TrackAndField(TrackAndFieldEvent::M100),
+                competition_category: CompetitionCategory::OW,
+                round_type: RoundType::SemiFinal,
+                place: 2,
+                qualified_to_final: true,
+                size_of_final: 8,
+                main_event: false,
+            }),
+            Some(140)
+        );
+    }
+
+    #[test]
+    fn test_heat_and_qualification_rounds() {
+        let with_early_rounds = get_test_json().replace(
+            r#""cross_country_finals": {}"#,
+            r#""cross_country_finals": {},
+            "track_field_heat": {"OW": {"1": 60}},
+            "track_field_qualification": {"OW": {"1": 140, "13": 95}}"#,
+        );
+        let calculator = PlacementCalculator::new(&with_early_rounds).unwrap();
+        let input = |event, round_type, place, qualified_to_final| PlacementScoreCalcInput {
+            event: Event::TrackAndField(event),
+            competition_category: CompetitionCategory::OW,
+            round_type,
+            place,
+            qualified_to_final,
+            size_of_final: 8,
+            main_event: false,
+        };
+        let score = |input| calculator.calculate_placement_score(input);
+
+        // Heat qualifiers all score the same, and heat non-qualifiers score nothing
+        assert_eq!(
+            score(input(TrackAndFieldEvent::M400, RoundType::Heat, 4, true)),
+            Some(60)
+        );
+        assert_eq!(
+            score(input(TrackAndFieldEvent::M400, RoundType::Heat, 5, false)),
+            None
+        );
+        assert_eq!(
+            score(input(
+                TrackAndFieldEvent::LJ,
+                RoundType::Qualification,
+                7,
+                true
+            )),
+            Some(140)
+        );
+        assert_eq!(
+            score(input(
+                TrackAndFieldEvent::LJ,
+                RoundType::Qualification,
+                13,
+                false
+            )),
+            Some(95)
+        );
+
+        // Field events have no semi-finals or heats, and track events no qualification round
+        assert_eq!(
+            score(input(TrackAndFieldEvent::HJ, RoundType::Heat, 1, true)),
+            None
+        );
+        assert_eq!(
+            score(input(
+                TrackAndFieldEvent::M100,
+                RoundType::Qualification,
+                1,
+                true
+            )),
+            None
+        );
+        assert_eq!(
+            rounds_for_event(&Event::TrackAndField(TrackAndFieldEvent::JT)),
+            [RoundType::Final, RoundType::Qualification, RoundType::Other]
+        );
+
+        // Tables without the early rounds still load, and score nothing for them
+        let calculator = PlacementCalculator::new(get_test_json()).unwrap();
+        assert_eq!(
+            calculator.calculate_placement_score(input(
+                TrackAndFieldEvent::M400,
+                RoundType::Heat,
+                1,
+                true
+            )),
+            None
+        );
+    }
+
+    #[test]
+    fn test_estimated_early_rounds() {
+        let input = |round_type| PlacementScoreCalcInput {
+            event: Event::TrackAndField(TrackAndFieldEvent::M400),
+            competition_category: CompetitionCategory::OW,
+            round_type,
+            place: 1,
+            qualified_to_final: true,
+            size_of_final: 8,
+            main_event: false,
+        };
+
+        // The official tables don't score heats; the estimates fill them in and are flagged
+        let calculator = PlacementCalculator::new(get_test_json())
+            .unwrap()
+            .with_estimates(estimated_placement_scores());
+        assert_eq!(
+            calculator.calculate_placement_score(input(RoundType::Heat)),
+            Some(60)
+        );
+        assert!(calculator.is_estimated(&input(RoundType::Heat)));
+        assert!(!calculator.is_estimated(&input(RoundType::Final)));
+        assert!(is_estimated_placement(&input(RoundType::Heat)));
+    }
+
+    #[test]
+    fn test_half_marathon_main_event() {
+        let tables = get_test_json()
+            .replace(
+                r#""half_marathon_similar_event":{}"#,
+                r#""half_marathon_similar_event": {"GW": {"1": 45}}"#,
+            )
+            .replace(
+                r#""road_running_event_group": {}"#,
+                r#""road_running_event_group": {"GW": {"1": 140}}"#,
+            );
+        let calculator = PlacementCalculator::new(&tables).unwrap();
+        let score = |event, main_event| {
+            calculator.calculate_placement_score(PlacementScoreCalcInput {
+                event: Event::RoadRunning(event),
+                competition_category: CompetitionCategory::GW,
+                round_type: RoundType::Final,
+                place: 1,
+                qualified_to_final: false,
+                size_of_final: 8,
+                main_event,
+            })
+        };
+
+        assert_eq!(score(RoadRunningEvent::RoadHM, false), Some(45));
+        assert_eq!(score(RoadRunningEvent::RoadHM, true), Some(140));
+        assert_eq!(score(RoadRunningEvent::Road30km, true), Some(140));
+        // Other road races have one table whatever the race's billing
+        assert_eq!(score(RoadRunningEvent::Road15km, false), Some(140));
+    }
+
+    #[test]
+    fn test_placement_grid() {
+        let calculator = PlacementCalculator::new(get_test_json()).unwrap();
+        let scorer = |input| calculator.calculate_placement_score(input);
+
+        let grid = placement_grid(
+            PlacementScoreEventGroup::TrackAndField,
+            RoundType::Final,
+            8,
+            scorer,
+        );
+        assert_eq!(
+            grid.categories,
+            [
+                CompetitionCategory::OW,
+                CompetitionCategory::DF,
+                CompetitionCategory::F
+            ]
+        );
+        assert_eq!(grid.rows.len(), 16);
+        assert_eq!(grid.rows[2], (3, vec![Some(300), Some(185), Some(5)]));
+        assert_eq!(grid.rows[12], (13, vec![Some(95), None, None]));
+
+        assert_eq!(
+            PlacementScoreEventGroup::Road10km.rounds(),
+            [RoundType::Final, RoundType::Other]
+        );
+        assert_eq!(
+            placement_grid(
+                PlacementScoreEventGroup::Road10km,
+                RoundType::Heat,
+                8,
+                scorer
+            ),
+            PlacementGrid::default()
+        );
+    }
+
+    #[test]
+    fn test_placement_points_by_place() {
+        let calculator = PlacementCalculator::new(get_test_json()).unwrap();
+        let scorer = |input| calculator.calculate_placement_score(input);
+        let event = Event::TrackAndField(TrackAndFieldEvent::M100);
+
+        let final_points = placement_points_by_place(
+            &event,
+            CompetitionCategory::F,
+            RoundType::Final,
+            8,
+            false,
+            scorer,
+        );
+        assert_eq!(final_points, vec![(1, 15), (2, 10), (3, 5)]);
+
+        // Semifinal tables only cover the places that don't make the final
+        let semi_points = placement_points_by_place(
+            &event,
+            CompetitionCategory::DF,
+            RoundType::SemiFinal,
+            8,
+            false,
+            scorer,
+        );
+        assert_eq!(
+            semi_points,
+            vec![(1, 95), (9, 90), (10, 80), (11, 70), (12, 60)]
+        );
+
+        assert!(placement_points_by_place(
+            &event,
+            CompetitionCategory::OW,
+            RoundType::Other,
+            8,
+            false,
+            scorer
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_generated_rows_match_json() {
+        let generated =
+            PlacementCalculator::from_rows(crate::scoring_logic::bundled::PLACEMENT_SCORES)
+                .unwrap();
+        let parsed = PlacementCalculator::new(include_str!(
+            "../../data/track_and_field_placement_scores.json"
+        ))
+        .unwrap();
+        assert_eq!(generated.data, parsed.data);
+
+        assert!(PlacementCalculator::from_rows(&[("track_field_final", "ZZ", 1, 10)]).is_err());
+        assert!(PlacementCalculator::from_rows(&[("indoor_final", "A", 1, 10)]).is_err());
+    }
+}
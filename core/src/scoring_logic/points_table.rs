@@ -0,0 +1,141 @@
+//! The full points table of an event: the mark needed for every result score from 1 to
+//! `MAX_RESULT_SCORE`, as in the official PDF tables. Marks are rounded to what can be
+//! measured (hundredths of a second, centimetres or whole points), to the worst such mark
+//! that still scores the row's points.
+use super::coefficients::MAX_RESULT_SCORE;
+use crate::models::{Event, Gender, PerformanceType};
+use serde::Serialize;
+
+/// Rows per page of the table view and the API
+pub const POINTS_TABLE_PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PointsTableRow {
+    pub points: u32,
+    /// The mark in the event's standard unit (seconds, meters or points)
+    pub performance: f64,
+    /// The mark as written, e.g. "00:10.000" or "8.95m"
+    pub mark: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PointsTablePage {
+    /// Zero-based
+    pub page: usize,
+    pub page_count: usize,
+    pub rows: Vec<PointsTableRow>,
+}
+
+/// The worst measurable mark that still scores: times are rounded down to the hundredth,
+/// distances up to the centimetre and points totals up to the point
+fn round_mark(performance_type: PerformanceType, performance: f64) -> f64 {
+    // Allow for floating point noise in marks that are already round
+    const EPSILON: f64 = 1e-9;
+    match performance_type {
+        PerformanceType::Time => (performance * 100.0 + EPSILON).floor() / 100.0,
+        PerformanceType::Distance => (performance * 100.0 - EPSILON).ceil() / 100.0,
+        PerformanceType::Points => (performance - EPSILON).ceil(),
+    }
+}
+
+/// The mark needed for every result score from 1 to `MAX_RESULT_SCORE`, best last
+pub fn points_table(
+    gender: Gender,
+    event: &Event,
+    performance_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+) -> Result<Vec<PointsTableRow>, String> {
+    (1..=MAX_RESULT_SCORE as u32)
+        .map(|points| {
+            let performance = round_mark(
+                event.performance_type(),
+                performance_calculator(points as f64, gender, event)?,
+            );
+            Ok(PointsTableRow {
+                points,
+                performance,
+                mark: event.format_performance(performance),
+            })
+        })
+        .collect()
+}
+
+/// One page of a table, `page_size` rows from the top (best) score down
+pub fn points_table_page(
+    rows: &[PointsTableRow],
+    page: usize,
+    page_size: usize,
+) -> Result<PointsTablePage, String> {
+    if page_size == 0 {
+        return Err("Page size must be at least 1 row".to_string());
+    }
+    let page_count = rows.len().div_ceil(page_size).max(1);
+    if page >= page_count {
+        return Err(format!(
+            "Page {} is past the end of the table ({} pages)",
+            page + 1,
+            page_count
+        ));
+    }
+    Ok(PointsTablePage {
+        page,
+        page_count,
+        rows: rows
+            .iter()
+            .rev()
+            .skip(page * page_size)
+            .take(page_size)
+            .cloned()
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TrackAndFieldEvent;
+
+    // 1400 points at 9.00s, 1 point at 22.99s
+    fn mock_performance_calculator(
+        points: f64,
+        _gender: Gender,
+        event: &Event,
+    ) -> Result<f64, String> {
+        match event {
+            Event::TrackAndField(TrackAndFieldEvent::M100) => Ok(23.0 - points / 100.0),
+            Event::TrackAndField(TrackAndFieldEvent::LJ) => Ok(points / 200.0 + 0.001),
+            _ => Err(format!("Coefficients not found for event: {}", event)),
+        }
+    }
+
+    #[test]
+    fn test_points_table() {
+        let sprint = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let rows = points_table(Gender::Men, &sprint, mock_performance_calculator).unwrap();
+        assert_eq!(rows.len(), 1400);
+        assert_eq!(rows[0].points, 1);
+        assert_eq!(rows[0].performance, 22.99);
+        assert_eq!(rows[1399].performance, 9.0);
+
+        // Distances round up, so 0.006m needs 0.01m
+        let jump = Event::TrackAndField(TrackAndFieldEvent::LJ);
+        let jumps = points_table(Gender::Men, &jump, mock_performance_calculator).unwrap();
+        assert_eq!(jumps[0].performance, 0.01);
+        assert_eq!(jumps[0].mark, "0.01m");
+
+        let first = points_table_page(&rows, 0, POINTS_TABLE_PAGE_SIZE).unwrap();
+        assert_eq!(first.page_count, 14);
+        assert_eq!(first.rows[0].points, 1400);
+        assert_eq!(first.rows.len(), 100);
+        let last = points_table_page(&rows, 13, POINTS_TABLE_PAGE_SIZE).unwrap();
+        assert_eq!(last.rows.last().unwrap().points, 1);
+        assert!(points_table_page(&rows, 14, POINTS_TABLE_PAGE_SIZE).is_err());
+        assert!(points_table_page(&rows, 0, 0).is_err());
+
+        assert!(points_table(
+            Gender::Men,
+            &Event::TrackAndField(TrackAndFieldEvent::HJ),
+            mock_performance_calculator
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,159 @@
+//! The scoring tables behind the calculator, as a trait so integrators can score against
+//! their own (federation-specific or experimental) tables without forking the crate.
+//! `calculate_score_with_provider` scores against any provider.
+use super::coefficients::{self, Coefficients};
+use super::engine::{self, with_scoring_engine};
+use super::placement_score::RoundType;
+use crate::models::{CompetitionCategory, Event, Gender};
+use std::collections::HashMap;
+
+pub trait ScoringTableProvider {
+    /// The result score coefficients for an event
+    fn get_coefficients(&self, gender: Gender, event: &Event) -> Option<Coefficients>;
+
+    /// Placing points by place for an event, category and round. `size_of_final` selects
+    /// between the semi-final tables, and `main_event` between the half marathon tables.
+    fn get_placement_table(
+        &self,
+        event: &Event,
+        competition_category: CompetitionCategory,
+        round_type: RoundType,
+        size_of_final: i32,
+        main_event: bool,
+    ) -> Option<HashMap<i32, i32>>;
+
+    /// The edition of the tables, if known
+    fn table_edition(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The app's engine: the tables bundled with the crate, or their replacements fetched at
+/// runtime
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BundledTables;
+
+impl ScoringTableProvider for BundledTables {
+    fn get_coefficients(&self, gender: Gender, event: &Event) -> Option<Coefficients> {
+        coefficients::get_coefficients(gender, event).ok()
+    }
+
+    fn get_placement_table(
+        &self,
+        event: &Event,
+        competition_category: CompetitionCategory,
+        round_type: RoundType,
+        size_of_final: i32,
+        main_event: bool,
+    ) -> Option<HashMap<i32, i32>> {
+        with_scoring_engine(|engine| {
+            Ok(engine.get_placement_table(
+                event,
+                competition_category,
+                round_type,
+                size_of_final,
+                main_event,
+            ))
+        })
+        .ok()
+        .flatten()
+    }
+
+    fn table_edition(&self) -> Option<String> {
+        engine::table_edition()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Performance, PlacementInfo, TrackAndFieldEvent, WorldAthleticsScoreInput};
+    use crate::scoring_logic::calculator::{
+        calculate_score_with_provider, calculate_world_athletics_score,
+    };
+    use crate::scoring_logic::engine::ScoringEngine;
+    use crate::scoring_logic::placement_score::calculate_placement_score;
+
+    fn input() -> WorldAthleticsScoreInput {
+        WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            performance: Performance::Seconds(9.95),
+            wind_speed: Some(-0.5),
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::A,
+                place: 2,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        }
+    }
+
+    /// Scores every result as its mark, with a flat 10 placing points
+    struct FlatTables;
+
+    impl ScoringTableProvider for FlatTables {
+        fn get_coefficients(&self, _gender: Gender, _event: &Event) -> Option<Coefficients> {
+            Some(Coefficients {
+                conversion_factor: 0.0,
+                result_shift: 1.0,
+                point_shift: 0.0,
+            })
+        }
+
+        fn get_placement_table(
+            &self,
+            _event: &Event,
+            _competition_category: CompetitionCategory,
+            _round_type: RoundType,
+            _size_of_final: i32,
+            _main_event: bool,
+        ) -> Option<HashMap<i32, i32>> {
+            Some((1..=8).map(|place| (place, 10)).collect())
+        }
+    }
+
+    #[test]
+    fn test_providers_match_the_bundled_calculator() {
+        let expected = calculate_world_athletics_score(
+            input(),
+            coefficients::calculate_result_score,
+            calculate_placement_score,
+        )
+        .unwrap();
+
+        let bundled = calculate_score_with_provider(input(), &BundledTables).unwrap();
+        assert_eq!(bundled.points, expected.points);
+        assert_eq!(bundled.placement_score, expected.placement_score);
+
+        let json = ScoringEngine::from_json(
+            include_str!("../../data/world_athletics_constants_2025.json"),
+            include_str!("../../data/track_and_field_placement_scores.json"),
+        )
+        .unwrap();
+        let from_json = calculate_score_with_provider(input(), &json).unwrap();
+        assert_eq!(from_json.points, expected.points);
+
+        // The tables can also be passed to the calculator as scorers
+        let scored = calculate_world_athletics_score(
+            input(),
+            json.coefficients(),
+            json.placement().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(scored.points, expected.points);
+    }
+
+    #[test]
+    fn test_custom_provider() {
+        let output = calculate_score_with_provider(input(), &FlatTables).unwrap();
+        assert_eq!(output.result_score, 10.0);
+        assert_eq!(output.placement_score, 10);
+    }
+}
@@ -0,0 +1,163 @@
+//! Race time predictions from the scoring curves: the marks over every other running
+//! distance worth the same points as a result. The tables equate marks across distances for
+//! athletes in general, while any one athlete suits some distances better than others, so
+//! each prediction is given as the range within `PREDICTION_MARGIN` points of the result.
+use super::coefficients::MAX_RESULT_SCORE;
+use crate::models::{Event, Gender, RoadRunningEvent, TrackAndFieldEvent};
+
+/// Points either side of the result's score that a prediction's range covers
+pub const PREDICTION_MARGIN: f64 = 20.0;
+
+/// The flat running events predictions are made for, shortest first: the outdoor track
+/// distances from 100m and the road races. Short track, hurdles, steeplechase and relays
+/// are left out, as are the mile road race and 10 miles, which sit between metric distances.
+pub fn running_distances() -> Vec<Event> {
+    use RoadRunningEvent as R;
+    use TrackAndFieldEvent as T;
+    let track = [
+        T::M100,
+        T::M200,
+        T::M400,
+        T::M800,
+        T::M1000,
+        T::M1500,
+        T::M2000,
+        T::M3000,
+        T::M5000,
+        T::M10000,
+    ]
+    .into_iter()
+    .map(Event::TrackAndField);
+    let road = [
+        R::Road5km,
+        R::Road10km,
+        R::Road15km,
+        R::Road20km,
+        R::RoadHM,
+        R::Road25km,
+        R::Road30km,
+        R::RoadMarathon,
+    ]
+    .into_iter()
+    .map(Event::RoadRunning);
+    track.chain(road).collect()
+}
+
+/// The equal-points mark over one distance
+#[derive(Debug, Clone, PartialEq)]
+pub struct RacePrediction {
+    pub event: Event,
+    /// The mark worth exactly the result's points, in seconds
+    pub performance: f64,
+    /// The marks `PREDICTION_MARGIN` points better and worse
+    pub fastest: f64,
+    pub slowest: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RacePredictions {
+    /// The result score of the result the predictions are made from
+    pub points: f64,
+    pub predictions: Vec<RacePrediction>,
+}
+
+/// Predicts the marks over every other running distance from a result (in seconds) in one of
+/// `running_distances`. Distances without tables for the gender are left out.
+pub fn predict_races(
+    gender: Gender,
+    event: &Event,
+    performance: f64,
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    performance_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+) -> Result<RacePredictions, String> {
+    let distances = running_distances();
+    if !distances.contains(event) {
+        return Err(format!("{} isn't a flat running distance", event));
+    }
+    let points = result_score_calculator(performance, gender, event)?;
+    let predictions = distances
+        .into_iter()
+        .filter(|other| other != event)
+        .filter_map(|other| {
+            let at = |points: f64| {
+                performance_calculator(points.clamp(1.0, MAX_RESULT_SCORE), gender, &other).ok()
+            };
+            Some(RacePrediction {
+                performance: at(points)?,
+                fastest: at(points + PREDICTION_MARGIN)?,
+                slowest: at(points - PREDICTION_MARGIN)?,
+                event: other,
+            })
+        })
+        .collect();
+    Ok(RacePredictions {
+        points,
+        predictions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every distance scores 1 point per second under 2000 seconds per 1000m
+    fn meters(event: &Event) -> Result<f64, String> {
+        event
+            .distance_meters()
+            .ok_or_else(|| format!("No distance for {}", event))
+    }
+
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        event: &Event,
+    ) -> Result<f64, String> {
+        Ok(2000.0 - performance * 1000.0 / meters(event)?)
+    }
+
+    fn mock_performance_calculator(
+        points: f64,
+        _gender: Gender,
+        event: &Event,
+    ) -> Result<f64, String> {
+        if *event == Event::RoadRunning(RoadRunningEvent::RoadMarathon) {
+            return Err("No table".to_string());
+        }
+        Ok((2000.0 - points) * meters(event)? / 1000.0)
+    }
+
+    #[test]
+    fn test_predict_races() {
+        let five_k = Event::TrackAndField(TrackAndFieldEvent::M5000);
+        // 4000 seconds for 5000m is 800 seconds per 1000m, so 1200 points
+        let predicted = predict_races(
+            Gender::Men,
+            &five_k,
+            4000.0,
+            mock_result_score_calculator,
+            mock_performance_calculator,
+        )
+        .unwrap();
+        assert_eq!(predicted.points, 1200.0);
+        let ten_k = predicted
+            .predictions
+            .iter()
+            .find(|p| p.event == Event::TrackAndField(TrackAndFieldEvent::M10000))
+            .unwrap();
+        assert_eq!(ten_k.performance, 8000.0);
+        assert_eq!(ten_k.fastest, 7800.0);
+        assert_eq!(ten_k.slowest, 8200.0);
+        // Neither the result's own event nor distances without tables are predicted
+        assert_eq!(predicted.predictions.len(), running_distances().len() - 2);
+        assert!(!predicted.predictions.iter().any(|p| p.event == five_k));
+
+        assert!(predict_races(
+            Gender::Men,
+            &Event::TrackAndField(TrackAndFieldEvent::M400H),
+            50.0,
+            mock_result_score_calculator,
+            mock_performance_calculator
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,365 @@
+//! Ranking-period windows, the number of results averaged into a ranking score, and
+//! championship qualification deadlines.
+//!
+//! The calendar is bundled from `data/ranking_calendar.json`:
+//!
+//! ```json
+//! {
+//!   "source": "World Rankings Rules",
+//!   "ranking_period_months": { "Track": 12, "RoadRunning": 18 },
+//!   "results_counted": { "Track": 5, "CombinedEvents": 3 },
+//!   "event_overrides": {
+//!     "Road Marathon": { "results_counted": 2 }
+//!   },
+//!   "deadlines": [
+//!     {
+//!       "name": "World Championships",
+//!       "event_groups": ["Track", "Field"],
+//!       "window_start": "2024-08-01",
+//!       "deadline": "2025-08-24",
+//!       "source": "Qualification system"
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Events in `event_overrides`, keyed by display name, use their own period or count in
+//! place of their group's. Deadlines without `event_groups` apply to every group.
+use crate::models::{Event, EventGroup};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+/// Ranking period used for groups missing from the calendar
+pub const DEFAULT_RANKING_PERIOD_MONTHS: u32 = 12;
+
+/// Results averaged into the ranking score for groups missing from the calendar
+pub const DEFAULT_RESULTS_COUNTED: usize = 5;
+
+/// A calendar date, compared and formatted as ISO 8601 (YYYY-MM-DD)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl CalendarDate {
+    pub fn parse(date: &str) -> Result<Self, String> {
+        let invalid = || format!("Invalid date: {}. Expected YYYY-MM-DD", date);
+        let mut parts = date.trim().splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let year = next()?.parse().map_err(|_| invalid())?;
+        let month: u32 = next()?.parse().map_err(|_| invalid())?;
+        let day: u32 = next()?.parse().map_err(|_| invalid())?;
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(invalid());
+        }
+        Ok(CalendarDate { year, month, day })
+    }
+
+    /// Days since 1970-01-01
+    pub fn days_since_epoch(self) -> i64 {
+        // Howard Hinnant's days_from_civil
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The date `days` after 1970-01-01
+    pub fn from_days_since_epoch(days: i64) -> CalendarDate {
+        // Howard Hinnant's civil_from_days
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        CalendarDate { year, month, day }
+    }
+
+    /// Days from `self` until `other`; negative if `other` is earlier
+    pub fn days_until(self, other: CalendarDate) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// The same day `months` later, clamped to the end of shorter months
+    pub fn add_months(self, months: i32) -> CalendarDate {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        let (year, month) = (index.div_euclid(12), index.rem_euclid(12) as u32 + 1);
+        CalendarDate {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QualificationDeadline {
+    pub name: String,
+    #[serde(default)]
+    pub event_groups: Vec<EventGroup>,
+    pub window_start: Option<String>,
+    pub deadline: String,
+    /// The qualification system document the dates come from
+    pub source: Option<String>,
+}
+
+impl QualificationDeadline {
+    pub fn applies_to(&self, event_group: EventGroup) -> bool {
+        self.event_groups.is_empty() || self.event_groups.contains(&event_group)
+    }
+}
+
+/// An event whose ranking rules differ from the rest of its group
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct EventOverride {
+    pub ranking_period_months: Option<u32>,
+    pub results_counted: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct RankingCalendar {
+    /// The rules the period lengths and result counts come from
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    ranking_period_months: HashMap<EventGroup, u32>,
+    #[serde(default)]
+    results_counted: HashMap<EventGroup, usize>,
+    #[serde(default)]
+    event_overrides: HashMap<String, EventOverride>,
+    #[serde(default)]
+    pub deadlines: Vec<QualificationDeadline>,
+}
+
+/// The window in which results count towards an athlete's ranking today
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankingWindow {
+    pub event_group: EventGroup,
+    pub months: u32,
+    pub start: CalendarDate,
+    pub end: CalendarDate,
+}
+
+/// A deadline that hasn't passed yet, with the days remaining
+#[derive(Debug, Clone, PartialEq)]
+pub struct Countdown {
+    pub deadline: QualificationDeadline,
+    pub days_left: i64,
+}
+
+impl RankingCalendar {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Failed to parse ranking calendar: {}", e))
+    }
+
+    fn event_override(&self, event: &Event) -> Option<&EventOverride> {
+        self.event_overrides.get(&event.to_string())
+    }
+
+    /// The event's own ranking period, else its group's
+    pub fn ranking_period_months(&self, event: &Event) -> u32 {
+        self.event_override(event)
+            .and_then(|o| o.ranking_period_months)
+            .or_else(|| {
+                self.ranking_period_months
+                    .get(&event.event_group())
+                    .copied()
+            })
+            .unwrap_or(DEFAULT_RANKING_PERIOD_MONTHS)
+    }
+
+    /// How many of an athlete's best results are averaged into their ranking score: the
+    /// event's own count, else its group's
+    pub fn results_counted(&self, event: &Event) -> usize {
+        self.event_override(event)
+            .and_then(|o| o.results_counted)
+            .or_else(|| self.results_counted.get(&event.event_group()).copied())
+            .unwrap_or(DEFAULT_RESULTS_COUNTED)
+    }
+
+    pub fn ranking_window(&self, event: &Event, today: CalendarDate) -> RankingWindow {
+        let months = self.ranking_period_months(event);
+        RankingWindow {
+            event_group: event.event_group(),
+            months,
+            start: today.add_months(-(months as i32)),
+            end: today,
+        }
+    }
+
+    /// The last day a result achieved on `date` still counts towards the ranking
+    pub fn result_valid_until(&self, event: &Event, date: CalendarDate) -> CalendarDate {
+        date.add_months(self.ranking_period_months(event) as i32)
+    }
+
+    /// Deadlines for the group from today onwards, soonest first
+    pub fn upcoming_deadlines(
+        &self,
+        event_group: EventGroup,
+        today: CalendarDate,
+    ) -> Vec<Countdown> {
+        let mut countdowns: Vec<Countdown> = self
+            .deadlines
+            .iter()
+            .filter(|deadline| deadline.applies_to(event_group))
+            .filter_map(|deadline| {
+                let days_left = today.days_until(CalendarDate::parse(&deadline.deadline).ok()?);
+                (days_left >= 0).then(|| Countdown {
+                    deadline: deadline.clone(),
+                    days_left,
+                })
+            })
+            .collect();
+        countdowns.sort_by_key(|countdown| countdown.days_left);
+        countdowns
+    }
+}
+
+static RANKING_CALENDAR: OnceLock<RankingCalendar> = OnceLock::new();
+
+/// The bundled ranking calendar, parsed on first use
+pub fn ranking_calendar() -> &'static RankingCalendar {
+    RANKING_CALENDAR.get_or_init(|| {
+        RankingCalendar::from_json(include_str!("../../data/ranking_calendar.json")).unwrap_or_else(
+            |e| {
+                log::error!("{}", e);
+                RankingCalendar::default()
+            },
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CombinedEvent, RoadRunningEvent, TrackAndFieldEvent};
+
+    fn date(date: &str) -> CalendarDate {
+        CalendarDate::parse(date).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_date() {
+        assert_eq!(date("2025-06-14").to_string(), "2025-06-14");
+        assert!(CalendarDate::parse("2025-02-29").is_err());
+        assert!(CalendarDate::parse("2025-13-01").is_err());
+        assert_eq!(date("2024-02-28").days_until(date("2024-03-01")), 2);
+        assert_eq!(date("1970-01-01").days_since_epoch(), 0);
+        assert_eq!(CalendarDate::from_days_since_epoch(0), date("1970-01-01"));
+        for day in ["2024-02-29", "2000-03-01", "1969-12-31", "2025-12-31"] {
+            assert_eq!(
+                CalendarDate::from_days_since_epoch(date(day).days_since_epoch()),
+                date(day)
+            );
+        }
+        assert_eq!(date("2025-03-31").add_months(-1), date("2025-02-28"));
+        assert_eq!(date("2025-01-15").add_months(-18), date("2023-07-15"));
+    }
+
+    #[test]
+    fn test_ranking_calendar() {
+        let calendar = RankingCalendar::from_json(
+            r#"{
+                "ranking_period_months": { "RoadRunning": 18 },
+                "results_counted": { "CombinedEvents": 3, "RoadRunning": 5 },
+                "event_overrides": {
+                    "Road Marathon": { "results_counted": 2 },
+                    "Road Mile": { "ranking_period_months": 12 }
+                },
+                "deadlines": [
+                    { "name": "Past", "deadline": "2025-01-01" },
+                    { "name": "Later", "deadline": "2025-09-01" },
+                    { "name": "Road only", "event_groups": ["RoadRunning"], "deadline": "2025-07-01" },
+                    { "name": "Soon", "event_groups": ["Track"], "deadline": "2025-06-20" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let today = date("2025-06-14");
+
+        let marathon = Event::RoadRunning(RoadRunningEvent::RoadMarathon);
+        let half = Event::RoadRunning(RoadRunningEvent::RoadHM);
+        let road_mile = Event::RoadRunning(RoadRunningEvent::RoadMile);
+        let window = calendar.ranking_window(&half, today);
+        assert_eq!(window.event_group, EventGroup::RoadRunning);
+        assert_eq!(window.start, date("2023-12-14"));
+        assert_eq!(
+            calendar.ranking_period_months(&Event::TrackAndField(TrackAndFieldEvent::LJ)),
+            DEFAULT_RANKING_PERIOD_MONTHS
+        );
+        assert_eq!(
+            calendar.results_counted(&Event::CombinedEvents(CombinedEvent::Dec)),
+            3
+        );
+        assert_eq!(
+            calendar.results_counted(&Event::TrackAndField(TrackAndFieldEvent::M100)),
+            DEFAULT_RESULTS_COUNTED
+        );
+        assert_eq!(
+            calendar.result_valid_until(&Event::TrackAndField(TrackAndFieldEvent::M100), today),
+            date("2026-06-14")
+        );
+
+        // Overrides replace only the values they set
+        assert_eq!(calendar.results_counted(&half), 5);
+        assert_eq!(calendar.results_counted(&marathon), 2);
+        assert_eq!(calendar.ranking_period_months(&marathon), 18);
+        assert_eq!(calendar.ranking_period_months(&road_mile), 12);
+        assert_eq!(calendar.results_counted(&road_mile), 5);
+
+        let upcoming = calendar.upcoming_deadlines(EventGroup::Track, today);
+        let names: Vec<&str> = upcoming.iter().map(|c| c.deadline.name.as_str()).collect();
+        assert_eq!(names, vec!["Soon", "Later"]);
+        assert_eq!(upcoming[0].days_left, 6);
+    }
+
+    #[test]
+    fn test_bundled_calendar_parses() {
+        let calendar =
+            RankingCalendar::from_json(include_str!("../../data/ranking_calendar.json")).unwrap();
+        assert!(calendar.source.is_some());
+        for name in calendar.event_overrides.keys() {
+            assert!(Event::from_string(name).is_some(), "Unknown event {}", name);
+        }
+        assert!(!calendar.deadlines.is_empty());
+        for deadline in &calendar.deadlines {
+            assert!(CalendarDate::parse(&deadline.deadline).is_ok());
+            if let Some(start) = &deadline.window_start {
+                assert!(CalendarDate::parse(start).unwrap() < date(&deadline.deadline));
+            }
+            assert!(deadline.source.is_some(), "{} has no source", deadline.name);
+        }
+    }
+}
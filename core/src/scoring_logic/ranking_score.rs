@@ -0,0 +1,145 @@
+//! World Rankings score: the average of an athlete's best results within the ranking period.
+//!
+//! How many results are averaged and how far back they may date both depend on the event
+//! (its group's rules unless the event has its own), and come from the ranking calendar
+//! (see `ranking_calendar`). Each result's points
+//! are its full World Athletics score, placing points included.
+use crate::models::Event;
+use crate::scoring_logic::ranking_calendar::{CalendarDate, RankingCalendar, RankingWindow};
+
+/// A scored result entered into the simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RankingResult {
+    pub date: CalendarDate,
+    pub points: f64,
+}
+
+/// Whether a result counts towards the ranking score
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultStanding {
+    /// One of the best results in the window, averaged into the score
+    Counted,
+    /// In the window but not among the best results
+    Dropped,
+    /// Too old or after the ranking date
+    OutsideWindow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankingScore {
+    pub window: RankingWindow,
+    /// How many results the event averages
+    pub results_counted: usize,
+    /// The standing of each result, in the order they were given
+    pub standings: Vec<ResultStanding>,
+    /// The average of the counted results, or `None` without any
+    pub average: Option<f64>,
+}
+
+impl RankingScore {
+    /// The athlete has enough results in the window for an official ranking score.
+    /// With fewer, `average` is only provisional.
+    pub fn is_complete(&self) -> bool {
+        self.counted() >= self.results_counted
+    }
+
+    pub fn counted(&self) -> usize {
+        self.standings
+            .iter()
+            .filter(|standing| **standing == ResultStanding::Counted)
+            .count()
+    }
+}
+
+/// Averages the best results inside the ranking window ending on `ranking_date`
+pub fn ranking_score(
+    calendar: &RankingCalendar,
+    event: &Event,
+    ranking_date: CalendarDate,
+    results: &[RankingResult],
+) -> RankingScore {
+    let window = calendar.ranking_window(event, ranking_date);
+    let results_counted = calendar.results_counted(event);
+
+    let mut in_window: Vec<usize> = (0..results.len())
+        .filter(|&i| (window.start..=window.end).contains(&results[i].date))
+        .collect();
+    // Best first; ties keep the earlier entry
+    in_window.sort_by(|&a, &b| results[b].points.total_cmp(&results[a].points));
+
+    let mut standings = vec![ResultStanding::OutsideWindow; results.len()];
+    for (rank, &i) in in_window.iter().enumerate() {
+        standings[i] = if rank < results_counted {
+            ResultStanding::Counted
+        } else {
+            ResultStanding::Dropped
+        };
+    }
+    let counted: Vec<f64> = in_window
+        .iter()
+        .take(results_counted)
+        .map(|&i| results[i].points)
+        .collect();
+
+    RankingScore {
+        window,
+        results_counted,
+        standings,
+        average: (!counted.is_empty()).then(|| counted.iter().sum::<f64>() / counted.len() as f64),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CombinedEvent, TrackAndFieldEvent};
+
+    fn result(date: &str, points: f64) -> RankingResult {
+        RankingResult {
+            date: CalendarDate::parse(date).unwrap(),
+            points,
+        }
+    }
+
+    #[test]
+    fn test_ranking_score() {
+        let calendar = RankingCalendar::from_json(
+            r#"{"ranking_period_months": {"CombinedEvents": 18}, "results_counted": {"CombinedEvents": 2}}"#,
+        )
+        .unwrap();
+        let today = CalendarDate::parse("2025-06-14").unwrap();
+        let decathlon = Event::CombinedEvents(CombinedEvent::Dec);
+        let m100 = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let results = [
+            result("2025-05-01", 1100.0),
+            result("2023-11-01", 1300.0),
+            result("2024-06-01", 1000.0),
+            result("2025-06-01", 1200.0),
+            result("2025-07-01", 1400.0),
+        ];
+
+        let score = ranking_score(&calendar, &decathlon, today, &results);
+        assert_eq!(score.results_counted, 2);
+        assert_eq!(
+            score.standings,
+            vec![
+                ResultStanding::Counted,
+                ResultStanding::OutsideWindow,
+                ResultStanding::Dropped,
+                ResultStanding::Counted,
+                ResultStanding::OutsideWindow,
+            ]
+        );
+        assert_eq!(score.average, Some(1150.0));
+        assert!(score.is_complete());
+
+        // A 12 month default window and 5 results for the other groups
+        let score = ranking_score(&calendar, &m100, today, &results);
+        assert_eq!(score.counted(), 2);
+        assert!(!score.is_complete());
+        assert_eq!(score.average, Some(1150.0));
+
+        let score = ranking_score(&calendar, &m100, today, &[]);
+        assert_eq!(score.average, None);
+    }
+}
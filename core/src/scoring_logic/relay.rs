@@ -0,0 +1,152 @@
+//! Relay teams entered leg by leg. The splits are summed to the team time, and each split is
+//! also scored as the open event over the leg's distance. Legs after the first start running,
+//! so their equivalences flatter them compared with an open race.
+use crate::models::{Event, Gender, TrackAndFieldEvent};
+
+/// Number of legs in every supported relay
+pub const RELAY_LEGS: usize = 4;
+
+/// The open event run over one leg of a relay, or `None` for events that aren't relays or
+/// whose legs have no open equivalent (the mixed 4x400m, and the short track 4x100m as
+/// there's no short track 100m)
+pub fn relay_leg_event(event: &Event) -> Option<Event> {
+    use TrackAndFieldEvent as T;
+    let leg = match event {
+        Event::TrackAndField(T::M4x100m) => T::M100,
+        Event::TrackAndField(T::M4x200m) => T::M200,
+        Event::TrackAndField(T::M4x400m) => T::M400,
+        Event::TrackAndField(T::M4x200mSh) => T::M200mSh,
+        Event::TrackAndField(T::M4x400mSh) => T::M400mSh,
+        _ => return None,
+    };
+    Some(Event::TrackAndField(leg))
+}
+
+/// One leg's split with its open-event equivalence
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelayLeg {
+    /// Seconds
+    pub split: f64,
+    /// The split's result score in the open event, or why it couldn't be scored
+    pub open_equivalent: Result<f64, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelaySplits {
+    /// The open event each leg is compared with
+    pub leg_event: Event,
+    /// The sum of the splits, to the hundredth
+    pub team_time: f64,
+    pub legs: Vec<RelayLeg>,
+}
+
+/// Sums a relay team's leg splits (in seconds) and scores each one as the open event
+pub fn relay_splits(
+    gender: Gender,
+    event: &Event,
+    splits: &[f64],
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+) -> Result<RelaySplits, String> {
+    let leg_event =
+        relay_leg_event(event).ok_or_else(|| format!("{} can't be entered by leg", event))?;
+    if splits.len() != RELAY_LEGS {
+        return Err(format!(
+            "Expected {} splits, got {}",
+            RELAY_LEGS,
+            splits.len()
+        ));
+    }
+    if let Some(split) = splits.iter().find(|split| **split <= 0.0) {
+        return Err(format!("Invalid split: {}", split));
+    }
+    let legs = splits
+        .iter()
+        .map(|&split| RelayLeg {
+            split,
+            open_equivalent: result_score_calculator(split, gender, &leg_event),
+        })
+        .collect();
+    Ok(RelaySplits {
+        team_time: (splits.iter().sum::<f64>() * 100.0).round() / 100.0,
+        leg_event,
+        legs,
+    })
+}
+
+impl RelaySplits {
+    /// The team time written as a mark, e.g. "38.52" or "3:01.44"
+    pub fn team_mark(&self) -> String {
+        let hundredths = (self.team_time * 100.0).round() as u64;
+        let (minutes, hundredths) = (hundredths / 6000, hundredths % 6000);
+        if minutes == 0 {
+            format!("{}.{:02}", hundredths / 100, hundredths % 100)
+        } else {
+            format!(
+                "{}:{:02}.{:02}",
+                minutes,
+                hundredths / 100,
+                hundredths % 100
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        event: &Event,
+    ) -> Result<f64, String> {
+        match event {
+            Event::TrackAndField(TrackAndFieldEvent::M400) => Ok(2000.0 - performance * 20.0),
+            _ => Err(format!("No table for {}", event)),
+        }
+    }
+
+    #[test]
+    fn test_relay_splits() {
+        let relay = Event::TrackAndField(TrackAndFieldEvent::M4x400m);
+        let splits = relay_splits(
+            Gender::Men,
+            &relay,
+            &[45.31, 44.52, 44.9, 44.71],
+            mock_result_score_calculator,
+        )
+        .unwrap();
+        assert_eq!(
+            splits.leg_event,
+            Event::TrackAndField(TrackAndFieldEvent::M400)
+        );
+        assert_eq!(splits.team_time, 179.44);
+        assert_eq!(splits.team_mark(), "2:59.44");
+        assert_eq!(splits.legs[1].open_equivalent, Ok(1109.6));
+
+        let sprint = relay_splits(
+            Gender::Women,
+            &Event::TrackAndField(TrackAndFieldEvent::M4x100m),
+            &[10.9, 9.95, 10.12, 9.8],
+            mock_result_score_calculator,
+        )
+        .unwrap();
+        assert_eq!(sprint.team_mark(), "40.77");
+        assert!(sprint.legs[0].open_equivalent.is_err());
+
+        assert!(relay_splits(
+            Gender::Men,
+            &relay,
+            &[45.0; 3],
+            mock_result_score_calculator
+        )
+        .is_err());
+        assert!(relay_splits(
+            Gender::Men,
+            &Event::TrackAndField(TrackAndFieldEvent::M4x400mix),
+            &[45.0; 4],
+            mock_result_score_calculator
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::models::{Event, Gender, PerformanceType};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Bundled season lists: world-class marks per event, used for the elite distribution
+/// histogram and percentiles. Keyed by gender and event name like the coefficients table.
+#[derive(Debug, Default, Deserialize)]
+pub struct SeasonLists {
+    pub season: Option<u32>,
+    pub source: String,
+    men: HashMap<String, Vec<f64>>,
+    women: HashMap<String, Vec<f64>>,
+}
+
+impl SeasonLists {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut lists: SeasonLists = serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse season lists JSON: {}", e))?;
+        for marks in lists.men.values_mut().chain(lists.women.values_mut()) {
+            marks.retain(|mark| mark.is_finite());
+            marks.sort_by(f64::total_cmp);
+        }
+        Ok(lists)
+    }
+
+    /// The marks for an event, sorted ascending. `None` if the event isn't bundled.
+    pub fn marks(&self, gender: Gender, event: &Event) -> Option<&[f64]> {
+        let lists = match gender {
+            Gender::Men => &self.men,
+            Gender::Women => &self.women,
+        };
+        lists
+            .get(&event.to_string())
+            .filter(|marks| !marks.is_empty())
+            .map(Vec::as_slice)
+    }
+}
+
+static SEASON_LISTS: OnceLock<SeasonLists> = OnceLock::new();
+
+/// The bundled season lists, parsed on first use
+pub fn season_lists() -> &'static SeasonLists {
+    SEASON_LISTS.get_or_init(|| {
+        SeasonLists::from_json(include_str!("../../data/season_lists.json")).unwrap_or_else(|e| {
+            log::error!("{}", e);
+            SeasonLists::default()
+        })
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistogramBin {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: usize,
+}
+
+/// Splits sorted marks into `bins` equal-width bins between the lowest and highest mark
+pub fn histogram(marks: &[f64], bins: usize) -> Vec<HistogramBin> {
+    let (Some(&min), Some(&max)) = (marks.first(), marks.last()) else {
+        return Vec::new();
+    };
+    let bins = bins.max(1);
+    let width = (max - min) / bins as f64;
+    let mut result: Vec<HistogramBin> = (0..bins)
+        .map(|i| HistogramBin {
+            lower: min + width * i as f64,
+            upper: min + width * (i + 1) as f64,
+            count: 0,
+        })
+        .collect();
+    for mark in marks {
+        let index = if width > 0.0 {
+            (((mark - min) / width) as usize).min(bins - 1)
+        } else {
+            0
+        };
+        result[index].count += 1;
+    }
+    result
+}
+
+/// The percentage of marks in the list that `mark` beats or equals.
+/// Lower is better for timed events, higher for distance events and points totals.
+pub fn percentile(marks: &[f64], mark: f64, performance_type: PerformanceType) -> Option<f64> {
+    if marks.is_empty() || !mark.is_finite() {
+        return None;
+    }
+    let beaten = match performance_type {
+        PerformanceType::Time => marks.iter().filter(|&&m| m >= mark).count(),
+        PerformanceType::Distance | PerformanceType::Points => {
+            marks.iter().filter(|&&m| m <= mark).count()
+        }
+    };
+    Some(beaten as f64 * 100.0 / marks.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TrackAndFieldEvent;
+
+    const TEST_JSON: &str = r#"{
+        "season": 2024,
+        "source": "test",
+        "men": {"100m": [10.1, 9.9, 10.0, 10.3]},
+        "women": {}
+    }"#;
+
+    #[test]
+    fn test_marks_are_sorted() {
+        let lists = SeasonLists::from_json(TEST_JSON).unwrap();
+        let event = Event::TrackAndField(TrackAndFieldEvent::M100);
+        assert_eq!(
+            lists.marks(Gender::Men, &event),
+            Some(&[9.9, 10.0, 10.1, 10.3][..])
+        );
+        assert_eq!(lists.marks(Gender::Women, &event), None);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let bins = histogram(&[1.0, 1.5, 2.0, 3.0], 2);
+        assert_eq!(bins.len(), 2);
+        assert_eq!(bins[0].count, 2);
+        assert_eq!(bins[1].count, 2);
+        assert_eq!(histogram(&[5.0, 5.0], 4)[0].count, 2);
+        assert!(histogram(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let marks = [9.9, 10.0, 10.1, 10.3];
+        assert_eq!(percentile(&marks, 10.0, PerformanceType::Time), Some(75.0));
+        assert_eq!(
+            percentile(&marks, 10.0, PerformanceType::Distance),
+            Some(50.0)
+        );
+        assert_eq!(percentile(&[], 10.0, PerformanceType::Time), None);
+    }
+
+    #[test]
+    fn test_bundled_season_lists_parse() {
+        assert!(SeasonLists::from_json(include_str!("../../data/season_lists.json")).is_ok());
+    }
+}
@@ -0,0 +1,262 @@
+//! Selection criteria defined as data and evaluated against an athlete's results.
+//!
+//! ```json
+//! {
+//!   "name": "National team 100m",
+//!   "gender": "Men",
+//!   "criteria": [
+//!     { "type": "minimum_points", "event": "100m", "points": 1150 },
+//!     { "type": "standard_mark", "event": "100m", "mark": "10.05" },
+//!     { "type": "placing", "competition_category": "B", "place": 3 }
+//!   ]
+//! }
+//! ```
+use crate::models::*;
+use crate::scoring_logic::calculator::{calculate_world_athletics_score, is_wind_affected_event};
+use crate::scoring_logic::placement_score::{PlacementScoreCalcInput, RoundType};
+use serde::Deserialize;
+
+/// Wind above this (m/s) makes a mark wind-assisted, so it doesn't count towards a standard
+pub const MAX_LEGAL_WIND: f64 = 2.0;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SelectionCriterion {
+    /// A result worth at least `points` World Athletics points (result score, wind and placing)
+    /// in the event, or in any event if `event` is omitted
+    MinimumPoints {
+        #[serde(default)]
+        event: Option<String>,
+        points: f64,
+    },
+    /// A wind-legal mark equal to or better than `mark` in the event
+    StandardMark { event: String, mark: String },
+    /// A final placing of `place` or better at a meet of the competition category,
+    /// in the event or in any event if `event` is omitted
+    Placing {
+        competition_category: CompetitionCategory,
+        place: i32,
+        #[serde(default)]
+        event: Option<String>,
+    },
+}
+
+impl SelectionCriterion {
+    pub fn description(&self) -> String {
+        match self {
+            SelectionCriterion::MinimumPoints { event, points } => format!(
+                "{:.0} points in {}",
+                points,
+                event.as_deref().unwrap_or("any event")
+            ),
+            SelectionCriterion::StandardMark { event, mark } => {
+                format!("{} standard of {}", event, mark)
+            }
+            SelectionCriterion::Placing {
+                competition_category,
+                place,
+                event,
+            } => format!(
+                "Top {} at a category {} meet in {}",
+                place,
+                competition_category,
+                event.as_deref().unwrap_or("any event")
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SelectionCriteria {
+    pub name: String,
+    pub gender: Gender,
+    pub criteria: Vec<SelectionCriterion>,
+}
+
+impl SelectionCriteria {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid selection criteria: {}", e))
+    }
+}
+
+/// Whether one criterion was met, with the result that met it or the reason it wasn't
+#[derive(Debug, Clone, PartialEq)]
+pub struct CriterionReport {
+    pub description: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+fn event_matches(event: &Option<String>, result: &WorldAthleticsScoreInput) -> bool {
+    event
+        .as_ref()
+        .is_none_or(|name| Event::from_string(name).as_ref() == Some(&result.event))
+}
+
+fn is_wind_legal(result: &WorldAthleticsScoreInput) -> bool {
+    !is_wind_affected_event(&result.event)
+        || result.wind_speed.is_none_or(|wind| wind <= MAX_LEGAL_WIND)
+}
+
+/// Evaluates an athlete's results against each criterion. Results for the other gender
+/// are ignored.
+pub fn evaluate_selection(
+    selection: &SelectionCriteria,
+    results: &[WorldAthleticsScoreInput],
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Vec<CriterionReport> {
+    let results: Vec<&WorldAthleticsScoreInput> = results
+        .iter()
+        .filter(|result| result.gender == selection.gender)
+        .collect();
+
+    selection
+        .criteria
+        .iter()
+        .map(|criterion| {
+            let outcome = match criterion {
+                SelectionCriterion::MinimumPoints { event, points } => {
+                    let best = results
+                        .iter()
+                        .filter(|result| event_matches(event, result))
+                        .filter_map(|result| {
+                            calculate_world_athletics_score(
+                                (*result).clone(),
+                                result_score_calculator,
+                                placement_score_calculator,
+                            )
+                            .ok()
+                            .map(|score| (score.points, &result.event))
+                        })
+                        .max_by(|a, b| a.0.total_cmp(&b.0));
+                    match best {
+                        Some((best, event)) => Ok((
+                            best >= *points,
+                            format!("Best: {:.0} points in {}", best, event),
+                        )),
+                        None => Ok((false, "No scored results".to_string())),
+                    }
+                }
+                SelectionCriterion::StandardMark { event, mark } => Event::from_string(event)
+                    .ok_or_else(|| format!("Unknown event: {}", event))
+                    .and_then(|standard_event| {
+                        let standard = Performance::parse(&standard_event, mark)?.value();
+                        let better = |a: f64, b: f64| match standard_event.performance_type() {
+                            PerformanceType::Time => a <= b,
+                            PerformanceType::Distance | PerformanceType::Points => a >= b,
+                        };
+                        let best = results
+                            .iter()
+                            .filter(|result| {
+                                result.event == standard_event && is_wind_legal(result)
+                            })
+                            .map(|result| result.performance.value())
+                            .reduce(|a, b| if better(a, b) { a } else { b });
+                        Ok(match best {
+                            Some(best) => (
+                                better(best, standard),
+                                format!(
+                                    "Best legal mark: {}",
+                                    standard_event.format_performance(best)
+                                ),
+                            ),
+                            None => (false, "No legal marks".to_string()),
+                        })
+                    }),
+                SelectionCriterion::Placing {
+                    competition_category,
+                    place,
+                    event,
+                } => {
+                    let best = results
+                        .iter()
+                        .filter(|result| event_matches(event, result))
+                        .filter_map(|result| result.placement_info.as_ref())
+                        .filter(|info| {
+                            info.competition_category == *competition_category
+                                && info.round == RoundType::Final
+                        })
+                        .map(|info| info.place)
+                        .min();
+                    Ok(match best {
+                        Some(best) => (best <= *place, format!("Best placing: {}", best)),
+                        None => (false, "No finals at this category".to_string()),
+                    })
+                }
+            };
+            let (passed, detail) = outcome.unwrap_or_else(|e| (false, e));
+            CriterionReport {
+                description: criterion.description(),
+                passed,
+                detail,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_result_score(performance: f64, _: Gender, _: &Event) -> Result<f64, String> {
+        Ok(2000.0 - performance * 100.0)
+    }
+
+    fn mock_placement_score(input: PlacementScoreCalcInput) -> Option<i32> {
+        Some(100 - input.place * 10)
+    }
+
+    fn sprint(performance: f64, wind_speed: f64, place: i32) -> WorldAthleticsScoreInput {
+        WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            performance: Performance::Seconds(performance),
+            wind_speed: Some(wind_speed),
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: Some(PlacementInfo {
+                competition_category: CompetitionCategory::B,
+                place,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        }
+    }
+
+    #[test]
+    fn test_evaluate_selection() {
+        let selection = SelectionCriteria::from_json(
+            r#"{
+                "name": "Test team",
+                "gender": "Men",
+                "criteria": [
+                    { "type": "minimum_points", "event": "100m", "points": 1050 },
+                    { "type": "standard_mark", "event": "100m", "mark": "10.05" },
+                    { "type": "placing", "competition_category": "B", "place": 3 },
+                    { "type": "standard_mark", "event": "Egg and Spoon", "mark": "10.05" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        // The faster mark is wind-assisted, so only 10.10 counts towards the standard
+        let results = [sprint(10.10, 1.5, 4), sprint(10.00, 2.5, 2)];
+        let report = evaluate_selection(
+            &selection,
+            &results,
+            mock_result_score,
+            mock_placement_score,
+        );
+
+        assert!(report[0].passed, "{:?}", report[0]);
+        assert!(!report[1].passed);
+        assert_eq!(report[1].detail, "Best legal mark: 00:10.100");
+        assert!(report[2].passed);
+        assert!(!report[3].passed);
+        assert_eq!(report[3].detail, "Unknown event: Egg and Spoon");
+    }
+}
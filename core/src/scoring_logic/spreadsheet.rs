@@ -0,0 +1,57 @@
+// src/scoring_logic/spreadsheet.rs
+use super::coefficients::{Coefficients, MAX_RESULT_SCORE};
+
+/// Formats a coefficient as a signed term. `f64`'s `Display` never uses exponent
+/// notation, so the full precision is inlined as a plain decimal.
+fn signed_term(value: f64, suffix: &str) -> String {
+    let sign = if value < 0.0 { "-" } else { "+" };
+    format!("{}{}{}", sign, value.abs(), suffix)
+}
+
+/// Builds an Excel/Google Sheets formula that scores the performance in `cell`.
+///
+/// The formula mirrors `CoefficientsTable::calculate_result_score`: the quadratic is
+/// rounded half away from zero (`ROUND` matches Rust's `f64::round`) and capped at
+/// `MAX_RESULT_SCORE`. The cell must hold seconds for time-based events and meters
+/// for field events.
+pub fn result_score_formula(coefficients: &Coefficients, cell: &str) -> String {
+    format!(
+        "=MIN({},ROUND({}*{}^2{}{},0))",
+        MAX_RESULT_SCORE,
+        coefficients.conversion_factor,
+        cell,
+        signed_term(coefficients.result_shift, &format!("*{}", cell)),
+        signed_term(coefficients.point_shift, ""),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_result_score_formula() {
+        let coefficients = Coefficients {
+            conversion_factor: 24.642211664166098,
+            result_shift: -837.7135408530303,
+            point_shift: 7119.3125116789015,
+        };
+        assert_eq!(
+            result_score_formula(&coefficients, "B2"),
+            "=MIN(1400,ROUND(24.642211664166098*B2^2-837.7135408530303*B2+7119.3125116789015,0))"
+        );
+    }
+
+    #[test]
+    fn test_result_score_formula_small_coefficient() {
+        let coefficients = Coefficients {
+            conversion_factor: 2.735041573859393e-5,
+            result_shift: -0.623559717833615,
+            point_shift: 3554.1219906366896,
+        };
+        assert_eq!(
+            result_score_formula(&coefficients, "A1"),
+            "=MIN(1400,ROUND(0.00002735041573859393*A1^2-0.623559717833615*A1+3554.1219906366896,0))"
+        );
+    }
+}
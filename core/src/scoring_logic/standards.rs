@@ -0,0 +1,127 @@
+use crate::models::{Event, Gender};
+
+/// One event on a meet's program
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgramEvent {
+    pub gender: Gender,
+    pub event: Event,
+}
+
+/// A named points threshold, e.g. "Entry standard" at 1000 points
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointsThreshold {
+    pub name: String,
+    pub points: f64,
+}
+
+/// The performances matching each threshold for one program event,
+/// in the same order as the thresholds
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventStandards {
+    pub program_event: ProgramEvent,
+    pub standards: Vec<Result<f64, String>>,
+}
+
+/// Converts points thresholds into performance standards for every event on the program
+pub fn performance_standards(
+    program: &[ProgramEvent],
+    thresholds: &[PointsThreshold],
+    performance_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+) -> Vec<EventStandards> {
+    program
+        .iter()
+        .map(|program_event| EventStandards {
+            program_event: program_event.clone(),
+            standards: thresholds
+                .iter()
+                .map(|threshold| {
+                    performance_calculator(
+                        threshold.points,
+                        program_event.gender,
+                        &program_event.event,
+                    )
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+/// Rows of an entry-standards sheet: a header, then one row per program event
+pub fn standards_rows(
+    competition_category: &str,
+    thresholds: &[PointsThreshold],
+    standards: &[EventStandards],
+) -> Vec<Vec<String>> {
+    let header = ["Category", "Gender", "Event"]
+        .into_iter()
+        .map(String::from)
+        .chain(
+            thresholds
+                .iter()
+                .map(|threshold| format!("{} ({:.0} pts)", threshold.name, threshold.points)),
+        )
+        .collect();
+    std::iter::once(header)
+        .chain(standards.iter().map(|event_standards| {
+            let event = &event_standards.program_event.event;
+            [
+                competition_category.to_string(),
+                event_standards.program_event.gender.to_string(),
+                event.to_string(),
+            ]
+            .into_iter()
+            .chain(
+                event_standards
+                    .standards
+                    .iter()
+                    .map(|standard| match standard {
+                        Ok(performance) => event.format_performance(*performance),
+                        Err(_) => String::new(),
+                    }),
+            )
+            .collect()
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TrackAndFieldEvent;
+
+    fn mock_performance_calculator(points: f64, _: Gender, event: &Event) -> Result<f64, String> {
+        match event {
+            Event::TrackAndField(TrackAndFieldEvent::M100) => Ok(20.0 - points / 100.0),
+            _ => Err(format!("No coefficients for {}", event)),
+        }
+    }
+
+    #[test]
+    fn test_standards_sheet() {
+        let program = vec![
+            ProgramEvent {
+                gender: Gender::Men,
+                event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            },
+            ProgramEvent {
+                gender: Gender::Women,
+                event: Event::TrackAndField(TrackAndFieldEvent::LJ),
+            },
+        ];
+        let thresholds = vec![PointsThreshold {
+            name: "Entry standard".to_string(),
+            points: 1000.0,
+        }];
+        let standards = performance_standards(&program, &thresholds, mock_performance_calculator);
+        assert_eq!(standards[0].standards, vec![Ok(10.0)]);
+        assert!(standards[1].standards[0].is_err());
+
+        let rows = standards_rows("A", &thresholds, &standards);
+        assert_eq!(
+            rows[0],
+            ["Category", "Gender", "Event", "Entry standard (1000 pts)"]
+        );
+        assert_eq!(rows[1], ["A", "men", "100m", "00:10.000"]);
+        assert_eq!(rows[2][3], "");
+    }
+}
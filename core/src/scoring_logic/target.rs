@@ -0,0 +1,121 @@
+//! The performance an athlete needs to reach a points target, optionally counting the
+//! placing points they expect to earn at a competition.
+use crate::models::{Event, Gender, PlacementInfo};
+use crate::scoring_logic::calculator::shared_placement_score;
+use crate::scoring_logic::coefficients::MAX_RESULT_SCORE;
+use crate::scoring_logic::placement_score::PlacementScoreCalcInput;
+
+/// What it takes to reach a points target
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetPerformance {
+    /// The points the mark itself has to score
+    pub result_score: f64,
+    /// The placing points assumed to be earned on top of the mark
+    pub placement_score: i32,
+    /// The mark that scores `result_score`, in the event's unit. `None` when the placing
+    /// points alone reach the target.
+    pub performance: Option<f64>,
+}
+
+/// Works out the mark needed to score `target_points` in an event. Placing points for
+/// `placement_info` are taken off the target first. Wind and downhill adjustments are
+/// not counted.
+pub fn required_performance(
+    gender: Gender,
+    event: &Event,
+    target_points: f64,
+    placement_info: Option<&PlacementInfo>,
+    performance_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Result<TargetPerformance, String> {
+    let placement_score = placement_info
+        .map(|placement_info| {
+            shared_placement_score(event, placement_info, &placement_score_calculator)
+        })
+        .unwrap_or(0);
+    let result_score = target_points - placement_score as f64;
+    if result_score > MAX_RESULT_SCORE {
+        return Err(format!(
+            "{:.0} points needs a result score of {:.0}, above the tables' maximum of {:.0}",
+            target_points, result_score, MAX_RESULT_SCORE
+        ));
+    }
+    let performance = if result_score > 0.0 {
+        Some(performance_calculator(result_score, gender, event)?)
+    } else {
+        None
+    };
+    Ok(TargetPerformance {
+        result_score: result_score.max(0.0),
+        placement_score,
+        performance,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CompetitionCategory, TrackAndFieldEvent};
+    use crate::scoring_logic::placement_score::RoundType;
+
+    fn mock_performance_calculator(
+        points: f64,
+        _gender: Gender,
+        _event: &Event,
+    ) -> Result<f64, String> {
+        Ok(20.0 - points / 100.0)
+    }
+
+    fn mock_placement_score_calculator(input: PlacementScoreCalcInput) -> Option<i32> {
+        (input.place == 1).then_some(200)
+    }
+
+    fn placing(place: i32) -> PlacementInfo {
+        PlacementInfo {
+            competition_category: CompetitionCategory::A,
+            place,
+            round: RoundType::Final,
+            size_of_final: 8,
+            qualified_to_final: false,
+            tied_with: 0,
+            main_event: false,
+        }
+    }
+
+    #[test]
+    fn test_required_performance() {
+        let event = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let required = |points: f64, placement_info: Option<&PlacementInfo>| {
+            required_performance(
+                Gender::Men,
+                &event,
+                points,
+                placement_info,
+                mock_performance_calculator,
+                mock_placement_score_calculator,
+            )
+        };
+
+        let target = required(1000.0, None).unwrap();
+        assert_eq!(target.result_score, 1000.0);
+        assert_eq!(target.performance, Some(10.0));
+
+        let target = required(1000.0, Some(&placing(1))).unwrap();
+        assert_eq!(target.placement_score, 200);
+        assert_eq!(target.result_score, 800.0);
+        assert_eq!(target.performance, Some(12.0));
+
+        // No placing points for other places
+        assert_eq!(
+            required(1000.0, Some(&placing(5))).unwrap().result_score,
+            1000.0
+        );
+
+        let target = required(150.0, Some(&placing(1))).unwrap();
+        assert_eq!(target.result_score, 0.0);
+        assert_eq!(target.performance, None);
+
+        assert!(required(1500.0, None).is_err());
+        assert!(required(1500.0, Some(&placing(1))).is_ok());
+    }
+}
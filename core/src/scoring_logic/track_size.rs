@@ -0,0 +1,147 @@
+//! The size of the indoor track a short-track mark was set on.
+//!
+//! World Athletics counts marks from indoor tracks longer than 200m ("oversized" tracks) as
+//! outdoor marks for rankings, so they're scored with the outdoor tables. Flat and banked
+//! 200m tracks are both standard short tracks and score the same; the flat track is only
+//! noted alongside the score.
+use crate::models::{Event, TrackAndFieldEvent};
+use std::fmt;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, Default)]
+pub enum TrackSize {
+    /// A standard 200m track with banked turns
+    #[default]
+    Banked,
+    /// A 200m track without banked turns
+    Flat,
+    /// A track longer than 200m (typically 300m or more)
+    Oversized,
+}
+
+impl fmt::Display for TrackSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            TrackSize::Banked => "200m banked",
+            TrackSize::Flat => "200m flat",
+            TrackSize::Oversized => "Oversized (300m+)",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl TrackSize {
+    /// A short name for share links
+    pub fn code(&self) -> &'static str {
+        match self {
+            TrackSize::Banked => "banked",
+            TrackSize::Flat => "flat",
+            TrackSize::Oversized => "oversized",
+        }
+    }
+
+    pub fn from_code(s: &str) -> Option<TrackSize> {
+        TrackSize::iter().find(|variant| variant.code() == s)
+    }
+}
+
+/// Determines if an event is run on a short (indoor) track
+pub fn is_short_track_event(event: &Event) -> bool {
+    outdoor_equivalent(event).is_some()
+        || matches!(
+            event,
+            Event::TrackAndField(TrackAndFieldEvent::MileSh | TrackAndFieldEvent::M2MilesSh)
+        )
+}
+
+/// The outdoor event a short-track event is scored as on an oversized track. The Mile and 2
+/// Miles have no outdoor event in the calculator, so they have none.
+pub fn outdoor_equivalent(event: &Event) -> Option<Event> {
+    use TrackAndFieldEvent as T;
+    let Event::TrackAndField(event) = event else {
+        return None;
+    };
+    let outdoor = match event {
+        T::M200mSh => T::M200,
+        T::M300mSh => T::M300,
+        T::M400mSh => T::M400,
+        T::M500mSh => T::M500,
+        T::M600mSh => T::M600,
+        T::M800mSh => T::M800,
+        T::M1000mSh => T::M1000,
+        T::M1500mSh => T::M1500,
+        T::M2000mSh => T::M2000,
+        T::M3000mSh => T::M3000,
+        T::M5000mSh => T::M5000,
+        T::M4x100mSh => T::M4x100m,
+        T::M4x200mSh => T::M4x200m,
+        T::M4x400mSh => T::M4x400m,
+        T::M4x400mixSh => T::M4x400mix,
+        _ => return None,
+    };
+    Some(Event::TrackAndField(outdoor))
+}
+
+/// The event a mark is scored as, given the track it was set on, and a note on the treatment
+/// to show alongside the score (if any)
+pub fn scoring_event(event: &Event, track_size: TrackSize) -> (Event, Option<String>) {
+    if !is_short_track_event(event) {
+        return (event.clone(), None);
+    }
+    match track_size {
+        TrackSize::Banked => (event.clone(), None),
+        TrackSize::Flat => (
+            event.clone(),
+            Some("Flat track: scored the same as a banked track".to_string()),
+        ),
+        TrackSize::Oversized => match outdoor_equivalent(event) {
+            Some(outdoor) => {
+                let note = format!("Oversized track: scored as an outdoor {}", outdoor);
+                (outdoor, Some(note))
+            }
+            None => (
+                event.clone(),
+                Some(
+                    "Oversized track: no outdoor table for this event, scored as short track"
+                        .to_string(),
+                ),
+            ),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoring_event() {
+        let short = Event::TrackAndField(TrackAndFieldEvent::M800mSh);
+        assert_eq!(
+            scoring_event(&short, TrackSize::Banked),
+            (short.clone(), None)
+        );
+        let (event, note) = scoring_event(&short, TrackSize::Flat);
+        assert_eq!(event, short);
+        assert!(note.is_some());
+        let (event, note) = scoring_event(&short, TrackSize::Oversized);
+        assert_eq!(event, Event::TrackAndField(TrackAndFieldEvent::M800));
+        assert!(note.unwrap().contains("800m"));
+
+        let mile = Event::TrackAndField(TrackAndFieldEvent::MileSh);
+        let (event, note) = scoring_event(&mile, TrackSize::Oversized);
+        assert_eq!(event, mile);
+        assert!(note.is_some());
+
+        let outdoor = Event::TrackAndField(TrackAndFieldEvent::M800);
+        assert_eq!(
+            scoring_event(&outdoor, TrackSize::Oversized),
+            (outdoor.clone(), None)
+        );
+        assert_eq!(
+            TrackSize::from_code("oversized"),
+            Some(TrackSize::Oversized)
+        );
+    }
+}
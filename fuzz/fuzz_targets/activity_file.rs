@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use world_athletics_points_calulator::importers::activity_file::parse_activity_file;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_activity_file("activity.fit", data);
+    let _ = parse_activity_file("activity.tcx", data);
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use world_athletics_points_calulator::importers::open_track::{parse_results, score_and_rank};
+use world_athletics_points_calulator::models::CompetitionCategory;
+use world_athletics_points_calulator::scoring_logic::coefficients::calculate_result_score;
+use world_athletics_points_calulator::scoring_logic::placement_score::calculate_placement_score;
+
+// Parses and scores arbitrary results files with the real tables, as the Import page does
+fuzz_target!(|data: &str| {
+    if let Ok(results) = parse_results(data) {
+        let _ = score_and_rank(
+            results,
+            CompetitionCategory::A,
+            calculate_result_score,
+            calculate_placement_score,
+        );
+    }
+});
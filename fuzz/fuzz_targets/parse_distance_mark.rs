@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use world_athletics_points_calulator::models::parse_distance_mark;
+
+fuzz_target!(|data: &str| {
+    if let Ok(meters) = parse_distance_mark(data) {
+        assert!(meters.is_finite() && meters >= 0.0);
+    }
+});
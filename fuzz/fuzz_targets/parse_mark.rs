@@ -0,0 +1,17 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use world_athletics_points_calulator::importers::open_track::parse_mark;
+use world_athletics_points_calulator::models::Event;
+
+// The first byte picks the event so both time and distance parsing are exercised
+fuzz_target!(|data: &[u8]| {
+    let Some((selector, mark)) = data.split_first() else {
+        return;
+    };
+    let events = Event::all_variants();
+    let event = &events[*selector as usize % events.len()];
+    if let Ok(mark) = std::str::from_utf8(mark) {
+        let _ = parse_mark(event, mark);
+    }
+});
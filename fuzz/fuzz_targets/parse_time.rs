@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use world_athletics_points_calulator::models::Event;
+
+fuzz_target!(|data: &str| {
+    if let Ok(seconds) = Event::parse_time_to_seconds(data) {
+        let _ = Event::seconds_to_time_string(seconds);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use world_athletics_points_calulator::importers::results_csv::score_results_csv;
+use world_athletics_points_calulator::models::CompetitionCategory;
+use world_athletics_points_calulator::scoring_logic::coefficients::calculate_result_score;
+use world_athletics_points_calulator::scoring_logic::placement_score::calculate_placement_score;
+
+// Parses, scores and re-exports arbitrary CSV files with the real tables, as the Import page does
+fuzz_target!(|data: &str| {
+    if let Ok(scored) = score_results_csv(
+        data,
+        CompetitionCategory::A,
+        calculate_result_score,
+        calculate_placement_score,
+    ) {
+        let _ = scored.to_csv();
+    }
+});
@@ -0,0 +1,93 @@
+// Service worker for offline use at meets with poor reception.
+//
+// Trunk gives the WASM bundle, JS glue and CSS hashed file names, so they never change
+// once cached and are served cache-first. Pages are fetched from the network when it's
+// available and fall back to the cached app shell. The published scoring data under
+// `api/static/` is network-first so updated tables still arrive, with the cached copy
+// used offline (the app also has the official tables built in).
+
+// Bump to drop everything cached by older versions
+const CACHE_NAME = "wa-points-app-v1";
+const SCOPE = new URL(self.registration.scope);
+const SHELL_URL = SCOPE.pathname;
+const HASHED_ASSET = /-[0-9a-f]{8,16}(_bg)?\.(wasm|js|css)$/;
+
+// Caches the app shell and every asset it links to
+async function cacheShell() {
+  const cache = await caches.open(CACHE_NAME);
+  const response = await fetch(SHELL_URL, { cache: "no-cache" });
+  if (!response.ok) {
+    return;
+  }
+  const html = await response.clone().text();
+  await cache.put(SHELL_URL, response);
+  const assets = [...html.matchAll(/(?:href|src)="([^"]+)"/g)]
+    .map((match) => new URL(match[1], SCOPE).href)
+    .filter((url) => url.startsWith(SCOPE.origin));
+  await cache.addAll([...new Set(assets)]);
+}
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(cacheShell().then(() => self.skipWaiting()));
+});
+
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((names) =>
+        Promise.all(
+          names
+            .filter((name) => name.startsWith("wa-points-app-") && name !== CACHE_NAME)
+            .map((name) => caches.delete(name)),
+        ),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+async function cacheFirst(request) {
+  const cached = await caches.match(request);
+  if (cached) {
+    return cached;
+  }
+  const response = await fetch(request);
+  if (response.ok) {
+    const cache = await caches.open(CACHE_NAME);
+    await cache.put(request, response.clone());
+  }
+  return response;
+}
+
+async function networkFirst(request, fallbackUrl) {
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      const cache = await caches.open(CACHE_NAME);
+      await cache.put(fallbackUrl || request, response.clone());
+    }
+    return response;
+  } catch (error) {
+    const cached = await caches.match(fallbackUrl || request);
+    if (cached) {
+      return cached;
+    }
+    throw error;
+  }
+}
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  const url = new URL(request.url);
+  if (request.method !== "GET" || url.origin !== SCOPE.origin) {
+    return;
+  }
+  if (request.mode === "navigate") {
+    // Every route is rendered by the same shell
+    event.respondWith(networkFirst(request, SHELL_URL));
+  } else if (HASHED_ASSET.test(url.pathname)) {
+    event.respondWith(cacheFirst(request));
+  } else if (url.pathname.startsWith(SCOPE.pathname)) {
+    event.respondWith(networkFirst(request));
+  }
+});
@@ -0,0 +1,266 @@
+//! HTTP scoring API for meet-management software and other services.
+//!
+//! - `POST /score` scores one `WorldAthleticsScoreInput` and returns the score breakdown
+//! - `POST /score/batch` scores an array of inputs; each entry is a breakdown or an `error`
+//! - `GET /events` lists the events with their performance type and genders
+//! - `GET /availability` lists the genders and venues every event can be scored for
+//! - `GET /tables/{gender}/{event}?page=N` returns a page of the event's full points table,
+//!   100 rows from 1400 points down; `page` counts from 0
+//! - `GET /healthz` returns the status, server version, table edition and data checksums
+//!
+//! Run with `cargo run -p wa_points_server -- <address>` (default `127.0.0.1:8080`).
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use wa_points_core::build_info::{build_info, BuildInfo};
+use wa_points_core::models::*;
+use wa_points_core::scoring_logic::availability::{availability_matrix, EventAvailability};
+use wa_points_core::scoring_logic::calculator::{is_road_running_event, is_wind_affected_event};
+use wa_points_core::scoring_logic::coefficients::performance_for_score;
+use wa_points_core::scoring_logic::engine::score_in_edition;
+use wa_points_core::scoring_logic::points_table::{
+    points_table, points_table_page, PointsTablePage, POINTS_TABLE_PAGE_SIZE,
+};
+use wa_points_core::scoring_logic::provider::BundledTables;
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8080";
+
+/// The body of a failed request, and of a batch entry that couldn't be scored
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+/// One entry of a `/score/batch` response, in the order of the request
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum BatchEntry {
+    Scored(WorldAthleticsScoreOutput),
+    Failed(ErrorBody),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct EventInfo {
+    name: String,
+    code: &'static str,
+    performance_type: &'static str,
+    genders: Vec<Gender>,
+    wind_affected: bool,
+    road_running: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct TablePageQuery {
+    #[serde(default)]
+    page: usize,
+}
+
+/// Inputs naming a bundled edition are scored against its tables
+fn score_input(input: WorldAthleticsScoreInput) -> Result<WorldAthleticsScoreOutput, String> {
+    score_in_edition(input)
+}
+
+fn event_info(event: &Event) -> EventInfo {
+    EventInfo {
+        name: event.to_string(),
+        code: event.code(),
+        performance_type: match event.performance_type() {
+            PerformanceType::Time => "time",
+            PerformanceType::Distance => "distance",
+            PerformanceType::Points => "points",
+        },
+        genders: Gender::iter()
+            .filter(|gender| event.is_available_for(*gender, None))
+            .collect(),
+        wind_affected: is_wind_affected_event(event),
+        road_running: is_road_running_event(event),
+    }
+}
+
+async fn score(
+    Json(input): Json<WorldAthleticsScoreInput>,
+) -> Result<Json<WorldAthleticsScoreOutput>, (StatusCode, Json<ErrorBody>)> {
+    score_input(input)
+        .map(Json)
+        .map_err(|error| (StatusCode::UNPROCESSABLE_ENTITY, Json(ErrorBody { error })))
+}
+
+async fn score_batch(Json(inputs): Json<Vec<WorldAthleticsScoreInput>>) -> Json<Vec<BatchEntry>> {
+    Json(
+        inputs
+            .into_iter()
+            .map(|input| match score_input(input) {
+                Ok(output) => BatchEntry::Scored(output),
+                Err(error) => BatchEntry::Failed(ErrorBody { error }),
+            })
+            .collect(),
+    )
+}
+
+async fn events() -> Json<Vec<EventInfo>> {
+    Json(Event::all_variants().iter().map(event_info).collect())
+}
+
+async fn availability() -> Json<Vec<EventAvailability>> {
+    Json(availability_matrix(&BundledTables))
+}
+
+/// The gender and event come from the path, so the event may be a code ("LJ") or a name
+async fn table_page(
+    Path((gender, event)): Path<(String, String)>,
+    Query(query): Query<TablePageQuery>,
+) -> Result<Json<PointsTablePage>, (StatusCode, Json<ErrorBody>)> {
+    let gender = Gender::from_string(&gender)
+        .ok_or_else(|| format!("Unknown gender: {}", gender))
+        .map_err(|error| (StatusCode::NOT_FOUND, Json(ErrorBody { error })))?;
+    let event: Event = event
+        .parse()
+        .map_err(|error| (StatusCode::NOT_FOUND, Json(ErrorBody { error })))?;
+    points_table(gender, &event, performance_for_score)
+        .and_then(|rows| points_table_page(&rows, query.page, POINTS_TABLE_PAGE_SIZE))
+        .map(Json)
+        .map_err(|error| (StatusCode::UNPROCESSABLE_ENTITY, Json(ErrorBody { error })))
+}
+
+async fn healthz() -> Json<BuildInfo> {
+    Json(build_info(env!("CARGO_PKG_VERSION")))
+}
+
+fn router() -> Router {
+    Router::new()
+        .route("/score", post(score))
+        .route("/score/batch", post(score_batch))
+        .route("/events", get(events))
+        .route("/availability", get(availability))
+        .route("/tables/{gender}/{event}", get(table_page))
+        .route("/healthz", get(healthz))
+}
+
+#[tokio::main]
+async fn main() {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+
+    let listener = match tokio::net::TcpListener::bind(&address).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Failed to listen on {}: {}", address, e);
+            std::process::exit(1);
+        }
+    };
+    println!("Scoring API listening on http://{}", address);
+    if let Err(e) = axum::serve(listener, router()).await {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_score_batch() {
+        let inputs: Vec<WorldAthleticsScoreInput> = serde_json::from_str(
+            r#"[{"gender": "men", "event": "100m", "performance": {"seconds": 10.0}},
+                {"gender": "men", "event": "100m", "performance": {"meters": 10.0}}]"#,
+        )
+        .unwrap();
+        let Json(entries) = score_batch(Json(inputs)).await;
+        assert!(matches!(entries[0], BatchEntry::Scored(_)));
+        assert!(matches!(entries[1], BatchEntry::Failed(_)));
+
+        let json = serde_json::to_value(&entries).unwrap();
+        assert!(json[0]["points"].is_number());
+        assert!(json[1]["error"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_score_rejects_out_of_range_ties() {
+        let input: WorldAthleticsScoreInput = serde_json::from_str(
+            r#"{"gender": "men", "event": "100m", "performance": {"seconds": 10.0},
+                "placement_info": {"competition_category": "A", "place": 3, "round": "Final",
+                                   "tied_with": 2147483647}}"#,
+        )
+        .unwrap();
+        let (status, Json(body)) = score(Json(input)).await.unwrap_err();
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.error.contains("tied"));
+    }
+
+    #[tokio::test]
+    async fn test_events() {
+        let Json(events) = events().await;
+        assert_eq!(events.len(), Event::all_variants().len());
+        let hundred = events.iter().find(|e| e.name == "100m").unwrap();
+        assert_eq!(hundred.performance_type, "time");
+        assert_eq!(hundred.genders, vec![Gender::Men, Gender::Women]);
+        assert!(hundred.wind_affected);
+    }
+
+    #[tokio::test]
+    async fn test_availability() {
+        let Json(matrix) = availability().await;
+        let json = serde_json::to_value(&matrix).unwrap();
+        let hurdles = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|row| row["event"] == "110m Hurdle")
+            .unwrap();
+        assert_eq!(hurdles["genders"], serde_json::json!(["men"]));
+        assert_eq!(hurdles["venues"], serde_json::json!(["outdoor"]));
+    }
+
+    #[tokio::test]
+    async fn test_table_page() {
+        let page = |gender: &str, event: &str, page: usize| {
+            table_page(
+                Path((gender.to_string(), event.to_string())),
+                Query(TablePageQuery { page }),
+            )
+        };
+        let Json(first) = page("men", "100m", 0).await.unwrap();
+        assert_eq!(first.page_count, 14);
+        assert_eq!(first.rows[0].points, 1400);
+
+        let Json(last) = page("women", "LJ", 13).await.unwrap();
+        assert_eq!(last.rows.last().unwrap().points, 1);
+
+        assert_eq!(
+            page("men", "100m", 14).await.unwrap_err().0,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            page("women", "110mH", 0).await.unwrap_err().0,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            page("men", "egg and spoon", 0).await.unwrap_err().0,
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn test_healthz() {
+        use axum::body::{to_bytes, Body};
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let response = router()
+            .oneshot(Request::get("/healthz").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["status"], "ok");
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        assert!(json["table_edition"].is_string());
+        assert!(!json["data"].as_array().unwrap().is_empty());
+    }
+}
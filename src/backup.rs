@@ -0,0 +1,156 @@
+//! Full backup of the app's local data (settings, stored results and calculation history) as one versioned JSON
+//! file, for moving to another device or guarding against the browser evicting storage.
+use crate::history::HistoryEntry;
+use crate::performance_db::{PerformanceDb, StoredPerformance};
+use crate::settings::Settings;
+use serde::{Deserialize, Serialize};
+
+/// Bump when the file format changes in a way older versions can't read
+pub const BACKUP_VERSION: u32 = 1;
+
+/// The file written by `export_backup`. Sections added in later versions default to empty
+/// so older backups still import.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Backup {
+    pub version: u32,
+    /// ISO 8601 date the backup was made
+    #[serde(default)]
+    pub created: String,
+    #[serde(default)]
+    pub settings: Settings,
+    #[serde(default)]
+    pub performances: Vec<StoredPerformance>,
+    #[serde(default)]
+    pub history: Vec<HistoryEntry>,
+}
+
+/// Serializes the local data for saving to a file
+pub fn export_backup(
+    created: &str,
+    settings: &Settings,
+    performances: &[StoredPerformance],
+    history: &[HistoryEntry],
+) -> String {
+    serde_json::to_string_pretty(&Backup {
+        version: BACKUP_VERSION,
+        created: created.to_string(),
+        settings: settings.clone(),
+        performances: performances.to_vec(),
+        history: history.to_vec(),
+    })
+    .unwrap_or_default()
+}
+
+/// Reads a backup file
+pub fn import_backup(json: &str) -> Result<Backup, String> {
+    let backup: Backup =
+        serde_json::from_str(json).map_err(|e| format!("Invalid backup file: {}", e))?;
+    if backup.version > BACKUP_VERSION {
+        return Err(format!(
+            "Backup file version {} is newer than this app supports ({})",
+            backup.version, BACKUP_VERSION
+        ));
+    }
+    Ok(Backup {
+        settings: backup.settings.sanitized(),
+        ..backup
+    })
+}
+
+/// The same result, ignoring its database key and revision
+fn same_result(a: &StoredPerformance, b: &StoredPerformance) -> bool {
+    StoredPerformance {
+        id: None,
+        revision: 0,
+        ..a.clone()
+    } == StoredPerformance {
+        id: None,
+        revision: 0,
+        ..b.clone()
+    }
+}
+
+/// The backed-up results that aren't stored already, so restoring the same backup twice
+/// doesn't duplicate them
+pub fn missing_performances(
+    stored: &[StoredPerformance],
+    backup: &[StoredPerformance],
+) -> Vec<StoredPerformance> {
+    let mut missing: Vec<StoredPerformance> = Vec::new();
+    for performance in backup {
+        let known = stored
+            .iter()
+            .chain(missing.iter())
+            .any(|existing| same_result(existing, performance));
+        if !known {
+            missing.push(StoredPerformance {
+                id: None,
+                revision: 0,
+                ..performance.clone()
+            });
+        }
+    }
+    missing
+}
+
+/// Adds the backed-up results missing from the database, returning how many were added
+pub async fn restore_performances(backup: &Backup) -> Result<usize, String> {
+    let db = PerformanceDb::open().await?;
+    let missing = missing_performances(&db.all().await?, &backup.performances);
+    db.put_all(&missing).await?;
+    Ok(missing.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(athlete: &str, mark: &str) -> StoredPerformance {
+        StoredPerformance {
+            athlete: athlete.to_string(),
+            mark: mark.to_string(),
+            date: "2025-06-14".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_backup_round_trip() {
+        let settings = Settings {
+            points_decimals: 0,
+            ..Default::default()
+        };
+        let performances = vec![result("Ana", "11.20")];
+        let history = vec![HistoryEntry {
+            timestamp: 1_750_000_000_000.0,
+            inputs: vec![("event".to_string(), "100m".to_string())],
+            points: 1000.0,
+            conditions: "Headwind on the bend".to_string(),
+        }];
+        let json = export_backup("2025-06-14", &settings, &performances, &history);
+        let backup = import_backup(&json).unwrap();
+        assert_eq!(backup.version, BACKUP_VERSION);
+        assert_eq!(backup.settings, settings);
+        assert_eq!(backup.performances, performances);
+        assert_eq!(backup.history, history);
+
+        assert!(import_backup(r#"{"version": 99}"#).is_err());
+        assert!(import_backup(r#"{"version": 1}"#).is_ok());
+    }
+
+    #[test]
+    fn test_missing_performances_skips_stored_results() {
+        let stored = vec![StoredPerformance {
+            id: Some(3),
+            revision: 2,
+            ..result("Ana", "11.20")
+        }];
+        let backup = vec![
+            result("Ana", "11.20"),
+            result("Bea", "11.50"),
+            result("Bea", "11.50"),
+        ];
+        let missing = missing_performances(&stored, &backup);
+        assert_eq!(missing, vec![result("Bea", "11.50")]);
+    }
+}
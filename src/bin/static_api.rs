@@ -0,0 +1,151 @@
+//! Generates the machine-readable JSON artifacts published under `/api/static/`.
+//!
+//! Run by Trunk as a post-build hook, or manually:
+//! `cargo run --bin static_api -- dist/api/static`
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+use strum::IntoEnumIterator;
+use world_athletics_points_calulator::models::{Event, Gender, PerformanceType};
+use world_athletics_points_calulator::scoring_logic::availability::availability_matrix;
+use world_athletics_points_calulator::scoring_logic::calculator::{
+    is_road_running_event, is_wind_affected_event,
+};
+use world_athletics_points_calulator::scoring_logic::coefficients::{
+    get_coefficients, score_table, MAX_RESULT_SCORE,
+};
+use world_athletics_points_calulator::scoring_logic::provider::BundledTables;
+
+const DEFAULT_OUTPUT_DIR: &str = "dist/api/static";
+
+/// A file-name friendly version of an event name, e.g. "Road 10 km" -> "road-10-km"
+fn slug(event: &Event) -> String {
+    event
+        .to_string()
+        .to_lowercase()
+        .chars()
+        .filter(|c| !matches!(c, ',' | '.'))
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn event_metadata(event: &Event) -> Value {
+    let genders: Vec<String> = Gender::iter()
+        .filter(|gender| get_coefficients(*gender, event).is_ok())
+        .map(|gender| gender.to_string())
+        .collect();
+    json!({
+        "name": event.to_string(),
+        "slug": slug(event),
+        "performance_type": match event.performance_type() {
+            PerformanceType::Time => "time",
+            PerformanceType::Distance => "distance",
+            PerformanceType::Points => "points",
+        },
+        "genders": genders,
+        "wind_affected": is_wind_affected_event(event),
+        "road_running": is_road_running_event(event),
+        "placement_group": format!("{:?}", event.to_placement_score_event_group()),
+    })
+}
+
+fn write_json(path: &Path, value: &Value) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let contents = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", path.display(), e))?;
+    fs::write(path, contents).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+fn generate(output_dir: &Path) -> Result<(), String> {
+    let events = Event::all_variants();
+    write_json(
+        &output_dir.join("events.json"),
+        &Value::Array(events.iter().map(event_metadata).collect()),
+    )?;
+
+    let availability = serde_json::to_value(availability_matrix(&BundledTables))
+        .map_err(|e| format!("Failed to serialize the availability matrix: {}", e))?;
+    write_json(&output_dir.join("availability.json"), &availability)?;
+
+    for gender in Gender::iter() {
+        for event in &events {
+            // Not every event is contested by both genders
+            let Ok(rows) = score_table(gender, event, 1) else {
+                continue;
+            };
+            let table = json!({
+                "event": event.to_string(),
+                "gender": gender.to_string(),
+                "max_points": MAX_RESULT_SCORE,
+                "rows": rows
+                    .iter()
+                    .map(|row| json!({ "points": row.points, "performance": row.performance }))
+                    .collect::<Vec<_>>(),
+            });
+            write_json(
+                &output_dir
+                    .join("tables")
+                    .join(gender.to_string())
+                    .join(format!("{}.json", slug(event))),
+                &table,
+            )?;
+        }
+    }
+
+    let placement: Value = serde_json::from_str(include_str!(
+        "../../core/data/track_and_field_placement_scores.json"
+    ))
+    .map_err(|e| format!("Failed to parse placement scores JSON: {}", e))?;
+    write_json(&output_dir.join("placement.json"), &placement)?;
+
+    let coefficients: Value = serde_json::from_str(include_str!(
+        "../../core/data/world_athletics_constants_2025.json"
+    ))
+    .map_err(|e| format!("Failed to parse coefficients JSON: {}", e))?;
+    write_json(&output_dir.join("coefficients.json"), &coefficients)
+}
+
+fn main() {
+    let output_dir = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_OUTPUT_DIR));
+
+    match generate(&output_dir) {
+        Ok(()) => println!("Wrote static API to {}", output_dir.display()),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world_athletics_points_calulator::models::{RaceWalkingEvent, RoadRunningEvent};
+
+    #[test]
+    fn test_slug() {
+        assert_eq!(slug(&Event::default()), "100m");
+        assert_eq!(
+            slug(&Event::RoadRunning(RoadRunningEvent::Road10km)),
+            "road-10-km"
+        );
+        assert_eq!(
+            slug(&Event::RaceWalking(RaceWalkingEvent::M15000mW)),
+            "15000m-walk"
+        );
+    }
+
+    #[test]
+    fn test_slugs_are_unique() {
+        let mut slugs: Vec<String> = Event::all_variants().iter().map(slug).collect();
+        slugs.sort();
+        slugs.dedup();
+        assert_eq!(slugs.len(), Event::all_variants().len());
+    }
+}
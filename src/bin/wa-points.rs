@@ -0,0 +1,236 @@
+//! Scores performances from the command line.
+//!
+//! A single performance:
+//! `wa-points --gender men --event 100m --mark 10.23 --wind 1.4 --category A --place 1`
+//!
+//! Every row of a results CSV (see `importers::results_csv` for the columns), written back
+//! to stdout with the scores appended. Use `-` to read the file from stdin:
+//! `wa-points --csv results.csv --category A > scored.csv`
+use std::io::Read;
+use world_athletics_points_calulator::importers::open_track::round_from_code;
+use world_athletics_points_calulator::importers::results_csv::score_results_csv;
+use world_athletics_points_calulator::models::*;
+use world_athletics_points_calulator::scoring_logic::calculator::calculate_world_athletics_score;
+use world_athletics_points_calulator::scoring_logic::coefficients::calculate_result_score;
+use world_athletics_points_calulator::scoring_logic::placement_score::{
+    calculate_placement_score, RoundType,
+};
+
+const USAGE: &str = "\
+Usage:
+  wa-points --gender <men|women> --event <event> --mark <mark> [options]
+  wa-points --csv <file|-> [--category <category>]
+
+Options:
+  --wind <m/s>          Wind reading for sprints, hurdles and horizontal jumps
+  --downhill <m/km>     Net downhill of a road course
+  --altitude <m>        Venue altitude
+  --category <cat>      Competition category (F, E, D, C, B, A, GL, GW, DF, OW)
+  --place <n>           Place in the race, for placing points (needs --category)
+  --round <round>       F (final), SF (semifinal), H (heat), Q (qualification) or
+                        another round; default F
+  --size-of-final <n>   Size of the final, for semifinal placing points; default 8
+  --qualified           The athlete qualified for the next round
+  --main-event          The half marathon, 25km or 30km was the competition's main
+                        event, not a race held alongside a marathon
+  --csv <file|->        Score every row of a results CSV and print it with the scores
+  -h, --help            Show this message";
+
+/// The parsed command line
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    gender: Option<String>,
+    event: Option<String>,
+    mark: Option<String>,
+    wind: Option<f64>,
+    downhill: Option<f64>,
+    altitude: Option<f64>,
+    category: Option<CompetitionCategory>,
+    place: Option<i32>,
+    round: Option<RoundType>,
+    size_of_final: Option<i32>,
+    qualified: bool,
+    main_event: bool,
+    csv: Option<String>,
+    help: bool,
+}
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .trim_start_matches('+')
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut args = args.into_iter();
+    while let Some(flag) = args.next() {
+        if matches!(flag.as_str(), "-h" | "--help") {
+            parsed.help = true;
+            continue;
+        }
+        if flag == "--qualified" {
+            parsed.qualified = true;
+            continue;
+        }
+        if flag == "--main-event" {
+            parsed.main_event = true;
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing a value for {}", flag))?;
+        match flag.as_str() {
+            "--gender" => parsed.gender = Some(value),
+            "--event" => parsed.event = Some(value),
+            "--mark" => parsed.mark = Some(value),
+            "--wind" => parsed.wind = Some(parse_number(&flag, &value)?),
+            "--downhill" => parsed.downhill = Some(parse_number(&flag, &value)?),
+            "--altitude" => parsed.altitude = Some(parse_number(&flag, &value)?),
+            "--category" => {
+                parsed.category = Some(
+                    CompetitionCategory::from_string(&value.to_uppercase())
+                        .ok_or_else(|| format!("Unknown competition category: {}", value))?,
+                )
+            }
+            "--place" => parsed.place = Some(parse_number(&flag, &value)?),
+            "--round" => parsed.round = Some(round_from_code(&value)),
+            "--size-of-final" => parsed.size_of_final = Some(parse_number(&flag, &value)?),
+            "--csv" => parsed.csv = Some(value),
+            _ => return Err(format!("Unknown option: {}", flag)),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Builds the score input for a single performance
+fn score_input(args: &Args) -> Result<WorldAthleticsScoreInput, String> {
+    let required = |value: &Option<String>, flag: &str| {
+        value.clone().ok_or_else(|| format!("Missing {}", flag))
+    };
+    let gender = required(&args.gender, "--gender")?;
+    let gender = Gender::from_string(&gender.to_lowercase())
+        .ok_or_else(|| format!("Unknown gender: {}", gender))?;
+    let event = required(&args.event, "--event")?.parse::<Event>()?;
+    let performance = Performance::parse(&event, &required(&args.mark, "--mark")?)?;
+    let placement_info = match (args.category, args.place) {
+        (Some(competition_category), Some(place)) => Some(PlacementInfo {
+            competition_category,
+            place,
+            round: args.round.unwrap_or(RoundType::Final),
+            size_of_final: args.size_of_final.unwrap_or(8),
+            qualified_to_final: args.qualified,
+            tied_with: 0,
+            main_event: args.main_event,
+        }),
+        (None, Some(_)) => return Err("--place needs a --category".to_string()),
+        _ => None,
+    };
+    Ok(WorldAthleticsScoreInput {
+        gender,
+        event,
+        performance,
+        wind_speed: args.wind,
+        net_downhill: args.downhill,
+        venue_altitude: args.altitude,
+        placement_info,
+        table_edition: None,
+    })
+}
+
+fn read_csv(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("Failed to read stdin: {}", e))?;
+        Ok(text)
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))
+    }
+}
+
+fn run(args: Args) -> Result<String, String> {
+    if let Some(path) = &args.csv {
+        let scored = score_results_csv(
+            &read_csv(path)?,
+            args.category.unwrap_or_default(),
+            calculate_result_score,
+            calculate_placement_score,
+        )?;
+        for (line, row) in scored.rows.iter().enumerate() {
+            if let Err(e) = &row.score {
+                // The header is line 1
+                eprintln!("Line {}: {}", line + 2, e);
+            }
+        }
+        return Ok(scored.to_csv());
+    }
+
+    let output = calculate_world_athletics_score(
+        score_input(&args)?,
+        calculate_result_score,
+        calculate_placement_score,
+    )?;
+    Ok(format!("{:.0}\n", output.points))
+}
+
+fn main() {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) if args.help => {
+            println!("{}", USAGE);
+            return;
+        }
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match run(args) {
+        Ok(output) => print!("{}", output),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Result<Args, String> {
+        parse_args(line.split_whitespace().map(str::to_string))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed =
+            args("--gender men --event 100m --mark 10.23 --wind +1.4 --category a --place 1")
+                .unwrap();
+        assert_eq!(parsed.gender.as_deref(), Some("men"));
+        assert_eq!(parsed.mark.as_deref(), Some("10.23"));
+        assert_eq!(parsed.wind, Some(1.4));
+        assert_eq!(parsed.category, Some(CompetitionCategory::A));
+        assert_eq!(parsed.place, Some(1));
+
+        assert!(args("--gender").is_err());
+        assert!(args("--speed 10").is_err());
+        assert!(args("--wind calm").is_err());
+        assert!(args("--category Z").is_err());
+    }
+
+    #[test]
+    fn test_score() {
+        let output =
+            run(args("--gender men --event 100m --mark 10.00 --category A --place 1").unwrap())
+                .unwrap();
+        assert!(output.trim().parse::<f64>().unwrap() > 1200.0);
+
+        assert!(run(args("--gender men --event 100m").unwrap()).is_err());
+        assert!(run(args("--gender men --event 100m --mark 10.00 --place 1").unwrap()).is_err());
+    }
+}
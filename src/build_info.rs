@@ -0,0 +1,10 @@
+// src/build_info.rs
+//! The app's version, table edition and data checksums, summarised in the footer. See
+//! `wa_points_core::build_info`.
+pub use wa_points_core::build_info::BuildInfo;
+
+pub const APP_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub fn build_info() -> BuildInfo {
+    wa_points_core::build_info::build_info(APP_VERSION)
+}
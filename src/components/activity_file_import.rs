@@ -0,0 +1,43 @@
+use crate::components::file_upload::BinaryFileUpload;
+use crate::importers::activity_file::{parse_activity_file, ACTIVITY_FILE_EXTENSIONS};
+use crate::models::Event;
+use crate::settings::use_density;
+use leptos::prelude::*;
+
+/// Pre-fills a road race from a watch export (Garmin FIT or TCX).
+#[component]
+pub fn ActivityFileImport(
+    set_event: WriteSignal<Event>,
+    set_performance_input: WriteSignal<String>,
+    set_performance: WriteSignal<f64>,
+    set_net_downhill: WriteSignal<Option<f64>>,
+) -> impl IntoView {
+    let density = use_density();
+    let (error, set_error) = signal(Option::<String>::None);
+
+    let on_load = Callback::new(move |(file_name, bytes): (String, Vec<u8>)| {
+        match parse_activity_file(&file_name, &bytes) {
+            Ok(race) => {
+                set_event.set(Event::RoadRunning(race.event));
+                set_performance_input.set(Event::seconds_to_time_string(race.elapsed_seconds));
+                set_performance.set(race.elapsed_seconds);
+                set_net_downhill.set(race.net_downhill);
+                set_error.set(None);
+            }
+            Err(e) => set_error.set(Some(e)),
+        }
+    });
+
+    view! {
+        <div class=move || density.get().row_start_class()>
+            <label class="text-gray-800 font-medium">"Import Road Race:"</label>
+            <div class="md:col-span-2">
+                <BinaryFileUpload accept=ACTIVITY_FILE_EXTENSIONS on_load=on_load />
+                <p class="mt-1 text-sm text-gray-500" class:hidden=move || !density.get().show_helper_text()>
+                    "Garmin FIT or TCX export. Fills in the event, time and net downhill."
+                </p>
+                {move || error.get().map(|e| view! { <p class="mt-1 text-sm text-red-600">{e}</p> })}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,102 @@
+use crate::exporters::csv::csv_data_url;
+use crate::exporters::results_export::{export_csv, history_rows};
+use crate::history::use_history;
+use crate::settings::use_settings;
+use crate::share_link::CalculatorState;
+use leptos::prelude::*;
+
+/// When a calculation was made, in the browser's locale and time zone
+fn format_timestamp(timestamp: f64) -> String {
+    let date = js_sys::Date::new(&timestamp.into());
+    date.to_locale_string("default", &js_sys::Object::new())
+        .as_string()
+        .unwrap_or_default()
+}
+
+/// The saved calculations, newest first. Loading one passes its inputs to `on_load`.
+#[component]
+pub fn CalculationHistory(on_load: Callback<CalculatorState>) -> impl IntoView {
+    let history = use_history();
+    let settings = use_settings();
+
+    view! {
+        <details class="mt-6">
+            <summary class="cursor-pointer text-gray-800 font-medium">
+                {move || format!("History ({})", history.with(Vec::len))}
+            </summary>
+            <Show
+                when=move || history.with(|history| !history.is_empty())
+                fallback=|| {
+                    view! { <p class="mt-2 text-sm text-gray-500 italic">"No calculations yet."</p> }
+                }
+            >
+                <ul class="mt-2 divide-y divide-gray-200 text-sm">
+                    {move || {
+                        history
+                            .get()
+                            .into_iter()
+                            .map(|entry| {
+                                let timestamp = entry.timestamp;
+                                let state = entry.state();
+                                let summary = state
+                                    .as_ref()
+                                    .map(|state| {
+                                        format!("{} {}: {}", state.gender, state.event, state.mark)
+                                    })
+                                    .unwrap_or_else(|| "Unrecognised inputs".to_string());
+                                let points = settings.with(|s| s.format_points(entry.points));
+                                view! {
+                                    <li class="py-2 flex items-center justify-between gap-4">
+                                        <div>
+                                            <p class="text-gray-800">
+                                                {summary} " - " {points} " points"
+                                            </p>
+                                            <p class="text-gray-500">
+                                                {format_timestamp(timestamp)}
+                                                {(!entry.conditions.is_empty())
+                                                    .then(|| format!(" - {}", entry.conditions))}
+                                            </p>
+                                        </div>
+                                        <div class="flex gap-3 shrink-0">
+                                            {state
+                                                .map(|state| {
+                                                    view! {
+                                                        <button
+                                                            type="button"
+                                                            class="text-gray-700 underline hover:text-gray-900"
+                                                            on:click=move |_| on_load.run(state.clone())
+                                                        >
+                                                            "Load"
+                                                        </button>
+                                                    }
+                                                })}
+                                            <button
+                                                type="button"
+                                                class="text-red-700 underline hover:text-red-900"
+                                                on:click=move |_| {
+                                                    history
+                                                        .update(|history| {
+                                                            history.retain(|entry| entry.timestamp != timestamp)
+                                                        })
+                                                }
+                                            >
+                                                "Delete"
+                                            </button>
+                                        </div>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+                <a
+                    class="inline-block mt-2 text-sm text-gray-700 underline hover:text-gray-900"
+                    href=move || history.with(|history| csv_data_url(&export_csv(&history_rows(history))))
+                    download="calculation-history.csv"
+                >
+                    "Download CSV"
+                </a>
+            </Show>
+        </details>
+    }
+}
@@ -0,0 +1,63 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::WorldAthleticsScoreInput;
+use crate::scoring_logic::calculator::scores_by_category;
+use leptos::prelude::*;
+
+/// The score the last calculated performance and place would earn at every competition
+/// category. Renders nothing without a placing.
+#[component]
+pub fn CategoryMatrix(input: ReadSignal<Option<WorldAthleticsScoreInput>>) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let scores = Memo::new(move |_| {
+        input.with(|input| {
+            let input = input.as_ref()?;
+            let current = input.placement_info.as_ref()?.competition_category;
+            let scores = scores_by_category(
+                input,
+                providers.result_score_calculator,
+                providers.placement_score_calculator,
+            )
+            .ok()?;
+            Some((current, scores))
+        })
+    });
+
+    move || {
+        let (current, scores) = scores.get()?;
+        Some(view! {
+            <details class="w-full mt-4">
+                <summary class="cursor-pointer text-gray-800 font-medium">
+                    "Score by Competition Category"
+                </summary>
+                <table class="w-full mt-2 text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-2">"Category"</th>
+                            <th class="py-1 pr-2">"Placing Points"</th>
+                            <th class="py-1">"Score"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {scores
+                            .into_iter()
+                            .map(|(category, output)| {
+                                let class = if category == current {
+                                    "border-t border-gray-200 font-semibold text-gray-900"
+                                } else {
+                                    "border-t border-gray-200 text-gray-700"
+                                };
+                                view! {
+                                    <tr class=class>
+                                        <td class="py-1 pr-2">{category.to_string()}</td>
+                                        <td class="py-1 pr-2">{output.placement_score}</td>
+                                        <td class="py-1">{format!("{:.0}", output.points)}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </details>
+        })
+    }
+}
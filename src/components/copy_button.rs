@@ -0,0 +1,33 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen_futures::JsFuture;
+
+/// A button that copies the given text to the clipboard, confirming once it has.
+#[component]
+pub fn CopyButton(
+    #[prop(into)] text: Signal<String>,
+    #[prop(into, default = "Copy".to_string())] label: String,
+) -> impl IntoView {
+    let (copied, set_copied) = signal(false);
+
+    let copy = move |_| {
+        let promise = window().navigator().clipboard().write_text(&text.get());
+        spawn_local(async move {
+            match JsFuture::from(promise).await {
+                Ok(_) => set_copied.set(true),
+                Err(e) => log::error!("Failed to copy to clipboard: {:?}", e),
+            }
+        });
+    };
+
+    view! {
+        <button
+            type="button"
+            class="px-4 py-2 bg-gray-900 text-white text-sm font-medium rounded-md hover:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-gray-500 transition-colors"
+            on:click=copy
+            on:mouseleave=move |_| set_copied.set(false)
+        >
+            {move || if copied.get() { "Copied!".to_string() } else { label.clone() }}
+        </button>
+    }
+}
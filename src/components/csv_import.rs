@@ -0,0 +1,76 @@
+use crate::components::file_upload::FileUpload;
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::exporters::csv::csv_data_url;
+use crate::exporters::results_export::{export_csv, scored_csv_rows};
+use crate::importers::results_csv::score_results_csv;
+use crate::models::CompetitionCategory;
+use leptos::prelude::*;
+
+/// Scores every row of an uploaded results CSV and offers the file back with the scores
+/// appended
+#[component]
+pub fn CsvImport(competition_category: ReadSignal<CompetitionCategory>) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (file_contents, set_file_contents) = signal(None::<String>);
+
+    let scored = Memo::new(move |_| {
+        file_contents.get().map(|csv| {
+            score_results_csv(
+                &csv,
+                competition_category.get(),
+                providers.result_score_calculator,
+                providers.placement_score_calculator,
+            )
+        })
+    });
+
+    view! {
+        <section class="space-y-2">
+            <h3 class="text-lg font-medium text-gray-800">"Score a CSV file"</h3>
+            <p class="text-sm text-gray-500">
+                "Upload a CSV with Gender, Event and Mark columns (plus optional Athlete, Place, Wind and Round) to score every row and download it with the points added, or in the same standard columns as the calculation history export."
+            </p>
+            <FileUpload
+                accept=".csv,text/csv"
+                on_load=Callback::new(move |text| set_file_contents.set(Some(text)))
+            />
+            {move || match scored.get() {
+                None => ().into_any(),
+                Some(Err(e)) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                Some(Ok(scored)) => {
+                    let unscored = scored
+                        .rows
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(index, row)| {
+                            row.score.as_ref().err().map(|e| format!("Row {}: {}", index + 2, e))
+                        })
+                        .collect::<Vec<_>>();
+                    view! {
+                        <p class="text-sm text-gray-700">
+                            {format!("Scored {} of {} rows.", scored.scored_count(), scored.rows.len())}
+                        </p>
+                        <ul class="text-sm text-gray-600 list-disc pl-5">
+                            {unscored.into_iter().map(|e| view! { <li>{e}</li> }).collect_view()}
+                        </ul>
+                        <a
+                            class="inline-block text-sm text-gray-700 underline hover:text-gray-900"
+                            href=csv_data_url(&scored.to_csv())
+                            download="scored-results.csv"
+                        >
+                            "Download scored CSV"
+                        </a>
+                        <a
+                            class="inline-block text-sm text-gray-700 underline hover:text-gray-900"
+                            href=csv_data_url(&export_csv(&scored_csv_rows(&scored)))
+                            download="results.csv"
+                        >
+                            "Download with standard columns"
+                        </a>
+                    }
+                        .into_any()
+                }
+            }}
+        </section>
+    }
+}
@@ -0,0 +1,52 @@
+use crate::startup_timing::{startup_timings, FIRST_RENDER, STARTUP_BUDGET_MS};
+use leptos::prelude::*;
+
+/// Developer diagnostics, shown when the page URL contains `debug`.
+#[component]
+pub fn DebugPanel() -> impl IntoView {
+    let (timings, set_timings) = signal(startup_timings());
+    // The first render is recorded after this panel mounts, so refresh once painted
+    request_animation_frame(move || set_timings.set(startup_timings()));
+
+    let enabled = window()
+        .location()
+        .search()
+        .map(|search| search.contains("debug"))
+        .unwrap_or(false);
+
+    view! {
+        <Show when=move || enabled>
+            <div class="container mx-auto px-4 mt-4">
+                <div class="p-3 bg-gray-50 border border-gray-200 rounded-md text-xs font-mono text-gray-700">
+                    <div class="flex items-center justify-between mb-1">
+                        <span class="font-semibold">"Startup timings"</span>
+                        <button
+                            type="button"
+                            class="underline"
+                            on:click=move |_| set_timings.set(startup_timings())
+                        >
+                            "Refresh"
+                        </button>
+                    </div>
+                    <ul>
+                        {move || {
+                            timings
+                                .get()
+                                .into_iter()
+                                .map(|timing| {
+                                    let over_budget = timing.name == FIRST_RENDER
+                                        && timing.duration_ms > STARTUP_BUDGET_MS;
+                                    view! {
+                                        <li class=if over_budget { "text-red-600" } else { "" }>
+                                            {format!("{}: {:.1}ms", timing.name, timing.duration_ms)}
+                                        </li>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </ul>
+                </div>
+            </div>
+        </Show>
+    }
+}
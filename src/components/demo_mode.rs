@@ -0,0 +1,158 @@
+//! Dev-only demo mode (enabled with the `demo` feature) that fills the form with randomized
+//! plausible inputs and cycles through every event, for demos, screenshots and smoke-testing
+//! each input path.
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::*;
+use crate::scoring_logic::calculator::{is_road_running_event, is_wind_affected_event};
+use crate::scoring_logic::placement_score::{rounds_for_event, RoundType};
+use leptos::prelude::*;
+use std::time::Duration;
+
+/// How long each generated input stays on screen
+const DEMO_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Plausible performances are drawn from this band of result scores
+const PLAUSIBLE_POINTS: (f64, f64) = (600.0, 1300.0);
+
+/// One randomized set of form inputs
+#[derive(Debug, Clone, PartialEq)]
+pub struct DemoInput {
+    pub gender: Gender,
+    pub event: Event,
+    pub performance: f64,
+    pub performance_input: String,
+    pub wind_speed: Option<f64>,
+    pub net_downhill: Option<f64>,
+    pub place: i32,
+    pub round: RoundType,
+}
+
+/// Builds a plausible input for `event`. `random` must return values in `[0, 1)`.
+/// Returns `None` when neither gender has coefficients for the event.
+pub fn demo_input(
+    event: &Event,
+    scorer: fn(f64, Gender, &Event) -> Result<f64, String>,
+    random: &mut impl FnMut() -> f64,
+) -> Option<DemoInput> {
+    let genders = if random() < 0.5 {
+        [Gender::Men, Gender::Women]
+    } else {
+        [Gender::Women, Gender::Men]
+    };
+    let points = PLAUSIBLE_POINTS.0 + random() * (PLAUSIBLE_POINTS.1 - PLAUSIBLE_POINTS.0);
+    let (gender, performance) = genders
+        .into_iter()
+        .find_map(|gender| Some((gender, scorer(points, gender, &event).ok()?)))?;
+
+    let performance_input = match event.performance_type() {
+        PerformanceType::Time => Event::seconds_to_time_string(performance),
+        PerformanceType::Distance => format!("{:.2}", performance),
+        PerformanceType::Points => format!("{:.0}", performance),
+    };
+    // Wind between -2.5 and +2.5 m/s, downhill up to 3 m/km
+    let wind_speed =
+        is_wind_affected_event(event).then(|| ((random() * 50.0).round() - 25.0) / 10.0);
+    let net_downhill = is_road_running_event(event).then(|| (random() * 30.0).round() / 10.0);
+    let place = 1 + (random() * 8.0) as i32;
+    // Any of the rounds the event has, e.g. no semi-finals for a jump
+    let rounds = rounds_for_event(event);
+    let round = rounds[(random() * rounds.len() as f64) as usize % rounds.len()];
+
+    Some(DemoInput {
+        gender,
+        event: event.clone(),
+        performance,
+        performance_input,
+        wind_speed,
+        net_downhill,
+        place,
+        round,
+    })
+}
+
+/// Start/stop button that feeds `on_input` a new demo input every few seconds.
+#[component]
+pub fn DemoMode(on_input: Callback<DemoInput>) -> impl IntoView {
+    let (running, set_running) = signal(false);
+    let (event_index, set_event_index) = signal(0usize);
+    let events = Event::all_variants();
+    let providers = use_scoring_providers();
+
+    let next = move || {
+        let index = event_index.get_untracked();
+        set_event_index.set((index + 1) % events.len());
+        let mut random = js_sys::Math::random;
+        if let Some(input) = demo_input(
+            &events[index],
+            providers.performance_calculator,
+            &mut random,
+        ) {
+            on_input.run(input);
+        }
+    };
+
+    let handle = StoredValue::new_local(None::<IntervalHandle>);
+    let toggle = move |_| {
+        if let Some(interval) = handle.get_value() {
+            interval.clear();
+            handle.set_value(None);
+            set_running.set(false);
+        } else {
+            next.clone()();
+            handle.set_value(set_interval_with_handle(next.clone(), DEMO_INTERVAL).ok());
+            set_running.set(true);
+        }
+    };
+    on_cleanup(move || {
+        if let Some(interval) = handle.get_value() {
+            interval.clear();
+        }
+    });
+
+    view! {
+        <div class="flex items-center justify-between p-3 bg-purple-50 border border-purple-200 rounded-md">
+            <span class="text-sm text-purple-800">"Demo mode"</span>
+            <button
+                type="button"
+                class="px-3 py-1 text-sm bg-purple-600 text-white rounded-md hover:bg-purple-700"
+                on:click=toggle
+            >
+                {move || if running.get() { "Stop" } else { "Start" }}
+            </button>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring_logic::coefficients::performance_for_score;
+
+    #[test]
+    fn test_demo_input_covers_every_event() {
+        let mut seed = 0.0;
+        let mut random = move || {
+            seed = (seed + 0.618_033_988_75) % 1.0;
+            seed
+        };
+        for event in Event::all_variants() {
+            let Some(input) = demo_input(&event, performance_for_score, &mut random) else {
+                continue;
+            };
+            let score = crate::scoring_logic::coefficients::calculate_result_score(
+                input.performance,
+                input.gender,
+                &event,
+            )
+            .unwrap();
+            assert!(
+                (PLAUSIBLE_POINTS.0 - 1.0..=PLAUSIBLE_POINTS.1 + 1.0).contains(&score),
+                "{} scored {}",
+                event,
+                score
+            );
+            assert_eq!(input.wind_speed.is_some(), is_wind_affected_event(&event));
+            assert!((1..=8).contains(&input.place));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::models::{Event, Gender};
+use crate::scoring_logic::season_lists::{
+    histogram, percentile, season_lists, HistogramBin, SeasonLists,
+};
+use leptos::prelude::*;
+
+const HISTOGRAM_BINS: usize = 20;
+
+/// Each bin's bar height as a percentage of the tallest
+fn bar_heights(bins: &[HistogramBin]) -> Vec<usize> {
+    let max_count = bins.iter().map(|bin| bin.count).max().unwrap_or(1).max(1);
+    bins.iter().map(|bin| bin.count * 100 / max_count).collect()
+}
+
+/// Where a mark sits between the lowest and highest marks, as a percentage of the width
+fn marker_offset(mark: f64, min: f64, max: f64) -> f64 {
+    if max > min {
+        ((mark - min) / (max - min)).clamp(0.0, 1.0) * 100.0
+    } else {
+        50.0
+    }
+}
+
+/// Histogram of world-class marks for the event from the season lists (the bundled ones
+/// unless `lists` is given), with a marker where the athlete's mark lands. Renders nothing
+/// for events without a list, so it stays hidden while no lists are bundled.
+#[component]
+pub fn EliteHistogram(
+    gender: ReadSignal<Gender>,
+    event: ReadSignal<Event>,
+    performance: ReadSignal<f64>,
+    #[prop(default = season_lists())] lists: &'static SeasonLists,
+) -> impl IntoView {
+    let marks = Memo::new(move |_| lists.marks(gender.get(), &event.get()).map(<[f64]>::to_vec));
+
+    move || {
+        let marks = marks.get()?;
+        let bins = histogram(&marks, HISTOGRAM_BINS);
+        let heights = bar_heights(&bins);
+        let (min, max) = (marks[0], marks[marks.len() - 1]);
+        let event_value = event.get();
+        let marker = move || format!("left: {:.1}%", marker_offset(performance.get(), min, max));
+        let summary = {
+            let event_value = event_value.clone();
+            let marks = marks.clone();
+            move || {
+                percentile(&marks, performance.get(), event_value.performance_type()).map(
+                    |percentile| {
+                        format!(
+                            "{} is better than or equal to {:.0}% of the {} marks in the season list.",
+                            event_value.format_performance(performance.get()),
+                            percentile,
+                            marks.len()
+                        )
+                    },
+                )
+            }
+        };
+
+        Some(view! {
+            <figure class="w-full mt-4">
+                <div class="relative">
+                    <div class="flex items-end gap-px h-24 border-b border-gray-300" aria-hidden="true">
+                        {bins
+                            .into_iter()
+                            .zip(heights)
+                            .map(|(bin, height)| {
+                                view! {
+                                    <div
+                                        class="flex-1 bg-gray-300 histogram-bar"
+                                        style=format!("height: {}%", height)
+                                        title=format!("{} marks", bin.count)
+                                    ></div>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                    <div class="absolute top-0 bottom-0 w-0.5 bg-gray-900" style=marker aria-hidden="true"></div>
+                </div>
+                <figcaption class="mt-1 text-xs text-gray-500">
+                    <div class="flex justify-between">
+                        <span>{event_value.format_performance(min)}</span>
+                        <span>{event_value.format_performance(max)}</span>
+                    </div>
+                    <p>{summary}</p>
+                </figcaption>
+            </figure>
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_heights() {
+        let bins = histogram(&[9.8, 9.9, 9.9, 10.0, 10.2], 4);
+        assert_eq!(bar_heights(&bins), vec![50, 100, 50, 50]);
+        assert!(bar_heights(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_marker_offset() {
+        assert!((marker_offset(10.0, 9.8, 10.2) - 50.0).abs() < 1e-9);
+        assert_eq!(marker_offset(9.0, 9.8, 10.2), 0.0);
+        assert_eq!(marker_offset(11.0, 9.8, 10.2), 100.0);
+        assert_eq!(marker_offset(10.0, 10.0, 10.0), 50.0);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{Event, Gender, PerformanceType};
+use crate::scoring_logic::entry_standards::{compare_to_standard, entry_standards};
+use leptos::prelude::*;
+
+/// Whether the mark meets each bundled championship entry standard for the event, and by how
+/// much. Renders nothing for events without a standard.
+#[component]
+pub fn EntryStandardsDisplay(
+    gender: ReadSignal<Gender>,
+    event: ReadSignal<Event>,
+    performance: ReadSignal<f64>,
+) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let comparisons = Memo::new(move |_| {
+        entry_standards()
+            .standards(gender.get(), &event.get())
+            .into_iter()
+            .map(|standard| {
+                compare_to_standard(
+                    standard,
+                    performance.get(),
+                    providers.result_score_calculator,
+                )
+            })
+            .collect::<Vec<_>>()
+    });
+
+    move || {
+        let comparisons = comparisons.get();
+        if comparisons.is_empty() {
+            return None;
+        }
+        let event = event.get();
+        let unit = match event.performance_type() {
+            PerformanceType::Time => "s",
+            PerformanceType::Distance => "m",
+            PerformanceType::Points => " pts",
+        };
+        Some(view! {
+            <section class="w-full mt-4">
+                <h3 class="text-sm font-medium text-gray-800">"Entry Standards"</h3>
+                <ul class="mt-1 space-y-1 text-sm">
+                    {comparisons
+                        .into_iter()
+                        .map(|comparison| {
+                            let (status, class) = if comparison.met {
+                                ("Meets", "text-green-700")
+                            } else {
+                                ("Misses", "text-red-600")
+                            };
+                            let points = comparison
+                                .points_margin
+                                .map(|points| format!(", {:+.0} points", points))
+                                .unwrap_or_default();
+                            view! {
+                                <li class="flex justify-between gap-2">
+                                    <span class="text-gray-700">
+                                        {format!(
+                                            "{} ({})",
+                                            comparison.standard.championship,
+                                            event.format_performance(comparison.standard.mark),
+                                        )}
+                                    </span>
+                                    <span class=class>
+                                        {format!(
+                                            "{} by {:.2}{}{}",
+                                            status,
+                                            comparison.margin.abs(),
+                                            unit,
+                                            points,
+                                        )}
+                                    </span>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+                <p class="mt-1 text-xs text-gray-500">
+                    "Points compare result scores only, without wind or placing points."
+                </p>
+            </section>
+        })
+    }
+}
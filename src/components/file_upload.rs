@@ -0,0 +1,67 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen_futures::JsFuture;
+
+const FILE_INPUT_CLASS: &str = "block w-full text-sm text-gray-700 file:mr-4 file:py-2 file:px-3 file:rounded-md file:border-0 file:bg-gray-900 file:text-white hover:file:bg-gray-700";
+
+fn selected_file(ev: &web_sys::Event) -> Option<web_sys::File> {
+    event_target::<web_sys::HtmlInputElement>(ev)
+        .files()
+        .and_then(|files| files.get(0))
+}
+
+/// A file picker that reads the chosen file as text and hands it to `on_load`.
+#[component]
+pub fn FileUpload(
+    /// Value for the input's `accept` attribute, e.g. ".json"
+    accept: &'static str,
+    on_load: Callback<String>,
+) -> impl IntoView {
+    view! {
+        <input
+            type="file"
+            accept=accept
+            class=FILE_INPUT_CLASS
+            on:change=move |ev| {
+                let Some(file) = selected_file(&ev) else {
+                    return;
+                };
+                spawn_local(async move {
+                    match JsFuture::from(file.text()).await {
+                        Ok(text) => on_load.run(text.as_string().unwrap_or_default()),
+                        Err(e) => log::error!("Failed to read file: {:?}", e),
+                    }
+                });
+            }
+        />
+    }
+}
+
+/// A file picker for binary formats that hands the file name and bytes to `on_load`.
+#[component]
+pub fn BinaryFileUpload(
+    /// Value for the input's `accept` attribute, e.g. ".fit"
+    accept: &'static str,
+    on_load: Callback<(String, Vec<u8>)>,
+) -> impl IntoView {
+    view! {
+        <input
+            type="file"
+            accept=accept
+            class=FILE_INPUT_CLASS
+            on:change=move |ev| {
+                let Some(file) = selected_file(&ev) else {
+                    return;
+                };
+                spawn_local(async move {
+                    match JsFuture::from(file.array_buffer()).await {
+                        Ok(buffer) => {
+                            on_load.run((file.name(), js_sys::Uint8Array::new(&buffer).to_vec()))
+                        }
+                        Err(e) => log::error!("Failed to read file: {:?}", e),
+                    }
+                });
+            }
+        />
+    }
+}
@@ -0,0 +1,168 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::exporters::csv::{csv_data_url, to_csv};
+use crate::models::{Event, Gender};
+use crate::scoring_logic::points_table::{
+    points_table, points_table_page, PointsTableRow, POINTS_TABLE_PAGE_SIZE,
+};
+use leptos::prelude::*;
+
+const PAGE_BUTTON_CLASS: &str = "px-3 py-1 text-sm border border-gray-300 rounded-md hover:bg-gray-50 disabled:opacity-50 disabled:cursor-not-allowed";
+
+/// Whether `page` (counting from 0) is the last of `page_count` pages
+fn is_last_page(page: usize, page_count: usize) -> bool {
+    page + 1 >= page_count
+}
+
+/// The whole table as CSV rows, best score first
+fn csv_rows(rows: &[PointsTableRow]) -> Vec<Vec<String>> {
+    std::iter::once(vec![
+        "Points".to_string(),
+        "Performance".to_string(),
+        "Mark".to_string(),
+    ])
+    .chain(rows.iter().rev().map(|row| {
+        vec![
+            row.points.to_string(),
+            row.performance.to_string(),
+            row.mark.clone(),
+        ]
+    }))
+    .collect()
+}
+
+/// The event's full points table, a page at a time, with the whole table as a download
+#[component]
+pub fn FullPointsTable(gender: ReadSignal<Gender>, event: ReadSignal<Event>) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (page, set_page) = signal(0usize);
+
+    let rows = Memo::new(move |_| {
+        points_table(gender.get(), &event.get(), providers.performance_calculator)
+    });
+    // Back to the top of the table for a new event
+    Effect::new(move |_| {
+        rows.track();
+        set_page.set(0);
+    });
+    let current = Memo::new(move |_| {
+        rows.with(|rows| {
+            rows.as_ref()
+                .map_err(Clone::clone)
+                .and_then(|rows| points_table_page(rows, page.get(), POINTS_TABLE_PAGE_SIZE))
+        })
+    });
+    let on_last_page = move || {
+        current
+            .with(|current| is_last_page(page.get(), current.as_ref().map_or(1, |p| p.page_count)))
+    };
+    let download_href = move || {
+        rows.with(|rows| {
+            rows.as_ref()
+                .map(|rows| csv_data_url(&to_csv(csv_rows(rows))))
+                .unwrap_or_default()
+        })
+    };
+    let download_name = move || {
+        format!(
+            "{}-{}-points-table.csv",
+            gender.get().to_string().to_lowercase(),
+            event.get().code()
+        )
+    };
+
+    view! {
+        {move || match current.get() {
+            Err(e) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+            Ok(current) => {
+                view! {
+                    <div class="flex flex-wrap items-center gap-2 text-sm text-gray-700">
+                        <button
+                            type="button"
+                            class=PAGE_BUTTON_CLASS
+                            disabled=move || page.get() == 0
+                            on:click=move |_| set_page.update(|page| *page = page.saturating_sub(1))
+                        >
+                            "Previous"
+                        </button>
+                        <span>{format!("Page {} of {}", current.page + 1, current.page_count)}</span>
+                        <button
+                            type="button"
+                            class=PAGE_BUTTON_CLASS
+                            disabled=on_last_page
+                            on:click=move |_| set_page.update(|page| *page += 1)
+                        >
+                            "Next"
+                        </button>
+                        <a
+                            class="ml-auto text-gray-700 underline hover:text-gray-900"
+                            href=download_href
+                            download=download_name
+                        >
+                            "Download CSV"
+                        </a>
+                    </div>
+                    <table class="w-full text-sm">
+                        <thead>
+                            <tr class="text-left text-gray-600">
+                                <th class="py-1 pr-4">"Points"</th>
+                                <th class="py-1 pr-4">"Mark"</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {current
+                                .rows
+                                .into_iter()
+                                .map(|row| {
+                                    view! {
+                                        <tr class="border-t border-gray-200">
+                                            <td class="py-1 pr-4 text-gray-600">{row.points}</td>
+                                            <td class="py-1 pr-4 text-gray-900">{row.mark}</td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()}
+                        </tbody>
+                    </table>
+                }
+                    .into_any()
+            }
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_last_page() {
+        assert!(!is_last_page(0, 2));
+        assert!(is_last_page(1, 2));
+        // An empty table still has one page
+        assert!(is_last_page(0, 1));
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let rows = vec![
+            PointsTableRow {
+                points: 1,
+                performance: 16.77,
+                mark: "00:16.770".to_string(),
+            },
+            PointsTableRow {
+                points: 2,
+                performance: 16.76,
+                mark: "00:16.760".to_string(),
+            },
+        ];
+        assert_eq!(
+            csv_rows(&rows),
+            vec![
+                vec!["Points", "Performance", "Mark"],
+                vec!["2", "16.76", "00:16.760"],
+                vec!["1", "16.77", "00:16.770"],
+            ]
+        );
+    }
+}
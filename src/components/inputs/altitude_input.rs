@@ -0,0 +1,44 @@
+use crate::models::Event;
+use crate::scoring_logic::altitude::{is_altitude_affected_event, ALTITUDE_THRESHOLD_M};
+use crate::settings::use_density;
+use leptos::prelude::*;
+
+/// Venue altitude, shown only for events that benefit from altitude
+#[component]
+pub fn AltitudeInput(
+    event: ReadSignal<Event>,
+    venue_altitude: ReadSignal<Option<f64>>,
+    set_venue_altitude: WriteSignal<Option<f64>>,
+) -> impl IntoView {
+    let density = use_density();
+    view! {
+        <Show when=move || is_altitude_affected_event(&event.get())>
+            <div class=move || density.get().row_start_class()>
+                <label for="venue_altitude" class="text-gray-800 font-medium">
+                    "Venue Altitude (m):"
+                </label>
+                <div class="md:col-span-2">
+                    <input
+                        id="venue_altitude"
+                        type="number"
+                        step="1"
+                        min="0"
+                        placeholder="Optional"
+                        prop:value=move || venue_altitude.get().map(|v| v.to_string()).unwrap_or_default()
+                        class=move || density.get().control_class()
+                        on:input=move |ev| {
+                            let value = event_target_value(&ev);
+                            set_venue_altitude.set(value.trim().parse().ok());
+                        }
+                    />
+                    <p class="mt-1 text-sm text-gray-500" class:hidden=move || !density.get().show_helper_text()>
+                        {format!(
+                            "Marks at {} m or higher are flagged as altitude-assisted (A). The points are not modified.",
+                            ALTITUDE_THRESHOLD_M,
+                        )}
+                    </p>
+                </div>
+            </div>
+        </Show>
+    }
+}
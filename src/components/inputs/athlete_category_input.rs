@@ -0,0 +1,41 @@
+use crate::models::{AthleteCategory, Gender};
+use crate::settings::use_density;
+use leptos::prelude::*;
+
+/// The athlete's category (U18, U20, senior or a masters age group), which narrows the
+/// event list
+#[component]
+pub fn AthleteCategoryInput(
+    gender: ReadSignal<Gender>,
+    category: ReadSignal<AthleteCategory>,
+    set_category: WriteSignal<AthleteCategory>,
+) -> impl IntoView {
+    let density = use_density();
+    view! {
+        <div class=move || density.get().row_class()>
+            <label for="athlete_category" class="text-gray-800 font-medium">
+                "Category:"
+            </label>
+            <select
+                id="athlete_category"
+                class=move || format!("md:col-span-2 {}", density.get().control_class())
+                on:change=move |ev| {
+                    if let Some(selected) = AthleteCategory::from_code(&event_target_value(&ev)) {
+                        set_category.set(selected);
+                    }
+                }
+            >
+                {AthleteCategory::all()
+                    .into_iter()
+                    .map(|c| {
+                        view! {
+                            <option value=c.code() selected=move || category.get() == c>
+                                {move || c.label(gender.get())}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
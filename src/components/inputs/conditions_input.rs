@@ -0,0 +1,31 @@
+use crate::settings::use_density;
+use leptos::prelude::*;
+
+/// Freeform notes on the conditions of a performance (temperature, rain, surface)
+#[component]
+pub fn ConditionsInput(
+    conditions: ReadSignal<String>,
+    set_conditions: WriteSignal<String>,
+) -> impl IntoView {
+    let density = use_density();
+    view! {
+        <div class=move || density.get().row_start_class()>
+            <label for="conditions" class="text-gray-800 font-medium">
+                "Conditions:"
+            </label>
+            <div class="md:col-span-2">
+                <textarea
+                    id="conditions"
+                    rows="2"
+                    placeholder="e.g., 12°C, light rain, wet track"
+                    prop:value=move || conditions.get()
+                    class=move || density.get().control_class()
+                    on:input=move |ev| set_conditions.set(event_target_value(&ev))
+                ></textarea>
+                <p class="mt-1 text-sm text-gray-500" class:hidden=move || !density.get().show_helper_text()>
+                    "Notes don't affect the score; they are kept with the calculation for context."
+                </p>
+            </div>
+        </div>
+    }
+}
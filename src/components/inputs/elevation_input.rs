@@ -1,19 +1,22 @@
 use crate::scoring_logic::calculator::is_road_running_event;
-use crate::models::Event;
+use crate::models::{format_gradient, DistanceUnit, Event};
+use crate::settings::{use_density, use_unit_preferences};
 use leptos::prelude::*;
 
 #[component]
 pub fn ElevationInput(
     event: ReadSignal<Event>,
-    #[allow(unused_variables)] net_downhill: ReadSignal<Option<f64>>,
+    net_downhill: ReadSignal<Option<f64>>,
     set_net_downhill: WriteSignal<Option<f64>>,
 ) -> impl IntoView {
+    let density = use_density();
+    let preferences = use_unit_preferences();
     view! {
         <Show
             when=move || { is_road_running_event(&event.get()) }
             fallback=|| view! { <div></div> }
         >
-            <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-start">
+            <div class=move || density.get().row_start_class()>
                 <label for="net_downhill" class="text-gray-800 font-medium">
                     "Net Downhill (m/km):"
                 </label>
@@ -22,7 +25,8 @@ pub fn ElevationInput(
                         id="net_downhill"
                         type="number"
                         step="0.1"
-                        class="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                        prop:value=move || net_downhill.get().map(|v| v.to_string()).unwrap_or_default()
+                        class=move || density.get().control_class()
                         on:input=move |ev| {
                             let value = event_target_value(&ev);
                             if value.is_empty() {
@@ -37,9 +41,25 @@ pub fn ElevationInput(
                             }
                         }
                     />
-                    <p class="mt-1 text-sm text-gray-500">
-                        "Values over 1.0 m/km will result in point deductions"
+                    <p class="mt-1 text-sm text-gray-500" class:hidden=move || !density.get().show_helper_text()>
+                        {move || match preferences.get().distance {
+                            DistanceUnit::Metric => "Values over 1.0 m/km will result in point deductions".to_string(),
+                            DistanceUnit::Imperial => format!(
+                                "Enter in m/km. Values over 1.0 m/km ({}) will result in point deductions",
+                                format_gradient(1.0, DistanceUnit::Imperial),
+                            ),
+                        }}
                     </p>
+                    {move || {
+                        let downhill = net_downhill.get()?;
+                        (preferences.get().distance == DistanceUnit::Imperial).then(|| {
+                            view! {
+                                <p class="mt-1 text-sm text-gray-600">
+                                    {format!("≈ {}", format_gradient(downhill, DistanceUnit::Imperial))}
+                                </p>
+                            }
+                        })
+                    }}
                 </div>
             </div>
         </Show>
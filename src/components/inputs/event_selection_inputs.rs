@@ -1,22 +1,49 @@
-use crate::models::{Event, Gender};
+use crate::models::{AthleteCategory, Event, EventCategory, Gender, Venue};
+use crate::settings::use_density;
 use leptos::prelude::*;
 use strum::IntoEnumIterator;
 
+/// Gender, venue and event selects. Only the events the gender contests (in `category`, if
+/// given, and at the chosen venue) are listed, and the event moves to the first of them when
+/// a change of gender, venue or category leaves it out.
 #[component]
 pub fn EventSelectionInputs(
     gender: ReadSignal<Gender>,
     set_gender: WriteSignal<Gender>,
     event: ReadSignal<Event>,
     set_event: WriteSignal<Event>,
+    #[prop(optional, into)] category: Option<Signal<AthleteCategory>>,
 ) -> impl IntoView {
+    let density = use_density();
+    let category = category.unwrap_or_default();
+    // `None` lists the events of every venue
+    let (venue, set_venue) = signal(None::<Venue>);
+    let events = move || {
+        let gender = gender.get();
+        let venue = venue.get();
+        category
+            .get()
+            .events(gender)
+            .into_iter()
+            .filter(|event| event.is_available_for(gender, venue))
+            .collect::<Vec<_>>()
+    };
+    Effect::new(move |_| {
+        let events = events();
+        if !events.contains(&event.get_untracked()) {
+            if let Some(first) = events.into_iter().next() {
+                set_event.set(first);
+            }
+        }
+    });
     view! {
-        <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+        <div class=move || density.get().row_class()>
             <label for="gender" class="text-gray-800 font-medium">
                 "Gender:"
             </label>
             <select
                 id="gender"
-                class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                class=move || format!("md:col-span-2 {}", density.get().control_class())
                 on:change=move |ev| {
                     let value = event_target_value(&ev);
                     log::info!("Gender selected: {}", value);
@@ -39,13 +66,40 @@ pub fn EventSelectionInputs(
             </select>
         </div>
 
-        <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+        <div class=move || density.get().row_class()>
+            <label for="venue" class="text-gray-800 font-medium">
+                "Venue:"
+            </label>
+            <select
+                id="venue"
+                class=move || format!("md:col-span-2 {}", density.get().control_class())
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    set_venue.set(Venue::iter().find(|v| v.to_string() == value));
+                }
+            >
+                <option value="" selected=move || venue.get().is_none()>
+                    "Any"
+                </option>
+                {Venue::iter()
+                    .map(|v| {
+                        view! {
+                            <option value=v.to_string() selected=move || venue.get() == Some(v)>
+                                {v.to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+
+        <div class=move || density.get().row_class()>
             <label for="event" class="text-gray-800 font-medium">
                 "Event:"
             </label>
             <select
                 id="event"
-                class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                class=move || format!("md:col-span-2 {}", density.get().control_class())
                 on:change=move |ev| {
                     let value = event_target_value(&ev);
                     log::info!("Select changed to: {}", value);
@@ -54,19 +108,30 @@ pub fn EventSelectionInputs(
                     }
                 }
             >
-                {Event::all_variants()
-                    .into_iter()
-                    .map(|e| {
-                        view! {
-                            <option
-                                value=format!("{}", e)
-                                selected=move || event.get().to_string() == e.to_string()
-                            >
-                                {format!("{}", e)}
-                            </option>
-                        }
-                    })
-                    .collect_view()}
+                {move || {
+                    let events = events();
+                    EventCategory::iter()
+                        .filter_map(|group| {
+                            let options = events
+                                .iter()
+                                .filter(|e| e.category() == group)
+                                .cloned()
+                                .map(|e| {
+                                    view! {
+                                        <option
+                                            value=format!("{}", e)
+                                            selected=move || event.get().to_string() == e.to_string()
+                                        >
+                                            {format!("{}", e)}
+                                        </option>
+                                    }
+                                })
+                                .collect_view();
+                            (!options.is_empty())
+                                .then(|| view! { <optgroup label=group.to_string()>{options}</optgroup> })
+                        })
+                        .collect_view()
+                }}
             </select>
         </div>
     }
@@ -1,13 +1,27 @@
-pub mod performance_input;
-pub mod wind_speed_input;
+pub mod altitude_input;
+pub mod athlete_category_input;
+pub mod conditions_input;
 pub mod elevation_input;
 pub mod event_selection_inputs;
+pub mod pace_input;
+pub mod performance_input;
 pub mod placement_info_section;
+pub mod relay_splits_input;
 pub mod score_display;
+pub mod table_edition_input;
+pub mod track_size_input;
+pub mod wind_speed_input;
 
-pub use performance_input::PerformanceInput;
-pub use wind_speed_input::WindSpeedInput;
+pub use altitude_input::AltitudeInput;
+pub use athlete_category_input::AthleteCategoryInput;
+pub use conditions_input::ConditionsInput;
 pub use elevation_input::ElevationInput;
 pub use event_selection_inputs::EventSelectionInputs;
+pub use pace_input::PaceInput;
+pub use performance_input::PerformanceInput;
 pub use placement_info_section::PlacementInfoSection;
-pub use score_display::ScoreDisplay;
\ No newline at end of file
+pub use relay_splits_input::RelaySplitsInput;
+pub use score_display::ScoreDisplay;
+pub use table_edition_input::TableEditionInput;
+pub use track_size_input::TrackSizeInput;
+pub use wind_speed_input::WindSpeedInput;
@@ -0,0 +1,125 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{format_pace, format_race_distance, Event, Gender, PaceUnit};
+use crate::scoring_logic::pace::{
+    even_splits, format_race_time, pace_target, parse_pace, road_race_meters,
+};
+use crate::settings::{use_density, use_settings, use_unit_preferences};
+use leptos::prelude::*;
+
+/// Even splits every 5 km, or every mile for paces per mile
+fn split_interval(unit: PaceUnit) -> f64 {
+    match unit {
+        PaceUnit::PerKilometer => 5000.0,
+        PaceUnit::PerMile => unit.meters(),
+    }
+}
+
+/// Pace and even-split targets for the entered time of a road race, and the reverse: the
+/// time and points of the race run at a given pace, which can fill in the performance.
+#[component]
+pub fn PaceInput(
+    gender: ReadSignal<Gender>,
+    event: ReadSignal<Event>,
+    performance_input: ReadSignal<String>,
+    set_performance_input: WriteSignal<String>,
+    set_performance: WriteSignal<f64>,
+    set_parse_error: WriteSignal<Option<String>>,
+) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let density = use_density();
+    let settings = use_settings();
+    let preferences = use_unit_preferences();
+    let (pace_input, set_pace_input) = signal(String::new());
+
+    let meters = move || road_race_meters(&event.get());
+    let splits = move || {
+        let meters = meters()?;
+        let seconds = Event::parse_time_to_seconds(performance_input.get().trim()).ok()?;
+        let unit = preferences.get().pace;
+        Some((
+            format_pace(seconds, meters, unit),
+            even_splits(seconds, meters, split_interval(unit)),
+        ))
+    };
+    let target = Memo::new(move |_| {
+        let pace = pace_input.with(|pace| parse_pace(pace))?;
+        pace_target(
+            gender.get(),
+            &event.get(),
+            pace,
+            preferences.get().pace,
+            providers.result_score_calculator,
+        )
+    });
+
+    view! {
+        <Show when=move || meters().is_some()>
+            <div class=move || density.get().row_start_class()>
+                <span class="text-gray-800 font-medium">"Pace:"</span>
+                <div class="md:col-span-2 space-y-2 text-sm">
+                    {move || {
+                        splits()
+                            .map(|(pace, splits)| {
+                                view! {
+                                    <p class="text-gray-700">{format!("Even pace: {}", pace)}</p>
+                                    <ul class="flex flex-wrap gap-x-4 gap-y-1 text-gray-600">
+                                        {splits
+                                            .into_iter()
+                                            .map(|split| {
+                                                let at = format_race_distance(split.meters, preferences.get().distance);
+                                                view! { <li>{format!("{}: {}", at, format_race_time(split.elapsed))}</li> }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                            })
+                    }}
+                    <label class="flex items-center gap-2 text-gray-700">
+                        "Run at"
+                        <input
+                            type="text"
+                            aria-label="Pace"
+                            placeholder="e.g., 2:58"
+                            value=move || pace_input.get()
+                            class=move || format!("w-24 {}", density.get().control_class())
+                            on:input=move |ev| set_pace_input.set(event_target_value(&ev))
+                        />
+                        {move || preferences.get().pace.to_string()}
+                    </label>
+                    {move || {
+                        if pace_input.with(|pace| pace.trim().is_empty()) {
+                            return ().into_any();
+                        }
+                        match target.get() {
+                            Ok(target) => {
+                                let mark = format_race_time(target.time);
+                                let points = match &target.points {
+                                    Ok(points) => settings.with(|s| format!("{} points", s.format_points(*points))),
+                                    Err(e) => e.clone(),
+                                };
+                                view! {
+                                    <div class="flex items-center gap-2 text-gray-700">
+                                        <span>{format!("= {} · {}", mark, points)}</span>
+                                        <button
+                                            type="button"
+                                            class="text-gray-700 underline hover:text-gray-900"
+                                            on:click=move |_| {
+                                                set_performance_input.set(mark.clone());
+                                                set_performance.set(target.time);
+                                                set_parse_error.set(None);
+                                            }
+                                        >
+                                            "Use this time"
+                                        </button>
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                            Err(e) => view! { <p class="text-red-600">{e}</p> }.into_any(),
+                        }
+                    }}
+                </div>
+            </div>
+        </Show>
+    }
+}
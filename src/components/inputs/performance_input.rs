@@ -1,4 +1,8 @@
-use crate::models::{Event, PerformanceType};
+use crate::models::{
+    format_distance, format_pace, format_race_distance, parse_distance_mark, DistanceUnit, Event,
+    EventGroup, Performance, PerformanceType,
+};
+use crate::settings::{use_density, use_unit_preferences};
 use leptos::prelude::*;
 
 #[component]
@@ -10,8 +14,50 @@ pub fn PerformanceInput(
     parse_error: ReadSignal<Option<String>>,
     set_parse_error: WriteSignal<Option<String>>,
 ) -> impl IntoView {
+    let density = use_density();
+    let preferences = use_unit_preferences();
+    // The entered mark in the user's preferred units: a pace for races (with the distance for
+    // road races), and the other unit for field events entered in feet and inches or when
+    // imperial units are selected
+    let converted = move || {
+        let value = performance_input.get();
+        let event = event.get();
+        match event.performance_type() {
+            PerformanceType::Time => {
+                let seconds = Event::parse_time_to_seconds(&value).ok()?;
+                let meters = event.distance_meters()?;
+                let pace = format!(
+                    "Pace: {}",
+                    format_pace(seconds, meters, preferences.get().pace)
+                );
+                if event.event_group() == EventGroup::RoadRunning {
+                    Some(format!(
+                        "{} · {}",
+                        format_race_distance(meters, preferences.get().distance),
+                        pace
+                    ))
+                } else {
+                    Some(pace)
+                }
+            }
+            PerformanceType::Distance => {
+                let meters = parse_distance_mark(&value).ok()?;
+                if value.trim().parse::<f64>().is_err() && !value.trim().ends_with('m') {
+                    Some(format!(
+                        "= {}",
+                        format_distance(meters, DistanceUnit::Metric)
+                    ))
+                } else {
+                    (preferences.get().distance == DistanceUnit::Imperial)
+                        .then(|| format!("≈ {}", format_distance(meters, DistanceUnit::Imperial)))
+                }
+            }
+            PerformanceType::Points => None,
+        }
+    };
+
     view! {
-        <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-start">
+        <div class=move || density.get().row_start_class()>
             <label for="performance" class="text-gray-800 font-medium">
                 "Performance:"
             </label>
@@ -22,15 +68,23 @@ pub fn PerformanceInput(
                     value=move || performance_input.get()
                     class=move || {
                         if parse_error.get().is_some() {
-                            "w-full px-3 py-2 border border-red-300 rounded-md focus:outline-none focus:ring-1 focus:ring-red-500 bg-red-50"
+                            density
+                                .get()
+                                .control_class()
+                                .replace("border-gray-300", "border-red-300")
+                                .replace("focus:ring-black", "focus:ring-red-500 bg-red-50")
                         } else {
-                            "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                            density.get().control_class().to_string()
                         }
                     }
                     placeholder=move || {
                         match event.get().performance_type() {
                             PerformanceType::Time => "e.g., 10.50 or 1:30.25 or 2:15:30.50",
-                            PerformanceType::Distance => "e.g., 8.95 (meters)",
+                            PerformanceType::Distance => match preferences.get().distance {
+                                DistanceUnit::Metric => "e.g., 8.95 (meters)",
+                                DistanceUnit::Imperial => "e.g., 29' 4.5\" or 8.95 (meters)",
+                            },
+                            PerformanceType::Points => "e.g., 8500 (points)",
                         }
                     }
                     on:input=move |ev| {
@@ -41,21 +95,9 @@ pub fn PerformanceInput(
                         set_parse_error.set(None);
 
                         // Validate input and update parse error if needed
-                        let validation_result = match event.get().performance_type() {
-                            PerformanceType::Time => {
-                                // Try to parse as time string first, then as direct seconds
-                                Event::parse_time_to_seconds(&value).or_else(|_| {
-                                    value.parse::<f64>().map_err(|_| "Invalid time format. Use formats like 10.50, 1:30.25, or 2:15:30.50".to_string())
-                                })
-                            }
-                            PerformanceType::Distance => {
-                                value.parse::<f64>().map_err(|_| "Invalid distance format. Enter a number in meters (e.g., 8.95)".to_string())
-                            }
-                        };
-
-                        match validation_result {
+                        match Performance::parse(&event.get(), &value) {
                             Ok(parsed_value) => {
-                                set_performance.set(parsed_value);
+                                set_performance.set(parsed_value.value());
                                 set_parse_error.set(None);
                             }
                             Err(error_msg) => {
@@ -71,11 +113,12 @@ pub fn PerformanceInput(
                     when=move || parse_error.get().is_some()
                     fallback=move || {
                         view! {
-                            <p class="mt-1 text-sm text-gray-500">
+                            <p class="mt-1 text-sm text-gray-500" class:hidden=move || !density.get().show_helper_text()>
                                 {move || {
                                     match event.get().performance_type() {
                                         PerformanceType::Time => "Enter time as seconds (10.50) or formatted time (mm:ss.mmm or hh:mm:ss.mmm)",
-                                        PerformanceType::Distance => "Enter distance in meters (e.g., 8.95 for long jump)",
+                                        PerformanceType::Distance => "Enter distance in meters (e.g., 8.95 for long jump) or feet and inches (29' 4.5\")",
+                                        PerformanceType::Points => "Enter the points total (e.g., 8500 for a decathlon)",
                                     }
                                 }}
                             </p>
@@ -89,7 +132,11 @@ pub fn PerformanceInput(
                         {move || parse_error.get().unwrap_or_default()}
                     </p>
                 </Show>
+                {move || {
+                    converted()
+                        .map(|text| view! { <p class="mt-1 text-sm text-gray-600">{text}</p> })
+                }}
             </div>
         </div>
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,22 @@
-use crate::models::CompetitionCategory;
-use crate::scoring_logic::placement_score::RoundType;
+use crate::error_reporting::report_error;
+use crate::models::{CompetitionCategory, Event};
+use crate::scoring_logic::engine::load_placement_tables;
+use crate::scoring_logic::placement_score::{rounds_for_event, RoundType};
+use crate::settings::use_density;
+use crate::startup_timing::measure;
 use leptos::prelude::*;
 use strum::IntoEnumIterator;
+use wasm_bindgen_futures::JsFuture;
+
+/// Resolves on the next task, after the browser has had a chance to paint
+async fn next_task() {
+    let promise = js_sys::Promise::new(&mut |resolve, _| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, 0);
+        }
+    });
+    let _ = JsFuture::from(promise).await;
+}
 
 #[component]
 pub fn PlacementInfoSection(
@@ -11,45 +26,107 @@ pub fn PlacementInfoSection(
     set_competition_category: WriteSignal<CompetitionCategory>,
     place: ReadSignal<i32>,
     set_place: WriteSignal<i32>,
+    /// How many other athletes share the place
+    tied_with: ReadSignal<i32>,
+    set_tied_with: WriteSignal<i32>,
     round: ReadSignal<RoundType>,
     set_round: WriteSignal<RoundType>,
     size_of_final: ReadSignal<i32>,
     set_size_of_final: WriteSignal<i32>,
     qualified_to_final: ReadSignal<bool>,
     set_qualified_to_final: WriteSignal<bool>,
+    /// Whether the race was the competition's main event, asked only for the events it
+    /// affects (see `Event::placement_depends_on_main_event`)
+    main_event: ReadSignal<bool>,
+    set_main_event: WriteSignal<bool>,
+    /// Placement is the whole score (cross country), so it can't be left out
+    #[prop(optional, into)]
+    required: Option<Signal<bool>>,
+    /// Limits the rounds to the ones the event has; every round is offered without it
+    #[prop(optional, into)]
+    event: Option<Signal<Event>>,
 ) -> impl IntoView {
+    let density = use_density();
+    let rounds = move || match event {
+        Some(event) => rounds_for_event(&event.get()),
+        None => RoundType::iter().collect(),
+    };
+    // A round the new event doesn't have, such as a semi-final after switching to a jump
+    Effect::new(move |_| {
+        if !rounds().contains(&round.get_untracked()) {
+            set_round.set(RoundType::Final);
+        }
+    });
+    let asks_main_event =
+        move || event.is_some_and(|event| event.get().placement_depends_on_main_event());
+    let required = move || required.is_some_and(|required| required.get());
+    let included = move || include_placement.get() || required();
+    // The placement tables are only indexed once the section is enabled, after the current
+    // paint, so calculations without a placing never pay for them. `None` while loading.
+    let placement_tables = LocalResource::new(move || {
+        let included = included();
+        async move {
+            if !included {
+                return Ok(());
+            }
+            next_task().await;
+            let loaded = measure("load_placement_tables", load_placement_tables);
+            if let Err(e) = &loaded {
+                report_error("load_placement_tables", e);
+            }
+            loaded
+        }
+    });
+
     view! {
-        <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
-            <label for="include_placement" class="text-gray-800 font-medium">
-                "Include Placement Info:"
-            </label>
-            <div class="md:col-span-2 flex items-center">
-                <input
-                    id="include_placement"
-                    type="checkbox"
-                    checked=move || include_placement.get()
-                    class="h-5 w-5 rounded border-gray-300 text-black focus:ring-black"
-                    on:change=move |ev| {
-                        set_include_placement.set(event_target_checked(&ev));
-                    }
-                />
-                <label for="include_placement" class="ml-2 text-gray-700">
-                    "Add placement information for additional points"
+        <Show when=move || !required()>
+            <div class=move || density.get().row_class()>
+                <label for="include_placement" class="text-gray-800 font-medium">
+                    "Include Placement Info:"
                 </label>
+                <div class="md:col-span-2 flex items-center">
+                    <input
+                        id="include_placement"
+                        type="checkbox"
+                        checked=move || include_placement.get()
+                        class="h-5 w-5 rounded border-gray-300 text-black focus:ring-black"
+                        on:change=move |ev| {
+                            set_include_placement.set(event_target_checked(&ev));
+                        }
+                    />
+                    <label for="include_placement" class="ml-2 text-gray-700">
+                        "Add placement information for additional points"
+                    </label>
+                </div>
             </div>
-        </div>
+        </Show>
 
         <Show
-            when=move || include_placement.get()
+            when=included
             fallback=|| view! { <div></div> }
         >
-            <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+            {move || match placement_tables.get() {
+                None => {
+                    view! { <p class="text-sm text-gray-500 italic">"Loading placing tables…"</p> }
+                        .into_any()
+                }
+                Some(Err(e)) => {
+                    view! {
+                        <p class="text-sm text-red-600">
+                            {format!("Placing tables unavailable: {}", e)}
+                        </p>
+                    }
+                        .into_any()
+                }
+                Some(Ok(())) => ().into_any(),
+            }}
+            <div class=move || density.get().row_class()>
                 <label for="competition_category" class="text-gray-800 font-medium">
                     "Competition Category:"
                 </label>
             <select
                 id="competition_category"
-                class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                class=move || format!("md:col-span-2 {}", density.get().control_class())
                 on:change=move |ev| {
                     let value = event_target_value(&ev);
                     log::info!("Select changed to: {}", value);
@@ -73,7 +150,7 @@ pub fn PlacementInfoSection(
             </select>
             </div>
 
-            <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+            <div class=move || density.get().row_class()>
                 <label for="place" class="text-gray-800 font-medium">
                     "Place:"
                 </label>
@@ -82,7 +159,7 @@ pub fn PlacementInfoSection(
                     type="number"
                     min="1"
                     value=move || place.get()
-                    class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                    class=move || format!("md:col-span-2 {}", density.get().control_class())
                     on:input=move |ev| {
                         if let Ok(val) = event_target_value(&ev).parse::<i32>() {
                             set_place.set(val);
@@ -91,32 +168,55 @@ pub fn PlacementInfoSection(
                 />
             </div>
 
-            <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+            <div class=move || density.get().row_class()>
+                <label for="tied_with" class="text-gray-800 font-medium">
+                    "Tied With:"
+                </label>
+                <div class="md:col-span-2 flex items-center gap-2">
+                    <input
+                        id="tied_with"
+                        type="number"
+                        min="0"
+                        value=move || tied_with.get()
+                        class=move || format!("w-20 {}", density.get().control_class())
+                        on:input=move |ev| {
+                            if let Ok(val) = event_target_value(&ev).parse::<i32>() {
+                                set_tied_with.set(val.max(0));
+                            }
+                        }
+                    />
+                    <span class="text-sm text-gray-600">
+                        "other athletes; tied athletes share the points of the places they fill"
+                    </span>
+                </div>
+            </div>
+
+            <div class=move || density.get().row_class()>
                 <label for="round" class="text-gray-800 font-medium">
                     "Round:"
                 </label>
                 <select
                     id="round"
-                    class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                    class=move || format!("md:col-span-2 {}", density.get().control_class())
                     on:change=move |ev| {
                         let value = event_target_value(&ev);
-                        match value.as_str() {
-                            "Final" => set_round.set(RoundType::Final),
-                            "Semifinal" => set_round.set(RoundType::SemiFinal),
-                            "Other" => set_round.set(RoundType::Other),
-                            _ => {}
+                        if let Some(selected) = rounds().into_iter().find(|r| r.to_string() == value) {
+                            set_round.set(selected);
                         }
                     }
                 >
-                    <option value="Final" selected=move || matches!(round.get(), RoundType::Final)>
-                        "Final"
-                    </option>
-                    <option value="Semifinal" selected=move || matches!(round.get(), RoundType::SemiFinal)>
-                        "Semifinal"
-                    </option>
-                    <option value="Other" selected=move || matches!(round.get(), RoundType::Other)>
-                        "Other"
-                    </option>
+                    {move || {
+                        rounds()
+                            .into_iter()
+                            .map(|r| {
+                                view! {
+                                    <option value=r.to_string() selected=move || round.get() == r>
+                                        {r.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()
+                    }}
                 </select>
             </div>
 
@@ -124,7 +224,7 @@ pub fn PlacementInfoSection(
                 when=move || matches!(round.get(), RoundType::SemiFinal)
                 fallback=|| view! { <div></div> }
             >
-                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+                <div class=move || density.get().row_class()>
                     <label for="size_of_final" class="text-gray-800 font-medium">
                         "Size of Final:"
                     </label>
@@ -133,7 +233,7 @@ pub fn PlacementInfoSection(
                         type="number"
                         min="1"
                         value=move || size_of_final.get()
-                        class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                        class=move || format!("md:col-span-2 {}", density.get().control_class())
                         on:input=move |ev| {
                             if let Ok(val) = event_target_value(&ev).parse::<i32>() {
                                 set_size_of_final.set(val);
@@ -141,10 +241,21 @@ pub fn PlacementInfoSection(
                         }
                     />
                 </div>
+            </Show>
 
-                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+            // Qualifiers from earlier rounds all score the points for 1st place
+            <Show
+                when=move || {
+                    matches!(
+                        round.get(),
+                        RoundType::SemiFinal | RoundType::Heat | RoundType::Qualification
+                    )
+                }
+                fallback=|| view! { <div></div> }
+            >
+                <div class=move || density.get().row_class()>
                     <label for="qualified_to_final" class="text-gray-800 font-medium">
-                        "Qualified to Final:"
+                        "Qualified:"
                     </label>
                     <div class="md:col-span-2 flex items-center">
                         <input
@@ -157,11 +268,43 @@ pub fn PlacementInfoSection(
                             }
                         />
                         <label for="qualified_to_final" class="ml-2 text-gray-700">
-                            "Athlete qualified to the final round"
+                            {move || {
+                                if round.get() == RoundType::Heat {
+                                    "Athlete qualified to the next round"
+                                } else {
+                                    "Athlete qualified to the final round"
+                                }
+                            }}
+                        </label>
+                    </div>
+                </div>
+            </Show>
+
+            // A half marathon held alongside a marathon scores on a smaller table
+            <Show
+                when=asks_main_event
+                fallback=|| view! { <div></div> }
+            >
+                <div class=move || density.get().row_class()>
+                    <label for="main_event" class="text-gray-800 font-medium">
+                        "Main Event:"
+                    </label>
+                    <div class="md:col-span-2 flex items-center">
+                        <input
+                            id="main_event"
+                            type="checkbox"
+                            checked=move || main_event.get()
+                            class="h-5 w-5 rounded border-gray-300 text-black focus:ring-black"
+                            on:change=move |ev| {
+                                set_main_event.set(event_target_checked(&ev));
+                            }
+                        />
+                        <label for="main_event" class="ml-2 text-gray-700">
+                            "The race was the competition's main event, not one held alongside a marathon"
                         </label>
                     </div>
                 </div>
             </Show>
         </Show>
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,118 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{Event, Gender};
+use crate::scoring_logic::relay::{relay_leg_event, relay_splits, RELAY_LEGS};
+use crate::settings::use_density;
+use leptos::prelude::*;
+
+/// Leg-by-leg entry for relays, shown only for relays run over one open event. Once all four
+/// splits are valid their sum fills in the performance, and each split is shown with the
+/// points it would score as the open event.
+#[component]
+pub fn RelaySplitsInput(
+    gender: ReadSignal<Gender>,
+    event: ReadSignal<Event>,
+    set_performance_input: WriteSignal<String>,
+    set_performance: WriteSignal<f64>,
+    set_parse_error: WriteSignal<Option<String>>,
+) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let density = use_density();
+    let (by_leg, set_by_leg) = signal(false);
+    let (splits, set_splits) = signal(vec![String::new(); RELAY_LEGS]);
+
+    let relay = Memo::new(move |_| {
+        let seconds = splits.with(|splits| {
+            splits
+                .iter()
+                .map(|split| Event::parse_time_to_seconds(split.trim()))
+                .collect::<Result<Vec<f64>, String>>()
+        })?;
+        relay_splits(
+            gender.get(),
+            &event.get(),
+            &seconds,
+            providers.result_score_calculator,
+        )
+    });
+
+    Effect::new(move |_| {
+        if !by_leg.get() {
+            return;
+        }
+        if let Ok(relay) = relay.get() {
+            set_performance_input.set(relay.team_mark());
+            set_performance.set(relay.team_time);
+            set_parse_error.set(None);
+        }
+    });
+
+    view! {
+        <Show when=move || relay_leg_event(&event.get()).is_some()>
+            <div class=move || density.get().row_start_class()>
+                <span class="text-gray-800 font-medium">"Leg splits:"</span>
+                <div class="md:col-span-2 space-y-2">
+                    <label class="flex items-center gap-2 text-sm text-gray-700">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || by_leg.get()
+                            on:change=move |ev| set_by_leg.set(event_target_checked(&ev))
+                        />
+                        "Enter the team time as four splits"
+                    </label>
+                    <Show when=move || by_leg.get()>
+                        <div class="grid grid-cols-2 md:grid-cols-4 gap-2">
+                            {(0..RELAY_LEGS)
+                                .map(|leg| {
+                                    view! {
+                                        <input
+                                            type="text"
+                                            aria-label=format!("Leg {} split", leg + 1)
+                                            placeholder=format!("Leg {}", leg + 1)
+                                            value=move || splits.with(|splits| splits[leg].clone())
+                                            class=move || density.get().control_class()
+                                            on:change=move |ev| {
+                                                let split = event_target_value(&ev);
+                                                set_splits.update(|splits| splits[leg] = split);
+                                            }
+                                        />
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                        {move || match relay.get() {
+                            Ok(relay) => {
+                                let leg_event = relay.leg_event.to_string();
+                                view! {
+                                    <ul class="text-sm text-gray-600">
+                                        {relay
+                                            .legs
+                                            .into_iter()
+                                            .enumerate()
+                                            .map(|(leg, split)| {
+                                                let equivalent = match split.open_equivalent {
+                                                    Ok(points) => format!("{:.0} pts as a {}", points, leg_event),
+                                                    Err(e) => e,
+                                                };
+                                                view! {
+                                                    <li>
+                                                        {format!("Leg {}: {:.2}s — {}", leg + 1, split.split, equivalent)}
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                    <p class="text-xs text-gray-500">
+                                        "Legs after the first start running, so they score higher than the same time in an open race."
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                            Err(_) if splits.with(|splits| splits.iter().any(|s| s.trim().is_empty())) => ().into_any(),
+                            Err(e) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                        }}
+                    </Show>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -1,13 +1,130 @@
+use crate::components::copy_button::CopyButton;
+use crate::components::nav_bar::PAGES_BASE;
+use crate::components::share_button::ShareButton;
+use crate::components::share_qr_code::ShareQrCode;
+use crate::exporters::csv::{csv_data_url, to_csv};
+use crate::exporters::summary::result_summary;
+use crate::models::WorldAthleticsScoreOutput;
+use crate::scoring_logic::calculator::is_placement_only_event;
+use crate::scoring_logic::citations::{breakdown_rows, score_breakdown};
+use crate::settings::{use_density, use_settings, Settings};
+use crate::share_link::CalculatorState;
 use leptos::prelude::*;
+use leptos_router::hooks::use_location;
+
+/// The sentence read out by screen readers when a calculation completes
+pub fn score_announcement(score: &WorldAthleticsScoreOutput, settings: &Settings) -> String {
+    let mut parts = vec![format!("{} points.", settings.format_points(score.points))];
+    parts.push(format!(
+        "Result score {}.",
+        settings.format_points(score.result_score)
+    ));
+    if score.wind_adjustment != 0.0 {
+        parts.push(format!("Wind adjustment {:+}.", score.wind_adjustment));
+    }
+    if score.downhill_adjustment != 0.0 {
+        parts.push(format!(
+            "Downhill adjustment {:+}.",
+            score.downhill_adjustment
+        ));
+    }
+    if score.placement_score != 0 {
+        parts.push(format!("Placement score {}.", score.placement_score));
+    }
+    if score.beyond_table_range {
+        parts.push("Beyond table range, capped at 1400 points.".to_string());
+    }
+    if score.estimated {
+        parts.push("Estimated.".to_string());
+    }
+    if score.altitude_assisted {
+        parts.push("Set at altitude.".to_string());
+    }
+    parts.join(" ")
+}
+
+/// What to try when a calculation fails, by the calculator's error message
+pub fn score_error_hint(error: &str) -> &'static str {
+    if error.starts_with("Coefficients not found") {
+        "The scoring tables in use have no entry for this event and gender. Pick another event or gender."
+    } else if error.contains("outside the range") {
+        "Check the mark's units and format, e.g. 10.23 for seconds, 1:45.30 for minutes or 8.12 for meters."
+    } else if error.contains("isn't contested by") {
+        "Switch the gender or pick another event."
+    } else if error.contains("scored on placing only") {
+        "Turn on placement info and enter a place."
+    } else if error.contains("not a valid mark") || error.starts_with("Invalid mark") {
+        "Re-enter the mark in the event's format."
+    } else {
+        "Check the inputs and try again. If it keeps failing, reload the page."
+    }
+}
 
 #[component]
 pub fn ScoreDisplay(
     points: ReadSignal<f64>,
     points_calculated: ReadSignal<bool>,
+    breakdown: ReadSignal<Option<WorldAthleticsScoreOutput>>,
+    /// The inputs behind the calculation, for the copied and shared summary
+    state: ReadSignal<Option<CalculatorState>>,
+    beyond_table_range: ReadSignal<bool>,
     parse_error: ReadSignal<Option<String>>,
+    /// Why the last calculation failed, if it did
+    score_error: ReadSignal<Option<String>>,
 ) -> impl IntoView {
+    let settings = use_settings();
+    let density = use_density();
+    let (show_qr_code, set_show_qr_code) = signal(false);
+    let location = use_location();
+    // The current page URL, which the form keeps in step with the calculation's inputs
+    let share_url = Signal::derive(move || {
+        points.track();
+        location.search.track();
+        window().location().href().unwrap_or_default()
+    });
+    let report_href = move || {
+        let base = if location.pathname.get().starts_with(PAGES_BASE) {
+            PAGES_BASE
+        } else {
+            ""
+        };
+        let search = location.search.get();
+        format!("{}/score-report?{}", base, search.trim_start_matches('?'))
+    };
+    let placing_only = move || {
+        state.with(|state| {
+            state
+                .as_ref()
+                .is_some_and(|state| is_placement_only_event(&state.event))
+        })
+    };
+    let summary = Signal::derive(move || {
+        state.with(|state| {
+            breakdown.with(|score| match (state, score) {
+                (Some(state), Some(score)) => settings.with(|s| result_summary(state, score, s)),
+                _ => String::new(),
+            })
+        })
+    });
+
     view! {
-        <div class="mt-8 flex flex-col items-center">
+        <div class=move || {
+            if density.get().show_helper_text() {
+                "mt-8 flex flex-col items-center"
+            } else {
+                "mt-4 flex flex-col items-center"
+            }
+        }>
+            // Kept in the DOM at all times so screen readers pick up changes to it
+            <div class="sr-only" role="status" aria-live="polite" aria-atomic="true">
+                {move || {
+                    breakdown
+                        .get()
+                        .filter(|_| points_calculated.get())
+                        .map(|score| settings.with(|s| score_announcement(&score, s)))
+                        .unwrap_or_default()
+                }}
+            </div>
             <button
                 type="submit"
                 class=move || {
@@ -24,11 +141,30 @@ pub fn ScoreDisplay(
 
             <Show
                 when=move || points_calculated.get()
-                fallback=|| {
-                    view! {
-                        <div class="mt-6 text-center text-gray-500 italic">
-                            "Submit the form to calculate points"
-                        </div>
+                fallback=move || {
+                    // A mark that doesn't parse has its own message by the input
+                    match score_error.get().filter(|_| parse_error.get().is_none()) {
+                        Some(error) => {
+                            view! {
+                                <div
+                                    class="mt-6 max-w-md text-center p-4 bg-red-50 rounded-lg border border-red-200"
+                                    role="alert"
+                                >
+                                    <p class="font-medium text-red-800">"Couldn't calculate a score"</p>
+                                    <p class="text-sm text-red-700 mt-1">{error.clone()}</p>
+                                    <p class="text-sm text-gray-600 mt-2">{score_error_hint(&error)}</p>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        None => {
+                            view! {
+                                <div class="mt-6 text-center text-gray-500 italic">
+                                    "Enter a mark to calculate points"
+                                </div>
+                            }
+                                .into_any()
+                        }
                     }
                 }
             >
@@ -36,14 +172,169 @@ pub fn ScoreDisplay(
                     <h3 class="text-2xl font-bold text-gray-800">
                         {"Points: "}
                         <span class="text-gray-900">
-                            {move || format!("{:.2}", points.get())}
+                            {move || settings.with(|s| s.format_points(points.get()))}
                         </span>
                     </h3>
-                    <p class="text-sm text-gray-600 mt-1">
-                        Based on World Athletics scoring tables with adjustments for wind and elevation change. Due to how scores are calculated, you may see a discrepancy of +-1 point vs. your official World Athletics score.
-                    </p>
+                    <Show when=move || beyond_table_range.get()>
+                        <p class="text-sm font-medium text-amber-700 mt-1">
+                            "Beyond table range: this performance is better than the official tables cover, so the result score is capped at 1400 points."
+                        </p>
+                    </Show>
+                    {move || {
+                        breakdown
+                            .get()
+                            .filter(|score| score.estimated)
+                            .map(|score| {
+                                let note = if score.result_estimated {
+                                    "Estimated: the official scoring tables don't include this event, so these points use estimated coefficients."
+                                } else {
+                                    "Estimated: the official placing tables don't score this round, so the placing points are estimates."
+                                };
+                                view! {
+                                    <p class="text-sm font-medium text-amber-700 mt-1">{note}</p>
+                                }
+                            })
+                    }}
+                    {move || {
+                        breakdown
+                            .get()
+                            .filter(|score| score.altitude_assisted)
+                            .map(|_| {
+                                view! {
+                                    <p class="text-sm font-medium text-gray-700 mt-1">
+                                        "A: set at altitude (1000 m or higher). World Athletics rankings don't modify the score for altitude."
+                                    </p>
+                                }
+                            })
+                    }}
+                    {move || {
+                        breakdown.get().map(|score| view! { <ScoreBreakdown score=score /> })
+                    }}
+                    <Show
+                        when=placing_only
+                        fallback=|| {
+                            view! {
+                                <p class="text-sm text-gray-600 mt-1">
+                                    Based on World Athletics scoring tables with adjustments for wind and elevation change. Due to how scores are calculated, you may see a discrepancy of +-1 point vs. your official World Athletics score.
+                                </p>
+                            }
+                        }
+                    >
+                        <p class="text-sm text-gray-600 mt-1">
+                            "Cross country has no scoring tables, so the time isn't scored: the points are the placing score for the place and competition category, from the World Athletics cross country placing table. Only GW, GL, A and B meetings award placing points."
+                        </p>
+                    </Show>
+                    <div class="mt-3 flex flex-wrap justify-center items-center gap-4">
+                        <CopyButton text=summary label="Copy result".to_string() />
+                        <ShareButton text=summary url=share_url />
+                        <CopyButton text=share_url label="Copy link".to_string() />
+                        <button
+                            type="button"
+                            class="text-sm text-gray-700 underline hover:text-gray-900"
+                            on:click=move |_| set_show_qr_code.update(|show| *show = !*show)
+                        >
+                            {move || if show_qr_code.get() { "Hide QR code" } else { "Share via QR code" }}
+                        </button>
+                        <a
+                            class="text-sm text-gray-700 underline hover:text-gray-900"
+                            href=report_href
+                        >
+                            "Printable report"
+                        </a>
+                    </div>
+                    <Show when=move || show_qr_code.get()>
+                        <div class="mt-3">
+                            <ShareQrCode url=share_url />
+                        </div>
+                    </Show>
                 </div>
             </Show>
         </div>
     }
 }
+
+/// Each component of the score with an expandable reference to the rule behind it
+#[component]
+fn ScoreBreakdown(score: WorldAthleticsScoreOutput) -> impl IntoView {
+    let settings = use_settings();
+    let csv_href = csv_data_url(&to_csv(breakdown_rows(&score)));
+
+    view! {
+        <ul class="mt-3 space-y-1 text-sm text-left max-w-md mx-auto">
+            {score_breakdown(&score)
+                .into_iter()
+                .map(|component| {
+                    view! {
+                        <li>
+                            <details>
+                                <summary class="flex justify-between cursor-pointer text-gray-700">
+                                    <span>{component.label}</span>
+                                    <span class="font-medium text-gray-900">
+                                        {settings.with(|s| s.format_points(component.points))}
+                                    </span>
+                                </summary>
+                                <p class="mt-1 pl-3 border-l-2 border-gray-200 text-xs text-gray-600">
+                                    <cite class="not-italic font-medium">{component.citation.citation}</cite>
+                                    {format!(" [{}]. {}", component.citation.id, component.citation.summary)}
+                                </p>
+                            </details>
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+        <a
+            class="inline-block mt-2 text-sm text-gray-700 underline hover:text-gray-900"
+            href=csv_href
+            download="score-breakdown.csv"
+        >
+            "Download breakdown (CSV)"
+        </a>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score_announcement() {
+        let score = WorldAthleticsScoreOutput {
+            points: 1180.0,
+            result_score: 1150.0,
+            wind_adjustment: -10.0,
+            downhill_adjustment: 0.0,
+            placement_score: 40,
+            beyond_table_range: false,
+            estimated: false,
+            result_estimated: false,
+            placement_estimated: false,
+            altitude_assisted: false,
+        };
+        let settings = Settings {
+            points_decimals: 0,
+            ..Default::default()
+        };
+        assert_eq!(
+            score_announcement(&score, &settings),
+            "1180 points. Result score 1150. Wind adjustment -10. Placement score 40."
+        );
+    }
+
+    #[test]
+    fn test_score_error_hint() {
+        assert_eq!(
+            score_error_hint("Coefficients not found for gender Women and event: 110mH"),
+            score_error_hint("Coefficients not found for gender Men and event: Mile"),
+        );
+        assert!(score_error_hint(
+            "3.5 is outside the range the 100m scoring tables cover (9.46 to 16.37)"
+        )
+        .contains("units"));
+        assert!(
+            score_error_hint("Cross Country 10km is scored on placing only; enter a place")
+                .contains("enter a place")
+        );
+        assert!(score_error_hint("Scoring engine lock poisoned").contains("reload"));
+    }
+}
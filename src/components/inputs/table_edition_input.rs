@@ -0,0 +1,39 @@
+use crate::scoring_logic::coefficients::table_editions;
+use crate::settings::use_density;
+use leptos::prelude::*;
+
+/// The edition of the scoring tables to score against, from the bundled editions
+#[component]
+pub fn TableEditionInput(
+    table_edition: ReadSignal<String>,
+    set_table_edition: WriteSignal<String>,
+) -> impl IntoView {
+    let density = use_density();
+    view! {
+        <div class=move || density.get().row_class()>
+            <label for="table_edition" class="text-gray-800 font-medium">
+                "Scoring Tables:"
+            </label>
+            <select
+                id="table_edition"
+                class=move || format!("md:col-span-2 {}", density.get().control_class())
+                on:change=move |ev| set_table_edition.set(event_target_value(&ev))
+            >
+                {table_editions()
+                    .into_iter()
+                    .map(|edition| {
+                        let selected = {
+                            let edition = edition.clone();
+                            move || table_edition.get() == edition
+                        };
+                        view! {
+                            <option value=edition.clone() selected=selected>
+                                {format!("{} edition", edition)}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
@@ -0,0 +1,51 @@
+use crate::models::Event;
+use crate::scoring_logic::track_size::{is_short_track_event, scoring_event, TrackSize};
+use crate::settings::use_density;
+use leptos::prelude::*;
+use strum::IntoEnumIterator;
+
+/// The indoor track a mark was set on, shown only for short-track events, with how the
+/// choice changes the scoring
+#[component]
+pub fn TrackSizeInput(
+    event: ReadSignal<Event>,
+    track_size: ReadSignal<TrackSize>,
+    set_track_size: WriteSignal<TrackSize>,
+) -> impl IntoView {
+    let density = use_density();
+    view! {
+        <Show when=move || is_short_track_event(&event.get())>
+            <div class=move || density.get().row_start_class()>
+                <label for="track_size" class="text-gray-800 font-medium">
+                    "Track:"
+                </label>
+                <div class="md:col-span-2">
+                    <select
+                        id="track_size"
+                        class=move || density.get().control_class()
+                        on:change=move |ev| {
+                            if let Some(size) = TrackSize::from_code(&event_target_value(&ev)) {
+                                set_track_size.set(size);
+                            }
+                        }
+                    >
+                        {TrackSize::iter()
+                            .map(|size| {
+                                view! {
+                                    <option value=size.code() selected=move || track_size.get() == size>
+                                        {size.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                    {move || {
+                        scoring_event(&event.get(), track_size.get())
+                            .1
+                            .map(|note| view! { <p class="mt-1 text-sm text-amber-700">{note}</p> })
+                    }}
+                </div>
+            </div>
+        </Show>
+    }
+}
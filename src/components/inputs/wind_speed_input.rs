@@ -1,19 +1,21 @@
 use crate::scoring_logic::calculator::is_wind_affected_event;
 use crate::models::Event;
+use crate::settings::use_density;
 use leptos::prelude::*;
 
 #[component]
 pub fn WindSpeedInput(
     event: ReadSignal<Event>,
-    #[allow(unused_variables)] wind_speed: ReadSignal<Option<f64>>,
+    wind_speed: ReadSignal<Option<f64>>,
     set_wind_speed: WriteSignal<Option<f64>>,
 ) -> impl IntoView {
+    let density = use_density();
     view! {
         <Show
             when=move || { is_wind_affected_event(&event.get()) }
             fallback=|| view! { <div></div> }
         >
-            <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+            <div class=move || density.get().row_class()>
                 <label for="wind_speed" class="text-gray-800 font-medium">
                     "Wind Speed (m/s):"
                 </label>
@@ -21,7 +23,8 @@ pub fn WindSpeedInput(
                     id="wind_speed"
                     type="number"
                     step="0.1"
-                    class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                    prop:value=move || wind_speed.get().map(|v| v.to_string()).unwrap_or_default()
+                    class=move || format!("md:col-span-2 {}", density.get().control_class())
                     on:input=move |ev| {
                         let value = event_target_value(&ev);
                         let parsed_value = if value.is_empty() {
@@ -1,2 +1,29 @@
-pub mod world_athletics_score_form;
+pub mod activity_file_import;
+pub mod calculation_history;
+pub mod category_matrix;
+pub mod copy_button;
+pub mod csv_import;
+pub mod debug_panel;
+#[cfg(feature = "demo")]
+pub mod demo_mode;
+pub mod elite_histogram;
+pub mod entry_standards_display;
+pub mod file_upload;
+pub mod full_points_table;
 pub mod inputs;
+pub mod nav_bar;
+pub mod placement_chart;
+pub mod points_curve_chart;
+pub mod progression_chart;
+pub mod ranking_calendar;
+pub mod save_result;
+pub mod scoring_providers;
+pub mod share_button;
+pub mod share_qr_code;
+#[cfg(feature = "strava")]
+pub mod strava_import;
+pub mod theme_toggle;
+pub mod unit_preferences_menu;
+pub mod version_indicator;
+pub mod wind_sensitivity;
+pub mod world_athletics_score_form;
@@ -0,0 +1,41 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_location;
+
+/// The path prefix the app is served under on GitHub Pages
+pub(crate) const PAGES_BASE: &str = "/world_athletics_points_calculator";
+
+/// Links between the top-level pages, preserving the GitHub Pages prefix when present.
+#[component]
+pub fn NavBar() -> impl IntoView {
+    let location = use_location();
+    let base = move || {
+        if location.pathname.get().starts_with(PAGES_BASE) {
+            PAGES_BASE
+        } else {
+            ""
+        }
+    };
+
+    view! {
+        <nav class="flex gap-4 text-sm text-gray-300">
+            <A href=move || format!("{}/", base())>"Calculator"</A>
+            <A href=move || format!("{}/combined-events", base())>"Combined"</A>
+            <A href=move || format!("{}/tables", base())>"Tables"</A>
+            <A href=move || format!("{}/placing", base())>"Placing"</A>
+            <A href=move || format!("{}/target", base())>"Target"</A>
+            <A href=move || format!("{}/predictor", base())>"Predictor"</A>
+            <A href=move || format!("{}/rankings", base())>"Rankings"</A>
+            <A href=move || format!("{}/roster", base())>"Roster"</A>
+            <A href=move || format!("{}/import", base())>"Import"</A>
+            <A href=move || format!("{}/dashboard", base())>"Dashboard"</A>
+            <A href=move || format!("{}/results", base())>"Results"</A>
+            <A href=move || format!("{}/leaderboard", base())>"Leaderboard"</A>
+            <A href=move || format!("{}/report", base())>"Report"</A>
+            <A href=move || format!("{}/progression", base())>"Progression"</A>
+            <A href=move || format!("{}/profile", base())>"Profile"</A>
+            <A href=move || format!("{}/program", base())>"Program"</A>
+            <A href=move || format!("{}/settings", base())>"Settings"</A>
+        </nav>
+    }
+}
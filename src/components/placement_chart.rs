@@ -0,0 +1,80 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{CompetitionCategory, Event};
+use crate::scoring_logic::placement_score::{placement_points_by_place, RoundType};
+use leptos::prelude::*;
+
+/// Bar chart of placing points by finishing place for the selected category and round,
+/// with the athlete's place highlighted.
+#[component]
+pub fn PlacementChart(
+    event: ReadSignal<Event>,
+    competition_category: ReadSignal<CompetitionCategory>,
+    round: ReadSignal<RoundType>,
+    size_of_final: ReadSignal<i32>,
+    main_event: ReadSignal<bool>,
+    place: ReadSignal<i32>,
+) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let bars = Memo::new(move |_| {
+        placement_points_by_place(
+            &event.get(),
+            competition_category.get(),
+            round.get(),
+            size_of_final.get(),
+            main_event.get(),
+            providers.placement_score_calculator,
+        )
+    });
+
+    view! {
+        <Show
+            when=move || !bars.with(Vec::is_empty)
+            fallback=|| {
+                view! {
+                    <p class="text-sm text-gray-500 italic">
+                        "No placing points for this category and round."
+                    </p>
+                }
+            }
+        >
+            <figure class="w-full">
+                <div class="flex items-end gap-px h-32 border-b border-gray-300" role="img" aria-label=move || {
+                    bars.with(|bars| {
+                        bars.iter()
+                            .map(|(place, points)| format!("place {place}: {points} points"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                }>
+                    {move || {
+                        let bars = bars.get();
+                        let max = bars.iter().map(|(_, points)| *points).max().unwrap_or(1).max(1);
+                        bars.into_iter()
+                            .map(|(bar_place, points)| {
+                                let height = format!("height: {}%", points * 100 / max);
+                                view! {
+                                    <div
+                                        class=move || {
+                                            if place.get() == bar_place {
+                                                "flex-1 bg-gray-900"
+                                            } else {
+                                                "flex-1 bg-gray-300"
+                                            }
+                                        }
+                                        style=height
+                                        title=format!("{bar_place}: {points} pts")
+                                    ></div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+                <figcaption class="mt-1 flex justify-between text-xs text-gray-500">
+                    <span>{move || bars.with(|bars| bars.first().map(|(p, _)| format!("Place {p}")))}</span>
+                    <span>"Placing points by finishing place"</span>
+                    <span>{move || bars.with(|bars| bars.last().map(|(p, _)| format!("Place {p}")))}</span>
+                </figcaption>
+            </figure>
+        </Show>
+    }
+}
@@ -0,0 +1,193 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::importers::open_track::parse_mark;
+use crate::models::{Event, Gender};
+use crate::scoring_logic::coefficients::{score_table, ScoreTableRow, MAX_RESULT_SCORE};
+use leptos::prelude::*;
+
+const CHART_WIDTH: f64 = 400.0;
+const CHART_HEIGHT: f64 = 200.0;
+/// Points between generated table rows on the curve
+const CURVE_STEP: usize = 20;
+
+/// Maps a table row onto the chart, with better performances towards the top.
+/// Works for timed and distance events alike since `worst` and `best` carry the direction.
+fn chart_position(points: f64, performance: f64, (worst, best): (f64, f64)) -> (f64, f64) {
+    let x = points / MAX_RESULT_SCORE * CHART_WIDTH;
+    let fraction = if (best - worst).abs() > f64::EPSILON {
+        (performance - worst) / (best - worst)
+    } else {
+        1.0
+    };
+    (x, CHART_HEIGHT - fraction.clamp(0.0, 1.0) * CHART_HEIGHT)
+}
+
+/// The whole number of points under a horizontal position on the rendered chart
+fn points_at(offset: f64, width: f64) -> f64 {
+    if width <= 0.0 {
+        return 1.0;
+    }
+    (offset / width * MAX_RESULT_SCORE)
+        .round()
+        .clamp(1.0, MAX_RESULT_SCORE)
+}
+
+/// The worst and best performances in a table
+fn performance_range(rows: &[ScoreTableRow]) -> Option<(f64, f64)> {
+    Some((rows.first()?.performance, rows.last()?.performance))
+}
+
+/// Points curve for an event with a goal marker that can be dragged along the curve.
+/// Dragging updates the goal points and the performance needed; typing either moves the marker.
+#[component]
+pub fn PointsCurveChart(gender: ReadSignal<Gender>, event: ReadSignal<Event>) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (goal_points, set_goal_points) = signal(1000.0);
+    let (dragging, set_dragging) = signal(false);
+    let (performance_error, set_performance_error) = signal(None::<String>);
+    let svg_ref = NodeRef::<leptos::svg::Svg>::new();
+
+    let rows =
+        Memo::new(move |_| score_table(gender.get(), &event.get(), CURVE_STEP).unwrap_or_default());
+    let goal_performance = Memo::new(move |_| {
+        (providers.performance_calculator)(goal_points.get(), gender.get(), &event.get()).ok()
+    });
+
+    let curve = move || {
+        let rows = rows.get();
+        let Some(range) = performance_range(&rows) else {
+            return String::new();
+        };
+        rows.iter()
+            .map(|row| {
+                let (x, y) = chart_position(row.points as f64, row.performance, range);
+                format!("{:.1},{:.1}", x, y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let marker = move || {
+        let range = rows.with(|rows| performance_range(rows))?;
+        let performance = goal_performance.get()?;
+        Some(chart_position(goal_points.get(), performance, range))
+    };
+
+    let drag_to = move |client_x: i32| {
+        if let Some(svg) = svg_ref.get_untracked() {
+            let rect = svg.get_bounding_client_rect();
+            set_goal_points.set(points_at(client_x as f64 - rect.left(), rect.width()));
+            set_performance_error.set(None);
+        }
+    };
+
+    view! {
+        <div class="space-y-2">
+            <svg
+                node_ref=svg_ref
+                viewBox=format!("0 0 {} {}", CHART_WIDTH, CHART_HEIGHT)
+                class="w-full h-48 bg-gray-50 border border-gray-200 rounded-md touch-none cursor-ew-resize"
+                role="img"
+                aria-label="Points curve. Drag to set a goal."
+                on:pointerdown=move |ev| {
+                    set_dragging.set(true);
+                    drag_to(ev.client_x());
+                }
+                on:pointermove=move |ev| {
+                    if dragging.get_untracked() {
+                        drag_to(ev.client_x());
+                    }
+                }
+                on:pointerup=move |_| set_dragging.set(false)
+                on:pointerleave=move |_| set_dragging.set(false)
+            >
+                <polyline points=curve fill="none" stroke="#9ca3af" stroke-width="2" />
+                {move || {
+                    marker()
+                        .map(|(x, y)| {
+                            view! {
+                                <line x1=x y1=0 x2=x y2=CHART_HEIGHT stroke="#d1d5db" stroke-dasharray="4" />
+                                <circle cx=x cy=y r="6" fill="#111827" />
+                            }
+                        })
+                }}
+            </svg>
+            <div class="grid grid-cols-2 gap-4">
+                <label class="text-sm text-gray-800 font-medium">
+                    "Goal points"
+                    <input
+                        type="number"
+                        min="1"
+                        max=MAX_RESULT_SCORE
+                        prop:value=move || goal_points.get()
+                        class="mt-1 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                        on:input=move |ev| {
+                            if let Ok(points) = event_target_value(&ev).parse::<f64>() {
+                                set_goal_points.set(points.clamp(1.0, MAX_RESULT_SCORE));
+                                set_performance_error.set(None);
+                            }
+                        }
+                    />
+                </label>
+                <label class="text-sm text-gray-800 font-medium">
+                    "Performance needed"
+                    <input
+                        type="text"
+                        prop:value=move || {
+                            goal_performance
+                                .get()
+                                .map(|performance| event.get().format_performance(performance))
+                                .unwrap_or_default()
+                        }
+                        class="mt-1 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                        on:change=move |ev| {
+                            let event = event.get_untracked();
+                            let points = parse_mark(&event, &event_target_value(&ev)).and_then(
+                                |performance| {
+                                    (providers.result_score_calculator)(
+                                        performance.value(),
+                                        gender.get_untracked(),
+                                        &event,
+                                    )
+                                },
+                            );
+                            match points {
+                                Ok(points) => {
+                                    set_goal_points.set(points.round().clamp(1.0, MAX_RESULT_SCORE));
+                                    set_performance_error.set(None);
+                                }
+                                Err(e) => set_performance_error.set(Some(e)),
+                            }
+                        }
+                    />
+                </label>
+            </div>
+            {move || performance_error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_position_puts_better_performances_higher() {
+        // 100m: slower times score fewer points
+        let range = (20.0, 9.5);
+        let (x, y) = chart_position(1400.0, 9.5, range);
+        assert_eq!((x, y), (CHART_WIDTH, 0.0));
+        let (_, y) = chart_position(1.0, 20.0, range);
+        assert_eq!(y, CHART_HEIGHT);
+
+        // Long jump: longer jumps score more points
+        let (_, y) = chart_position(1400.0, 9.0, (3.0, 9.0));
+        assert_eq!(y, 0.0);
+    }
+
+    #[test]
+    fn test_points_at() {
+        assert_eq!(points_at(200.0, 400.0), 700.0);
+        assert_eq!(points_at(-10.0, 400.0), 1.0);
+        assert_eq!(points_at(500.0, 400.0), MAX_RESULT_SCORE);
+        assert_eq!(points_at(10.0, 0.0), 1.0);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::models::{Event, PerformanceType};
+use crate::scoring_logic::progression::ProgressionPoint;
+use leptos::prelude::*;
+
+const CHART_WIDTH: f64 = 400.0;
+const CHART_HEIGHT: f64 = 120.0;
+
+/// Chart positions for values on the given days: x spaced by date across the width, y
+/// scaled to the values' range with the best at the top. Lower is better when `lower_is_better`.
+fn positions(days: &[i64], values: &[f64], lower_is_better: bool) -> Vec<(f64, f64)> {
+    let (first, last) = (
+        days.first().copied().unwrap_or(0),
+        days.last().copied().unwrap_or(0),
+    );
+    let (low, high) = values.iter().fold((f64::MAX, f64::MIN), |(low, high), v| {
+        (low.min(*v), high.max(*v))
+    });
+    let span = (high - low).max(f64::EPSILON);
+    days.iter()
+        .zip(values)
+        .map(|(day, value)| {
+            let x = if last > first {
+                (day - first) as f64 / (last - first) as f64 * CHART_WIDTH
+            } else {
+                CHART_WIDTH / 2.0
+            };
+            let better = if lower_is_better {
+                high - value
+            } else {
+                value - low
+            };
+            (
+                x,
+                CHART_HEIGHT - better / span * (CHART_HEIGHT - 10.0) - 5.0,
+            )
+        })
+        .collect()
+}
+
+/// One line of the chart, with a tooltip on each result
+#[component]
+fn ProgressionLine(
+    positions: Vec<(f64, f64)>,
+    labels: Vec<String>,
+    #[prop(into)] label: String,
+) -> impl IntoView {
+    let line = positions
+        .iter()
+        .map(|(x, y)| format!("{:.1},{:.1}", x, y))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    view! {
+        <svg
+            viewBox=format!("-6 0 {} {}", CHART_WIDTH + 12.0, CHART_HEIGHT)
+            class="w-full h-32 bg-gray-50 border border-gray-200 rounded-md"
+            role="img"
+            aria-label=label
+        >
+            <polyline points=line fill="none" stroke="#111827" stroke-width="2" />
+            {positions
+                .into_iter()
+                .zip(labels)
+                .map(|((x, y), label)| {
+                    view! {
+                        <circle cx=x cy=y r="3" fill="#111827">
+                            <title>{label}</title>
+                        </circle>
+                    }
+                })
+                .collect_view()}
+        </svg>
+    }
+}
+
+/// An athlete's marks and points in one event over time, spaced by date, with better
+/// results higher on both charts
+#[component]
+pub fn ProgressionChart(event: Event, progression: Vec<ProgressionPoint>) -> impl IntoView {
+    let days: Vec<i64> = progression.iter().map(|p| p.day).collect();
+    let marks: Vec<f64> = progression.iter().map(|p| p.performance).collect();
+    let points: Vec<f64> = progression.iter().map(|p| p.points).collect();
+    let lower_is_better = event.performance_type() == PerformanceType::Time;
+    let mark_labels = progression
+        .iter()
+        .map(|p| format!("{}: {}", p.date, p.mark))
+        .collect();
+    let points_labels = progression
+        .iter()
+        .map(|p| format!("{}: {:.0} points", p.date, p.points))
+        .collect();
+    let first = progression.first().map(|p| p.date.clone());
+    let last = progression.last().map(|p| p.date.clone());
+
+    view! {
+        <figure class="space-y-2">
+            <p class="text-xs text-gray-500">"Marks"</p>
+            <ProgressionLine
+                positions=positions(&days, &marks, lower_is_better)
+                labels=mark_labels
+                label=format!("{} marks over time", event)
+            />
+            <p class="text-xs text-gray-500">"Points"</p>
+            <ProgressionLine
+                positions=positions(&days, &points, false)
+                labels=points_labels
+                label=format!("{} points over time", event)
+            />
+            <figcaption class="flex justify-between text-xs text-gray-500">
+                <span>{first}</span>
+                <span>{last}</span>
+            </figcaption>
+        </figure>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        // Times: the fastest is highest, and x follows the dates rather than the order
+        let placed = positions(&[0, 10, 40], &[110.0, 106.0, 108.0], true);
+        assert_eq!(placed[0], (0.0, CHART_HEIGHT - 5.0));
+        assert_eq!(placed[1], (100.0, 5.0));
+        assert_eq!(placed[2].0, CHART_WIDTH);
+
+        let distances = positions(&[0, 10], &[7.5, 8.0], false);
+        assert_eq!(distances[1].1, 5.0);
+
+        // A single result sits in the middle
+        assert_eq!(positions(&[5], &[1000.0], false)[0].0, CHART_WIDTH / 2.0);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::models::Event;
+use crate::scoring_logic::ranking_calendar::{ranking_calendar, CalendarDate};
+use leptos::prelude::*;
+
+/// Today's date in the browser's local time zone
+pub fn today() -> CalendarDate {
+    let now = js_sys::Date::new_0();
+    CalendarDate {
+        year: now.get_full_year() as i32,
+        month: now.get_month() + 1,
+        day: now.get_date(),
+    }
+}
+
+/// The ranking window results currently count towards for the event and how many are
+/// averaged, plus countdowns to the upcoming championship qualification deadlines for its group
+#[component]
+pub fn RankingCalendar(#[prop(into)] event: Signal<Event>) -> impl IntoView {
+    let today = today();
+
+    move || {
+        let calendar = ranking_calendar();
+        let event = event.get();
+        let window = calendar.ranking_window(&event, today);
+        let deadlines = calendar.upcoming_deadlines(event.event_group(), today);
+
+        view! {
+            <section class="p-4 bg-gray-50 rounded-md border border-gray-200 space-y-2 text-sm">
+                <h3 class="font-medium text-gray-800">{format!("{} ranking calendar", event)}</h3>
+                <p class="text-gray-700">
+                    {format!(
+                        "Results from {} to {} count ({} month window), and the best {} are averaged. A result set today stays valid until {}.",
+                        window.start,
+                        window.end,
+                        window.months,
+                        calendar.results_counted(&event),
+                        calendar.result_valid_until(&event, today),
+                    )}
+                </p>
+                {calendar
+                    .source
+                    .clone()
+                    .map(|source| view! { <p class="text-xs text-gray-500">{format!("Source: {}", source)}</p> })}
+                {if deadlines.is_empty() {
+                    view! { <p class="text-gray-500 italic">"No upcoming qualification deadlines."</p> }
+                        .into_any()
+                } else {
+                    view! {
+                        <ul class="space-y-1">
+                            {deadlines
+                                .into_iter()
+                                .map(|countdown| {
+                                    view! {
+                                        <li class="flex justify-between">
+                                            <span
+                                                class="text-gray-700"
+                                                title=countdown.deadline.source.clone()
+                                            >
+                                                {format!("{} ({})", countdown.deadline.name, countdown.deadline.deadline)}
+                                            </span>
+                                            <span class="font-medium text-gray-900">
+                                                {match countdown.days_left {
+                                                    0 => "Today".to_string(),
+                                                    1 => "1 day left".to_string(),
+                                                    days => format!("{} days left", days),
+                                                }}
+                                            </span>
+                                        </li>
+                                    }
+                                })
+                                .collect_view()}
+                        </ul>
+                    }
+                        .into_any()
+                }}
+            </section>
+        }
+    }
+}
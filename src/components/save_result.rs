@@ -0,0 +1,107 @@
+use crate::components::ranking_calendar::today;
+use crate::models::{WorldAthleticsScoreInput, WorldAthleticsScoreOutput};
+use crate::performance_db::{PerformanceDb, StoredPerformance};
+use crate::scoring_logic::ranking_calendar::CalendarDate;
+use crate::settings::use_density;
+use crate::share_link::CalculatorState;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+/// Saves the calculated result to the local database under an athlete and date, for the
+/// results, report and progression pages.
+#[component]
+pub fn SaveResult(
+    state: ReadSignal<Option<CalculatorState>>,
+    input: ReadSignal<Option<WorldAthleticsScoreInput>>,
+    breakdown: ReadSignal<Option<WorldAthleticsScoreOutput>>,
+) -> impl IntoView {
+    let density = use_density();
+    let (athlete, set_athlete) = signal(String::new());
+    let (date, set_date) = signal(today().to_string());
+    let (venue, set_venue) = signal(String::new());
+    let (message, set_message) = signal(None::<Result<String, String>>);
+
+    let save = move |_| {
+        let athlete = athlete.get();
+        if athlete.trim().is_empty() {
+            set_message.set(Some(Err("Enter the athlete's name".to_string())));
+            return;
+        }
+        let date = match CalendarDate::parse(&date.get()) {
+            Ok(date) => date.to_string(),
+            Err(e) => {
+                set_message.set(Some(Err(e)));
+                return;
+            }
+        };
+        let (Some(state), Some(input), Some(score)) = (state.get(), input.get(), breakdown.get())
+        else {
+            return;
+        };
+        let record = StoredPerformance::from_calculation(
+            &athlete,
+            &date,
+            &venue.get(),
+            &state,
+            &input,
+            &score,
+        );
+        spawn_local(async move {
+            let saved = match PerformanceDb::open().await {
+                Ok(db) => db.put(&record).await,
+                Err(e) => Err(e),
+            };
+            set_message.set(Some(saved.map(|_| {
+                format!(
+                    "Saved {} {} for {}",
+                    record.event, record.mark, record.athlete
+                )
+            })));
+        });
+    };
+
+    view! {
+        <details class="border border-gray-200 rounded-md p-3">
+            <summary class="cursor-pointer text-gray-800 font-medium">"Save result"</summary>
+            <div class="mt-2 grid grid-cols-1 md:grid-cols-3 gap-2 items-center text-sm">
+                <input
+                    type="text"
+                    aria-label="Athlete"
+                    placeholder="Athlete"
+                    value=move || athlete.get()
+                    class=move || density.get().control_class()
+                    on:input=move |ev| set_athlete.set(event_target_value(&ev))
+                />
+                <input
+                    type="date"
+                    aria-label="Date"
+                    value=move || date.get()
+                    class=move || density.get().control_class()
+                    on:input=move |ev| set_date.set(event_target_value(&ev))
+                />
+                <input
+                    type="text"
+                    aria-label="Venue"
+                    placeholder="Venue (optional)"
+                    value=move || venue.get()
+                    class=move || density.get().control_class()
+                    on:input=move |ev| set_venue.set(event_target_value(&ev))
+                />
+            </div>
+            <div class="mt-2 flex items-center gap-3 text-sm">
+                <button
+                    type="button"
+                    class="px-3 py-2 bg-gray-900 text-white rounded-md hover:bg-gray-800"
+                    on:click=save
+                >
+                    "Save"
+                </button>
+                {move || match message.get() {
+                    None => ().into_any(),
+                    Some(Ok(saved)) => view! { <span class="text-gray-700">{saved}</span> }.into_any(),
+                    Some(Err(e)) => view! { <span class="text-red-600">{e}</span> }.into_any(),
+                }}
+            </div>
+        </details>
+    }
+}
@@ -0,0 +1,121 @@
+use crate::models::{Event, Gender, WorldAthleticsScoreInput, WorldAthleticsScoreOutput};
+use crate::scoring_logic::calculator::calculate_world_athletics_score;
+use crate::scoring_logic::coefficients::{
+    calculate_result_score, performance_for_score, valid_range, PerformanceRange,
+};
+use crate::scoring_logic::engine::{bundled_engine, table_edition};
+use crate::scoring_logic::placement_score::{calculate_placement_score, PlacementScoreCalcInput};
+use leptos::prelude::*;
+
+/// The scoring functions components use, provided through Leptos context so tests and previews
+/// can run against small fake tables instead of the global statics.
+#[derive(Debug, Clone, Copy)]
+pub struct ScoringProviders {
+    pub result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    pub placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+    /// Inverse of `result_score_calculator`: the performance worth a number of points
+    pub performance_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    /// The performances the tables cover for an event
+    pub range_calculator: fn(Gender, &Event) -> Result<PerformanceRange, String>,
+}
+
+impl Default for ScoringProviders {
+    /// The official World Athletics tables loaded at startup
+    fn default() -> Self {
+        ScoringProviders {
+            result_score_calculator: calculate_result_score,
+            placement_score_calculator: calculate_placement_score,
+            performance_calculator: performance_for_score,
+            range_calculator: valid_range,
+        }
+    }
+}
+
+impl ScoringProviders {
+    /// Scores `input` with these functions, which score against the app's engine, like
+    /// `engine::score_in_edition`. An input naming another edition is scored against that
+    /// bundled edition's tables instead.
+    pub fn score(
+        &self,
+        input: WorldAthleticsScoreInput,
+    ) -> Result<WorldAthleticsScoreOutput, String> {
+        match &input.table_edition {
+            Some(edition) if Some(edition) != table_edition().as_ref() => {
+                bundled_engine(edition)?.score(input)
+            }
+            _ => calculate_world_athletics_score(
+                input,
+                self.result_score_calculator,
+                self.placement_score_calculator,
+            ),
+        }
+    }
+
+    /// The performances `edition`'s tables cover for an event, from `range_calculator` for
+    /// the app's engine and from the bundled tables for another edition
+    pub fn valid_range(
+        &self,
+        edition: &str,
+        gender: Gender,
+        event: &Event,
+    ) -> Result<PerformanceRange, String> {
+        if table_edition().as_deref() == Some(edition) {
+            return (self.range_calculator)(gender, event);
+        }
+        bundled_engine(edition)?
+            .coefficients()
+            .valid_range(gender, event)
+            .ok_or_else(|| {
+                format!(
+                    "Coefficients not found for gender {} and event: {}",
+                    gender, event,
+                )
+            })
+    }
+}
+
+/// Provides `providers` to every component below the current owner.
+pub fn provide_scoring_providers(providers: ScoringProviders) {
+    provide_context(providers);
+}
+
+/// The nearest provided scoring functions, falling back to the official tables.
+pub fn use_scoring_providers() -> ScoringProviders {
+    use_context::<ScoringProviders>().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Performance, TrackAndFieldEvent};
+    use crate::scoring_logic::coefficients::TABLE_VERSION;
+
+    #[test]
+    fn test_scoring_by_edition() {
+        let providers = ScoringProviders::default();
+        let event = Event::TrackAndField(TrackAndFieldEvent::M100);
+        let input = WorldAthleticsScoreInput {
+            gender: Gender::Men,
+            event: event.clone(),
+            performance: Performance::Seconds(10.0),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: Some(TABLE_VERSION.to_string()),
+        };
+        assert!(providers.score(input.clone()).is_ok());
+        assert!(providers
+            .score(WorldAthleticsScoreInput {
+                table_edition: Some("1985".to_string()),
+                ..input
+            })
+            .is_err());
+
+        assert_eq!(
+            providers.valid_range(TABLE_VERSION, Gender::Men, &event),
+            valid_range(Gender::Men, &event)
+        );
+        assert!(providers.valid_range("1985", Gender::Men, &event).is_err());
+    }
+}
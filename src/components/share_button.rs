@@ -0,0 +1,43 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use wasm_bindgen_futures::JsFuture;
+
+/// Whether the browser has the Web Share API (`navigator.share`)
+fn can_share() -> bool {
+    let navigator = window().navigator();
+    js_sys::Reflect::has(navigator.as_ref(), &"share".into()).unwrap_or(false)
+}
+
+/// A button that opens the system share sheet with the given text and URL. Renders nothing
+/// in browsers without the Web Share API.
+#[component]
+pub fn ShareButton(
+    #[prop(into)] text: Signal<String>,
+    #[prop(into)] url: Signal<String>,
+    #[prop(into, default = "Share".to_string())] label: String,
+) -> impl IntoView {
+    let share = move |_| {
+        let data = web_sys::ShareData::new();
+        data.set_text(&text.get());
+        data.set_url(&url.get());
+        let promise = window().navigator().share_with_data(&data);
+        spawn_local(async move {
+            // Dismissing the share sheet rejects the promise too, so this is only logged
+            if let Err(e) = JsFuture::from(promise).await {
+                log::info!("Share cancelled or failed: {:?}", e);
+            }
+        });
+    };
+
+    can_share().then(|| {
+        view! {
+            <button
+                type="button"
+                class="px-4 py-2 bg-gray-900 text-white text-sm font-medium rounded-md hover:bg-gray-800 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-gray-500 transition-colors"
+                on:click=share
+            >
+                {label}
+            </button>
+        }
+    })
+}
@@ -0,0 +1,50 @@
+use leptos::prelude::*;
+use qrcode::render::svg;
+use qrcode::QrCode;
+
+/// Smallest rendered size of the QR code, in pixels
+const QR_CODE_SIZE: u32 = 200;
+
+/// Renders `data` as a QR code SVG, or `None` if it is too long to encode.
+pub fn qr_code_svg(data: &str) -> Option<String> {
+    QrCode::new(data.as_bytes()).ok().map(|code| {
+        code.render::<svg::Color>()
+            .min_dimensions(QR_CODE_SIZE, QR_CODE_SIZE)
+            .build()
+    })
+}
+
+/// A QR code for a URL, so it can be scanned from another phone's screen.
+#[component]
+pub fn ShareQrCode(#[prop(into)] url: Signal<String>) -> impl IntoView {
+    view! {
+        {move || match qr_code_svg(&url.get()) {
+            Some(svg) => {
+                view! {
+                    <div class="flex flex-col items-center">
+                        <div inner_html=svg></div>
+                        <p class="mt-2 text-xs text-gray-500 break-all">{url.get()}</p>
+                    </div>
+                }
+                    .into_any()
+            }
+            None => {
+                view! { <p class="text-sm text-red-600">"This link is too long for a QR code."</p> }
+                    .into_any()
+            }
+        }}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_code_svg() {
+        let svg = qr_code_svg("https://example.com/?event=100m").unwrap();
+        assert!(svg.contains("<svg"));
+        // Beyond the capacity of the largest QR version
+        assert!(qr_code_svg(&"x".repeat(8000)).is_none());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::importers::strava::{
+    authorize_url, exchange_code, fetch_race_activities, fetch_road_race, StravaActivity,
+    STRAVA_CLIENT_ID,
+};
+use crate::models::Event;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_query_map;
+
+/// Lists the user's Strava races and pre-fills the calculator with the chosen one.
+#[component]
+pub fn StravaImport(
+    set_event: WriteSignal<Event>,
+    set_performance_input: WriteSignal<String>,
+    set_performance: WriteSignal<f64>,
+    set_net_downhill: WriteSignal<Option<f64>>,
+) -> impl IntoView {
+    let (access_token, set_access_token) = signal(Option::<String>::None);
+    let (activities, set_activities) = signal(Vec::<StravaActivity>::new());
+    let (error, set_error) = signal(Option::<String>::None);
+
+    // Strava redirects back with `?code=...` once the user approves access
+    let query = use_query_map();
+    if let Some(code) = query.get_untracked().get("code") {
+        spawn_local(async move {
+            let result = match exchange_code(&code).await {
+                Ok(token) => {
+                    let races = fetch_race_activities(&token).await;
+                    set_access_token.set(Some(token));
+                    races
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(races) => set_activities.set(races),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    }
+
+    let redirect_uri = move || {
+        let location = window().location();
+        format!(
+            "{}{}",
+            location.origin().unwrap_or_default(),
+            location.pathname().unwrap_or_default()
+        )
+    };
+
+    let use_activity = move |activity: StravaActivity| {
+        let Some(token) = access_token.get_untracked() else {
+            return;
+        };
+        spawn_local(async move {
+            match fetch_road_race(&token, &activity).await {
+                Ok(race) => {
+                    set_event.set(Event::RoadRunning(race.event));
+                    set_performance_input.set(Event::seconds_to_time_string(race.elapsed_seconds));
+                    set_performance.set(race.elapsed_seconds);
+                    set_net_downhill.set(race.net_downhill);
+                    set_error.set(None);
+                }
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    };
+
+    view! {
+        <div class="p-4 bg-orange-50 border border-orange-200 rounded-md space-y-2">
+            <p class="text-sm text-orange-800">
+                "Unofficial results: Strava activities are GPS recordings, not ratified results, and won't count towards official World Rankings."
+            </p>
+            {move || match STRAVA_CLIENT_ID {
+                None => view! { <p class="text-sm text-gray-500">"Strava is not configured for this build."</p> }.into_any(),
+                Some(client_id) if access_token.get().is_none() => {
+                    view! {
+                        <a
+                            href=authorize_url(client_id, &redirect_uri())
+                            class="inline-block px-3 py-2 bg-orange-600 text-white text-sm rounded-md hover:bg-orange-700"
+                        >
+                            "Connect Strava"
+                        </a>
+                    }
+                        .into_any()
+                }
+                Some(_) => {
+                    view! {
+                        <ul class="space-y-1">
+                            <For
+                                each=move || activities.get()
+                                key=|activity| activity.id
+                                children=move |activity| {
+                                    let label = format!(
+                                        "{} ({}, {:.2} km, {})",
+                                        activity.name,
+                                        activity.start_date_local.get(..10).unwrap_or_default(),
+                                        activity.distance / 1000.0,
+                                        Event::seconds_to_time_string(activity.elapsed_time),
+                                    );
+                                    view! {
+                                        <li class="flex items-center justify-between gap-2 text-sm">
+                                            <span>{label}</span>
+                                            <button
+                                                type="button"
+                                                class="px-2 py-1 border border-gray-300 rounded-md hover:bg-gray-100"
+                                                on:click=move |_| use_activity(activity.clone())
+                                            >
+                                                "Use"
+                                            </button>
+                                        </li>
+                                    }
+                                }
+                            />
+                        </ul>
+                    }
+                        .into_any()
+                }
+            }}
+            {move || error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+        </div>
+    }
+}
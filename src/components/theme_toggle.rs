@@ -0,0 +1,33 @@
+use crate::settings::{use_settings, Theme};
+use leptos::prelude::*;
+
+/// Header button switching between the light and dark themes. The choice is saved with the
+/// other settings; "System" (follow the OS) can be restored on the Settings page.
+#[component]
+pub fn ThemeToggle(
+    /// Whether the dark theme is currently shown
+    #[prop(into)]
+    dark: Signal<bool>,
+) -> impl IntoView {
+    let settings = use_settings();
+    let toggle = move |_| {
+        let theme = if dark.get_untracked() {
+            Theme::Light
+        } else {
+            Theme::Dark
+        };
+        settings.update(|s| s.theme = theme);
+    };
+
+    view! {
+        <button
+            type="button"
+            class="px-2 py-1 text-sm bg-gray-800 text-gray-100 border border-gray-700 rounded-md hover:bg-gray-700"
+            aria-label=move || if dark.get() { "Switch to light theme" } else { "Switch to dark theme" }
+            aria-pressed=move || dark.get().to_string()
+            on:click=toggle
+        >
+            {move || if dark.get() { "☀ Light" } else { "☾ Dark" }}
+        </button>
+    }
+}
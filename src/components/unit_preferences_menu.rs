@@ -0,0 +1,83 @@
+use crate::models::{DistanceUnit, PaceUnit, TemperatureUnit};
+use crate::settings::use_settings;
+use leptos::prelude::*;
+use strum::IntoEnumIterator;
+
+const SELECT_CLASS: &str = "bg-gray-800 text-gray-100 border border-gray-700 rounded-md px-2 py-1";
+
+/// Compact header controls for the app-wide unit preferences.
+#[component]
+pub fn UnitPreferencesMenu() -> impl IntoView {
+    let settings = use_settings();
+
+    view! {
+        <div class="flex gap-2 text-sm">
+            <select
+                aria-label="Distance units"
+                class=SELECT_CLASS
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Some(unit) = DistanceUnit::iter().find(|u| u.to_string() == value) {
+                        settings.update(|s| s.units.distance = unit);
+                    }
+                }
+            >
+                {DistanceUnit::iter()
+                    .map(|unit| {
+                        view! {
+                            <option
+                                value=unit.to_string()
+                                selected=move || settings.with(|s| s.units.distance) == unit
+                            >
+                                {unit.to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            <select
+                aria-label="Pace units"
+                class=SELECT_CLASS
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Some(unit) = PaceUnit::iter().find(|u| u.to_string() == value) {
+                        settings.update(|s| s.units.pace = unit);
+                    }
+                }
+            >
+                {PaceUnit::iter()
+                    .map(|unit| {
+                        view! {
+                            <option value=unit.to_string() selected=move || settings.with(|s| s.units.pace) == unit>
+                                {unit.to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+            <select
+                aria-label="Temperature units"
+                class=SELECT_CLASS
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Some(unit) = TemperatureUnit::iter().find(|u| u.to_string() == value) {
+                        settings.update(|s| s.units.temperature = unit);
+                    }
+                }
+            >
+                {TemperatureUnit::iter()
+                    .map(|unit| {
+                        view! {
+                            <option
+                                value=unit.to_string()
+                                selected=move || settings.with(|s| s.units.temperature) == unit
+                            >
+                                {unit.to_string()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
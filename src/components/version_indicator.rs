@@ -0,0 +1,35 @@
+use crate::build_info::build_info;
+use crate::scoring_logic::remote_tables::{use_tables_status, TablesStatus};
+use leptos::prelude::*;
+
+/// Footer line with the app version, table edition, data fingerprint and whether the
+/// published tables have been fetched. Hovering shows each data file's checksum.
+#[component]
+pub fn VersionIndicator() -> impl IntoView {
+    let info = build_info();
+    let tables_status = use_tables_status();
+    let source = move || match tables_status.get() {
+        TablesStatus::Loading => " · updating tables…",
+        TablesStatus::Remote { offline: false, .. } => "",
+        TablesStatus::Remote { offline: true, .. } => " · cached tables (offline)",
+        TablesStatus::Embedded { .. } => " · built-in tables",
+    };
+    let checksums = info
+        .data
+        .iter()
+        .map(|d| format!("{}: {}", d.file, d.fnv1a))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    view! {
+        <span class="text-xs text-gray-500" title=checksums>
+            {format!(
+                "v{} · {} tables · data {}",
+                info.version,
+                info.table_edition,
+                info.data_fingerprint(),
+            )}
+            {source}
+        </span>
+    }
+}
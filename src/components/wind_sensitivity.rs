@@ -0,0 +1,69 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::WorldAthleticsScoreInput;
+use crate::scoring_logic::calculator::scores_by_wind;
+use leptos::prelude::*;
+
+/// The score the last calculated mark would earn at winds from −4.0 to +4.0 m/s, with the
+/// entered wind highlighted. Renders nothing for events that aren't wind-affected.
+#[component]
+pub fn WindSensitivity(input: ReadSignal<Option<WorldAthleticsScoreInput>>) -> impl IntoView {
+    let providers = use_scoring_providers();
+    let scores = Memo::new(move |_| {
+        input.with(|input| {
+            let input = input.as_ref()?;
+            let scores = scores_by_wind(
+                input,
+                providers.result_score_calculator,
+                providers.placement_score_calculator,
+            )
+            .ok()?;
+            Some((input.wind_speed, scores))
+        })
+    });
+
+    move || {
+        let (entered, scores) = scores.get()?;
+        Some(view! {
+            <details class="w-full mt-4">
+                <summary class="cursor-pointer text-gray-800 font-medium">
+                    "Score by Wind"
+                </summary>
+                <p class="mt-1 text-xs text-gray-500">
+                    "Headwinds add 6 points per m/s. Tailwinds up to +2.0 m/s don't change the score; above that, 6 points per m/s are deducted from 0.0 m/s."
+                </p>
+                <table class="w-full mt-2 text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-2">"Wind (m/s)"</th>
+                            <th class="py-1 pr-2">"Adjustment"</th>
+                            <th class="py-1">"Score"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {scores
+                            .into_iter()
+                            .map(|(wind_speed, output)| {
+                                let is_entered = entered
+                                    .is_some_and(|entered| (entered - wind_speed).abs() < 0.25);
+                                let class = if is_entered {
+                                    "border-t border-gray-200 font-semibold text-gray-900 bg-gray-100"
+                                } else {
+                                    "border-t border-gray-200 text-gray-700"
+                                };
+                                view! {
+                                    <tr class=class>
+                                        <td class="py-1 pr-2">{format!("{:+.1}", wind_speed)}</td>
+                                        <td class="py-1 pr-2">
+                                            {format!("{:+.0}", output.wind_adjustment)}
+                                        </td>
+                                        <td class="py-1">{format!("{:.0}", output.points)}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </details>
+        })
+    }
+}
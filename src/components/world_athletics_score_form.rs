@@ -1,92 +1,172 @@
+use crate::components::activity_file_import::ActivityFileImport;
+use crate::components::calculation_history::CalculationHistory;
+use crate::components::category_matrix::CategoryMatrix;
+use crate::components::elite_histogram::EliteHistogram;
+use crate::components::entry_standards_display::EntryStandardsDisplay;
 use crate::components::inputs::{
-    ElevationInput, EventSelectionInputs, PerformanceInput, PlacementInfoSection, ScoreDisplay,
-    WindSpeedInput,
+    AltitudeInput, AthleteCategoryInput, ConditionsInput, ElevationInput, EventSelectionInputs,
+    PaceInput, PerformanceInput, PlacementInfoSection, RelaySplitsInput, ScoreDisplay,
+    TableEditionInput, TrackSizeInput, WindSpeedInput,
 };
+use crate::components::placement_chart::PlacementChart;
+use crate::components::save_result::SaveResult;
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::components::wind_sensitivity::WindSensitivity;
+use crate::error_reporting::report_error;
+use crate::history::{add_entry, use_history, HistoryEntry};
 use crate::models::*;
+use crate::scoring_logic::altitude::is_altitude_affected_event;
 use crate::scoring_logic::calculator::{
-    calculate_world_athletics_score, is_road_running_event, is_wind_affected_event,
+    is_placement_only_event, is_road_running_event, is_wind_affected_event,
 };
-use crate::scoring_logic::coefficients::calculate_result_score;
-use crate::scoring_logic::placement_score::{calculate_placement_score, RoundType};
+use crate::scoring_logic::placement_score::RoundType;
+use crate::scoring_logic::track_size::{scoring_event, TrackSize};
+use crate::settings::{use_density, use_settings};
+use crate::share_link::{parse_mark, CalculatorState};
 
 use leptos::prelude::*;
+use leptos_router::hooks::{use_location, use_navigate, use_query_map};
+use leptos_router::NavigateOptions;
 
+/// The calculator form. Scoring functions come from `ScoringProviders` in context.
 #[component]
 pub fn WorldAthleticsScoreForm() -> impl IntoView {
+    let providers = use_scoring_providers();
     // State for form inputs
-    let (gender, set_gender) = signal(Gender::Men);
-    let (event, set_event) = signal(Event::TrackAndField(
-        crate::models::TrackAndFieldEvent::M100,
-    ));
-    let (_performance, set_performance) = signal(0.0);
+    let settings = use_settings().get_untracked();
+    let density = use_density();
+    let (gender, set_gender) = signal(settings.default_gender);
+    let (event, set_event) = signal(settings.default_event());
+    let (performance, set_performance) = signal(0.0);
     let (performance_input, set_performance_input) = signal(String::new());
     let (wind_speed, set_wind_speed) = signal(Some(0.0));
     let (net_downhill, set_net_downhill) = signal(None);
+    let (venue_altitude, set_venue_altitude) = signal(None::<f64>);
+    let (track_size, set_track_size) = signal(TrackSize::Banked);
+    let (athlete_category, set_athlete_category) = signal(AthleteCategory::Senior);
+    let (table_edition, set_table_edition) = signal(settings.table_version.clone());
     let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
     let (place, set_place) = signal(1);
+    let (tied_with, set_tied_with) = signal(0);
     let (round, set_round) = signal(RoundType::Final);
     let (size_of_final, set_size_of_final) = signal(8);
     let (qualified_to_final, set_qualified_to_final) = signal(false);
+    let (main_event, set_main_event) = signal(false);
     let (include_placement, set_include_placement) = signal(true);
     let (points, set_points) = signal(0.0);
     let (points_calculated, set_points_calculated) = signal(false);
+    let (beyond_table_range, set_beyond_table_range) = signal(false);
+    let (breakdown, set_breakdown) = signal(None::<WorldAthleticsScoreOutput>);
+    let (scored_input, set_scored_input) = signal(None::<WorldAthleticsScoreInput>);
+    let (scored_state, set_scored_state) = signal(None::<CalculatorState>);
     let (parse_error, set_parse_error) = signal(Option::<String>::None);
+    let (score_error, set_score_error) = signal(None::<String>);
+    let (conditions, set_conditions) = signal(String::new());
+    // Cross country is scored on placing alone, so the form asks only for the placing
+    let placement_only = move || is_placement_only_event(&event.get());
 
-    // Submit handler
-    let handle_submit = move || {
+    // Fills in the form from a shared link or history entry
+    let apply_state = move |state: &CalculatorState| {
+        set_gender.set(state.gender);
+        set_event.set(state.event.clone());
+        set_performance_input.set(state.mark.clone());
+        set_performance.set(
+            Performance::parse(&state.event, &state.mark)
+                .map(|performance| performance.value())
+                .unwrap_or(0.0),
+        );
+        set_parse_error.set(None);
+        set_wind_speed.set(state.wind_speed);
+        set_net_downhill.set(state.net_downhill);
+        set_venue_altitude.set(state.venue_altitude);
+        set_track_size.set(state.track_size);
+        set_athlete_category.set(state.athlete_category);
+        if let Some(edition) = &state.table_edition {
+            set_table_edition.set(edition.clone());
+        }
+        set_include_placement.set(state.placement.is_some());
+        if let Some(placement) = &state.placement {
+            set_competition_category.set(placement.competition_category);
+            set_place.set(placement.place);
+            set_tied_with.set(placement.tied_with);
+            set_round.set(placement.round);
+            set_size_of_final.set(placement.size_of_final);
+            set_qualified_to_final.set(placement.qualified_to_final);
+            set_main_event.set(placement.main_event);
+        }
+    };
+
+    // Restore the inputs from a shared link and calculate straight away
+    let location = use_location();
+    let navigate = use_navigate();
+    let history = use_history();
+    let shared =
+        use_query_map().with_untracked(|query| CalculatorState::from_query(|key| query.get(key)));
+    if let Some(state) = &shared {
+        apply_state(state);
+    }
+
+    // Scores the inputs. Only `record`ed calculations are added to the history, so
+    // recalculating as the user types doesn't flood it.
+    let calculate = move |record: bool| {
         // Check if there's a parsing error before calculating
-        if parse_error.get().is_some() {
+        if parse_error.get().is_some() && !placement_only() {
             return; // Don't calculate if there's a parsing error
         }
 
-        // Parse performance based on event type
-        let parsed_performance = match event.get().performance_type() {
-            PerformanceType::Time => {
-                // Try to parse as time string first, then as direct seconds
-                match Event::parse_time_to_seconds(&performance_input.get()) {
-                    Ok(seconds) => seconds,
-                    Err(_) => {
-                        // If time parsing fails, try to parse as direct number (seconds)
-                        match performance_input.get().parse::<f64>() {
-                            Ok(seconds) => seconds,
-                            Err(_) => {
-                                set_parse_error.set(Some("Invalid time format. Use formats like 10.50, 1:30.25, or 2:15:30.50".to_string()));
-                                return;
-                            }
-                        }
-                    }
-                }
-            }
-            PerformanceType::Distance => {
-                // For distance events, parse directly as meters
-                match performance_input.get().parse::<f64>() {
-                    Ok(distance) => distance,
-                    Err(_) => {
-                        set_parse_error.set(Some("Invalid distance format. Enter a number in meters (e.g., 8.95)".to_string()));
-                        return;
-                    }
-                }
+        // Parse performance based on event type; placement-only events have no mark
+        let mark = if placement_only() {
+            String::new()
+        } else {
+            performance_input.get()
+        };
+        let parsed_performance = match parse_mark(&event.get(), &mark) {
+            Ok(performance) => performance,
+            Err(e) => {
+                set_parse_error.set(Some(e));
+                return;
             }
         };
 
-        let placement_info = if include_placement.get() {
+        // Oversized indoor tracks are scored with the outdoor tables
+        let (scored_event, _) = scoring_event(&event.get(), track_size.get());
+
+        // Marks worse than the bottom of the tables would score nonsense
+        if let Ok(range) = providers.valid_range(&table_edition.get(), gender.get(), &scored_event)
+        {
+            if !range.covers(parsed_performance.value()) {
+                let (low, high) = range.ascending();
+                set_parse_error.set(Some(format!(
+                    "Outside the range the scoring tables cover for this event ({:.2} to {:.2})",
+                    low, high
+                )));
+                return;
+            }
+        }
+
+        let placement_info = if include_placement.get() || placement_only() {
             Some(PlacementInfo {
                 competition_category: competition_category.get(),
                 place: place.get(),
                 round: round.get(),
                 size_of_final: size_of_final.get(),
                 qualified_to_final: qualified_to_final.get(),
+                tied_with: tied_with.get(),
+                main_event: main_event.get(),
             })
         } else {
             None
         };
 
+        let placement_info_for_link = placement_info.clone();
         let input = WorldAthleticsScoreInput {
             gender: gender.get(),
-            event: event.get(),
+            event: scored_event.clone(),
             performance: parsed_performance,
             wind_speed: if is_wind_affected_event(&event.get()) {
                 wind_speed.get()
+            } else if is_wind_affected_event(&scored_event) {
+                Some(0.0) // An indoor mark scored as outdoor had no wind
             } else {
                 None
             },
@@ -95,25 +175,138 @@ pub fn WorldAthleticsScoreForm() -> impl IntoView {
             } else {
                 None
             },
+            venue_altitude: if is_altitude_affected_event(&event.get()) {
+                venue_altitude.get()
+            } else {
+                None
+            },
             placement_info,
+            table_edition: Some(table_edition.get()),
         };
 
         // Calculate the score
-        match calculate_world_athletics_score(input, calculate_result_score, calculate_placement_score) {
+        match providers.score(input.clone()) {
             Ok(score) => {
-                set_points.set(score);
+                // Keep the URL in step with the inputs so it can be shared
+                let state = CalculatorState {
+                    gender: gender.get(),
+                    event: event.get(),
+                    mark,
+                    wind_speed: wind_speed.get(),
+                    net_downhill: net_downhill.get(),
+                    venue_altitude: venue_altitude.get(),
+                    track_size: track_size.get(),
+                    athlete_category: athlete_category.get(),
+                    placement: placement_info_for_link,
+                    table_edition: Some(table_edition.get()),
+                };
+                navigate(
+                    &format!("{}?{}", location.pathname.get_untracked(), state.to_query()),
+                    NavigateOptions {
+                        replace: true,
+                        scroll: false,
+                        ..Default::default()
+                    },
+                );
+                if record {
+                    history.update(|history| {
+                        add_entry(
+                            history,
+                            HistoryEntry::new(
+                                js_sys::Date::now(),
+                                &state,
+                                score.points,
+                                &conditions.get(),
+                            ),
+                        )
+                    });
+                }
+                set_points.set(score.points);
+                set_beyond_table_range.set(score.beyond_table_range);
+                set_breakdown.set(Some(score));
+                set_scored_input.set(Some(input));
+                set_scored_state.set(Some(state));
                 set_points_calculated.set(true);
+                set_score_error.set(None);
             }
             Err(e) => {
-                log::error!("Error calculating score: {}", e);
+                if record {
+                    report_error("score", &e);
+                }
                 set_points_calculated.set(false);
+                set_score_error.set(Some(e));
             }
         }
     };
 
+    // Submitting still works as a fallback and records the calculation in the history
+    let handle_submit = {
+        let calculate = calculate.clone();
+        move || calculate(true)
+    };
+
+    // Recalculate whenever an input changes and the mark is valid, so the effect of
+    // tweaking the wind or place shows straight away
+    {
+        let calculate = calculate.clone();
+        Effect::new(move |_| {
+            if placement_only()
+                || (parse_error.get().is_none() && !performance_input.get().trim().is_empty())
+            {
+                calculate(false);
+            }
+        });
+    }
+
+    // A mark left invalid in another event shouldn't block scoring a placing
+    Effect::new(move |_| {
+        if placement_only() {
+            set_parse_error.set(None);
+        }
+    });
+
+    if shared.is_some() {
+        let handle_submit = handle_submit.clone();
+        Effect::new(move |done: Option<()>| {
+            if done.is_none() {
+                untrack(handle_submit.clone());
+            }
+        });
+    }
+
+    let on_load_history = {
+        let handle_submit = handle_submit.clone();
+        Callback::new(move |state: CalculatorState| {
+            apply_state(&state);
+            handle_submit();
+        })
+    };
+
+    #[cfg(feature = "demo")]
+    let demo_mode = {
+        use crate::components::demo_mode::{DemoInput, DemoMode};
+        let handle_submit = handle_submit.clone();
+        let on_input = Callback::new(move |input: DemoInput| {
+            set_gender.set(input.gender);
+            set_event.set(input.event);
+            set_performance_input.set(input.performance_input);
+            set_performance.set(input.performance);
+            set_parse_error.set(None);
+            set_wind_speed.set(input.wind_speed);
+            set_net_downhill.set(input.net_downhill);
+            set_place.set(input.place);
+            set_tied_with.set(0);
+            set_round.set(input.round);
+            handle_submit();
+        });
+        view! { <DemoMode on_input=on_input /> }.into_any()
+    };
+    #[cfg(not(feature = "demo"))]
+    let demo_mode = ();
+
     view! {
         <form
-            class="space-y-4"
+            class=move || density.get().stack_class()
             on:submit=move |ev| {
                 ev.prevent_default();
                 handle_submit();
@@ -123,19 +316,47 @@ pub fn WorldAthleticsScoreForm() -> impl IntoView {
                 World Athletics Points Calculator
             </h2>
 
+            {demo_mode}
+
+            <AthleteCategoryInput
+                gender=gender
+                category=athlete_category
+                set_category=set_athlete_category
+            />
+
             <EventSelectionInputs
                 gender=gender
                 set_gender=set_gender
                 event=event
                 set_event=set_event
+                category=athlete_category
             />
 
-            <PerformanceInput
+            <Show when=move || !placement_only()>
+                <PerformanceInput
+                    event=event
+                    performance_input=performance_input
+                    set_performance_input=set_performance_input
+                    set_performance=set_performance
+                    parse_error=parse_error
+                    set_parse_error=set_parse_error
+                />
+            </Show>
+
+            <PaceInput
+                gender=gender
                 event=event
                 performance_input=performance_input
                 set_performance_input=set_performance_input
                 set_performance=set_performance
-                parse_error=parse_error
+                set_parse_error=set_parse_error
+            />
+
+            <RelaySplitsInput
+                gender=gender
+                event=event
+                set_performance_input=set_performance_input
+                set_performance=set_performance
                 set_parse_error=set_parse_error
             />
 
@@ -145,6 +366,32 @@ pub fn WorldAthleticsScoreForm() -> impl IntoView {
                 set_wind_speed=set_wind_speed
             />
 
+            <AltitudeInput
+                event=event
+                venue_altitude=venue_altitude
+                set_venue_altitude=set_venue_altitude
+            />
+
+            <TrackSizeInput
+                event=event
+                track_size=track_size
+                set_track_size=set_track_size
+            />
+
+            <TableEditionInput
+                table_edition=table_edition
+                set_table_edition=set_table_edition
+            />
+
+            <ActivityFileImport
+                set_event=set_event
+                set_performance_input=set_performance_input
+                set_performance=set_performance
+                set_net_downhill=set_net_downhill
+            />
+
+            {strava_import(set_event, set_performance_input, set_performance, set_net_downhill)}
+
             <ElevationInput
                 event=event
                 net_downhill=net_downhill
@@ -158,19 +405,107 @@ pub fn WorldAthleticsScoreForm() -> impl IntoView {
                 set_competition_category=set_competition_category
                 place=place
                 set_place=set_place
+                tied_with=tied_with
+                set_tied_with=set_tied_with
                 round=round
                 set_round=set_round
                 size_of_final=size_of_final
                 set_size_of_final=set_size_of_final
                 qualified_to_final=qualified_to_final
                 set_qualified_to_final=set_qualified_to_final
+                main_event=main_event
+                set_main_event=set_main_event
+                required=Signal::derive(placement_only)
+                event=event
             />
 
+            <Show when=move || include_placement.get() || placement_only()>
+                <PlacementChart
+                    event=event
+                    competition_category=competition_category
+                    round=round
+                    size_of_final=size_of_final
+                    main_event=main_event
+                    place=place
+                />
+            </Show>
+
+            <details>
+                <summary class="cursor-pointer text-gray-800 font-medium">"Advanced"</summary>
+                <div class="mt-2">
+                    <ConditionsInput conditions=conditions set_conditions=set_conditions />
+                </div>
+            </details>
+
             <ScoreDisplay
                 points=points
                 points_calculated=points_calculated
+                breakdown=breakdown
+                state=scored_state
+                beyond_table_range=beyond_table_range
                 parse_error=parse_error
+                score_error=score_error
             />
+
+            <Show when=move || points_calculated.get()>
+                <SaveResult state=scored_state input=scored_input breakdown=breakdown />
+            </Show>
+
+            <Show when=move || points_calculated.get()>
+                {move || {
+                    athlete_category
+                        .get()
+                        .note(gender.get(), &event.get())
+                        .map(|note| view! { <p class="text-center text-sm text-gray-600">{note}</p> })
+                }}
+            </Show>
+
+            <Show when=move || points_calculated.get() && !placement_only()>
+                <EliteHistogram gender=gender event=event performance=performance />
+            </Show>
+
+            <Show when=move || points_calculated.get()>
+                <CategoryMatrix input=scored_input />
+            </Show>
+
+            <Show when=move || points_calculated.get()>
+                <WindSensitivity input=scored_input />
+            </Show>
+
+            <Show when=move || points_calculated.get() && !placement_only()>
+                <EntryStandardsDisplay gender=gender event=event performance=performance />
+            </Show>
+
+            <CalculationHistory on_load=on_load_history />
         </form>
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "strava")]
+fn strava_import(
+    set_event: WriteSignal<Event>,
+    set_performance_input: WriteSignal<String>,
+    set_performance: WriteSignal<f64>,
+    set_net_downhill: WriteSignal<Option<f64>>,
+) -> AnyView {
+    use crate::components::strava_import::StravaImport;
+    view! {
+        <StravaImport
+            set_event=set_event
+            set_performance_input=set_performance_input
+            set_performance=set_performance
+            set_net_downhill=set_net_downhill
+        />
+    }
+    .into_any()
+}
+
+#[cfg(not(feature = "strava"))]
+fn strava_import(
+    _set_event: WriteSignal<Event>,
+    _set_performance_input: WriteSignal<String>,
+    _set_performance: WriteSignal<f64>,
+    _set_net_downhill: WriteSignal<Option<f64>>,
+) -> AnyView {
+    ().into_any()
+}
@@ -0,0 +1,102 @@
+// src/error_reporting.rs
+//! Optional error reporting (enabled with the `error-reporting` feature).
+//!
+//! Panics and scoring errors are posted as JSON to the endpoint set by `ERROR_REPORT_URL` at
+//! build time, so failures in production (bad table data, unexpected input) reach maintainers
+//! instead of vanishing into users' consoles. Reports go out with `navigator.sendBeacon`, which
+//! still delivers while a panicking page is being torn down. Without the feature, or without an
+//! endpoint, reporting is a no-op and panics only go to the console.
+use serde::Serialize;
+
+pub const ERROR_REPORT_URL: Option<&str> = option_env!("ERROR_REPORT_URL");
+
+/// The payload posted to `ERROR_REPORT_URL`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ErrorReport {
+    /// "panic" or "error"
+    pub kind: &'static str,
+    /// Where the error surfaced, e.g. "score" or "load_placement_tables"
+    pub source: String,
+    pub message: String,
+    /// The page the user was on, which includes their inputs once they live in the URL
+    pub url: String,
+    pub version: &'static str,
+}
+
+impl ErrorReport {
+    pub fn new(kind: &'static str, source: &str, message: &str, url: String) -> Self {
+        ErrorReport {
+            kind,
+            source: source.to_string(),
+            message: message.to_string(),
+            url,
+            version: env!("CARGO_PKG_VERSION"),
+        }
+    }
+}
+
+/// Installs the panic hook. Call once at startup.
+pub fn init() {
+    console_error_panic_hook::set_once();
+    #[cfg(feature = "error-reporting")]
+    {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous(info);
+            send(ErrorReport::new(
+                "panic",
+                "panic",
+                &info.to_string(),
+                current_url(),
+            ));
+        }));
+    }
+}
+
+/// Reports an error that was handled but shouldn't happen, e.g. a scoring failure.
+pub fn report_error(source: &str, message: &str) {
+    log::error!("{}: {}", source, message);
+    #[cfg(feature = "error-reporting")]
+    send(ErrorReport::new("error", source, message, current_url()));
+}
+
+#[cfg(feature = "error-reporting")]
+fn current_url() -> String {
+    web_sys::window()
+        .and_then(|window| window.location().href().ok())
+        .unwrap_or_default()
+}
+
+#[cfg(feature = "error-reporting")]
+fn send(report: ErrorReport) {
+    let (Some(endpoint), Some(window)) = (ERROR_REPORT_URL, web_sys::window()) else {
+        return;
+    };
+    if let Ok(body) = serde_json::to_string(&report) {
+        // Reporting must never fail loudly itself
+        let _ = window
+            .navigator()
+            .send_beacon_with_opt_str(endpoint, Some(&body));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_report_payload() {
+        let report = ErrorReport::new(
+            "error",
+            "score",
+            "Coefficients not found",
+            "https://example.com/".to_string(),
+        );
+        let json: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["kind"], "error");
+        assert_eq!(json["source"], "score");
+        assert_eq!(json["message"], "Coefficients not found");
+        assert_eq!(json["url"], "https://example.com/");
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+    }
+}
@@ -0,0 +1,47 @@
+/// Quotes a field if it contains a delimiter, quote or line break (RFC 4180)
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Joins rows of fields into a CSV document with CRLF line endings
+pub fn to_csv<R, F>(rows: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = F>,
+    F: AsRef<str>,
+{
+    rows.into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|field| csv_field(field.as_ref()))
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .map(|line| line + "\r\n")
+        .collect()
+}
+
+/// A `data:` URL for downloading a CSV document from an anchor's `href`
+pub fn csv_data_url(csv: &str) -> String {
+    format!(
+        "data:text/csv;charset=utf-8,{}",
+        js_sys::encode_uri_component(csv)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_csv_quotes_fields() {
+        let csv = to_csv([vec!["Event", "Mark"], vec!["100m, heat 2", "10.12 \"PB\""]]);
+        assert_eq!(
+            csv,
+            "Event,Mark\r\n\"100m, heat 2\",\"10.12 \"\"PB\"\"\"\r\n"
+        );
+    }
+}
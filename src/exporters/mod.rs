@@ -0,0 +1,5 @@
+pub mod csv;
+pub mod pdf;
+pub mod results_export;
+pub mod score_report;
+pub mod summary;
@@ -0,0 +1,155 @@
+//! A minimal PDF writer for text reports: A4 pages of Helvetica lines, generated in the
+//! browser without a PDF library. Characters outside ASCII are replaced, since the standard
+//! fonts are used without embedding.
+
+const PAGE_WIDTH: f64 = 595.0;
+const PAGE_HEIGHT: f64 = 842.0;
+const MARGIN: f64 = 56.0;
+const LINE_HEIGHT: f64 = 16.0;
+const TEXT_SIZE: f64 = 10.0;
+const HEADING_SIZE: f64 = 14.0;
+
+/// One line of a PDF report
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfLine {
+    Heading(String),
+    Text(String),
+    Blank,
+}
+
+/// Escapes text for a PDF string literal, replacing what the standard fonts can't show
+fn pdf_string(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\\' | '(' | ')' => format!("\\{}", c),
+            '—' | '–' | '−' => "-".to_string(),
+            ' '..='~' => c.to_string(),
+            _ => "?".to_string(),
+        })
+        .collect()
+}
+
+fn page_content(lines: &[PdfLine]) -> String {
+    let mut content = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        let y = PAGE_HEIGHT - MARGIN - LINE_HEIGHT * (i + 1) as f64;
+        let (font, size, text) = match line {
+            PdfLine::Heading(text) => ("F2", HEADING_SIZE, text),
+            PdfLine::Text(text) => ("F1", TEXT_SIZE, text),
+            PdfLine::Blank => continue,
+        };
+        content.push_str(&format!(
+            "BT /{} {} Tf {} {} Td ({}) Tj ET\n",
+            font,
+            size,
+            MARGIN,
+            y,
+            pdf_string(text)
+        ));
+    }
+    content
+}
+
+/// A PDF document with the lines laid out top to bottom, continuing onto new pages as needed.
+/// The document is plain ASCII, so it can be returned as a `String`.
+pub fn pdf_document(lines: &[PdfLine]) -> String {
+    let lines_per_page = ((PAGE_HEIGHT - 2.0 * MARGIN) / LINE_HEIGHT) as usize;
+    let pages: Vec<&[PdfLine]> = if lines.is_empty() {
+        vec![&[]]
+    } else {
+        lines.chunks(lines_per_page).collect()
+    };
+
+    // Objects 1-4 are the catalog, page tree and fonts; each page adds a page and its content
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 5 + 2 * i).collect();
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold >>".to_string(),
+    ];
+    for (page, id) in pages.iter().zip(&page_ids) {
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+            id + 1
+        ));
+        let content = page_content(page);
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = "%PDF-1.4\n".to_string();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+    }
+    let xref = pdf.len();
+    pdf.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref
+    ));
+    pdf
+}
+
+/// A `data:` URL for downloading a PDF document from an anchor's `href`
+pub fn pdf_data_url(pdf: &str) -> String {
+    format!("data:application/pdf,{}", js_sys::encode_uri_component(pdf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pdf_document() {
+        let pdf = pdf_document(&[
+            PdfLine::Heading("Score Report".to_string()),
+            PdfLine::Blank,
+            PdfLine::Text("Men 100m 10.12 (+1.3) — 1211 pts".to_string()),
+        ]);
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.is_ascii());
+        assert!(pdf.contains("(Men 100m 10.12 \\(+1.3\\) - 1211 pts) Tj"));
+        assert!(pdf.contains("/Count 1"));
+
+        // Every cross-reference entry points at the start of its object
+        let xref = pdf.find("\nxref\n").unwrap() + 1;
+        let entries: Vec<usize> = pdf[xref..]
+            .lines()
+            .skip(3)
+            .take_while(|line| line.ends_with(" n "))
+            .map(|line| line[..10].parse().unwrap())
+            .collect();
+        assert_eq!(entries.len(), 6);
+        for (i, offset) in entries.iter().enumerate() {
+            assert!(pdf[*offset..].starts_with(&format!("{} 0 obj", i + 1)));
+        }
+        assert!(pdf.contains(&format!("startxref\n{}\n", xref)));
+
+        let long: Vec<PdfLine> = (0..60).map(|i| PdfLine::Text(i.to_string())).collect();
+        assert!(pdf_document(&long).contains("/Count 2"));
+    }
+}
@@ -0,0 +1,275 @@
+//! The calculation history and batch-scored results as CSV with a fixed set of columns, so
+//! spreadsheets built on one export keep working with the next:
+//!
+//! ```csv
+//! Date,Athlete,Gender,Event,Mark,Wind,Category,Place,Round,Points,Result Score,Placing Score,Notes
+//! 2025-06-14,A. Runner,men,100m,10.12,0.8,A,1,Final,1291,1211,80,
+//! ```
+//!
+//! Fields that don't apply (e.g. wind for a distance race) are left empty. Points are rounded
+//! to whole numbers, and rows that couldn't be scored say why in Notes.
+use crate::exporters::csv::to_csv;
+use crate::history::HistoryEntry;
+use crate::importers::open_track::{ImportedResult, ScoredResult};
+use crate::importers::results_csv::ScoredCsv;
+use crate::models::{CompetitionCategory, WorldAthleticsScoreOutput};
+use crate::scoring_logic::calculator::is_wind_affected_event;
+use crate::scoring_logic::ranking_calendar::CalendarDate;
+
+pub const EXPORT_COLUMNS: [&str; 13] = [
+    "Date",
+    "Athlete",
+    "Gender",
+    "Event",
+    "Mark",
+    "Wind",
+    "Category",
+    "Place",
+    "Round",
+    "Points",
+    "Result Score",
+    "Placing Score",
+    "Notes",
+];
+
+/// One exported row, in the order of `EXPORT_COLUMNS`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExportRow {
+    pub date: String,
+    pub athlete: String,
+    pub gender: String,
+    pub event: String,
+    pub mark: String,
+    pub wind: String,
+    pub category: String,
+    pub place: String,
+    pub round: String,
+    pub points: String,
+    pub result_score: String,
+    pub placement_score: String,
+    pub notes: String,
+}
+
+impl ExportRow {
+    fn fields(&self) -> [&str; 13] {
+        [
+            self.date.as_str(),
+            self.athlete.as_str(),
+            self.gender.as_str(),
+            self.event.as_str(),
+            self.mark.as_str(),
+            self.wind.as_str(),
+            self.category.as_str(),
+            self.place.as_str(),
+            self.round.as_str(),
+            self.points.as_str(),
+            self.result_score.as_str(),
+            self.placement_score.as_str(),
+            self.notes.as_str(),
+        ]
+    }
+
+    fn with_score(mut self, score: &Result<WorldAthleticsScoreOutput, String>) -> Self {
+        match score {
+            Ok(score) => {
+                self.points = format!("{:.0}", score.points);
+                self.result_score = format!("{:.0}", score.result_score);
+                self.placement_score = score.placement_score.to_string();
+            }
+            Err(e) => self.notes = e.clone(),
+        }
+        self
+    }
+
+    fn from_result(
+        result: &ImportedResult,
+        competition_category: CompetitionCategory,
+        score: &Result<WorldAthleticsScoreOutput, String>,
+    ) -> Self {
+        ExportRow {
+            date: result.date.clone().unwrap_or_default(),
+            athlete: result.athlete.clone(),
+            gender: result.gender.to_string(),
+            event: result.event.to_string(),
+            mark: result.mark.clone(),
+            wind: result
+                .wind_speed
+                .map(|wind| wind.to_string())
+                .unwrap_or_default(),
+            category: competition_category.to_string(),
+            place: result
+                .place
+                .map(|place| place.to_string())
+                .unwrap_or_default(),
+            round: result.round.to_string(),
+            ..Default::default()
+        }
+        .with_score(score)
+    }
+}
+
+/// The calculation history, newest first. Dates are the UTC day of the calculation, and the
+/// conditions notes go in Notes; only the total points are kept in the history.
+pub fn history_rows(history: &[HistoryEntry]) -> Vec<ExportRow> {
+    history
+        .iter()
+        .map(|entry| {
+            let date = CalendarDate::from_days_since_epoch(
+                (entry.timestamp / 86_400_000.0).floor() as i64,
+            )
+            .to_string();
+            let points = format!("{:.0}", entry.points);
+            match entry.state() {
+                Some(state) => ExportRow {
+                    date,
+                    gender: state.gender.to_string(),
+                    event: state.event.to_string(),
+                    mark: state.mark,
+                    wind: match state.wind_speed {
+                        _ if !is_wind_affected_event(&state.event) => String::new(),
+                        Some(wind) => wind.to_string(),
+                        None => "NWI".to_string(),
+                    },
+                    category: state
+                        .placement
+                        .as_ref()
+                        .map(|p| p.competition_category.to_string())
+                        .unwrap_or_default(),
+                    place: state
+                        .placement
+                        .as_ref()
+                        .map(|p| p.place.to_string())
+                        .unwrap_or_default(),
+                    round: state
+                        .placement
+                        .as_ref()
+                        .map(|p| p.round.to_string())
+                        .unwrap_or_default(),
+                    points,
+                    notes: entry.conditions.clone(),
+                    ..Default::default()
+                },
+                None => ExportRow {
+                    date,
+                    points,
+                    notes: "Unrecognised inputs".to_string(),
+                    ..Default::default()
+                },
+            }
+        })
+        .collect()
+}
+
+/// Results imported from a results file and scored at `competition_category`
+pub fn scored_result_rows(
+    results: &[ScoredResult],
+    competition_category: CompetitionCategory,
+) -> Vec<ExportRow> {
+    results
+        .iter()
+        .map(|scored| ExportRow::from_result(&scored.result, competition_category, &scored.score))
+        .collect()
+}
+
+/// The rows of a scored CSV file. Rows that couldn't be read keep only the reason.
+pub fn scored_csv_rows(scored: &ScoredCsv) -> Vec<ExportRow> {
+    scored
+        .rows
+        .iter()
+        .map(|row| match &row.result {
+            Some(result) => ExportRow::from_result(result, scored.competition_category, &row.score),
+            None => ExportRow::default().with_score(&row.score),
+        })
+        .collect()
+}
+
+/// The rows as a CSV document with the `EXPORT_COLUMNS` header
+pub fn export_csv(rows: &[ExportRow]) -> String {
+    to_csv(std::iter::once(EXPORT_COLUMNS).chain(rows.iter().map(ExportRow::fields)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::importers::results_csv::score_results_csv;
+    use crate::models::{AthleteCategory, Event, Gender, PlacementInfo, TrackAndFieldEvent};
+    use crate::scoring_logic::placement_score::{PlacementScoreCalcInput, RoundType};
+    use crate::scoring_logic::track_size::TrackSize;
+    use crate::share_link::CalculatorState;
+
+    fn mock_result_score_calculator(performance: f64, _: Gender, _: &Event) -> Result<f64, String> {
+        Ok(performance * 100.0)
+    }
+
+    fn mock_placement_score_calculator(input: PlacementScoreCalcInput) -> Option<i32> {
+        (input.place == 1).then_some(80)
+    }
+
+    #[test]
+    fn test_history_rows() {
+        let state = CalculatorState {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            mark: "10.12".to_string(),
+            wind_speed: Some(0.8),
+            net_downhill: None,
+            venue_altitude: None,
+            track_size: TrackSize::Banked,
+            athlete_category: AthleteCategory::Senior,
+            placement: Some(PlacementInfo {
+                competition_category: CompetitionCategory::A,
+                place: 1,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        };
+        // 2025-06-14T12:00:00Z
+        let entry = HistoryEntry::new(1_749_902_400_000.0, &state, 1291.4, "Humid, \"fast\" track");
+        let csv = export_csv(&history_rows(&[entry]));
+        assert_eq!(
+            csv,
+            "Date,Athlete,Gender,Event,Mark,Wind,Category,Place,Round,Points,Result Score,Placing Score,Notes\r\n\
+             2025-06-14,,men,100m,10.12,0.8,A,1,Final,1291,,,\"Humid, \"\"fast\"\" track\"\r\n"
+        );
+
+        let distance = CalculatorState {
+            event: Event::TrackAndField(TrackAndFieldEvent::M800),
+            mark: "1:45.00".to_string(),
+            placement: None,
+            ..state
+        };
+        let rows = history_rows(&[HistoryEntry::new(0.0, &distance, 1100.0, "")]);
+        assert_eq!(rows[0].date, "1970-01-01");
+        assert_eq!(rows[0].wind, "");
+        assert_eq!(rows[0].place, "");
+    }
+
+    #[test]
+    fn test_scored_csv_rows() {
+        let scored = score_results_csv(
+            "Venue,Athlete,Gender,Event,Mark,Place\nOslo,A. Runner,M,100m,10.12,1\nOslo,B. Runner,M,Sprint,10.30,2\n",
+            CompetitionCategory::B,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .unwrap();
+        let rows = scored_csv_rows(&scored);
+        assert_eq!(rows[0].athlete, "A. Runner");
+        assert_eq!(rows[0].category, "B");
+        // No wind column, so the 100m is scored as NWI
+        assert_eq!(rows[0].points, "1062");
+        assert_eq!(rows[0].result_score, "1012");
+        assert_eq!(rows[0].placement_score, "80");
+        assert_eq!(rows[1].athlete, "");
+        assert_eq!(rows[1].notes, "Unknown event: Sprint");
+
+        // The venue column isn't part of the schema
+        let csv = export_csv(&rows);
+        assert!(csv.starts_with("Date,Athlete,Gender,Event,"));
+        assert!(!csv.contains("Oslo"));
+    }
+}
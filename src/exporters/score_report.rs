@@ -0,0 +1,197 @@
+//! A score report for selection paperwork: the calculation's inputs, the breakdown with the
+//! rule behind each component, and the table edition it was scored against. Shown on the
+//! printable report page and exported as a PDF.
+use crate::exporters::pdf::{pdf_document, PdfLine};
+use crate::exporters::summary::result_summary;
+use crate::models::{Gender, WorldAthleticsScoreOutput};
+use crate::scoring_logic::altitude::is_altitude_affected_event;
+use crate::scoring_logic::calculator::{is_road_running_event, is_wind_affected_event};
+use crate::scoring_logic::citations::score_breakdown;
+use crate::scoring_logic::track_size::is_short_track_event;
+use crate::settings::Settings;
+use crate::share_link::CalculatorState;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreReport {
+    pub summary: String,
+    /// Label and value for each input that affected the score
+    pub inputs: Vec<(String, String)>,
+    /// Label (with the rule identifier) and points for each score component
+    pub breakdown: Vec<(String, String)>,
+    pub total: String,
+    pub table_edition: String,
+}
+
+pub fn score_report(
+    state: &CalculatorState,
+    score: &WorldAthleticsScoreOutput,
+    settings: &Settings,
+) -> ScoreReport {
+    let mut inputs = vec![
+        (
+            "Gender".to_string(),
+            match state.gender {
+                Gender::Men => "Men",
+                Gender::Women => "Women",
+            }
+            .to_string(),
+        ),
+        ("Event".to_string(), state.event.to_string()),
+        ("Mark".to_string(), state.mark.trim().to_string()),
+    ];
+    if is_wind_affected_event(&state.event) {
+        let wind = match state.wind_speed {
+            Some(wind) => format!("{:+.1} m/s", wind),
+            None => "No reading (NWI)".to_string(),
+        };
+        inputs.push(("Wind".to_string(), wind));
+    }
+    if let Some(drop) = state
+        .net_downhill
+        .filter(|_| is_road_running_event(&state.event))
+    {
+        inputs.push(("Net downhill".to_string(), format!("{} m/km", drop)));
+    }
+    if let Some(altitude) = state
+        .venue_altitude
+        .filter(|_| is_altitude_affected_event(&state.event))
+    {
+        inputs.push(("Venue altitude".to_string(), format!("{} m", altitude)));
+    }
+    if is_short_track_event(&state.event) {
+        inputs.push(("Track".to_string(), state.track_size.to_string()));
+    }
+    if let Some(placement) = &state.placement {
+        inputs.extend([
+            (
+                "Competition category".to_string(),
+                placement.competition_category.to_string(),
+            ),
+            ("Place".to_string(), placement.place.to_string()),
+            ("Round".to_string(), placement.round.to_string()),
+            (
+                "Athletes in final".to_string(),
+                placement.size_of_final.to_string(),
+            ),
+        ]);
+    }
+
+    ScoreReport {
+        summary: result_summary(state, score, settings),
+        inputs,
+        breakdown: score_breakdown(score)
+            .into_iter()
+            .map(|component| {
+                (
+                    format!("{} [{}]", component.label, component.citation.id),
+                    settings.format_points(component.points),
+                )
+            })
+            .collect(),
+        total: settings.format_points(score.points),
+        table_edition: state
+            .table_edition
+            .clone()
+            .unwrap_or_else(|| settings.table_version.clone()),
+    }
+}
+
+/// The report as a PDF document, noting when it was generated
+pub fn score_report_pdf(report: &ScoreReport, generated: &str) -> String {
+    let row = |(label, value): &(String, String)| PdfLine::Text(format!("{}: {}", label, value));
+    let mut lines = vec![
+        PdfLine::Heading("World Athletics Score Report".to_string()),
+        PdfLine::Text(report.summary.clone()),
+        PdfLine::Blank,
+        PdfLine::Heading("Inputs".to_string()),
+    ];
+    lines.extend(report.inputs.iter().map(row));
+    lines.push(PdfLine::Blank);
+    lines.push(PdfLine::Heading("Score".to_string()));
+    lines.extend(report.breakdown.iter().map(row));
+    lines.push(PdfLine::Text(format!("Total: {} points", report.total)));
+    lines.push(PdfLine::Blank);
+    lines.push(PdfLine::Text(format!(
+        "Scoring tables: {} edition. Generated {}.",
+        report.table_edition, generated
+    )));
+    pdf_document(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        AthleteCategory, CompetitionCategory, Event, PlacementInfo, TrackAndFieldEvent,
+    };
+    use crate::scoring_logic::placement_score::RoundType;
+    use crate::scoring_logic::track_size::TrackSize;
+
+    #[test]
+    fn test_score_report() {
+        let state = CalculatorState {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            mark: "11.02".to_string(),
+            wind_speed: Some(-0.4),
+            net_downhill: Some(3.0),
+            venue_altitude: None,
+            track_size: TrackSize::Banked,
+            athlete_category: AthleteCategory::Senior,
+            placement: Some(PlacementInfo {
+                competition_category: CompetitionCategory::A,
+                place: 2,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+            table_edition: None,
+        };
+        let score = WorldAthleticsScoreOutput {
+            points: 1240.0,
+            result_score: 1150.0,
+            wind_adjustment: 2.0,
+            downhill_adjustment: 0.0,
+            placement_score: 88,
+            beyond_table_range: false,
+            estimated: false,
+            result_estimated: false,
+            placement_estimated: false,
+            altitude_assisted: false,
+        };
+        let settings = Settings {
+            points_decimals: 0,
+            ..Default::default()
+        };
+        let report = score_report(&state, &score, &settings);
+        let labels: Vec<&str> = report
+            .inputs
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "Gender",
+                "Event",
+                "Mark",
+                "Wind",
+                "Competition category",
+                "Place",
+                "Round",
+                "Athletes in final"
+            ]
+        );
+        assert_eq!(report.inputs[3].1, "-0.4 m/s");
+        assert_eq!(report.breakdown.len(), 3);
+        assert_eq!(report.breakdown[0].1, "1150");
+        assert_eq!(report.total, "1240");
+        assert_eq!(report.table_edition, settings.table_version);
+
+        let pdf = score_report_pdf(&report, "2025-06-14");
+        assert!(pdf.contains("(Total: 1240 points) Tj"));
+        assert!(pdf.contains("Generated 2025-06-14."));
+    }
+}
@@ -0,0 +1,132 @@
+//! One-line text summaries of a calculation, for copying, sharing and exports:
+//!
+//! `Men 100m 10.12 (+1.3) — 1211 pts, +80 placing = 1291`
+use crate::models::{Gender, WorldAthleticsScoreOutput};
+use crate::scoring_logic::calculator::{is_placement_only_event, is_wind_affected_event};
+use crate::settings::Settings;
+use crate::share_link::CalculatorState;
+
+/// Summarises a calculation, with points formatted per `settings`. Placing points are
+/// listed separately when there are any, and events scored on placing alone give the place
+/// instead of a mark.
+pub fn result_summary(
+    state: &CalculatorState,
+    score: &WorldAthleticsScoreOutput,
+    settings: &Settings,
+) -> String {
+    let gender = match state.gender {
+        Gender::Men => "Men",
+        Gender::Women => "Women",
+    };
+    if is_placement_only_event(&state.event) {
+        let place = state
+            .placement
+            .as_ref()
+            .map(|p| format!(", place {} ({})", p.place, p.competition_category))
+            .unwrap_or_default();
+        return format!(
+            "{} {}{} — {} pts from placing",
+            gender,
+            state.event,
+            place,
+            settings.format_points(score.points)
+        );
+    }
+    let mut summary = format!("{} {} {}", gender, state.event, state.mark.trim());
+    if is_wind_affected_event(&state.event) {
+        match state.wind_speed {
+            Some(wind) => summary.push_str(&format!(" ({:+.1})", wind)),
+            None => summary.push_str(" (NWI)"),
+        }
+    }
+    if score.placement_score == 0 {
+        summary.push_str(&format!(" — {} pts", settings.format_points(score.points)));
+    } else {
+        summary.push_str(&format!(
+            " — {} pts, {:+} placing = {}",
+            settings.format_points(score.points - score.placement_score as f64),
+            score.placement_score,
+            settings.format_points(score.points),
+        ));
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        AthleteCategory, CompetitionCategory, CrossCountryEvent, Event, PlacementInfo,
+        TrackAndFieldEvent,
+    };
+    use crate::scoring_logic::placement_score::RoundType;
+    use crate::scoring_logic::track_size::TrackSize;
+
+    #[test]
+    fn test_result_summary() {
+        let mut state = CalculatorState {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            mark: "10.12".to_string(),
+            wind_speed: Some(1.3),
+            net_downhill: None,
+            venue_altitude: None,
+            track_size: TrackSize::Banked,
+            athlete_category: AthleteCategory::Senior,
+            placement: None,
+            table_edition: None,
+        };
+        let mut score = WorldAthleticsScoreOutput {
+            points: 1291.0,
+            result_score: 1211.0,
+            wind_adjustment: 0.0,
+            downhill_adjustment: 0.0,
+            placement_score: 80,
+            beyond_table_range: false,
+            estimated: false,
+            result_estimated: false,
+            placement_estimated: false,
+            altitude_assisted: false,
+        };
+        let settings = Settings {
+            points_decimals: 0,
+            ..Default::default()
+        };
+        assert_eq!(
+            result_summary(&state, &score, &settings),
+            "Men 100m 10.12 (+1.3) — 1211 pts, +80 placing = 1291"
+        );
+
+        state.wind_speed = None;
+        score.points = 1181.0;
+        score.placement_score = 0;
+        assert_eq!(
+            result_summary(&state, &score, &settings),
+            "Men 100m 10.12 (NWI) — 1181 pts"
+        );
+
+        state.gender = Gender::Women;
+        state.event = Event::TrackAndField(TrackAndFieldEvent::M800);
+        state.mark = "1:58.40".to_string();
+        assert_eq!(
+            result_summary(&state, &score, &settings),
+            "Women 800m 1:58.40 — 1181 pts"
+        );
+
+        state.event = Event::CrossCountry(CrossCountryEvent::XcChampionship);
+        state.mark = String::new();
+        state.placement = Some(PlacementInfo {
+            competition_category: CompetitionCategory::GW,
+            place: 3,
+            round: RoundType::Final,
+            size_of_final: 8,
+            qualified_to_final: false,
+            tied_with: 0,
+            main_event: false,
+        });
+        assert_eq!(
+            result_summary(&state, &score, &settings),
+            "Women XC Championship, place 3 (GW) — 1181 pts from placing"
+        );
+    }
+}
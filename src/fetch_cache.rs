@@ -0,0 +1,207 @@
+//! Fetch-and-cache for remote data (runtime scoring tables, published standards, profile
+//! imports) backed by the browser's Cache API.
+//!
+//! Responses are served stale-while-revalidate: a cached copy younger than `max_age_ms` is
+//! returned as-is, an older one is returned immediately while a fresh copy is fetched in
+//! the background, and a cached copy of any age is the fallback when the network fails.
+//! Where the Cache API is unavailable (e.g. outside a secure context) it falls back to a
+//! plain fetch.
+use js_sys::Date;
+use leptos::task::spawn_local;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Cache, Response, ResponseInit};
+
+/// Bump when the cached format changes so old entries are ignored
+const CACHE_NAME: &str = "wa-points-fetch-v1";
+/// Header on cached responses recording when they were fetched (ms since the epoch)
+const FETCHED_AT_HEADER: &str = "x-fetched-at";
+
+/// How long a cached response is served without revalidating
+pub const DEFAULT_MAX_AGE_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Freshness {
+    /// Young enough to serve without going to the network
+    Fresh,
+    /// Served, but refreshed in the background
+    Stale,
+}
+
+/// Whether a response fetched at `fetched_at` can still be served without revalidating
+pub fn freshness(fetched_at: f64, now: f64, max_age_ms: f64) -> Freshness {
+    if now - fetched_at <= max_age_ms {
+        Freshness::Fresh
+    } else {
+        Freshness::Stale
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedText {
+    pub body: String,
+    /// When the body was fetched from the network (ms since the epoch)
+    pub fetched_at: f64,
+    /// The body came from the cache after the network request failed
+    pub offline: bool,
+}
+
+fn js_error(e: JsValue) -> String {
+    e.as_string().unwrap_or_else(|| format!("{:?}", e))
+}
+
+async fn open_cache() -> Result<Cache, String> {
+    let caches = leptos::prelude::window().caches().map_err(js_error)?;
+    let cache = JsFuture::from(caches.open(CACHE_NAME))
+        .await
+        .map_err(js_error)?;
+    Ok(cache.unchecked_into())
+}
+
+async fn response_text(response: &Response) -> Result<String, String> {
+    let text = JsFuture::from(response.text().map_err(js_error)?)
+        .await
+        .map_err(js_error)?;
+    text.as_string()
+        .ok_or_else(|| "Response body is not text".to_string())
+}
+
+async fn fetch_text(url: &str) -> Result<String, String> {
+    let response = JsFuture::from(leptos::prelude::window().fetch_with_str(url))
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, js_error(e)))?;
+    let response: Response = response.unchecked_into();
+    if !response.ok() {
+        return Err(format!(
+            "Failed to fetch {}: HTTP {}",
+            url,
+            response.status()
+        ));
+    }
+    response_text(&response).await
+}
+
+async fn cached(cache: &Cache, url: &str) -> Option<CachedText> {
+    let response = JsFuture::from(cache.match_with_str(url)).await.ok()?;
+    let response: Response = response.dyn_into().ok()?;
+    let fetched_at = response
+        .headers()
+        .get(FETCHED_AT_HEADER)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0.0);
+    let body = response_text(&response).await.ok()?;
+    Some(CachedText {
+        body,
+        fetched_at,
+        offline: false,
+    })
+}
+
+async fn store(cache: &Cache, url: &str, body: &str, fetched_at: f64) -> Result<(), String> {
+    let init = ResponseInit::new();
+    let headers = web_sys::Headers::new().map_err(js_error)?;
+    headers
+        .set(FETCHED_AT_HEADER, &fetched_at.to_string())
+        .map_err(js_error)?;
+    init.set_headers(&headers);
+    let response = Response::new_with_opt_str_and_init(Some(body), &init).map_err(js_error)?;
+    JsFuture::from(cache.put_with_str(url, &response))
+        .await
+        .map_err(js_error)?;
+    Ok(())
+}
+
+/// Fetches `url` from the network and stores the body in the cache
+async fn revalidate(cache: &Cache, url: &str) -> Result<CachedText, String> {
+    let body = fetch_text(url).await?;
+    let fetched_at = Date::now();
+    if let Err(e) = store(cache, url, &body, fetched_at).await {
+        log::warn!("Failed to cache {}: {}", url, e);
+    }
+    Ok(CachedText {
+        body,
+        fetched_at,
+        offline: false,
+    })
+}
+
+/// Fetches `url` as text through the shared cache (see the module docs)
+pub async fn fetch_cached(url: &str, max_age_ms: f64) -> Result<CachedText, String> {
+    let cache = match open_cache().await {
+        Ok(cache) => cache,
+        Err(e) => {
+            log::warn!("Cache API unavailable, fetching {} directly: {}", url, e);
+            let body = fetch_text(url).await?;
+            return Ok(CachedText {
+                body,
+                fetched_at: Date::now(),
+                offline: false,
+            });
+        }
+    };
+
+    let Some(hit) = cached(&cache, url).await else {
+        return revalidate(&cache, url).await;
+    };
+    match freshness(hit.fetched_at, Date::now(), max_age_ms) {
+        Freshness::Fresh => Ok(hit),
+        Freshness::Stale if is_online() => {
+            let url = url.to_string();
+            spawn_local(async move {
+                if let Err(e) = revalidate(&cache, &url).await {
+                    log::warn!("Failed to revalidate {}: {}", url, e);
+                }
+            });
+            Ok(hit)
+        }
+        Freshness::Stale => Ok(CachedText {
+            offline: true,
+            ..hit
+        }),
+    }
+}
+
+fn is_online() -> bool {
+    leptos::prelude::window().navigator().on_line()
+}
+
+/// Fetches `url` from the network, falling back to the cached copy when offline or when
+/// the request fails. Use this where the latest data matters more than speed.
+pub async fn fetch_network_first(url: &str) -> Result<CachedText, String> {
+    let Ok(cache) = open_cache().await else {
+        let body = fetch_text(url).await?;
+        return Ok(CachedText {
+            body,
+            fetched_at: Date::now(),
+            offline: false,
+        });
+    };
+    match revalidate(&cache, url).await {
+        Ok(fresh) => Ok(fresh),
+        Err(e) => cached(&cache, url)
+            .await
+            .map(|hit| CachedText {
+                offline: true,
+                ..hit
+            })
+            .ok_or(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freshness() {
+        assert_eq!(freshness(1_000.0, 2_000.0, 1_000.0), Freshness::Fresh);
+        assert_eq!(freshness(1_000.0, 2_001.0, 1_000.0), Freshness::Stale);
+        // Entries without a timestamp are always stale
+        assert_eq!(
+            freshness(0.0, 1_700_000_000_000.0, DEFAULT_MAX_AGE_MS),
+            Freshness::Stale
+        );
+    }
+}
@@ -0,0 +1,155 @@
+//! Calculations made on the calculator page, kept in localStorage so they survive a refresh.
+//! Each entry stores the inputs as share link parameters, so it can be loaded back into the
+//! form the same way a link is.
+use crate::settings::{load_from_storage, save_to_storage};
+use crate::share_link::CalculatorState;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+const HISTORY_KEY: &str = "wa_points_history";
+
+/// Older entries are dropped once the history is this long
+pub const HISTORY_LIMIT: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Milliseconds since the Unix epoch
+    pub timestamp: f64,
+    /// The calculator inputs as share link parameters
+    pub inputs: Vec<(String, String)>,
+    pub points: f64,
+    /// Free-text notes on the conditions, if any were entered
+    #[serde(default)]
+    pub conditions: String,
+}
+
+impl HistoryEntry {
+    pub fn new(timestamp: f64, state: &CalculatorState, points: f64, conditions: &str) -> Self {
+        HistoryEntry {
+            timestamp,
+            inputs: state
+                .to_params()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+            points,
+            conditions: conditions.to_string(),
+        }
+    }
+
+    /// The calculator inputs, or `None` if they no longer parse (e.g. a removed event)
+    pub fn state(&self) -> Option<CalculatorState> {
+        CalculatorState::from_query(|key| {
+            self.inputs
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.clone())
+        })
+    }
+}
+
+/// Adds an entry to the front of the history. Repeating a calculation moves it to the front
+/// rather than listing it twice.
+pub fn add_entry(history: &mut Vec<HistoryEntry>, entry: HistoryEntry) {
+    history.retain(|existing| existing.inputs != entry.inputs);
+    history.insert(0, entry);
+    history.truncate(HISTORY_LIMIT);
+}
+
+/// The history with the entries from `other` that it doesn't have, newest first
+pub fn merge_history(history: &[HistoryEntry], other: &[HistoryEntry]) -> Vec<HistoryEntry> {
+    let mut merged = history.to_vec();
+    for entry in other {
+        if !merged
+            .iter()
+            .any(|existing| existing.timestamp == entry.timestamp)
+        {
+            merged.push(entry.clone());
+        }
+    }
+    merged.sort_by(|a, b| b.timestamp.total_cmp(&a.timestamp));
+    merged.truncate(HISTORY_LIMIT);
+    merged
+}
+
+/// Provides the saved history to the app and saves it whenever it changes. Like the settings,
+/// changes saved by other tabs are picked up from the `storage` event.
+pub fn provide_history() {
+    let history =
+        RwSignal::new(load_from_storage::<Vec<HistoryEntry>>(HISTORY_KEY).unwrap_or_default());
+    Effect::new(move |_| save_to_storage(HISTORY_KEY, &history.get()));
+    let listener = window_event_listener(leptos::ev::storage, move |ev| {
+        if ev.key().as_deref() != Some(HISTORY_KEY) {
+            return;
+        }
+        let changed = ev
+            .new_value()
+            .and_then(|json| serde_json::from_str::<Vec<HistoryEntry>>(&json).ok())
+            .unwrap_or_default();
+        if history.with_untracked(|current| current != &changed) {
+            history.set(changed);
+        }
+    });
+    on_cleanup(move || listener.remove());
+    provide_context(history);
+}
+
+/// The calculation history, empty outside the app (e.g. in tests)
+pub fn use_history() -> RwSignal<Vec<HistoryEntry>> {
+    use_context::<RwSignal<Vec<HistoryEntry>>>().unwrap_or_else(|| RwSignal::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Event, Gender, TrackAndFieldEvent};
+    use crate::scoring_logic::coefficients::TABLE_VERSION;
+
+    fn entry(timestamp: f64, mark: &str) -> HistoryEntry {
+        let state = CalculatorState {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            mark: mark.to_string(),
+            wind_speed: Some(0.4),
+            net_downhill: None,
+            venue_altitude: None,
+            track_size: Default::default(),
+            athlete_category: Default::default(),
+            placement: None,
+            table_edition: Some(TABLE_VERSION.to_string()),
+        };
+        HistoryEntry::new(timestamp, &state, 1100.0, "")
+    }
+
+    #[test]
+    fn test_add_entry_moves_repeats_to_front() {
+        let mut history = Vec::new();
+        add_entry(&mut history, entry(1.0, "10.10"));
+        add_entry(&mut history, entry(2.0, "10.20"));
+        add_entry(&mut history, entry(3.0, "10.10"));
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].timestamp, 3.0);
+        assert_eq!(history[0].state().unwrap().mark, "10.10");
+        assert_eq!(history[0].state().unwrap().wind_speed, Some(0.4));
+        assert_eq!(
+            history[0].state().unwrap().table_edition.as_deref(),
+            Some(TABLE_VERSION)
+        );
+
+        for i in 0..HISTORY_LIMIT {
+            add_entry(
+                &mut history,
+                entry(10.0 + i as f64, &format!("{}", 11.0 + i as f64)),
+            );
+        }
+        assert_eq!(history.len(), HISTORY_LIMIT);
+    }
+
+    #[test]
+    fn test_merge_history() {
+        let history = vec![entry(3.0, "10.30"), entry(1.0, "10.10")];
+        let merged = merge_history(&history, &[entry(2.0, "10.20"), entry(1.0, "10.10")]);
+        let timestamps: Vec<f64> = merged.iter().map(|entry| entry.timestamp).collect();
+        assert_eq!(timestamps, vec![3.0, 2.0, 1.0]);
+    }
+}
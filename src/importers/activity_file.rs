@@ -0,0 +1,47 @@
+// src/importers/activity_file.rs
+//! Reads a watch export (FIT or TCX) into a road race, picking the format from the file name.
+use crate::importers::course::{road_race_from_recording, RoadRaceResult};
+use crate::importers::fit::parse_fit;
+use crate::importers::tcx::parse_tcx;
+
+/// File extensions accepted by `parse_activity_file`
+pub const ACTIVITY_FILE_EXTENSIONS: &str = ".fit,.tcx";
+
+pub fn parse_activity_file(file_name: &str, bytes: &[u8]) -> Result<RoadRaceResult, String> {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    let recording = match extension.as_str() {
+        "fit" => parse_fit(bytes)?,
+        "tcx" => parse_tcx(
+            std::str::from_utf8(bytes).map_err(|_| "TCX file is not valid UTF-8".to_string())?,
+        )?,
+        _ => return Err(format!("Unsupported activity file: {}", file_name)),
+    };
+    road_race_from_recording(&recording).ok_or_else(|| {
+        format!(
+            "{:.2} km doesn't match a road event distance",
+            recording.distance_meters / 1000.0
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::RoadRunningEvent;
+
+    #[test]
+    fn test_parse_activity_file() {
+        let tcx = br#"<TrainingCenterDatabase><Activities><Activity><Lap>
+            <TotalTimeSeconds>1800</TotalTimeSeconds><DistanceMeters>10040</DistanceMeters>
+            </Lap></Activity></Activities></TrainingCenterDatabase>"#;
+        let race = parse_activity_file("Morning_Race.TCX", tcx).unwrap();
+        assert_eq!(race.event, RoadRunningEvent::Road10km);
+        assert_eq!(race.elapsed_seconds, 1800.0);
+
+        assert!(parse_activity_file("route.gpx", tcx).is_err());
+        assert!(parse_activity_file("race.fit", tcx).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+// src/importers/course.rs
+//! Course geometry shared by the activity importers: matching a measured distance to a road
+//! event and deriving the net downhill used for the elevation adjustment.
+use crate::models::{Event, RoadRunningEvent};
+use strum::IntoEnumIterator;
+
+/// How far a measured distance may stray from an event's official distance and still match it.
+/// GPS watches routinely over-measure road courses by a percent or two.
+pub const DISTANCE_TOLERANCE: f64 = 0.03;
+
+/// The parts of a recorded activity (watch file or Strava) needed to score it as a road race
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityRecording {
+    pub distance_meters: f64,
+    pub elapsed_seconds: f64,
+    /// Elevations in meters from start to finish
+    pub elevation_profile: Vec<f64>,
+}
+
+/// A road race prepared for the calculator form
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoadRaceResult {
+    pub event: RoadRunningEvent,
+    pub elapsed_seconds: f64,
+    /// Net elevation drop in m/km, `None` when the course finishes at or above the start
+    pub net_downhill: Option<f64>,
+}
+
+/// The official distance of a road event in meters
+pub fn road_event_distance(event: &RoadRunningEvent) -> f64 {
+    Event::RoadRunning(event.clone())
+        .distance_meters()
+        .expect("every road event has a fixed distance")
+}
+
+/// Finds the road event whose official distance is closest to `distance_meters`,
+/// provided it is within `DISTANCE_TOLERANCE`.
+pub fn nearest_road_event(distance_meters: f64) -> Option<RoadRunningEvent> {
+    RoadRunningEvent::iter()
+        .map(|event| {
+            let official = road_event_distance(&event);
+            (event, (distance_meters - official).abs() / official)
+        })
+        .filter(|(_, relative_error)| *relative_error <= DISTANCE_TOLERANCE)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(event, _)| event)
+}
+
+/// Net elevation drop between start and finish in m/km over the official course distance.
+pub fn net_downhill_per_km(
+    start_elevation: f64,
+    finish_elevation: f64,
+    event: &RoadRunningEvent,
+) -> Option<f64> {
+    let drop = start_elevation - finish_elevation;
+    if drop <= 0.0 {
+        return None;
+    }
+    Some(drop / (road_event_distance(event) / 1000.0))
+}
+
+/// Builds a road race from an activity's measured distance, elapsed time and elevation profile.
+pub fn road_race_from_recording(recording: &ActivityRecording) -> Option<RoadRaceResult> {
+    let event = nearest_road_event(recording.distance_meters)?;
+    let net_downhill = match (
+        recording.elevation_profile.first(),
+        recording.elevation_profile.last(),
+    ) {
+        (Some(start), Some(finish)) => net_downhill_per_km(*start, *finish, &event),
+        _ => None,
+    };
+    Some(RoadRaceResult {
+        event,
+        elapsed_seconds: recording.elapsed_seconds,
+        net_downhill,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_road_event() {
+        assert_eq!(
+            nearest_road_event(42_400.0),
+            Some(RoadRunningEvent::RoadMarathon)
+        );
+        assert_eq!(nearest_road_event(21_300.0), Some(RoadRunningEvent::RoadHM));
+        assert_eq!(
+            nearest_road_event(10_050.0),
+            Some(RoadRunningEvent::Road10km)
+        );
+        assert_eq!(nearest_road_event(8_000.0), None);
+    }
+
+    #[test]
+    fn test_road_race_from_recording() {
+        // 84.39m drop over a marathon is exactly 2 m/km
+        let race = road_race_from_recording(&ActivityRecording {
+            distance_meters: 42_300.0,
+            elapsed_seconds: 7_800.0,
+            elevation_profile: vec![100.0, 120.0, 15.61],
+        })
+        .unwrap();
+        assert_eq!(race.event, RoadRunningEvent::RoadMarathon);
+        assert!((race.net_downhill.unwrap() - 2.0).abs() < 0.001);
+
+        let uphill = road_race_from_recording(&ActivityRecording {
+            distance_meters: 10_000.0,
+            elapsed_seconds: 1_800.0,
+            elevation_profile: vec![10.0, 30.0],
+        })
+        .unwrap();
+        assert_eq!(uphill.net_downhill, None);
+
+        let no_profile = road_race_from_recording(&ActivityRecording {
+            distance_meters: 5_000.0,
+            elapsed_seconds: 900.0,
+            elevation_profile: vec![],
+        })
+        .unwrap();
+        assert_eq!(no_profile.net_downhill, None);
+    }
+}
@@ -0,0 +1,218 @@
+// src/importers/fit.rs
+//! Minimal decoder for Garmin FIT activity files.
+//!
+//! Only the messages needed for scoring are read: the session summaries (elapsed time and
+//! distance) and the per-second records (altitude). Everything else is skipped using the
+//! sizes from its definition message.
+use crate::importers::course::ActivityRecording;
+use std::collections::HashMap;
+
+const SESSION_MESSAGE: u16 = 18;
+const RECORD_MESSAGE: u16 = 20;
+
+const SESSION_TOTAL_ELAPSED_TIME: u8 = 7;
+const SESSION_TOTAL_DISTANCE: u8 = 9;
+const RECORD_ALTITUDE: u8 = 2;
+const RECORD_ENHANCED_ALTITUDE: u8 = 78;
+
+struct FieldDefinition {
+    number: u8,
+    size: usize,
+}
+
+struct MessageDefinition {
+    global_message: u16,
+    big_endian: bool,
+    fields: Vec<FieldDefinition>,
+    developer_data_size: usize,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8], String> {
+        let end = self.position + count;
+        let slice = self
+            .bytes
+            .get(self.position..end)
+            .ok_or_else(|| "Truncated FIT file".to_string())?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn byte(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+}
+
+/// Reads an unsigned integer field, returning `None` for FIT's "invalid" sentinel (all ones).
+fn unsigned_value(bytes: &[u8], big_endian: bool) -> Option<u64> {
+    if bytes.is_empty() || bytes.len() > 8 || bytes.iter().all(|b| *b == 0xFF) {
+        return None;
+    }
+    let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+    Some(if big_endian {
+        bytes.iter().fold(0, fold)
+    } else {
+        bytes.iter().rev().fold(0, fold)
+    })
+}
+
+/// Decodes a FIT activity file into its elapsed time, distance and elevation profile.
+pub fn parse_fit(bytes: &[u8]) -> Result<ActivityRecording, String> {
+    let header_size = *bytes.first().ok_or_else(|| "Empty FIT file".to_string())? as usize;
+    if bytes.len() < header_size || header_size < 12 || &bytes[8..12] != b".FIT" {
+        return Err("Not a FIT file".to_string());
+    }
+    let data_size = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    // `data_size` comes straight from the file, so guard the addition on 32-bit targets
+    let data = bytes
+        .get(header_size..header_size.saturating_add(data_size))
+        .ok_or_else(|| "Truncated FIT file".to_string())?;
+
+    let mut reader = Reader {
+        bytes: data,
+        position: 0,
+    };
+    let mut definitions: HashMap<u8, MessageDefinition> = HashMap::new();
+    let mut elapsed_seconds = 0.0;
+    let mut distance_meters = 0.0;
+    let mut sessions = 0;
+    let mut elevation_profile = Vec::new();
+
+    while reader.position < data.len() {
+        let header = reader.byte()?;
+        let is_compressed_timestamp = header & 0x80 != 0;
+        let is_definition = !is_compressed_timestamp && header & 0x40 != 0;
+        let local_message = if is_compressed_timestamp {
+            (header >> 5) & 0x03
+        } else {
+            header & 0x0F
+        };
+
+        if is_definition {
+            let has_developer_data = header & 0x20 != 0;
+            let fixed = reader.take(5)?;
+            let big_endian = fixed[1] == 1;
+            let global_message = if big_endian {
+                u16::from_be_bytes([fixed[2], fixed[3]])
+            } else {
+                u16::from_le_bytes([fixed[2], fixed[3]])
+            };
+            let fields = (0..fixed[4])
+                .map(|_| {
+                    let field = reader.take(3)?;
+                    Ok(FieldDefinition {
+                        number: field[0],
+                        size: field[1] as usize,
+                    })
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            let mut developer_data_size = 0;
+            if has_developer_data {
+                for _ in 0..reader.byte()? {
+                    developer_data_size += reader.take(3)?[1] as usize;
+                }
+            }
+            definitions.insert(
+                local_message,
+                MessageDefinition {
+                    global_message,
+                    big_endian,
+                    fields,
+                    developer_data_size,
+                },
+            );
+            continue;
+        }
+
+        let definition = definitions
+            .get(&local_message)
+            .ok_or_else(|| format!("FIT data message {} has no definition", local_message))?;
+        let mut altitude = None;
+        for field in &definition.fields {
+            let value = unsigned_value(reader.take(field.size)?, definition.big_endian);
+            match (definition.global_message, field.number, value) {
+                (SESSION_MESSAGE, SESSION_TOTAL_ELAPSED_TIME, Some(v)) => {
+                    elapsed_seconds += v as f64 / 1000.0
+                }
+                (SESSION_MESSAGE, SESSION_TOTAL_DISTANCE, Some(v)) => {
+                    distance_meters += v as f64 / 100.0
+                }
+                (RECORD_MESSAGE, RECORD_ENHANCED_ALTITUDE, Some(v)) => {
+                    altitude = Some(v as f64 / 5.0 - 500.0)
+                }
+                (RECORD_MESSAGE, RECORD_ALTITUDE, Some(v)) if altitude.is_none() => {
+                    altitude = Some(v as f64 / 5.0 - 500.0)
+                }
+                _ => {}
+            }
+        }
+        reader.take(definition.developer_data_size)?;
+        if definition.global_message == SESSION_MESSAGE {
+            sessions += 1;
+        }
+        if let Some(altitude) = altitude {
+            elevation_profile.push(altitude);
+        }
+    }
+
+    if sessions == 0 {
+        return Err("FIT file contains no session summary".to_string());
+    }
+    Ok(ActivityRecording {
+        distance_meters,
+        elapsed_seconds,
+        elevation_profile,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a FIT file with one session and two altitude records
+    fn test_fit_file() -> Vec<u8> {
+        let mut data = vec![
+            // Definition: local 0 = session, little endian, total_elapsed_time + total_distance
+            0x40, 0, 0, 18, 0, 2, 7, 4, 0x86, 9, 4, 0x86,
+        ];
+        data.push(0x00);
+        data.extend_from_slice(&1_800_000u32.to_le_bytes()); // 1800.000 s
+        data.extend_from_slice(&1_005_000u32.to_le_bytes()); // 10050.00 m
+                                                             // Definition: local 1 = record, altitude only
+        data.extend_from_slice(&[0x41, 0, 0, 20, 0, 1, 2, 2, 0x84]);
+        for altitude in [3100u16, 3000u16] {
+            data.push(0x01);
+            data.extend_from_slice(&altitude.to_le_bytes()); // altitude / 5 - 500
+        }
+
+        let mut file = vec![12, 0x10, 0, 0];
+        file.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        file.extend_from_slice(b".FIT");
+        file.extend_from_slice(&data);
+        file.extend_from_slice(&[0, 0]); // CRC, not checked
+        file
+    }
+
+    #[test]
+    fn test_parse_fit() {
+        let recording = parse_fit(&test_fit_file()).unwrap();
+        assert_eq!(recording.elapsed_seconds, 1800.0);
+        assert_eq!(recording.distance_meters, 10050.0);
+        assert_eq!(recording.elevation_profile, vec![120.0, 100.0]);
+    }
+
+    #[test]
+    fn test_parse_fit_rejects_bad_files() {
+        assert!(parse_fit(&[]).is_err());
+        assert!(parse_fit(b"<TrainingCenterDatabase/>").is_err());
+
+        let mut truncated = test_fit_file();
+        truncated.truncate(20);
+        assert!(parse_fit(&truncated).is_err());
+    }
+}
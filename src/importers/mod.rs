@@ -0,0 +1,9 @@
+pub mod activity_file;
+pub mod course;
+pub mod fit;
+pub mod open_track;
+pub mod result_status;
+pub mod results_csv;
+#[cfg(feature = "strava")]
+pub mod strava;
+pub mod tcx;
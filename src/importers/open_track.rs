@@ -0,0 +1,503 @@
+// src/importers/open_track.rs
+//! Importer for competition results exported in the OpenTrack / World Athletics JSON format.
+//!
+//! Only the fields needed for scoring are read:
+//!
+//! ```json
+//! {
+//!   "name": "Area Championships",
+//!   "date": "2025-06-14",
+//!   "venue": "Olympic Stadium",
+//!   "indoor": false,
+//!   "events": [
+//!     {
+//!       "eventCode": "100",
+//!       "gender": "M",
+//!       "rounds": [
+//!         {
+//!           "round": "F",
+//!           "units": [
+//!             {
+//!               "wind": "+0.8",
+//!               "results": [{ "place": 1, "athlete": "A. Runner", "performance": "9.95" }]
+//!             }
+//!           ]
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Markers such as "DNF", "DQ" or "NM" in place of a performance (or no performance at all)
+//! are carried through as a `ResultStatus`: the result is listed but not scored.
+use super::result_status::ResultStatus;
+use crate::models::*;
+use crate::scoring_logic::calculator::calculate_world_athletics_score;
+use crate::scoring_logic::placement_score::{PlacementScoreCalcInput, RoundType};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenTrackCompetition {
+    /// ISO 8601 date of the competition
+    date: Option<String>,
+    venue: Option<String>,
+    #[serde(default)]
+    indoor: bool,
+    events: Vec<OpenTrackEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenTrackEvent {
+    event_code: String,
+    gender: String,
+    rounds: Vec<OpenTrackRound>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenTrackRound {
+    round: String,
+    units: Vec<OpenTrackUnit>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenTrackUnit {
+    wind: Option<String>,
+    results: Vec<OpenTrackResult>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenTrackResult {
+    place: Option<i32>,
+    athlete: String,
+    #[serde(default)]
+    performance: Option<String>,
+    /// Per-athlete wind, used by horizontal jumps
+    wind: Option<String>,
+}
+
+/// A single result read from a results file, ready for scoring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedResult {
+    pub athlete: String,
+    pub gender: Gender,
+    pub event: Event,
+    pub round: RoundType,
+    pub place: Option<i32>,
+    /// The mark as written in the file
+    pub mark: String,
+    pub wind_speed: Option<f64>,
+    /// Number of athletes in the event's final, which determines semifinal scoring
+    pub size_of_final: i32,
+    pub qualified_to_final: bool,
+    /// The competition's date (YYYY-MM-DD), if the file has one
+    pub date: Option<String>,
+    pub venue: Option<String>,
+    /// Set when the file has a marker such as "DNF" instead of a mark
+    pub status: Option<ResultStatus>,
+}
+
+/// An imported result with its calculated score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredResult {
+    pub result: ImportedResult,
+    pub score: Result<WorldAthleticsScoreOutput, String>,
+}
+
+/// Maps a World Athletics discipline code to an `Event`.
+/// Indoor competitions map flat-track codes onto their short track equivalents.
+pub fn event_from_code(code: &str, indoor: bool) -> Option<Event> {
+    use TrackAndFieldEvent as T;
+    let code = code.trim().to_uppercase();
+    let track = |outdoor: T, short_track: T| {
+        Some(Event::TrackAndField(if indoor {
+            short_track
+        } else {
+            outdoor
+        }))
+    };
+    match code.as_str() {
+        "50" => Some(Event::TrackAndField(T::M50)),
+        "55" => Some(Event::TrackAndField(T::M55)),
+        "60" => Some(Event::TrackAndField(T::M60)),
+        "100" => Some(Event::TrackAndField(T::M100)),
+        "200" => track(T::M200, T::M200mSh),
+        "300" => track(T::M300, T::M300mSh),
+        "400" => track(T::M400, T::M400mSh),
+        "500" => track(T::M500, T::M500mSh),
+        "600" => track(T::M600, T::M600mSh),
+        "800" => track(T::M800, T::M800mSh),
+        "1000" => track(T::M1000, T::M1000mSh),
+        "1500" => track(T::M1500, T::M1500mSh),
+        "2000" => track(T::M2000, T::M2000mSh),
+        "3000" => track(T::M3000, T::M3000mSh),
+        "5000" => track(T::M5000, T::M5000mSh),
+        "10000" => Some(Event::TrackAndField(T::M10000)),
+        "MILE" if indoor => Some(Event::TrackAndField(T::MileSh)),
+        "2MILES" if indoor => Some(Event::TrackAndField(T::M2MilesSh)),
+        "50H" => Some(Event::TrackAndField(T::M50H)),
+        "55H" => Some(Event::TrackAndField(T::M55H)),
+        "60H" => Some(Event::TrackAndField(T::M60H)),
+        "100H" => Some(Event::TrackAndField(T::M100H)),
+        "110H" => Some(Event::TrackAndField(T::M110H)),
+        "300H" => Some(Event::TrackAndField(T::M300H)),
+        "400H" => Some(Event::TrackAndField(T::M400H)),
+        "2000SC" => Some(Event::TrackAndField(T::M2000mSC)),
+        "3000SC" => Some(Event::TrackAndField(T::M3000mSC)),
+        "4X100" => track(T::M4x100m, T::M4x100mSh),
+        "4X200" => track(T::M4x200m, T::M4x200mSh),
+        "4X400" => track(T::M4x400m, T::M4x400mSh),
+        "4X400MX" => track(T::M4x400mix, T::M4x400mixSh),
+        "HJ" => Some(Event::TrackAndField(T::HJ)),
+        "PV" => Some(Event::TrackAndField(T::PV)),
+        "LJ" => Some(Event::TrackAndField(T::LJ)),
+        "TJ" => Some(Event::TrackAndField(T::TJ)),
+        "SP" => Some(Event::TrackAndField(T::SP)),
+        "DT" => Some(Event::TrackAndField(T::DT)),
+        "HT" => Some(Event::TrackAndField(T::HT)),
+        "JT" => Some(Event::TrackAndField(T::JT)),
+        "DEC" => Some(Event::CombinedEvents(CombinedEvent::Dec)),
+        "HEP" if indoor => Some(Event::CombinedEvents(CombinedEvent::HeptSh)),
+        "HEP" => Some(Event::CombinedEvents(CombinedEvent::Hept)),
+        "PEN" if indoor => Some(Event::CombinedEvents(CombinedEvent::PentSh)),
+        "5K" => Some(Event::RoadRunning(RoadRunningEvent::Road5km)),
+        "10K" => Some(Event::RoadRunning(RoadRunningEvent::Road10km)),
+        "15K" => Some(Event::RoadRunning(RoadRunningEvent::Road15km)),
+        "20K" => Some(Event::RoadRunning(RoadRunningEvent::Road20km)),
+        "25K" => Some(Event::RoadRunning(RoadRunningEvent::Road25km)),
+        "30K" => Some(Event::RoadRunning(RoadRunningEvent::Road30km)),
+        "HM" => Some(Event::RoadRunning(RoadRunningEvent::RoadHM)),
+        "MAR" => Some(Event::RoadRunning(RoadRunningEvent::RoadMarathon)),
+        "10MI" => Some(Event::RoadRunning(RoadRunningEvent::Road10Miles)),
+        "MILE" => Some(Event::RoadRunning(RoadRunningEvent::RoadMile)),
+        "5KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road5kmW)),
+        "10KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road10kmW)),
+        "15KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road15kmW)),
+        "20KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road20kmW)),
+        "30KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road30kmW)),
+        "35KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road35kmW)),
+        "50KW" => Some(Event::RaceWalking(RaceWalkingEvent::Road50kmW)),
+        "3000W" => Some(Event::RaceWalking(RaceWalkingEvent::M3000mW)),
+        "5000W" => Some(Event::RaceWalking(RaceWalkingEvent::M5000mW)),
+        "10000W" => Some(Event::RaceWalking(RaceWalkingEvent::M10000mW)),
+        "15000W" => Some(Event::RaceWalking(RaceWalkingEvent::M15000mW)),
+        "20000W" => Some(Event::RaceWalking(RaceWalkingEvent::M20000mW)),
+        "30000W" => Some(Event::RaceWalking(RaceWalkingEvent::M30000mW)),
+        "35000W" => Some(Event::RaceWalking(RaceWalkingEvent::M35000mW)),
+        "50000W" => Some(Event::RaceWalking(RaceWalkingEvent::M50000mW)),
+        _ => None,
+    }
+}
+
+/// Maps a round identifier (e.g. "F", "SF", "H1", "Q") to a `RoundType`. Numbered heats
+/// ("H1", "H2", ...) are all heats.
+pub fn round_from_code(code: &str) -> RoundType {
+    let code = code.trim().to_uppercase();
+    match code.as_str() {
+        "F" | "FINAL" => RoundType::Final,
+        "SF" | "SEMIFINAL" | "SEMI-FINAL" => RoundType::SemiFinal,
+        "H" | "HEAT" | "HEATS" => RoundType::Heat,
+        "Q" | "QUAL" | "QUALIFICATION" => RoundType::Qualification,
+        heat if heat
+            .strip_prefix('H')
+            .is_some_and(|n| n.parse::<u32>().is_ok()) =>
+        {
+            RoundType::Heat
+        }
+        _ => RoundType::Other,
+    }
+}
+
+pub(crate) fn gender_from_code(code: &str) -> Option<Gender> {
+    match code.trim().to_uppercase().as_str() {
+        "M" | "MEN" => Some(Gender::Men),
+        "F" | "W" | "WOMEN" => Some(Gender::Women),
+        _ => None,
+    }
+}
+
+/// Parses a wind reading such as "+1.2" or "-0.4". Returns `None` for missing readings.
+pub(crate) fn parse_wind(wind: Option<&str>) -> Option<f64> {
+    wind.and_then(|w| w.trim().trim_start_matches('+').parse::<f64>().ok())
+}
+
+/// Parses an OpenTrack / World Athletics results file into individual results.
+pub fn parse_results(json: &str) -> Result<Vec<ImportedResult>, String> {
+    let competition: OpenTrackCompetition =
+        serde_json::from_str(json).map_err(|e| format!("Invalid results file: {}", e))?;
+
+    let mut imported = Vec::new();
+    for event in competition.events {
+        let parsed_event = event_from_code(&event.event_code, competition.indoor)
+            .ok_or_else(|| format!("Unsupported event code: {}", event.event_code))?;
+        let gender = gender_from_code(&event.gender)
+            .ok_or_else(|| format!("Unsupported gender: {}", event.gender))?;
+
+        // The finalists determine both the size of the final and who qualified from earlier rounds
+        let finalists: Vec<&str> = event
+            .rounds
+            .iter()
+            .filter(|round| round_from_code(&round.round) == RoundType::Final)
+            .flat_map(|round| &round.units)
+            .flat_map(|unit| &unit.results)
+            .map(|result| result.athlete.as_str())
+            .collect();
+
+        for round in &event.rounds {
+            let round_type = round_from_code(&round.round);
+            for unit in &round.units {
+                for result in &unit.results {
+                    let mark = result.performance.clone().unwrap_or_default();
+                    imported.push(ImportedResult {
+                        athlete: result.athlete.clone(),
+                        gender,
+                        event: parsed_event.clone(),
+                        round: round_type,
+                        place: result.place,
+                        status: ResultStatus::from_mark(&mark),
+                        mark,
+                        wind_speed: parse_wind(result.wind.as_deref().or(unit.wind.as_deref())),
+                        size_of_final: finalists.len() as i32,
+                        qualified_to_final: finalists.contains(&result.athlete.as_str()),
+                        date: competition.date.clone(),
+                        venue: competition.venue.clone(),
+                    });
+                }
+            }
+        }
+    }
+    Ok(imported)
+}
+
+/// Converts a written mark into the event's standard unit.
+pub fn parse_mark(event: &Event, mark: &str) -> Result<Performance, String> {
+    Performance::parse(event, mark)
+}
+
+/// Scores a single imported result at the given competition category. Results with a
+/// status such as DNF are never scored.
+pub fn score_result(
+    result: &ImportedResult,
+    competition_category: CompetitionCategory,
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Result<WorldAthleticsScoreOutput, String> {
+    if let Some(status) = result.status {
+        return Err(format!("{} ({}), not scored", status, status.description()));
+    }
+    parse_mark(&result.event, &result.mark).and_then(|performance| {
+        calculate_world_athletics_score(
+            WorldAthleticsScoreInput {
+                gender: result.gender,
+                event: result.event.clone(),
+                performance,
+                wind_speed: result.wind_speed,
+                net_downhill: None,
+                venue_altitude: None,
+                placement_info: result.place.map(|place| PlacementInfo {
+                    competition_category,
+                    place,
+                    round: result.round,
+                    size_of_final: result.size_of_final,
+                    qualified_to_final: result.qualified_to_final,
+                    tied_with: 0,
+                    main_event: false,
+                }),
+                table_edition: None,
+            },
+            result_score_calculator,
+            placement_score_calculator,
+        )
+    })
+}
+
+/// Scores every imported result at the given competition category and ranks them by points.
+/// Results that cannot be scored are kept, after the scored ones, with their error, followed
+/// by results with a status such as DNF, which are never scored.
+pub fn score_and_rank(
+    results: Vec<ImportedResult>,
+    competition_category: CompetitionCategory,
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Vec<ScoredResult> {
+    let mut scored: Vec<ScoredResult> = results
+        .into_iter()
+        .map(|result| ScoredResult {
+            score: score_result(
+                &result,
+                competition_category,
+                result_score_calculator,
+                placement_score_calculator,
+            ),
+            result,
+        })
+        .collect();
+
+    let group = |scored: &ScoredResult| match (&scored.score, scored.result.status) {
+        (Ok(_), _) => 0,
+        (Err(_), None) => 1,
+        (Err(_), Some(_)) => 2,
+    };
+    scored.sort_by(|a, b| match (&a.score, &b.score) {
+        (Ok(a), Ok(b)) => b.points.total_cmp(&a.points),
+        _ => group(a).cmp(&group(b)),
+    });
+    scored
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_RESULTS: &str = r#"{
+        "name": "Test Championships",
+        "events": [
+            {
+                "eventCode": "100",
+                "gender": "M",
+                "rounds": [
+                    {
+                        "round": "F",
+                        "units": [{
+                            "wind": "+0.5",
+                            "results": [
+                                { "place": 1, "athlete": "Fast", "performance": "10.00" },
+                                { "place": 2, "athlete": "Slower", "performance": "10.20" }
+                            ]
+                        }]
+                    },
+                    {
+                        "round": "SF",
+                        "units": [{
+                            "wind": "-1.0",
+                            "results": [
+                                { "place": 1, "athlete": "Fast", "performance": "10.05" },
+                                { "place": 3, "athlete": "Out", "performance": "10.40" }
+                            ]
+                        }]
+                    }
+                ]
+            },
+            {
+                "eventCode": "LJ",
+                "gender": "W",
+                "rounds": [{
+                    "round": "F",
+                    "units": [{
+                        "results": [
+                            { "place": 1, "athlete": "Jumper", "performance": "6.80", "wind": "+2.4" },
+                            { "place": 2, "athlete": "Fouler", "performance": "X" },
+                            { "athlete": "Scratched", "performance": "DNS" },
+                            { "athlete": "No Mark" }
+                        ]
+                    }]
+                }]
+            }
+        ]
+    }"#;
+
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        _event: &Event,
+    ) -> Result<f64, String> {
+        Ok(performance * 100.0)
+    }
+
+    fn mock_placement_score_calculator(input: PlacementScoreCalcInput) -> Option<i32> {
+        if input.place == 1 {
+            Some(100)
+        } else {
+            Some(0)
+        }
+    }
+
+    #[test]
+    fn test_event_from_code() {
+        assert_eq!(
+            event_from_code("100", false),
+            Some(Event::TrackAndField(TrackAndFieldEvent::M100))
+        );
+        assert_eq!(
+            event_from_code("400", true),
+            Some(Event::TrackAndField(TrackAndFieldEvent::M400mSh))
+        );
+        assert_eq!(
+            event_from_code("mar", false),
+            Some(Event::RoadRunning(RoadRunningEvent::RoadMarathon))
+        );
+        assert_eq!(event_from_code("EGG", false), None);
+    }
+
+    #[test]
+    fn test_round_from_code() {
+        assert_eq!(round_from_code("F"), RoundType::Final);
+        assert_eq!(round_from_code("SF"), RoundType::SemiFinal);
+        assert_eq!(round_from_code("H1"), RoundType::Heat);
+        assert_eq!(round_from_code("q"), RoundType::Qualification);
+        assert_eq!(round_from_code("R1"), RoundType::Other);
+    }
+
+    #[test]
+    fn test_parse_results() {
+        let results = parse_results(TEST_RESULTS).unwrap();
+        assert_eq!(results.len(), 8);
+
+        let semi_winner = &results[2];
+        assert_eq!(semi_winner.round, RoundType::SemiFinal);
+        assert_eq!(semi_winner.wind_speed, Some(-1.0));
+        assert_eq!(semi_winner.size_of_final, 2);
+        assert!(semi_winner.qualified_to_final);
+        assert!(!results[3].qualified_to_final);
+
+        // Per-athlete wind in the jumps takes precedence over the unit's wind
+        assert_eq!(
+            results[4].event,
+            Event::TrackAndField(TrackAndFieldEvent::LJ)
+        );
+        assert_eq!(results[4].gender, Gender::Women);
+        assert_eq!(results[4].wind_speed, Some(2.4));
+
+        assert_eq!(results[5].status, None);
+        assert_eq!(results[6].status, Some(ResultStatus::DidNotStart));
+        assert_eq!(results[7].status, Some(ResultStatus::NoMark));
+
+        assert!(parse_results(
+            r#"{"events": [{"eventCode": "EGG", "gender": "M", "rounds": []}]}"#
+        )
+        .is_err());
+        assert!(parse_results("not json").is_err());
+    }
+
+    #[test]
+    fn test_score_and_rank() {
+        let results = parse_results(TEST_RESULTS).unwrap();
+        let ranked = score_and_rank(
+            results,
+            CompetitionCategory::A,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        );
+        assert_eq!(ranked.len(), 8);
+
+        // Best points first, then the unscorable foul, then the results without a mark
+        let points: Vec<f64> = ranked
+            .iter()
+            .filter_map(|r| r.score.as_ref().ok().map(|s| s.points))
+            .collect();
+        assert!(points.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(ranked[5].result.athlete, "Fouler");
+        assert!(ranked[5].score.is_err());
+        assert_eq!(ranked[6].result.athlete, "Scratched");
+        assert_eq!(
+            ranked[6].score,
+            Err("DNS (did not start), not scored".to_string())
+        );
+        assert_eq!(ranked[7].result.status, Some(ResultStatus::NoMark));
+    }
+}
@@ -0,0 +1,83 @@
+// src/importers/result_status.rs
+//! Non-performance markers written in place of a mark in results files.
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Why a result has no mark. Such results are listed but never scored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResultStatus {
+    DidNotStart,
+    DidNotFinish,
+    Disqualified,
+    /// No valid mark in a field event, or a dash in the results
+    NoMark,
+    /// No height cleared in the high jump or pole vault
+    NoHeight,
+}
+
+impl ResultStatus {
+    /// Recognises a marker written in place of a mark, e.g. "DNF", "DQ TR17.3.1" or "—".
+    /// Returns `None` for anything that could be a performance.
+    pub fn from_mark(mark: &str) -> Option<Self> {
+        let code = mark
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_end_matches('.')
+            .to_uppercase();
+        match code.as_str() {
+            "" | "-" | "—" | "–" => Some(ResultStatus::NoMark),
+            "DNS" => Some(ResultStatus::DidNotStart),
+            "DNF" => Some(ResultStatus::DidNotFinish),
+            "DQ" | "DSQ" => Some(ResultStatus::Disqualified),
+            "NM" => Some(ResultStatus::NoMark),
+            "NH" => Some(ResultStatus::NoHeight),
+            _ => None,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ResultStatus::DidNotStart => "did not start",
+            ResultStatus::DidNotFinish => "did not finish",
+            ResultStatus::Disqualified => "disqualified",
+            ResultStatus::NoMark => "no mark",
+            ResultStatus::NoHeight => "no height",
+        }
+    }
+}
+
+impl fmt::Display for ResultStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            ResultStatus::DidNotStart => "DNS",
+            ResultStatus::DidNotFinish => "DNF",
+            ResultStatus::Disqualified => "DQ",
+            ResultStatus::NoMark => "NM",
+            ResultStatus::NoHeight => "NH",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_mark() {
+        assert_eq!(
+            ResultStatus::from_mark("dnf"),
+            Some(ResultStatus::DidNotFinish)
+        );
+        assert_eq!(
+            ResultStatus::from_mark("DQ TR17.3.1"),
+            Some(ResultStatus::Disqualified)
+        );
+        assert_eq!(ResultStatus::from_mark(" — "), Some(ResultStatus::NoMark));
+        assert_eq!(ResultStatus::from_mark("NH"), Some(ResultStatus::NoHeight));
+        assert_eq!(ResultStatus::from_mark("10.05"), None);
+        // A single foul is an attempt, not a final status
+        assert_eq!(ResultStatus::from_mark("X"), None);
+    }
+}
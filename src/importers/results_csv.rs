@@ -0,0 +1,318 @@
+// src/importers/results_csv.rs
+//! Importer for results in a CSV file, one row per result, for scoring a whole meet at once.
+//!
+//! The first row names the columns (case-insensitive, in any order):
+//!
+//! ```csv
+//! Athlete,Gender,Event,Mark,Place,Wind,Round
+//! A. Runner,M,100m,10.12,1,+0.8,F
+//! ```
+//!
+//! `Gender`, `Event` and `Mark` are required. The event can be its name ("Long Jump") or
+//! its World Athletics code ("LJ"). Other columns are optional and any unknown ones are
+//! kept as they are, so the scored file can be written back with the scores appended.
+use super::open_track::{
+    event_from_code, gender_from_code, parse_wind, round_from_code, score_result, ImportedResult,
+};
+use super::result_status::ResultStatus;
+use crate::exporters::csv::to_csv;
+use crate::models::*;
+use crate::scoring_logic::placement_score::{PlacementScoreCalcInput, RoundType};
+
+/// Size of the final assumed for semifinal placing scores, as the file doesn't say
+const DEFAULT_SIZE_OF_FINAL: i32 = 8;
+
+/// Splits CSV text into rows of fields (RFC 4180: quoted fields may contain delimiters,
+/// doubled quotes and line breaks). Blank lines are skipped.
+pub fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    let mut line = 1;
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n' | '\r', false) => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                } else {
+                    row.clear();
+                }
+                line += 1;
+            }
+            (c, _) => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+    if in_quotes {
+        return Err(format!("Unterminated quoted field at line {}", line));
+    }
+    row.push(field);
+    if row.iter().any(|f| !f.is_empty()) {
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Where each known column is in the file
+#[derive(Debug, Default)]
+struct Columns {
+    athlete: Option<usize>,
+    gender: usize,
+    event: usize,
+    mark: usize,
+    place: Option<usize>,
+    wind: Option<usize>,
+    round: Option<usize>,
+    date: Option<usize>,
+    venue: Option<usize>,
+}
+
+impl Columns {
+    fn from_headers(headers: &[String]) -> Result<Self, String> {
+        let find = |names: &[&str]| {
+            headers
+                .iter()
+                .position(|h| names.contains(&h.trim().to_lowercase().as_str()))
+        };
+        let required = |names: &[&str]| {
+            find(names).ok_or_else(|| format!("Missing a \"{}\" column", names[0]))
+        };
+        Ok(Columns {
+            athlete: find(&["athlete", "name"]),
+            gender: required(&["gender", "sex"])?,
+            event: required(&["event", "discipline"])?,
+            mark: required(&["mark", "performance", "result", "time"])?,
+            place: find(&["place", "pos", "position"]),
+            wind: find(&["wind"]),
+            round: find(&["round"]),
+            date: find(&["date"]),
+            venue: find(&["venue"]),
+        })
+    }
+}
+
+/// Reads one data row into a result
+fn result_from_row(columns: &Columns, row: &[String]) -> Result<ImportedResult, String> {
+    let field = |index: usize| row.get(index).map(|f| f.trim()).unwrap_or_default();
+    let optional = |index: Option<usize>| {
+        index
+            .map(field)
+            .filter(|f| !f.is_empty())
+            .map(str::to_string)
+    };
+
+    let event_name = field(columns.event);
+    let event = event_name
+        .parse::<Event>()
+        .ok()
+        .or_else(|| event_from_code(event_name, false))
+        .ok_or_else(|| format!("Unknown event: {}", event_name))?;
+    let gender = Gender::from_string(&field(columns.gender).to_lowercase())
+        .or_else(|| gender_from_code(field(columns.gender)))
+        .ok_or_else(|| format!("Unknown gender: {}", field(columns.gender)))?;
+    let place = match optional(columns.place) {
+        Some(place) => Some(
+            place
+                .trim_end_matches('.')
+                .parse::<i32>()
+                .map_err(|_| format!("Invalid place: {}", place))?,
+        ),
+        None => None,
+    };
+    let mark = field(columns.mark).to_string();
+
+    Ok(ImportedResult {
+        athlete: optional(columns.athlete).unwrap_or_default(),
+        gender,
+        event,
+        round: optional(columns.round)
+            .map(|round| round_from_code(&round))
+            .unwrap_or(RoundType::Final),
+        place,
+        status: ResultStatus::from_mark(&mark),
+        mark,
+        wind_speed: parse_wind(optional(columns.wind).as_deref()),
+        size_of_final: DEFAULT_SIZE_OF_FINAL,
+        qualified_to_final: false,
+        date: optional(columns.date),
+        venue: optional(columns.venue),
+    })
+}
+
+/// A CSV row with its score
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredRow {
+    /// The row's fields as they were in the file
+    pub fields: Vec<String>,
+    /// The result read from the row, unless it couldn't be read
+    pub result: Option<ImportedResult>,
+    pub score: Result<WorldAthleticsScoreOutput, String>,
+}
+
+/// A scored CSV file, in the file's row order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredCsv {
+    pub headers: Vec<String>,
+    /// The category every row was scored at
+    pub competition_category: CompetitionCategory,
+    pub rows: Vec<ScoredRow>,
+}
+
+impl ScoredCsv {
+    pub fn scored_count(&self) -> usize {
+        self.rows.iter().filter(|row| row.score.is_ok()).count()
+    }
+
+    /// The file with Points, Result Score, Placing Score and Notes columns appended
+    pub fn to_csv(&self) -> String {
+        let mut headers = self.headers.clone();
+        headers.extend(
+            ["Points", "Result Score", "Placing Score", "Notes"]
+                .iter()
+                .map(|h| h.to_string()),
+        );
+        let width = self.headers.len();
+        let rows = self.rows.iter().map(|row| {
+            let mut fields = row.fields.clone();
+            fields.resize(width, String::new());
+            match &row.score {
+                Ok(score) => fields.extend([
+                    format!("{:.0}", score.points),
+                    format!("{:.0}", score.result_score),
+                    score.placement_score.to_string(),
+                    String::new(),
+                ]),
+                Err(e) => fields.extend([String::new(), String::new(), String::new(), e.clone()]),
+            }
+            fields
+        });
+        to_csv(std::iter::once(headers).chain(rows))
+    }
+}
+
+/// Parses and scores every row of a results CSV. Rows that can't be read or scored are
+/// kept with their error.
+pub fn score_results_csv(
+    text: &str,
+    competition_category: CompetitionCategory,
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Result<ScoredCsv, String> {
+    let mut rows = parse_csv(text)?.into_iter();
+    let headers = rows.next().ok_or("The CSV file is empty")?;
+    let columns = Columns::from_headers(&headers)?;
+    let rows = rows
+        .map(|fields| {
+            let result = result_from_row(&columns, &fields);
+            let score = result.as_ref().map_err(String::clone).and_then(|result| {
+                score_result(
+                    result,
+                    competition_category,
+                    result_score_calculator,
+                    placement_score_calculator,
+                )
+            });
+            ScoredRow {
+                fields,
+                result: result.ok(),
+                score,
+            }
+        })
+        .collect();
+    Ok(ScoredCsv {
+        headers,
+        competition_category,
+        rows,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        _event: &Event,
+    ) -> Result<f64, String> {
+        Ok(performance * 100.0)
+    }
+
+    fn mock_placement_score_calculator(input: PlacementScoreCalcInput) -> Option<i32> {
+        (input.place == 1).then_some(100)
+    }
+
+    #[test]
+    fn test_parse_csv() {
+        let rows = parse_csv("a,\"b, c\",\"say \"\"hi\"\"\"\r\n\r\n1,\"two\nlines\",3").unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["a", "b, c", "say \"hi\""],
+                vec!["1", "two\nlines", "3"]
+            ]
+        );
+        assert!(parse_csv("a,\"b").is_err());
+    }
+
+    #[test]
+    fn test_score_results_csv() {
+        let csv = "Athlete,Sex,Event,Mark,Place,Wind,Club\n\
+                   Fast,M,100m,10.00,1,+0.5,Harriers\n\
+                   Jumper,W,LJ,6.50,2,,AC\n\
+                   Scratched,M,100m,DNS,,,\n\
+                   Lost,M,Egg and spoon,1:00,,,\n";
+        let scored = score_results_csv(
+            csv,
+            CompetitionCategory::A,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .unwrap();
+        assert_eq!(scored.rows.len(), 4);
+        assert_eq!(scored.scored_count(), 2);
+        assert_eq!(scored.rows[0].score.as_ref().unwrap().points, 1100.0);
+        // No wind reading in a wind-affected event counts as NWI
+        assert!(scored.rows[1].score.is_ok());
+        assert_eq!(
+            scored.rows[3].score,
+            Err("Unknown event: Egg and spoon".to_string())
+        );
+
+        let output = scored.to_csv();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Athlete,Sex,Event,Mark,Place,Wind,Club,Points,Result Score,Placing Score,Notes"
+        );
+        assert_eq!(lines[1], "Fast,M,100m,10.00,1,+0.5,Harriers,1100,1000,100,");
+        assert_eq!(
+            lines[3],
+            "Scratched,M,100m,DNS,,,,,,,\"DNS (did not start), not scored\""
+        );
+
+        assert!(score_results_csv(
+            "Athlete,Mark\nA,10.00",
+            CompetitionCategory::A,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        )
+        .is_err());
+    }
+}
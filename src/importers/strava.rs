@@ -0,0 +1,182 @@
+// src/importers/strava.rs
+//! Optional Strava integration (enabled with the `strava` feature) that pulls a user's race
+//! activities and maps them to road events.
+//!
+//! Strava's token exchange needs the app's client secret, which can't ship in a browser bundle,
+//! so the authorization code is sent to a small token-exchange endpoint configured at build time:
+//!
+//! - `STRAVA_CLIENT_ID` – the Strava API application's client id
+//! - `STRAVA_TOKEN_EXCHANGE_URL` – accepts `{"code": "..."}` and returns Strava's token response
+use crate::importers::course::{road_race_from_recording, ActivityRecording, RoadRaceResult};
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+
+const STRAVA_AUTHORIZE_URL: &str = "https://www.strava.com/oauth/authorize";
+const STRAVA_API_URL: &str = "https://www.strava.com/api/v3";
+
+/// Strava's `workout_type` for runs tagged as a race
+const RACE_WORKOUT_TYPE: i32 = 1;
+
+pub const STRAVA_CLIENT_ID: Option<&str> = option_env!("STRAVA_CLIENT_ID");
+pub const STRAVA_TOKEN_EXCHANGE_URL: Option<&str> = option_env!("STRAVA_TOKEN_EXCHANGE_URL");
+
+/// The subset of a Strava activity summary used for scoring
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StravaActivity {
+    pub id: u64,
+    pub name: String,
+    pub sport_type: String,
+    /// Distance in meters
+    pub distance: f64,
+    /// Elapsed time in seconds
+    pub elapsed_time: f64,
+    pub workout_type: Option<i32>,
+    pub start_date_local: String,
+}
+
+impl StravaActivity {
+    pub fn is_race(&self) -> bool {
+        self.sport_type == "Run" && self.workout_type == Some(RACE_WORKOUT_TYPE)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct StravaStreams {
+    altitude: Option<StravaStream>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StravaStream {
+    data: Vec<f64>,
+}
+
+#[derive(Serialize)]
+struct TokenExchangeRequest<'a> {
+    code: &'a str,
+}
+
+#[derive(Deserialize)]
+struct TokenExchangeResponse {
+    access_token: String,
+}
+
+/// The Strava authorization page that redirects back to `redirect_uri` with a `code` parameter.
+pub fn authorize_url(client_id: &str, redirect_uri: &str) -> String {
+    url::Url::parse_with_params(
+        STRAVA_AUTHORIZE_URL,
+        &[
+            ("client_id", client_id),
+            ("redirect_uri", redirect_uri),
+            ("response_type", "code"),
+            ("approval_prompt", "auto"),
+            ("scope", "activity:read"),
+        ],
+    )
+    .map(|url| url.to_string())
+    .unwrap_or_else(|_| STRAVA_AUTHORIZE_URL.to_string())
+}
+
+/// Exchanges the authorization code from the OAuth redirect for an access token.
+pub async fn exchange_code(code: &str) -> Result<String, String> {
+    let exchange_url = STRAVA_TOKEN_EXCHANGE_URL
+        .ok_or_else(|| "Strava token exchange URL is not configured".to_string())?;
+    let response = Request::post(exchange_url)
+        .json(&TokenExchangeRequest { code })
+        .map_err(|e| e.to_string())?
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!(
+            "Strava authorization failed ({})",
+            response.status()
+        ));
+    }
+    response
+        .json::<TokenExchangeResponse>()
+        .await
+        .map(|token| token.access_token)
+        .map_err(|e| e.to_string())
+}
+
+async fn get_json<T: for<'de> Deserialize<'de>>(
+    access_token: &str,
+    url: &str,
+) -> Result<T, String> {
+    let response = Request::get(url)
+        .header("Authorization", &format!("Bearer {}", access_token))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!("Strava request failed ({})", response.status()));
+    }
+    response.json::<T>().await.map_err(|e| e.to_string())
+}
+
+/// Fetches the athlete's most recent activities that are tagged as races.
+pub async fn fetch_race_activities(access_token: &str) -> Result<Vec<StravaActivity>, String> {
+    let activities: Vec<StravaActivity> = get_json(
+        access_token,
+        &format!("{}/athlete/activities?per_page=100", STRAVA_API_URL),
+    )
+    .await?;
+    Ok(activities
+        .into_iter()
+        .filter(StravaActivity::is_race)
+        .collect())
+}
+
+/// Fetches an activity's elevation profile and maps it to the nearest road event.
+pub async fn fetch_road_race(
+    access_token: &str,
+    activity: &StravaActivity,
+) -> Result<RoadRaceResult, String> {
+    let streams: StravaStreams = get_json(
+        access_token,
+        &format!(
+            "{}/activities/{}/streams?keys=altitude&key_by_type=true",
+            STRAVA_API_URL, activity.id
+        ),
+    )
+    .await?;
+    road_race_from_recording(&ActivityRecording {
+        distance_meters: activity.distance,
+        elapsed_seconds: activity.elapsed_time,
+        elevation_profile: streams
+            .altitude
+            .map(|stream| stream.data)
+            .unwrap_or_default(),
+    })
+    .ok_or_else(|| format!("{} doesn't match a road event distance", activity.name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_activities() {
+        let json = r#"[
+            {"id": 1, "name": "City Marathon", "sport_type": "Run", "distance": 42310.2,
+             "elapsed_time": 9000, "workout_type": 1, "start_date_local": "2025-04-06T09:00:00Z"},
+            {"id": 2, "name": "Easy run", "sport_type": "Run", "distance": 8000.0,
+             "elapsed_time": 2700, "workout_type": 0, "start_date_local": "2025-04-08T07:00:00Z"},
+            {"id": 3, "name": "Commute", "sport_type": "Ride", "distance": 12000.0,
+             "elapsed_time": 2000, "workout_type": null, "start_date_local": "2025-04-09T08:00:00Z"}
+        ]"#;
+        let activities: Vec<StravaActivity> = serde_json::from_str(json).unwrap();
+        let races: Vec<&StravaActivity> = activities.iter().filter(|a| a.is_race()).collect();
+        assert_eq!(races.len(), 1);
+        assert_eq!(races[0].name, "City Marathon");
+    }
+
+    #[test]
+    fn test_authorize_url() {
+        let url = authorize_url("123", "https://example.com/calc?a=1");
+        assert!(url.starts_with(STRAVA_AUTHORIZE_URL));
+        assert!(url.contains("client_id=123"));
+        assert!(url.contains("redirect_uri=https%3A%2F%2Fexample.com%2Fcalc%3Fa%3D1"));
+        assert!(url.contains("scope=activity%3Aread"));
+    }
+}
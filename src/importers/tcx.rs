@@ -0,0 +1,82 @@
+// src/importers/tcx.rs
+//! Reader for Garmin Training Center XML (TCX) activity files.
+use crate::importers::course::ActivityRecording;
+
+/// Reads the elapsed time and distance summed over every lap, plus the trackpoint elevations.
+pub fn parse_tcx(xml: &str) -> Result<ActivityRecording, String> {
+    let document =
+        roxmltree::Document::parse(xml).map_err(|e| format!("Invalid TCX file: {}", e))?;
+
+    let child_value = |node: roxmltree::Node, name: &str| -> Option<f64> {
+        node.children()
+            .find(|child| child.has_tag_name(name))
+            .and_then(|child| child.text())
+            .and_then(|text| text.trim().parse::<f64>().ok())
+    };
+
+    let laps: Vec<roxmltree::Node> = document
+        .descendants()
+        .filter(|node| node.has_tag_name("Lap"))
+        .collect();
+    if laps.is_empty() {
+        return Err("TCX file contains no laps".to_string());
+    }
+
+    let elapsed_seconds = laps
+        .iter()
+        .filter_map(|lap| child_value(*lap, "TotalTimeSeconds"))
+        .sum();
+    let distance_meters = laps
+        .iter()
+        .filter_map(|lap| child_value(*lap, "DistanceMeters"))
+        .sum();
+    let elevation_profile = document
+        .descendants()
+        .filter(|node| node.has_tag_name("Trackpoint"))
+        .filter_map(|trackpoint| child_value(trackpoint, "AltitudeMeters"))
+        .collect();
+
+    Ok(ActivityRecording {
+        distance_meters,
+        elapsed_seconds,
+        elevation_profile,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tcx() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2">
+              <Activities>
+                <Activity Sport="Running">
+                  <Lap StartTime="2025-04-06T09:00:00Z">
+                    <TotalTimeSeconds>1500.0</TotalTimeSeconds>
+                    <DistanceMeters>5000.0</DistanceMeters>
+                    <Track>
+                      <Trackpoint><AltitudeMeters>120.0</AltitudeMeters><DistanceMeters>0.0</DistanceMeters></Trackpoint>
+                      <Trackpoint><AltitudeMeters>110.0</AltitudeMeters><DistanceMeters>5000.0</DistanceMeters></Trackpoint>
+                    </Track>
+                  </Lap>
+                  <Lap StartTime="2025-04-06T09:25:00Z">
+                    <TotalTimeSeconds>1520.0</TotalTimeSeconds>
+                    <DistanceMeters>5050.0</DistanceMeters>
+                    <Track>
+                      <Trackpoint><AltitudeMeters>100.0</AltitudeMeters></Trackpoint>
+                    </Track>
+                  </Lap>
+                </Activity>
+              </Activities>
+            </TrainingCenterDatabase>"#;
+        let recording = parse_tcx(xml).unwrap();
+        assert_eq!(recording.elapsed_seconds, 3020.0);
+        assert_eq!(recording.distance_meters, 10050.0);
+        assert_eq!(recording.elevation_profile, vec![120.0, 110.0, 100.0]);
+
+        assert!(parse_tcx("<TrainingCenterDatabase/>").is_err());
+        assert!(parse_tcx("not xml").is_err());
+    }
+}
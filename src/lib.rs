@@ -3,22 +3,79 @@ use leptos_meta::*;
 use leptos_router::{components::*, path};
 
 // Modules
-mod components;
-pub mod models;
+pub mod backup;
+pub mod build_info;
+pub mod components;
+pub mod error_reporting;
+pub mod exporters;
+pub mod fetch_cache;
+pub mod history;
+pub mod importers;
+pub use wa_points_core::models;
 mod pages;
+pub mod performance_db;
+pub mod prefetch;
+pub mod roster;
 pub mod scoring_logic;
+pub mod service_worker;
+pub mod settings;
+pub mod share_link;
+pub mod startup_timing;
 
 // Top-Level pages
+use crate::components::debug_panel::DebugPanel;
+use crate::components::nav_bar::NavBar;
+use crate::components::scoring_providers::{provide_scoring_providers, ScoringProviders};
+use crate::components::theme_toggle::ThemeToggle;
+use crate::components::unit_preferences_menu::UnitPreferencesMenu;
+use crate::components::version_indicator::VersionIndicator;
+use crate::pages::combined_events::CombinedEvents;
+use crate::pages::dashboard::Dashboard;
 use crate::pages::home::Home;
+use crate::pages::import::Import;
+use crate::pages::leaderboard::Leaderboard;
+use crate::pages::placement_tables::PlacementTables;
+use crate::pages::predictor::Predictor;
+use crate::pages::profile::Profile;
+use crate::pages::program::Program;
+use crate::pages::progression::Progression;
+use crate::pages::rankings::Rankings;
+use crate::pages::report::Report;
+use crate::pages::results::Results;
+use crate::pages::roster::Roster;
+use crate::pages::score_report::ScoreReportPage;
+use crate::pages::settings::Settings;
+use crate::pages::tables::Tables;
+use crate::pages::target::Target;
 
 /// An app router which renders the homepage and handles 404's
 #[component]
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    // The official scoring tables, which tests and previews can override
+    provide_scoring_providers(ScoringProviders::default());
+    settings::provide_settings();
+    history::provide_history();
+    let pages_base = window()
+        .location()
+        .pathname()
+        .is_ok_and(|path| path.starts_with(components::nav_bar::PAGES_BASE));
+    scoring_logic::remote_tables::provide_remote_tables(if pages_base {
+        components::nav_bar::PAGES_BASE
+    } else {
+        ""
+    });
+    let settings = settings::use_settings();
+    let dark = settings::use_dark_theme();
 
     view! {
-        <Html attr:lang="en" attr:dir="ltr" attr:data-theme="light" attr:class="h-full" />
+        <Html
+            attr:lang=move || settings.with(|s| s.locale.clone())
+            attr:dir="ltr"
+            attr:data-theme=move || if dark.get() { "dark" } else { "light" }
+            attr:class=move || if dark.get() { "h-full dark" } else { "h-full" }
+        />
 
         // sets the document title
         <Title text="World Athletics Points Calculator" />
@@ -26,27 +83,78 @@ pub fn App() -> impl IntoView {
         // injects metadata in the <head> of the page
         <Meta charset="UTF-8" />
         <Meta name="viewport" content="width=device-width, initial-scale=1.0" />
+        <Meta name="theme-color" content="#111827" />
+        <Link
+            rel="manifest"
+            href=if pages_base {
+                format!("{}/manifest.webmanifest", components::nav_bar::PAGES_BASE)
+            } else {
+                "/manifest.webmanifest".to_string()
+            }
+        />
 
         // <Body class="h-full bg-white text-gray-900 antialiased" />
 
         <Router>
             <div class="min-h-screen flex flex-col">
-                <header class="bg-gray-900 text-white py-4 shadow-md">
-                    <div class="container mx-auto px-4">
+                <header class="bg-gray-900 text-white py-4 shadow-md print:hidden">
+                    <div class="container mx-auto px-4 flex items-center justify-between">
                         <h1 class="text-2xl font-bold">World Athletics Points Calculator</h1>
+                        <div class="flex items-center gap-6">
+                            <NavBar />
+                            <UnitPreferencesMenu />
+                            <ThemeToggle dark />
+                        </div>
                     </div>
                 </header>
 
+                <DebugPanel />
+
                 <main class="flex-grow">
                     <Routes fallback=|| view! { NotFound }>
                         <Route path=path!("/") view=Home />
                         <Route path=path!("/world_athletics_points_calculator") view=Home />
+                        <Route path=path!("/tables") view=Tables />
+                        <Route path=path!("/world_athletics_points_calculator/tables") view=Tables />
+                        <Route path=path!("/placing") view=PlacementTables />
+                        <Route path=path!("/world_athletics_points_calculator/placing") view=PlacementTables />
+                        <Route path=path!("/target") view=Target />
+                        <Route path=path!("/world_athletics_points_calculator/target") view=Target />
+                        <Route path=path!("/predictor") view=Predictor />
+                        <Route path=path!("/world_athletics_points_calculator/predictor") view=Predictor />
+                        <Route path=path!("/rankings") view=Rankings />
+                        <Route path=path!("/world_athletics_points_calculator/rankings") view=Rankings />
+                        <Route path=path!("/roster") view=Roster />
+                        <Route path=path!("/world_athletics_points_calculator/roster") view=Roster />
+                        <Route path=path!("/score-report") view=ScoreReportPage />
+                        <Route path=path!("/world_athletics_points_calculator/score-report") view=ScoreReportPage />
+                        <Route path=path!("/combined-events") view=CombinedEvents />
+                        <Route path=path!("/world_athletics_points_calculator/combined-events") view=CombinedEvents />
+                        <Route path=path!("/import") view=Import />
+                        <Route path=path!("/world_athletics_points_calculator/import") view=Import />
+                        <Route path=path!("/leaderboard") view=Leaderboard />
+                        <Route path=path!("/world_athletics_points_calculator/leaderboard") view=Leaderboard />
+                        <Route path=path!("/results") view=Results />
+                        <Route path=path!("/world_athletics_points_calculator/results") view=Results />
+                        <Route path=path!("/dashboard") view=Dashboard />
+                        <Route path=path!("/world_athletics_points_calculator/dashboard") view=Dashboard />
+                        <Route path=path!("/report") view=Report />
+                        <Route path=path!("/world_athletics_points_calculator/report") view=Report />
+                        <Route path=path!("/progression") view=Progression />
+                        <Route path=path!("/world_athletics_points_calculator/progression") view=Progression />
+                        <Route path=path!("/profile") view=Profile />
+                        <Route path=path!("/world_athletics_points_calculator/profile") view=Profile />
+                        <Route path=path!("/program") view=Program />
+                        <Route path=path!("/world_athletics_points_calculator/program") view=Program />
+                        <Route path=path!("/settings") view=Settings />
+                        <Route path=path!("/world_athletics_points_calculator/settings") view=Settings />
                     </Routes>
                 </main>
 
                 <footer class="bg-gray-100 py-4 border-t border-gray-200">
                     <div class="container mx-auto px-4 text-center text-gray-600">
                         <p>2025 World Athletics Points Calculator</p>
+                        <VersionIndicator />
                     </div>
                 </footer>
             </div>
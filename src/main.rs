@@ -1,23 +1,24 @@
 use leptos::prelude::*;
-use world_athletics_points_calulator::scoring_logic::coefficients::load_coefficients;
-use world_athletics_points_calulator::scoring_logic::placement_score::init_placement_score_calculator;
+use world_athletics_points_calulator::error_reporting;
+use world_athletics_points_calulator::prefetch::prefetch_when_idle;
+use world_athletics_points_calulator::service_worker::register_service_worker;
+use world_athletics_points_calulator::startup_timing::{measure, record_first_render};
 use world_athletics_points_calulator::App;
 
 fn main() {
     // set up logging
     _ = console_log::init_with_level(log::Level::Debug);
-    console_error_panic_hook::set_once();
-    match load_coefficients() {
-        Ok(_) => log::debug!("Coefficients loaded successfully."),
-        Err(e) => log::error!("Failed to load coefficients: {}", e),
-    }
+    error_reporting::init();
 
-    match init_placement_score_calculator() {
-        Ok(_) => log::debug!("Placement scores loaded successfully."),
-        Err(e) => log::error!("Failed to load placement scores: {}", e),
-    }
-
-    mount_to_body(|| {
-        view! { <App /> }
-    })
+    // The scoring tables are generated at build time, so there's nothing to load. Placement
+    // tables are indexed on demand, when the placement section is first enabled
+    measure("mount", || {
+        mount_to_body(|| {
+            view! { <App /> }
+        })
+    });
+    record_first_render();
+    prefetch_when_idle();
+    // Cache the app so it keeps working without a connection
+    register_service_worker();
 }
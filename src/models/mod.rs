@@ -1,2 +0,0 @@
-pub mod performance;
-pub use performance::*;
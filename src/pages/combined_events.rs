@@ -0,0 +1,275 @@
+use crate::components::inputs::PlacementInfoSection;
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{
+    CombinedEvent, CompetitionCategory, Event, Gender, Performance, PlacementInfo,
+    WorldAthleticsScoreInput,
+};
+use crate::scoring_logic::calculator::calculate_world_athletics_score;
+use crate::scoring_logic::combined_events::{combined_events_tables, score_combined_event};
+use crate::scoring_logic::placement_score::RoundType;
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const INPUT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// The combined events with bundled discipline tables for a gender
+fn combined_events(gender: Gender) -> Vec<Event> {
+    CombinedEvent::iter()
+        .map(Event::CombinedEvents)
+        .filter(|event| {
+            combined_events_tables()
+                .disciplines(gender, event)
+                .is_some()
+        })
+        .collect()
+}
+
+/// Enter every discipline of a decathlon, heptathlon or pentathlon to get the running
+/// combined total, then the World Athletics result and placing score for the total
+#[component]
+pub fn CombinedEvents() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (gender, set_gender) = signal(Gender::Men);
+    let (event, set_event) = signal(Event::CombinedEvents(CombinedEvent::Dec));
+    // The written marks, in competition order
+    let (marks, set_marks) = signal(Vec::<String>::new());
+
+    let (include_placement, set_include_placement) = signal(false);
+    let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
+    let (place, set_place) = signal(1);
+    let (tied_with, set_tied_with) = signal(0);
+    let (round, set_round) = signal(RoundType::Final);
+    let (size_of_final, set_size_of_final) = signal(8);
+    let (qualified_to_final, set_qualified_to_final) = signal(false);
+    let (main_event, set_main_event) = signal(false);
+
+    let disciplines = Memo::new(move |_| {
+        combined_events_tables()
+            .disciplines(gender.get(), &event.get())
+            .map(<[_]>::to_vec)
+            .unwrap_or_default()
+    });
+    // Start over whenever the combined event changes
+    Effect::new(move |_| set_marks.set(vec![String::new(); disciplines.with(Vec::len)]));
+
+    let parsed = Memo::new(move |_| {
+        disciplines.with(|disciplines| {
+            marks.with(|marks| {
+                disciplines
+                    .iter()
+                    .enumerate()
+                    .map(|(index, discipline)| {
+                        let mark = marks.get(index).map(|m| m.trim()).unwrap_or_default();
+                        (!mark.is_empty()).then(|| discipline.parse_mark(mark))
+                    })
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+    let score = Memo::new(move |_| {
+        let marks: Vec<Option<f64>> = parsed.with(|parsed| {
+            parsed
+                .iter()
+                .map(|mark| mark.as_ref().and_then(|mark| mark.as_ref().ok().copied()))
+                .collect()
+        });
+        disciplines.with(|disciplines| score_combined_event(disciplines, &marks).ok())
+    });
+    let world_athletics_score = Memo::new(move |_| {
+        let total = score.with(|score| score.as_ref().map(|score| score.total))?;
+        let placement_info = include_placement.get().then(|| PlacementInfo {
+            competition_category: competition_category.get(),
+            place: place.get(),
+            round: round.get(),
+            size_of_final: size_of_final.get(),
+            qualified_to_final: qualified_to_final.get(),
+            tied_with: tied_with.get(),
+            main_event: main_event.get(),
+        });
+        Some(calculate_world_athletics_score(
+            WorldAthleticsScoreInput {
+                gender: gender.get(),
+                event: event.get(),
+                performance: Performance::Points(total.max(0) as u32),
+                wind_speed: None,
+                net_downhill: None,
+                venue_altitude: None,
+                placement_info,
+                table_edition: None,
+            },
+            providers.result_score_calculator,
+            providers.placement_score_calculator,
+        ))
+    });
+
+    view! {
+        <Title text="Combined Events - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Combined Events"</h2>
+                <p class="text-sm text-gray-500">
+                    "Enter each discipline's result to build the combined total, then get its World Athletics score."
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Gender"
+                        <select
+                            class=INPUT_CLASS
+                            on:change=move |ev| {
+                                if let Some(g) = Gender::from_string(&event_target_value(&ev)) {
+                                    set_gender.set(g);
+                                    let events = combined_events(g);
+                                    if !events.contains(&event.get_untracked()) {
+                                        if let Some(first) = events.into_iter().next() {
+                                            set_event.set(first);
+                                        }
+                                    }
+                                }
+                            }
+                        >
+                            {Gender::iter()
+                                .map(|g| view! { <option value=g.to_string() selected=move || gender.get() == g>{g.to_string()}</option> })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Event"
+                        <select
+                            class=INPUT_CLASS
+                            on:change=move |ev| {
+                                if let Some(e) = Event::from_string(&event_target_value(&ev)) {
+                                    set_event.set(e);
+                                }
+                            }
+                        >
+                            {move || {
+                                combined_events(gender.get())
+                                    .into_iter()
+                                    .map(|e| {
+                                        let selected = e == event.get();
+                                        view! { <option value=e.to_string() selected=selected>{e.to_string()}</option> }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                </div>
+
+                <table class="min-w-full text-sm text-left">
+                    <thead class="border-b border-gray-200 text-gray-600">
+                        <tr>
+                            <th class="py-2 pr-4">"Discipline"</th>
+                            <th class="py-2 pr-4">"Mark"</th>
+                            <th class="py-2 pr-4 text-right">"Points"</th>
+                            <th class="py-2 text-right">"Total"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            disciplines
+                                .get()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, discipline)| {
+                                    let points = move || {
+                                        score.with(|score| {
+                                            score.as_ref().map(|score| score.disciplines[index].points)
+                                        })
+                                    };
+                                    // The running total through this discipline
+                                    let running_total = move || {
+                                        score.with(|score| {
+                                            score.as_ref().map(|score| {
+                                                score.disciplines[..=index].iter().map(|d| d.points).sum::<i32>()
+                                            })
+                                        })
+                                    };
+                                    let error = move || {
+                                        parsed.with(|parsed| {
+                                            parsed.get(index).cloned().flatten().and_then(Result::err)
+                                        })
+                                    };
+                                    view! {
+                                        <tr class="border-b border-gray-100">
+                                            <td class="py-2 pr-4">{discipline.discipline.clone()}</td>
+                                            <td class="py-2 pr-4">
+                                                <input
+                                                    type="text"
+                                                    class=INPUT_CLASS
+                                                    aria-label=format!("{} mark", discipline.discipline)
+                                                    prop:value=move || marks.with(|m| m.get(index).cloned().unwrap_or_default())
+                                                    on:input=move |ev| {
+                                                        let value = event_target_value(&ev);
+                                                        set_marks.update(|m| {
+                                                            if let Some(mark) = m.get_mut(index) {
+                                                                *mark = value;
+                                                            }
+                                                        });
+                                                    }
+                                                />
+                                                {move || error().map(|e| view! { <p class="text-xs text-red-600">{e}</p> })}
+                                            </td>
+                                            <td class="py-2 pr-4 text-right">{points}</td>
+                                            <td class="py-2 text-right text-gray-600">{running_total}</td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+
+                <PlacementInfoSection
+                    include_placement=include_placement
+                    set_include_placement=set_include_placement
+                    competition_category=competition_category
+                    set_competition_category=set_competition_category
+                    place=place
+                    set_place=set_place
+                    tied_with=tied_with
+                    set_tied_with=set_tied_with
+                    round=round
+                    set_round=set_round
+                    size_of_final=size_of_final
+                    set_size_of_final=set_size_of_final
+                    qualified_to_final=qualified_to_final
+                    set_qualified_to_final=set_qualified_to_final
+                    main_event=main_event
+                    set_main_event=set_main_event
+                    event=event
+                />
+
+                <div class="p-4 bg-gray-50 rounded-lg border border-gray-200 text-center space-y-1">
+                    <h3 class="text-2xl font-bold text-gray-800">
+                        {move || {
+                            format!(
+                                "{}: {} points",
+                                event.get(),
+                                score.with(|score| score.as_ref().map(|s| s.total).unwrap_or_default()),
+                            )
+                        }}
+                    </h3>
+                    {move || match world_athletics_score.get() {
+                        Some(Ok(wa)) => {
+                            view! {
+                                <p class="text-gray-700">
+                                    {format!(
+                                        "World Athletics score: {:.0} (result score {:.0}, placing score {})",
+                                        wa.points,
+                                        wa.result_score,
+                                        wa.placement_score,
+                                    )}
+                                </p>
+                            }
+                                .into_any()
+                        }
+                        Some(Err(e)) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                        None => ().into_any(),
+                    }}
+                </div>
+            </div>
+        </main>
+    }
+}
@@ -0,0 +1,149 @@
+use crate::components::ranking_calendar::RankingCalendar;
+use crate::models::Event;
+use crate::performance_db::{use_performances_changed, PerformanceDb, StoredPerformance};
+use crate::scoring_logic::season_report::{
+    athletes, next_target, season_report, seasons, SeasonReport, TOP_RESULTS,
+};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_location;
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// Coach's overview: a card per stored athlete for the latest season, linking to their report
+#[component]
+pub fn Dashboard() -> impl IntoView {
+    let location = use_location();
+    let (records, set_records) = signal(Vec::<StoredPerformance>::new());
+    let (error, set_error) = signal(None::<String>);
+    let (event, set_event) = signal(Event::default());
+
+    let changes = use_performances_changed();
+
+    Effect::new(move |_| {
+        changes.track();
+        spawn_local(async move {
+            let loaded = match PerformanceDb::open().await {
+                Ok(db) => db.all().await,
+                Err(e) => Err(e),
+            };
+            match loaded {
+                Ok(all) => set_records.set(all),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let reports = Memo::new(move |_| {
+        records.with(|records| {
+            let Some(season) = seasons(records).into_iter().next() else {
+                return Vec::new();
+            };
+            athletes(records)
+                .iter()
+                .map(|athlete| season_report(athlete, &season, records))
+                .collect::<Vec<_>>()
+        })
+    });
+    // The report page sits next to this one, under the same prefix
+    let report_href = move |report: &SeasonReport| {
+        let pathname = location.pathname.get_untracked();
+        let base = pathname.trim_end_matches('/').trim_end_matches("dashboard");
+        format!(
+            "{}report?athlete={}&season={}",
+            base,
+            js_sys::encode_uri_component(&report.athlete),
+            js_sys::encode_uri_component(&report.season),
+        )
+    };
+
+    view! {
+        <Title text="Coach Dashboard - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-6xl space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Coach Dashboard"</h2>
+                {move || error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+                <Show
+                    when=move || !reports.with(Vec::is_empty)
+                    fallback=|| {
+                        view! {
+                            <p class="text-sm text-gray-500 italic">
+                                "No stored athletes yet. Save results from the Import page to see them here."
+                            </p>
+                        }
+                    }
+                >
+                    <div class="grid grid-cols-1 sm:grid-cols-2 lg:grid-cols-3 gap-4">
+                        {move || {
+                            reports
+                                .get()
+                                .into_iter()
+                                .map(|report| {
+                                    let href = report_href(&report);
+                                    view! { <AthleteCard report=report href=href /> }
+                                })
+                                .collect_view()
+                        }}
+                    </div>
+                </Show>
+
+                <div class="max-w-xl space-y-2">
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Event"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                if let Some(selected) = Event::from_string(&event_target_value(&ev)) {
+                                    set_event.set(selected);
+                                }
+                            }
+                        >
+                            {Event::all_variants()
+                                .into_iter()
+                                .map(|e| {
+                                    let selected = e == event.get_untracked();
+                                    view! { <option value=e.to_string() selected=selected>{e.to_string()}</option> }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <RankingCalendar event=event />
+                </div>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn AthleteCard(report: SeasonReport, href: String) -> impl IntoView {
+    let season_best = report.best.as_ref().and_then(|best| best.points);
+    let row = |label: String, value: String| {
+        view! {
+            <div class="flex justify-between text-sm">
+                <dt class="text-gray-500">{label}</dt>
+                <dd class="font-medium text-gray-900">{value}</dd>
+            </div>
+        }
+    };
+    let points = |points: Option<f64>| {
+        points
+            .map(|points| format!("{:.0}", points))
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    view! {
+        <A href=href attr:class="block p-4 bg-white rounded-lg shadow-sm border border-gray-200 hover:border-gray-400">
+            <h3 class="text-lg font-semibold text-gray-800">{report.athlete.clone()}</h3>
+            <p class="text-xs text-gray-500 mb-2">
+                {format!("{} season, {} results", report.season, report.results)}
+            </p>
+            <dl class="space-y-1">
+                {row("Season best".to_string(), points(season_best))}
+                {row(format!("Top {} average", TOP_RESULTS), points(report.top_average))}
+                {row("Next target".to_string(), points(season_best.map(next_target)))}
+            </dl>
+        </A>
+    }
+}
@@ -1,10 +1,12 @@
 use crate::components::world_athletics_score_form::WorldAthleticsScoreForm;
+use crate::settings::{use_density, Density};
 use leptos::prelude::*;
 use leptos_meta::*;
 
 /// Default Home Page
 #[component]
 pub fn Home() -> impl IntoView {
+    let density = use_density();
     view! {
         <ErrorBoundary fallback=|errors| {
             view! {
@@ -28,8 +30,14 @@ pub fn Home() -> impl IntoView {
             }
         }>
             <Title text="World Athletics Points Calculator" />
-            <main class="min-h-screen bg-white flex flex-col items-center justify-center p-4">
-                <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200">
+            <main class=move || match density.get() {
+                Density::Comfortable => "min-h-screen bg-white flex flex-col items-center justify-center p-4",
+                Density::Compact => "min-h-screen bg-white flex flex-col items-center justify-start p-1",
+            }>
+                <div class=move || match density.get() {
+                    Density::Comfortable => "w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200",
+                    Density::Compact => "w-full max-w-2xl bg-white rounded-lg shadow-sm p-3 border border-gray-200",
+                }>
                     <WorldAthleticsScoreForm />
                 </div>
             </main>
@@ -0,0 +1,180 @@
+use crate::components::csv_import::CsvImport;
+use crate::components::file_upload::FileUpload;
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::exporters::csv::csv_data_url;
+use crate::exporters::results_export::{export_csv, scored_result_rows};
+use crate::importers::open_track::{parse_results, score_and_rank, ScoredResult};
+use crate::models::CompetitionCategory;
+use crate::performance_db::{PerformanceDb, StoredPerformance};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+/// Scores and ranks every result in an uploaded competition results file
+#[component]
+pub fn Import() -> impl IntoView {
+    let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
+    let (file_contents, set_file_contents) = signal(None::<String>);
+    let providers = use_scoring_providers();
+    let (save_message, set_save_message) = signal(None::<Result<String, String>>);
+
+    let ranked = Memo::new(move |_| {
+        file_contents.get().map(|json| {
+            parse_results(&json).map(|results| {
+                score_and_rank(
+                    results,
+                    competition_category.get(),
+                    providers.result_score_calculator,
+                    providers.placement_score_calculator,
+                )
+            })
+        })
+    });
+
+    view! {
+        <Title text="Import Results - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-5xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Import Results"</h2>
+                <p class="text-sm text-gray-500">
+                    "Upload an OpenTrack / World Athletics results file (JSON) to score and rank every result in the competition."
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+                    <label for="import_category" class="text-gray-800 font-medium">
+                        "Competition Category:"
+                    </label>
+                    <select
+                        id="import_category"
+                        class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                        on:change=move |ev| {
+                            if let Some(category) = CompetitionCategory::from_string(&event_target_value(&ev)) {
+                                set_competition_category.set(category);
+                            }
+                        }
+                    >
+                        {CompetitionCategory::iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=c.to_string() selected=move || competition_category.get() == c>
+                                        {c.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <FileUpload
+                    accept=".json,application/json"
+                    on_load=Callback::new(move |text| set_file_contents.set(Some(text)))
+                />
+
+                {move || match ranked.get() {
+                    None => ().into_any(),
+                    Some(Err(e)) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    Some(Ok(results)) => {
+                        let category = competition_category.get();
+                        let records: Vec<StoredPerformance> = results
+                            .iter()
+                            .map(|scored| StoredPerformance::from_scored(scored, category))
+                            .collect();
+                        let csv_href = csv_data_url(&export_csv(&scored_result_rows(&results, category)));
+                        let save = move |_| {
+                            let records = records.clone();
+                            spawn_local(async move {
+                                let saved = match PerformanceDb::open().await {
+                                    Ok(db) => db.put_all(&records).await,
+                                    Err(e) => Err(e),
+                                };
+                                set_save_message
+                                    .set(Some(saved.map(|_| format!("Saved {} results", records.len()))));
+                            });
+                        };
+                        view! {
+                            <div class="flex items-center gap-3">
+                                <button
+                                    type="button"
+                                    class="px-3 py-2 text-sm bg-gray-900 text-white rounded-md hover:bg-gray-800"
+                                    on:click=save
+                                >
+                                    "Save to database"
+                                </button>
+                                <a
+                                    class="text-sm text-gray-700 underline hover:text-gray-900"
+                                    href=csv_href
+                                    download="results.csv"
+                                >
+                                    "Download CSV"
+                                </a>
+                                {move || match save_message.get() {
+                                    Some(Ok(message)) => view! { <span class="text-sm text-green-700">{message}</span> }.into_any(),
+                                    Some(Err(e)) => view! { <span class="text-sm text-red-600">{e}</span> }.into_any(),
+                                    None => ().into_any(),
+                                }}
+                            </div>
+                            <ResultsTable results=results />
+                        }
+                            .into_any()
+                    }
+                }}
+
+                <CsvImport competition_category=competition_category />
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn ResultsTable(results: Vec<ScoredResult>) -> impl IntoView {
+    view! {
+        <div class="overflow-x-auto">
+            <table class="min-w-full text-sm text-left">
+                <thead class="border-b border-gray-200 text-gray-600">
+                    <tr>
+                        <th class="py-2 pr-4">"Rank"</th>
+                        <th class="py-2 pr-4">"Athlete"</th>
+                        <th class="py-2 pr-4">"Event"</th>
+                        <th class="py-2 pr-4">"Gender"</th>
+                        <th class="py-2 pr-4">"Round"</th>
+                        <th class="py-2 pr-4">"Place"</th>
+                        <th class="py-2 pr-4">"Mark"</th>
+                        <th class="py-2 pr-4">"Points"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {results
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, scored)| {
+                            let result = scored.result;
+                            let (rank, points) = match scored.score {
+                                Ok(score) => ((index + 1).to_string(), format!("{:.0}", score.points)),
+                                // Results without a mark show their status where the rank would be
+                                Err(e) => (
+                                    result.status.map(|status| status.to_string()).unwrap_or_else(|| "-".to_string()),
+                                    e,
+                                ),
+                            };
+                            view! {
+                                <tr class="border-b border-gray-100">
+                                    <td class="py-2 pr-4">{rank}</td>
+                                    <td class="py-2 pr-4">{result.athlete}</td>
+                                    <td class="py-2 pr-4">{result.event.to_string()}</td>
+                                    <td class="py-2 pr-4">{result.gender.to_string()}</td>
+                                    <td class="py-2 pr-4">{result.round.to_string()}</td>
+                                    <td class="py-2 pr-4">
+                                        {result.place.map(|p| p.to_string()).unwrap_or_default()}
+                                    </td>
+                                    <td class="py-2 pr-4">{result.mark}</td>
+                                    <td class="py-2 pr-4 font-medium">{points}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,184 @@
+use crate::components::file_upload::FileUpload;
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::importers::open_track::{parse_results, score_and_rank, ImportedResult};
+use crate::models::{CompetitionCategory, EventGroup, Gender};
+use crate::scoring_logic::leaderboard::{leaderboard, LeaderboardEntry, LeaderboardFilter};
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// Club leaderboard ranking athletes by their best World Athletics score across every
+/// uploaded results file
+#[component]
+pub fn Leaderboard() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
+    let (results, set_results) = signal(Vec::<ImportedResult>::new());
+    let (import_error, set_import_error) = signal(None::<String>);
+    let (gender, set_gender) = signal(None::<Gender>);
+    let (event_group, set_event_group) = signal(None::<EventGroup>);
+    let (from, set_from) = signal(String::new());
+    let (to, set_to) = signal(String::new());
+
+    let scored = Memo::new(move |_| {
+        score_and_rank(
+            results.get(),
+            competition_category.get(),
+            providers.result_score_calculator,
+            providers.placement_score_calculator,
+        )
+    });
+    let entries = Memo::new(move |_| {
+        let non_empty = |date: String| (!date.is_empty()).then_some(date);
+        let filter = LeaderboardFilter {
+            gender: gender.get(),
+            event_group: event_group.get(),
+            from: non_empty(from.get()),
+            to: non_empty(to.get()),
+        };
+        scored.with(|scored| leaderboard(scored, &filter))
+    });
+
+    let on_load = Callback::new(move |json: String| match parse_results(&json) {
+        Ok(imported) => {
+            set_results.update(|results| results.extend(imported));
+            set_import_error.set(None);
+        }
+        Err(e) => set_import_error.set(Some(e)),
+    });
+
+    view! {
+        <Title text="Leaderboard - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-5xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Leaderboard"</h2>
+                <p class="text-sm text-gray-500">
+                    "Upload one or more OpenTrack / World Athletics results files to rank athletes across events by their best World Athletics score."
+                </p>
+
+                <FileUpload accept=".json,application/json" on_load=on_load />
+                {move || import_error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+                <p class="text-sm text-gray-600">
+                    {move || format!("{} results loaded", results.with(Vec::len))}
+                    <button
+                        type="button"
+                        class="ml-2 underline hover:text-gray-900"
+                        on:click=move |_| set_results.set(Vec::new())
+                    >
+                        "Clear"
+                    </button>
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-5 gap-4">
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Category"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                if let Some(category) = CompetitionCategory::from_string(&event_target_value(&ev)) {
+                                    set_competition_category.set(category);
+                                }
+                            }
+                        >
+                            {CompetitionCategory::iter()
+                                .map(|c| {
+                                    view! {
+                                        <option value=c.to_string() selected=move || competition_category.get() == c>
+                                            {c.to_string()}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Gender"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| set_gender.set(Gender::from_string(&event_target_value(&ev)))
+                        >
+                            <option value="">"All"</option>
+                            {Gender::iter()
+                                .map(|g| view! { <option value=g.to_string()>{g.to_string()}</option> })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Event group"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                set_event_group.set(EventGroup::iter().find(|group| group.to_string() == value));
+                            }
+                        >
+                            <option value="">"All events"</option>
+                            {EventGroup::iter()
+                                .map(|group| view! { <option value=group.to_string()>{group.to_string()}</option> })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "From"
+                        <input type="date" class=SELECT_CLASS on:change=move |ev| set_from.set(event_target_value(&ev)) />
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "To"
+                        <input type="date" class=SELECT_CLASS on:change=move |ev| set_to.set(event_target_value(&ev)) />
+                    </label>
+                </div>
+
+                {move || {
+                    let entries = entries.get();
+                    if entries.is_empty() {
+                        view! { <p class="text-sm text-gray-500 italic">"No scored results match."</p> }.into_any()
+                    } else {
+                        view! { <LeaderboardTable entries=entries /> }.into_any()
+                    }
+                }}
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn LeaderboardTable(entries: Vec<LeaderboardEntry>) -> impl IntoView {
+    view! {
+        <div class="overflow-x-auto">
+            <table class="min-w-full text-sm text-left">
+                <thead class="border-b border-gray-200 text-gray-600">
+                    <tr>
+                        <th class="py-2 pr-4">"Rank"</th>
+                        <th class="py-2 pr-4">"Athlete"</th>
+                        <th class="py-2 pr-4">"Gender"</th>
+                        <th class="py-2 pr-4">"Best event"</th>
+                        <th class="py-2 pr-4">"Mark"</th>
+                        <th class="py-2 pr-4">"Date"</th>
+                        <th class="py-2 pr-4">"Points"</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {entries
+                        .into_iter()
+                        .map(|entry| {
+                            let result = entry.best.result;
+                            view! {
+                                <tr class="border-b border-gray-100">
+                                    <td class="py-2 pr-4">{entry.rank}</td>
+                                    <td class="py-2 pr-4">{result.athlete}</td>
+                                    <td class="py-2 pr-4">{result.gender.to_string()}</td>
+                                    <td class="py-2 pr-4">{result.event.to_string()}</td>
+                                    <td class="py-2 pr-4">{result.mark}</td>
+                                    <td class="py-2 pr-4">{result.date.unwrap_or_default()}</td>
+                                    <td class="py-2 pr-4 font-medium">{format!("{:.0}", entry.points)}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -1,2 +1,19 @@
+pub mod combined_events;
+pub mod dashboard;
 pub mod home;
+pub mod import;
+pub mod leaderboard;
 pub mod not_found;
+pub mod placement_tables;
+pub mod predictor;
+pub mod profile;
+pub mod program;
+pub mod progression;
+pub mod rankings;
+pub mod report;
+pub mod results;
+pub mod roster;
+pub mod score_report;
+pub mod settings;
+pub mod tables;
+pub mod target;
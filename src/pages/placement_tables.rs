@@ -0,0 +1,210 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::scoring_logic::placement_score::{
+    placement_grid, PlacementGrid, PlacementScoreEventGroup, RoundType,
+};
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const SELECT_CLASS: &str = "px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// The placing points tables for every event group, as category × place grids
+#[component]
+pub fn PlacementTables() -> impl IntoView {
+    let providers = use_scoring_providers();
+    // `None` shows every group
+    let (group, set_group) = signal(None::<PlacementScoreEventGroup>);
+    let (round, set_round) = signal(RoundType::Final);
+    let (large_final, set_large_final) = signal(false);
+
+    let groups = move || match group.get() {
+        Some(group) => vec![group],
+        None => PlacementScoreEventGroup::iter().collect(),
+    };
+    // Rounds without placing points anywhere ("Other") aren't worth a table
+    let rounds = move || {
+        RoundType::iter()
+            .filter(|round| {
+                *round != RoundType::Other
+                    && groups().iter().any(|group| group.rounds().contains(round))
+            })
+            .collect::<Vec<_>>()
+    };
+    Effect::new(move |_| {
+        if !rounds().contains(&round.get_untracked()) {
+            set_round.set(RoundType::Final);
+        }
+    });
+
+    let grids = Memo::new(move |_| {
+        let size_of_final = if large_final.get() { 10 } else { 8 };
+        groups()
+            .into_iter()
+            .map(|group| {
+                let grid = placement_grid(
+                    group,
+                    round.get(),
+                    size_of_final,
+                    providers.placement_score_calculator,
+                );
+                (group, grid)
+            })
+            .filter(|(_, grid)| !grid.rows.is_empty())
+            .collect::<Vec<_>>()
+    });
+
+    view! {
+        <Title text="Placing Tables - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-5xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Placing Tables"</h2>
+                <p class="text-sm text-gray-500">
+                    "The placing points on offer at each competition category, by finishing place. Placing points are added to the result score, except in cross country where they are the whole score."
+                </p>
+
+                <div class="flex flex-wrap items-center gap-4 text-sm text-gray-700">
+                    <label class="flex items-center gap-2">
+                        "Event group"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                set_group.set(
+                                    PlacementScoreEventGroup::iter().find(|g| g.to_string() == value),
+                                );
+                            }
+                        >
+                            <option value="" selected=move || group.get().is_none()>
+                                "All groups"
+                            </option>
+                            {PlacementScoreEventGroup::iter()
+                                .map(|g| {
+                                    view! {
+                                        <option value=g.to_string() selected=move || group.get() == Some(g)>
+                                            {g.to_string()}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="flex items-center gap-2">
+                        "Round"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                let value = event_target_value(&ev);
+                                if let Some(selected) = rounds().into_iter().find(|r| r.to_string() == value) {
+                                    set_round.set(selected);
+                                }
+                            }
+                        >
+                            {move || {
+                                rounds()
+                                    .into_iter()
+                                    .map(|r| {
+                                        view! {
+                                            <option value=r.to_string() selected=move || round.get() == r>
+                                                {r.to_string()}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                    <Show when=move || round.get() == RoundType::SemiFinal>
+                        <label class="flex items-center gap-2">
+                            <input
+                                type="checkbox"
+                                prop:checked=move || large_final.get()
+                                on:change=move |ev| set_large_final.set(event_target_checked(&ev))
+                            />
+                            "Final of 10 or more"
+                        </label>
+                    </Show>
+                </div>
+
+                <Show when=move || round.get() != RoundType::Final>
+                    <p class="text-sm text-gray-500">
+                        "Place 1 is what every athlete who qualified for the next round scores; the other places are for those who didn't, by their overall place."
+                    </p>
+                </Show>
+
+                <Show
+                    when=move || !grids.with(Vec::is_empty)
+                    fallback=|| {
+                        view! {
+                            <p class="text-sm text-gray-500 italic">
+                                "No placing points for this round."
+                            </p>
+                        }
+                    }
+                >
+                    {move || {
+                        grids
+                            .get()
+                            .into_iter()
+                            .map(|(group, grid)| view! { <PlacementGridTable group=group grid=grid /> })
+                            .collect_view()
+                    }}
+                </Show>
+            </div>
+        </main>
+    }
+}
+
+/// One event group's grid, with the events it covers
+#[component]
+fn PlacementGridTable(group: PlacementScoreEventGroup, grid: PlacementGrid) -> impl IntoView {
+    let events = group
+        .events()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    view! {
+        <section class="space-y-2">
+            <h3 class="text-lg font-medium text-gray-800">{group.to_string()}</h3>
+            <p class="text-xs text-gray-500">{events}</p>
+            <div class="overflow-x-auto">
+                <table class="text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-4">"Place"</th>
+                            {grid
+                                .categories
+                                .iter()
+                                .map(|category| view! { <th class="py-1 pr-4 text-right">{category.to_string()}</th> })
+                                .collect_view()}
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {grid
+                            .rows
+                            .into_iter()
+                            .map(|(place, points)| {
+                                view! {
+                                    <tr class="border-t border-gray-200">
+                                        <td class="py-1 pr-4 text-gray-600">{place}</td>
+                                        {points
+                                            .into_iter()
+                                            .map(|points| {
+                                                view! {
+                                                    <td class="py-1 pr-4 text-right text-gray-900">
+                                                        {points.map(|p| p.to_string()).unwrap_or_default()}
+                                                    </td>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,150 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{Event, Gender, RoadRunningEvent};
+use crate::scoring_logic::race_predictor::{predict_races, running_distances, PREDICTION_MARGIN};
+use crate::settings::use_settings;
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// Equal-points marks over every other running distance from one result
+#[component]
+pub fn Predictor() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let settings = use_settings();
+    let (gender, set_gender) = signal(Gender::Men);
+    let (event, set_event) = signal(Event::RoadRunning(RoadRunningEvent::RoadMarathon));
+    let (mark, set_mark) = signal(String::new());
+
+    let predictions = Memo::new(move |_| {
+        let mark = mark.get();
+        if mark.trim().is_empty() {
+            return None;
+        }
+        Some(
+            Event::parse_time_to_seconds(mark.trim()).and_then(|seconds| {
+                predict_races(
+                    gender.get(),
+                    &event.get(),
+                    seconds,
+                    providers.result_score_calculator,
+                    providers.performance_calculator,
+                )
+            }),
+        )
+    });
+
+    view! {
+        <Title text="Race Predictor - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Race Predictor"</h2>
+                <p class="text-sm text-gray-500">
+                    {format!(
+                        "Enter a result to see the marks worth the same points over the other running distances. Each prediction's range covers {:.0} points either side, since few athletes are equally good at every distance.",
+                        PREDICTION_MARGIN,
+                    )}
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+                    <label for="predictor_gender" class="text-gray-800 font-medium">"Gender:"</label>
+                    <select
+                        id="predictor_gender"
+                        class=format!("md:col-span-2 {}", SELECT_CLASS)
+                        on:change=move |ev| {
+                            if let Some(g) = Gender::from_string(&event_target_value(&ev)) {
+                                set_gender.set(g);
+                            }
+                        }
+                    >
+                        {Gender::iter()
+                            .map(|g| {
+                                view! {
+                                    <option value=g.to_string() selected=move || gender.get() == g>
+                                        {g.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+
+                    <label for="predictor_event" class="text-gray-800 font-medium">"Event:"</label>
+                    <select
+                        id="predictor_event"
+                        class=format!("md:col-span-2 {}", SELECT_CLASS)
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            if let Some(selected) = running_distances().into_iter().find(|e| e.to_string() == value) {
+                                set_event.set(selected);
+                            }
+                        }
+                    >
+                        {running_distances()
+                            .into_iter()
+                            .map(|e| {
+                                view! {
+                                    <option value=e.to_string() selected=move || event.get() == e>
+                                        {e.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+
+                    <label for="predictor_mark" class="text-gray-800 font-medium">"Mark:"</label>
+                    <input
+                        id="predictor_mark"
+                        type="text"
+                        placeholder="e.g., 2:15:30 or 14:05.20"
+                        value=move || mark.get()
+                        class=format!("md:col-span-2 {}", SELECT_CLASS)
+                        on:input=move |ev| set_mark.set(event_target_value(&ev))
+                    />
+                </div>
+
+                {move || match predictions.get() {
+                    None => ().into_any(),
+                    Some(Err(e)) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    Some(Ok(predicted)) => {
+                        view! {
+                            <p class="text-sm text-gray-700">
+                                {settings.with(|s| format!("Result score: {} points", s.format_points(predicted.points)))}
+                            </p>
+                            <table class="w-full text-sm">
+                                <thead>
+                                    <tr class="text-left text-gray-600">
+                                        <th class="py-1 pr-4">"Event"</th>
+                                        <th class="py-1 pr-4">"Predicted"</th>
+                                        <th class="py-1 pr-4">"Range"</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {predicted
+                                        .predictions
+                                        .into_iter()
+                                        .map(|prediction| {
+                                            let format = |seconds| prediction.event.format_performance(seconds);
+                                            view! {
+                                                <tr class="border-t border-gray-200">
+                                                    <td class="py-1 pr-4 text-gray-600">{prediction.event.to_string()}</td>
+                                                    <td class="py-1 pr-4 text-gray-900 font-medium">
+                                                        {format(prediction.performance)}
+                                                    </td>
+                                                    <td class="py-1 pr-4 text-gray-600">
+                                                        {format!("{} – {}", format(prediction.fastest), format(prediction.slowest))}
+                                                    </td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </tbody>
+                            </table>
+                        }
+                            .into_any()
+                    }
+                }}
+            </div>
+        </main>
+    }
+}
@@ -0,0 +1,156 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::performance_db::{use_performances_changed, PerformanceDb, StoredPerformance};
+use crate::scoring_logic::athlete_profile::athlete_profile;
+use crate::scoring_logic::season_report::athletes;
+use crate::settings::use_settings;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// An athlete's personal bests across events from the stored results, with the points for
+/// each and their strongest event highlighted
+#[component]
+pub fn Profile() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let settings = use_settings();
+    let (records, set_records) = signal(Vec::<StoredPerformance>::new());
+    let (error, set_error) = signal(None::<String>);
+    let (selected_athlete, set_selected_athlete) = signal(None::<String>);
+
+    let changes = use_performances_changed();
+
+    Effect::new(move |_| {
+        changes.track();
+        spawn_local(async move {
+            let loaded = match PerformanceDb::open().await {
+                Ok(db) => db.all().await,
+                Err(e) => Err(e),
+            };
+            match loaded {
+                Ok(all) => set_records.set(all),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let athlete_options = Memo::new(move |_| records.with(|records| athletes(records)));
+    let athlete = Memo::new(move |_| {
+        let options = athlete_options.get();
+        selected_athlete
+            .get()
+            .filter(|athlete| options.contains(athlete))
+            .or_else(|| options.first().cloned())
+            .unwrap_or_default()
+    });
+    let profile = Memo::new(move |_| {
+        records.with(|records| {
+            athlete_profile(
+                &athlete.get(),
+                records,
+                providers.result_score_calculator,
+                providers.placement_score_calculator,
+            )
+        })
+    });
+
+    view! {
+        <Title text="Athlete Profile - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-3xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Athlete Profile"</h2>
+                <p class="text-sm text-gray-500">
+                    "Personal bests from the stored results, scored on the mark alone (with wind, without placing points) so events can be compared. Wind-assisted marks don't count."
+                </p>
+
+                <label class="block text-sm text-gray-800 font-medium">
+                    "Athlete"
+                    <select
+                        class=SELECT_CLASS
+                        on:change=move |ev| set_selected_athlete.set(Some(event_target_value(&ev)))
+                    >
+                        {move || {
+                            athlete_options
+                                .get()
+                                .into_iter()
+                                .map(|name| {
+                                    let selected = name == athlete.get();
+                                    view! { <option value=name.clone() selected=selected>{name.clone()}</option> }
+                                })
+                                .collect_view()
+                        }}
+                    </select>
+                </label>
+
+                {move || error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+
+                {move || {
+                    profile
+                        .with(|profile| profile.strongest().cloned())
+                        .map(|strongest| {
+                            let points = strongest
+                                .points
+                                .as_ref()
+                                .map(|points| settings.with(|s| s.format_points(*points)))
+                                .unwrap_or_default();
+                            view! {
+                                <p class="text-sm text-gray-700">
+                                    "Strongest event: "
+                                    <span class="font-semibold text-gray-900">
+                                        {format!("{} ({}, {} points)", strongest.event, strongest.mark, points)}
+                                    </span>
+                                </p>
+                            }
+                        })
+                }}
+
+                <table class="w-full text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-4">"Event"</th>
+                            <th class="py-1 pr-4">"Mark"</th>
+                            <th class="py-1 pr-4">"Points"</th>
+                            <th class="py-1 pr-4">"Date"</th>
+                            <th class="py-1 pr-4">"Venue"</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            let profile = profile.get();
+                            let strongest = profile.strongest().map(|pb| pb.event.clone());
+                            profile
+                                .personal_bests
+                                .into_iter()
+                                .map(|pb| {
+                                    let row_class = if Some(&pb.event) == strongest.as_ref() {
+                                        "border-t border-gray-200 bg-yellow-50 font-semibold"
+                                    } else {
+                                        "border-t border-gray-200"
+                                    };
+                                    let points = match &pb.points {
+                                        Ok(points) => settings.with(|s| s.format_points(*points)),
+                                        Err(e) => e.clone(),
+                                    };
+                                    let mark = match pb.wind_speed {
+                                        Some(wind) => format!("{} ({:+.1})", pb.mark, wind),
+                                        None => pb.mark.clone(),
+                                    };
+                                    view! {
+                                        <tr class=row_class>
+                                            <td class="py-1 pr-4 text-gray-600">{pb.event.to_string()}</td>
+                                            <td class="py-1 pr-4 text-gray-900">{mark}</td>
+                                            <td class="py-1 pr-4 text-gray-900">{points}</td>
+                                            <td class="py-1 pr-4 text-gray-600">{pb.date.clone()}</td>
+                                            <td class="py-1 pr-4 text-gray-600">{pb.venue.clone()}</td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+            </div>
+        </main>
+    }
+}
@@ -0,0 +1,224 @@
+use crate::components::inputs::EventSelectionInputs;
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::exporters::csv::{csv_data_url, to_csv};
+use crate::models::{CompetitionCategory, Event, Gender};
+use crate::scoring_logic::standards::{
+    performance_standards, standards_rows, PointsThreshold, ProgramEvent,
+};
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const INPUT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+const BUTTON_CLASS: &str = "px-3 py-2 text-sm bg-gray-900 text-white rounded-md hover:bg-gray-800";
+const REMOVE_CLASS: &str = "text-sm text-gray-500 underline hover:text-gray-900";
+
+/// Builds a meet's event program and turns points thresholds into performance standards,
+/// exportable as an entry-standards CSV sheet
+#[component]
+pub fn Program() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
+    let (gender, set_gender) = signal(Gender::Men);
+    let (event, set_event) = signal(Event::default());
+    let (program, set_program) = signal(Vec::<ProgramEvent>::new());
+    let (thresholds, set_thresholds) = signal(vec![PointsThreshold {
+        name: "Entry standard".to_string(),
+        points: 1000.0,
+    }]);
+
+    let rows = Memo::new(move |_| {
+        thresholds.with(|thresholds| {
+            let standards = program.with(|program| {
+                performance_standards(program, thresholds, providers.performance_calculator)
+            });
+            standards_rows(
+                &competition_category.get().to_string(),
+                thresholds,
+                &standards,
+            )
+        })
+    });
+
+    let add_event = move |_| {
+        let program_event = ProgramEvent {
+            gender: gender.get_untracked(),
+            event: event.get_untracked(),
+        };
+        set_program.update(|program| {
+            if !program.contains(&program_event) {
+                program.push(program_event);
+            }
+        });
+    };
+
+    view! {
+        <Title text="Program Builder - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-4xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Program Builder"</h2>
+                <p class="text-sm text-gray-500">
+                    "Add the events on your program and the points thresholds for your standards to get the matching marks for every event."
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+                    <label for="program_category" class="text-gray-800 font-medium">
+                        "Competition Category:"
+                    </label>
+                    <select
+                        id="program_category"
+                        class=format!("md:col-span-2 {}", INPUT_CLASS)
+                        on:change=move |ev| {
+                            if let Some(category) = CompetitionCategory::from_string(&event_target_value(&ev)) {
+                                set_competition_category.set(category);
+                            }
+                        }
+                    >
+                        {CompetitionCategory::iter()
+                            .map(|c| {
+                                view! {
+                                    <option value=c.to_string() selected=move || competition_category.get() == c>
+                                        {c.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </div>
+
+                <section class="space-y-2">
+                    <h3 class="text-lg font-medium text-gray-800">"Events"</h3>
+                    <EventSelectionInputs
+                        gender=gender
+                        set_gender=set_gender
+                        event=event
+                        set_event=set_event
+                    />
+                    <button type="button" class=BUTTON_CLASS on:click=add_event>
+                        "Add event"
+                    </button>
+                </section>
+
+                <section class="space-y-2">
+                    <h3 class="text-lg font-medium text-gray-800">"Points Thresholds"</h3>
+                    {move || {
+                        thresholds
+                            .get()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, threshold)| {
+                                view! {
+                                    <div class="grid grid-cols-1 md:grid-cols-5 gap-2 items-center">
+                                        <input
+                                            type="text"
+                                            aria-label="Threshold name"
+                                            value=threshold.name
+                                            class=format!("md:col-span-3 {}", INPUT_CLASS)
+                                            on:change=move |ev| {
+                                                let name = event_target_value(&ev);
+                                                set_thresholds.update(|t| t[index].name = name);
+                                            }
+                                        />
+                                        <input
+                                            type="number"
+                                            min="1"
+                                            max="1400"
+                                            aria-label="Threshold points"
+                                            value=threshold.points
+                                            class=INPUT_CLASS
+                                            on:change=move |ev| {
+                                                if let Ok(points) = event_target_value(&ev).parse::<f64>() {
+                                                    set_thresholds.update(|t| t[index].points = points);
+                                                }
+                                            }
+                                        />
+                                        <button
+                                            type="button"
+                                            class=REMOVE_CLASS
+                                            on:click=move |_| set_thresholds.update(|t| {
+                                                t.remove(index);
+                                            })
+                                        >
+                                            "Remove"
+                                        </button>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    <button
+                        type="button"
+                        class=BUTTON_CLASS
+                        on:click=move |_| set_thresholds.update(|t| t.push(PointsThreshold {
+                            name: format!("Standard {}", t.len() + 1),
+                            points: 1000.0,
+                        }))
+                    >
+                        "Add threshold"
+                    </button>
+                </section>
+
+                <Show
+                    when=move || !program.with(Vec::is_empty)
+                    fallback=|| view! { <p class="text-sm text-gray-500 italic">"No events on the program yet."</p> }
+                >
+                    <section class="space-y-2">
+                        <h3 class="text-lg font-medium text-gray-800">"Standards"</h3>
+                        <div class="overflow-x-auto">
+                            <table class="min-w-full text-sm text-left">
+                                <thead class="border-b border-gray-200 text-gray-600">
+                                    <tr>
+                                        {move || rows.with(|rows| {
+                                            rows[0]
+                                                .iter()
+                                                .skip(1)
+                                                .map(|heading| view! { <th class="py-2 pr-4">{heading.clone()}</th> })
+                                                .collect_view()
+                                        })}
+                                        <th></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {move || rows.with(|rows| {
+                                        rows[1..]
+                                            .iter()
+                                            .enumerate()
+                                            .map(|(index, row)| {
+                                                view! {
+                                                    <tr class="border-b border-gray-100">
+                                                        {row.iter()
+                                                            .skip(1)
+                                                            .map(|cell| view! { <td class="py-2 pr-4">{cell.clone()}</td> })
+                                                            .collect_view()}
+                                                        <td class="py-2">
+                                                            <button
+                                                                type="button"
+                                                                class=REMOVE_CLASS
+                                                                on:click=move |_| set_program.update(|p| {
+                                                                    p.remove(index);
+                                                                })
+                                                            >
+                                                                "Remove"
+                                                            </button>
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            })
+                                            .collect_view()
+                                    })}
+                                </tbody>
+                            </table>
+                        </div>
+                        <a
+                            class="inline-block text-sm text-gray-700 underline hover:text-gray-900"
+                            href=move || rows.with(|rows| csv_data_url(&to_csv(rows)))
+                            download="entry-standards.csv"
+                        >
+                            "Download CSV"
+                        </a>
+                    </section>
+                </Show>
+            </div>
+        </main>
+    }
+}
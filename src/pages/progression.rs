@@ -0,0 +1,193 @@
+use crate::components::progression_chart::ProgressionChart;
+use crate::models::Event;
+use crate::performance_db::{use_performances_changed, PerformanceDb, StoredPerformance};
+use crate::scoring_logic::progression::{athlete_events, event_progression};
+use crate::scoring_logic::season_report::athletes;
+use crate::settings::use_settings;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// An athlete's marks and points in one event over time, from the results saved in the
+/// calculator or imported, with the results listed for removal
+#[component]
+pub fn Progression() -> impl IntoView {
+    let settings = use_settings();
+    let (records, set_records) = signal(Vec::<StoredPerformance>::new());
+    let (error, set_error) = signal(None::<String>);
+    let (selected_athlete, set_selected_athlete) = signal(None::<String>);
+    let (selected_event, set_selected_event) = signal(None::<String>);
+
+    let changes = use_performances_changed();
+
+    Effect::new(move |_| {
+        changes.track();
+        spawn_local(async move {
+            let loaded = match PerformanceDb::open().await {
+                Ok(db) => db.all().await,
+                Err(e) => Err(e),
+            };
+            match loaded {
+                Ok(all) => set_records.set(all),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let athlete_options = Memo::new(move |_| records.with(|records| athletes(records)));
+    let athlete = Memo::new(move |_| {
+        let options = athlete_options.get();
+        selected_athlete
+            .get()
+            .filter(|athlete| options.contains(athlete))
+            .or_else(|| options.first().cloned())
+            .unwrap_or_default()
+    });
+    let event_options =
+        Memo::new(move |_| records.with(|records| athlete_events(&athlete.get(), records)));
+    let event = Memo::new(move |_| {
+        let options = event_options.get();
+        selected_event
+            .get()
+            .filter(|event| options.contains(event))
+            .or_else(|| options.first().cloned())
+            .unwrap_or_default()
+    });
+    let progression = Memo::new(move |_| {
+        records.with(|records| event_progression(&athlete.get(), &event.get(), records))
+    });
+
+    let delete = move |id: u32| {
+        spawn_local(async move {
+            let deleted = match PerformanceDb::open().await {
+                Ok(db) => db.delete(id).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = deleted {
+                set_error.set(Some(e));
+            }
+        });
+    };
+
+    view! {
+        <Title text="Progression - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-3xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Progression"</h2>
+                <p class="text-sm text-gray-500">
+                    "Save results from the calculator under an athlete's name, or import them, to follow their marks and points in each event over time."
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Athlete"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| set_selected_athlete.set(Some(event_target_value(&ev)))
+                        >
+                            {move || {
+                                athlete_options
+                                    .get()
+                                    .into_iter()
+                                    .map(|name| {
+                                        let selected = name == athlete.get();
+                                        view! { <option value=name.clone() selected=selected>{name.clone()}</option> }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Event"
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| set_selected_event.set(Some(event_target_value(&ev)))
+                        >
+                            {move || {
+                                event_options
+                                    .get()
+                                    .into_iter()
+                                    .map(|name| {
+                                        let selected = name == event.get();
+                                        view! { <option value=name.clone() selected=selected>{name.clone()}</option> }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                </div>
+
+                {move || error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+
+                {move || {
+                    let progression = progression.get();
+                    match Event::from_string(&event.get()) {
+                        Some(parsed) if !progression.is_empty() => {
+                            view! { <ProgressionChart event=parsed progression=progression /> }.into_any()
+                        }
+                        _ => view! { <p class="text-sm text-gray-500">"No scored results to chart yet."</p> }.into_any(),
+                    }
+                }}
+
+                <table class="w-full text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-4">"Date"</th>
+                            <th class="py-1 pr-4">"Mark"</th>
+                            <th class="py-1 pr-4">"Points"</th>
+                            <th class="py-1 pr-4">"Venue"</th>
+                            <th class="py-1"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            let (athlete, event) = (athlete.get(), event.get());
+                            let mut results: Vec<StoredPerformance> = records.with(|records| {
+                                records
+                                    .iter()
+                                    .filter(|record| record.athlete == athlete && record.event == event)
+                                    .cloned()
+                                    .collect()
+                            });
+                            results.sort_by(|a, b| b.date.cmp(&a.date));
+                            results
+                                .into_iter()
+                                .map(|record| {
+                                    let points = record
+                                        .points
+                                        .map(|points| settings.with(|s| s.format_points(points)))
+                                        .unwrap_or_else(|| "—".to_string());
+                                    view! {
+                                        <tr class="border-t border-gray-200">
+                                            <td class="py-1 pr-4 text-gray-600">{record.date.clone()}</td>
+                                            <td class="py-1 pr-4 text-gray-900">{record.mark.clone()}</td>
+                                            <td class="py-1 pr-4 text-gray-900">{points}</td>
+                                            <td class="py-1 pr-4 text-gray-600">{record.venue.clone()}</td>
+                                            <td class="py-1 text-right">
+                                                {record
+                                                    .id
+                                                    .map(|id| {
+                                                        view! {
+                                                            <button
+                                                                type="button"
+                                                                class="text-red-600 underline hover:text-red-800"
+                                                                on:click=move |_| delete(id)
+                                                            >
+                                                                "Delete"
+                                                            </button>
+                                                        }
+                                                    })}
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+            </div>
+        </main>
+    }
+}
@@ -0,0 +1,332 @@
+use crate::components::inputs::EventSelectionInputs;
+use crate::components::ranking_calendar::{today, RankingCalendar};
+use crate::components::scoring_providers::{use_scoring_providers, ScoringProviders};
+use crate::models::*;
+use crate::scoring_logic::calculator::{calculate_world_athletics_score, is_wind_affected_event};
+use crate::scoring_logic::placement_score::RoundType;
+use crate::scoring_logic::ranking_calendar::{ranking_calendar, CalendarDate};
+use crate::scoring_logic::ranking_score::{ranking_score, RankingResult, ResultStanding};
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const INPUT_CLASS: &str = "w-full px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+const BUTTON_CLASS: &str = "px-3 py-2 text-sm bg-gray-900 text-white rounded-md hover:bg-gray-800";
+const REMOVE_CLASS: &str = "text-sm text-gray-500 underline hover:text-gray-900";
+
+/// Most results that can be entered at once
+const MAX_RESULTS: usize = 12;
+
+/// One row of the simulator, as typed
+#[derive(Debug, Clone, PartialEq)]
+struct SimulatedResult {
+    date: String,
+    mark: String,
+    wind: String,
+    competition_category: CompetitionCategory,
+    /// Blank for no placing points
+    place: String,
+}
+
+impl SimulatedResult {
+    fn new(date: CalendarDate) -> Self {
+        SimulatedResult {
+            date: date.to_string(),
+            mark: String::new(),
+            wind: String::new(),
+            competition_category: CompetitionCategory::F,
+            place: String::new(),
+        }
+    }
+
+    /// The result's date and World Athletics score. Placings are scored as finals.
+    fn score(
+        &self,
+        gender: Gender,
+        event: &Event,
+        providers: ScoringProviders,
+    ) -> Result<RankingResult, String> {
+        let date = CalendarDate::parse(&self.date)?;
+        let performance = Performance::parse(event, &self.mark)?;
+        let wind_speed = match self.wind.trim() {
+            "" => None,
+            wind => Some(
+                wind.trim_start_matches('+')
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid wind: {}", wind))?,
+            ),
+        };
+        let placement_info = match self.place.trim() {
+            "" => None,
+            place => Some(PlacementInfo {
+                competition_category: self.competition_category,
+                place: place
+                    .parse()
+                    .map_err(|_| format!("Invalid place: {}", place))?,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 0,
+                main_event: false,
+            }),
+        };
+        let output = calculate_world_athletics_score(
+            WorldAthleticsScoreInput {
+                gender,
+                event: event.clone(),
+                performance,
+                wind_speed,
+                net_downhill: None,
+                venue_altitude: None,
+                placement_info,
+                table_edition: None,
+            },
+            providers.result_score_calculator,
+            providers.placement_score_calculator,
+        )?;
+        Ok(RankingResult {
+            date,
+            points: output.points,
+        })
+    }
+}
+
+/// Simulates a World Rankings score from a set of results in one event
+#[component]
+pub fn Rankings() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let today = today();
+    let (gender, set_gender) = signal(Gender::Men);
+    let (event, set_event) = signal(Event::default());
+    let (ranking_date, set_ranking_date) = signal(today.to_string());
+    let (results, set_results) = signal(vec![SimulatedResult::new(today)]);
+
+    let scored = Memo::new(move |_| {
+        let event = event.get();
+        results.with(|results| {
+            results
+                .iter()
+                .map(|result| result.score(gender.get(), &event, providers))
+                .collect::<Vec<_>>()
+        })
+    });
+    let ranking = Memo::new(move |_| {
+        let ranking_date = CalendarDate::parse(&ranking_date.get())?;
+        let scored = scored.get();
+        let valid: Vec<RankingResult> = scored.iter().filter_map(|r| r.clone().ok()).collect();
+        let score = ranking_score(ranking_calendar(), &event.get(), ranking_date, &valid);
+        // Map the standings back onto every row, including the ones that didn't score
+        let mut standings = score.standings.clone().into_iter();
+        let rows: Vec<Option<ResultStanding>> = scored
+            .iter()
+            .map(|r| r.as_ref().ok().and_then(|_| standings.next()))
+            .collect();
+        Ok::<_, String>((score, rows))
+    });
+
+    view! {
+        <Title text="Ranking Score Simulator - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-4xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Ranking Score Simulator"</h2>
+                <p class="text-sm text-gray-500">
+                    "Enter results in one event to see the World Rankings score they'd give: the average of the best results within the event's ranking period. Placings are scored as finals; leave the place blank for no placing points."
+                </p>
+
+                <EventSelectionInputs
+                    gender=gender
+                    set_gender=set_gender
+                    event=event
+                    set_event=set_event
+                />
+
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+                    <label for="ranking_date" class="text-gray-800 font-medium">
+                        "Ranking Date:"
+                    </label>
+                    <input
+                        id="ranking_date"
+                        type="date"
+                        value=move || ranking_date.get()
+                        class=format!("md:col-span-2 {}", INPUT_CLASS)
+                        on:change=move |ev| set_ranking_date.set(event_target_value(&ev))
+                    />
+                </div>
+
+                <table class="w-full text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-2">"Date"</th>
+                            <th class="py-1 pr-2">"Mark"</th>
+                            <Show when=move || is_wind_affected_event(&event.get())>
+                                <th class="py-1 pr-2">"Wind"</th>
+                            </Show>
+                            <th class="py-1 pr-2">"Category"</th>
+                            <th class="py-1 pr-2">"Place"</th>
+                            <th class="py-1 pr-2">"Points"</th>
+                            <th class="py-1"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            results
+                                .get()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, result)| {
+                                    let has_mark = !result.mark.is_empty();
+                                    let wind = result.wind.clone();
+                                    let points = move || {
+                                        let standing = ranking
+                                            .get()
+                                            .ok()
+                                            .and_then(|(_, rows)| rows.get(index).copied().flatten());
+                                        match scored.with(|scored| scored.get(index).cloned()) {
+                                            Some(Ok(scored)) => {
+                                                let note = match standing {
+                                                    Some(ResultStanding::Counted) => "counted",
+                                                    Some(ResultStanding::Dropped) => "not in best",
+                                                    Some(ResultStanding::OutsideWindow) | None => "outside window",
+                                                };
+                                                format!("{:.0} ({})", scored.points, note)
+                                            }
+                                            Some(Err(e)) if has_mark => e,
+                                            _ => String::new(),
+                                        }
+                                    };
+                                    view! {
+                                        <tr class="border-t border-gray-200">
+                                            <td class="py-1 pr-2">
+                                                <input
+                                                    type="date"
+                                                    aria-label="Result date"
+                                                    value=result.date.clone()
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        let date = event_target_value(&ev);
+                                                        set_results.update(|r| r[index].date = date);
+                                                    }
+                                                />
+                                            </td>
+                                            <td class="py-1 pr-2">
+                                                <input
+                                                    type="text"
+                                                    aria-label="Mark"
+                                                    value=result.mark.clone()
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        let mark = event_target_value(&ev);
+                                                        set_results.update(|r| r[index].mark = mark);
+                                                    }
+                                                />
+                                            </td>
+                                            <Show when=move || is_wind_affected_event(&event.get())>
+                                                <td class="py-1 pr-2">
+                                                    <input
+                                                        type="text"
+                                                        aria-label="Wind"
+                                                        value=wind.clone()
+                                                        class=INPUT_CLASS
+                                                        on:change=move |ev| {
+                                                            let wind = event_target_value(&ev);
+                                                            set_results.update(|r| r[index].wind = wind);
+                                                        }
+                                                    />
+                                                </td>
+                                            </Show>
+                                            <td class="py-1 pr-2">
+                                                <select
+                                                    aria-label="Competition category"
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        if let Some(category) = CompetitionCategory::from_string(&event_target_value(&ev)) {
+                                                            set_results.update(|r| r[index].competition_category = category);
+                                                        }
+                                                    }
+                                                >
+                                                    {CompetitionCategory::iter()
+                                                        .map(|c| {
+                                                            view! {
+                                                                <option value=c.to_string() selected=result.competition_category == c>
+                                                                    {c.to_string()}
+                                                                </option>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </select>
+                                            </td>
+                                            <td class="py-1 pr-2">
+                                                <input
+                                                    type="number"
+                                                    min="1"
+                                                    aria-label="Place"
+                                                    value=result.place.clone()
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        let place = event_target_value(&ev);
+                                                        set_results.update(|r| r[index].place = place);
+                                                    }
+                                                />
+                                            </td>
+                                            <td class="py-1 pr-2 text-gray-700">{points}</td>
+                                            <td class="py-1">
+                                                <button
+                                                    type="button"
+                                                    class=REMOVE_CLASS
+                                                    on:click=move |_| set_results.update(|results| {
+                                                        results.remove(index);
+                                                    })
+                                                >
+                                                    "Remove"
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                </table>
+                <Show when=move || results.with(|results| results.len() < MAX_RESULTS)>
+                    <button
+                        type="button"
+                        class=BUTTON_CLASS
+                        on:click=move |_| set_results.update(|results| results.push(SimulatedResult::new(today)))
+                    >
+                        "Add result"
+                    </button>
+                </Show>
+
+                <section class="p-4 bg-gray-50 border border-gray-200 rounded-md space-y-1" aria-live="polite">
+                    {move || match ranking.get() {
+                        Ok((score, _)) => {
+                            let heading = match score.average {
+                                Some(average) if score.is_complete() => format!("Ranking score: {:.0}", average),
+                                Some(average) => format!("Provisional ranking score: {:.0}", average),
+                                None => "No results in the ranking window".to_string(),
+                            };
+                            view! {
+                                <p class="text-2xl font-bold text-gray-900">{heading}</p>
+                                <p class="text-sm text-gray-600">
+                                    {format!(
+                                        "{} averages the best {} results from {} to {} ({} month window); {} counted.",
+                                        event.get(),
+                                        score.results_counted,
+                                        score.window.start,
+                                        score.window.end,
+                                        score.window.months,
+                                        score.counted(),
+                                    )}
+                                </p>
+                            }
+                                .into_any()
+                        }
+                        Err(e) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    }}
+                </section>
+
+                <RankingCalendar event=event />
+            </div>
+        </main>
+    }
+}
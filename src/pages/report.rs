@@ -0,0 +1,247 @@
+use crate::components::copy_button::CopyButton;
+use crate::performance_db::{use_performances_changed, PerformanceDb, StoredPerformance};
+use crate::scoring_logic::season_report::{
+    athletes, season_report, seasons, SeasonReport, STANDOUT_POINTS, TOP_RESULTS,
+};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+use leptos_router::hooks::{use_location, use_navigate, use_query_map};
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+const CHART_WIDTH: f64 = 400.0;
+const CHART_HEIGHT: f64 = 120.0;
+
+/// End-of-season summary for an athlete from the stored results. The athlete and season
+/// are kept in the URL so the page can be shared, and it prints cleanly to PDF.
+#[component]
+pub fn Report() -> impl IntoView {
+    let query = use_query_map();
+    let location = use_location();
+    let navigate = use_navigate();
+    let (records, set_records) = signal(Vec::<StoredPerformance>::new());
+    let (error, set_error) = signal(None::<String>);
+
+    let changes = use_performances_changed();
+
+    Effect::new(move |_| {
+        changes.track();
+        spawn_local(async move {
+            let loaded = match PerformanceDb::open().await {
+                Ok(db) => db.all().await,
+                Err(e) => Err(e),
+            };
+            match loaded {
+                Ok(all) => set_records.set(all),
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let athlete_options = Memo::new(move |_| records.with(|records| athletes(records)));
+    let season_options = Memo::new(move |_| records.with(|records| seasons(records)));
+    let athlete = Memo::new(move |_| {
+        query
+            .with(|query| query.get("athlete"))
+            .or_else(|| athlete_options.with(|athletes| athletes.first().cloned()))
+            .unwrap_or_default()
+    });
+    let season = Memo::new(move |_| {
+        query
+            .with(|query| query.get("season"))
+            .or_else(|| season_options.with(|seasons| seasons.first().cloned()))
+            .unwrap_or_default()
+    });
+    let report = Memo::new(move |_| {
+        records.with(|records| season_report(&athlete.get(), &season.get(), records))
+    });
+
+    let select = move |athlete: String, season: String| {
+        navigate(
+            &format!(
+                "{}?athlete={}&season={}",
+                location.pathname.get_untracked(),
+                js_sys::encode_uri_component(&athlete),
+                js_sys::encode_uri_component(&season),
+            ),
+            Default::default(),
+        );
+    };
+    let select_athlete = {
+        let select = select.clone();
+        move |ev| select(event_target_value(&ev), season.get_untracked())
+    };
+    let select_season = move |ev| select(athlete.get_untracked(), event_target_value(&ev));
+    let share_url = Signal::derive(move || {
+        query.track();
+        window().location().href().unwrap_or_default()
+    });
+
+    view! {
+        <Title text="Season Report - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-3xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4 print:shadow-none print:border-0">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Season Report"</h2>
+
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4 print:hidden">
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Athlete"
+                        <select class=SELECT_CLASS on:change=select_athlete>
+                            {move || {
+                                athlete_options
+                                    .get()
+                                    .into_iter()
+                                    .map(|name| {
+                                        let selected = name == athlete.get();
+                                        view! { <option value=name.clone() selected=selected>{name.clone()}</option> }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Season"
+                        <select class=SELECT_CLASS on:change=select_season>
+                            {move || {
+                                season_options
+                                    .get()
+                                    .into_iter()
+                                    .map(|year| {
+                                        let selected = year == season.get();
+                                        view! { <option value=year.clone() selected=selected>{year.clone()}</option> }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                </div>
+
+                {move || error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+
+                {move || {
+                    let report = report.get();
+                    if report.results == 0 {
+                        view! {
+                            <p class="text-sm text-gray-500 italic">
+                                "No scored results for this athlete and season. Save results from the Import page first."
+                            </p>
+                        }
+                            .into_any()
+                    } else {
+                        view! { <ReportSummary report=report /> }.into_any()
+                    }
+                }}
+
+                <div class="flex gap-3 print:hidden">
+                    <CopyButton text=share_url label="Copy link".to_string() />
+                    <button
+                        type="button"
+                        class="px-4 py-2 text-sm border border-gray-300 rounded-md hover:bg-gray-50"
+                        on:click=move |_| {
+                            if let Err(e) = window().print() {
+                                log::error!("Failed to print: {:?}", e);
+                            }
+                        }
+                    >
+                        "Print / Save as PDF"
+                    </button>
+                </div>
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn ReportSummary(report: SeasonReport) -> impl IntoView {
+    let best = report.best.map(|best| {
+        format!(
+            "{:.0} points ({} {}, {})",
+            best.points.unwrap_or_default(),
+            best.event,
+            best.mark,
+            best.date
+        )
+    });
+    let stat = |label: String, value: String| {
+        view! {
+            <div class="p-3 bg-gray-50 rounded-md border border-gray-200">
+                <dt class="text-xs text-gray-500">{label}</dt>
+                <dd class="text-lg font-semibold text-gray-900">{value}</dd>
+            </div>
+        }
+    };
+
+    view! {
+        <h3 class="text-lg font-medium text-gray-800">
+            {format!("{} — {} season", report.athlete, report.season)}
+        </h3>
+        <dl class="grid grid-cols-2 md:grid-cols-4 gap-3">
+            {stat("Results".to_string(), report.results.to_string())}
+            {stat(
+                format!("Average of top {}", TOP_RESULTS),
+                report.top_average.map(|average| format!("{:.0}", average)).unwrap_or_default(),
+            )}
+            {stat("Placing points".to_string(), report.placement_points.to_string())}
+            {stat(format!("{:.0}+ point results", STANDOUT_POINTS), report.standout_results.to_string())}
+        </dl>
+        <p class="text-sm text-gray-700">"Best: " {best}</p>
+        <ProgressionChart progression=report.progression />
+    }
+}
+
+/// Points for each result through the season, in date order
+#[component]
+fn ProgressionChart(progression: Vec<(String, f64)>) -> impl IntoView {
+    let (low, high) = progression
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(low, high), (_, points)| {
+            (low.min(*points), high.max(*points))
+        });
+    let span = (high - low).max(1.0);
+    let step = CHART_WIDTH / (progression.len().max(2) - 1) as f64;
+    let position = |index: usize, points: f64| {
+        (
+            index as f64 * step,
+            CHART_HEIGHT - (points - low) / span * (CHART_HEIGHT - 10.0) - 5.0,
+        )
+    };
+    let line = progression
+        .iter()
+        .enumerate()
+        .map(|(index, (_, points))| {
+            let (x, y) = position(index, *points);
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    view! {
+        <figure>
+            <svg
+                viewBox=format!("-6 0 {} {}", CHART_WIDTH + 12.0, CHART_HEIGHT)
+                class="w-full h-32 bg-gray-50 border border-gray-200 rounded-md"
+                role="img"
+                aria-label="Points progression through the season"
+            >
+                <polyline points=line fill="none" stroke="#111827" stroke-width="2" />
+                {progression
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (date, points))| {
+                        let (x, y) = position(index, *points);
+                        view! {
+                            <circle cx=x cy=y r="3" fill="#111827">
+                                <title>{format!("{}: {:.0} points", date, points)}</title>
+                            </circle>
+                        }
+                    })
+                    .collect_view()}
+            </svg>
+            <figcaption class="mt-1 flex justify-between text-xs text-gray-500">
+                <span>{progression.first().map(|(date, _)| date.clone())}</span>
+                <span>{format!("{:.0}–{:.0} points", low, high)}</span>
+                <span>{progression.last().map(|(date, _)| date.clone())}</span>
+            </figcaption>
+        </figure>
+    }
+}
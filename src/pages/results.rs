@@ -0,0 +1,251 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::Event;
+use crate::performance_db::{
+    use_performances_changed, PerformanceDb, PerformanceQuery, StoredPerformance,
+};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+
+const INPUT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+const CELL_INPUT_CLASS: &str = "w-full px-2 py-1 text-sm border border-gray-300 rounded-md";
+const LINK_CLASS: &str = "text-sm text-gray-600 underline hover:text-gray-900";
+
+enum Change {
+    Put(Box<StoredPerformance>),
+    Delete(u32),
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.trim().is_empty()).then(|| value.trim().to_string())
+}
+
+/// Browse, query, edit and delete the results stored in the local performance database
+#[component]
+pub fn Results() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (records, set_records) = signal(Vec::<StoredPerformance>::new());
+    let (error, set_error) = signal(None::<String>);
+    // Bumped after every change to reload the records
+    let (version, set_version) = signal(0u32);
+    // Changes made in other tabs
+    let changes = use_performances_changed();
+    let (editing, set_editing) = signal(None::<StoredPerformance>);
+
+    let (event, set_event) = signal(String::new());
+    let (from, set_from) = signal(String::new());
+    let (to, set_to) = signal(String::new());
+    let (min_points, set_min_points) = signal(String::new());
+    let (venue, set_venue) = signal(String::new());
+
+    Effect::new(move |_| {
+        version.track();
+        changes.track();
+        spawn_local(async move {
+            match PerformanceDb::open().await {
+                Ok(db) => match db.all().await {
+                    Ok(all) => set_records.set(all),
+                    Err(e) => set_error.set(Some(e)),
+                },
+                Err(e) => set_error.set(Some(e)),
+            }
+        });
+    });
+
+    let query = Memo::new(move |_| PerformanceQuery {
+        athlete: None,
+        event: non_empty(event.get()),
+        from: non_empty(from.get()),
+        to: non_empty(to.get()),
+        min_points: min_points.get().trim().parse().ok(),
+        venue: non_empty(venue.get()),
+    });
+    let matching = Memo::new(move |_| {
+        query.with(|query| {
+            records.with(|records| {
+                records
+                    .iter()
+                    .filter(|record| query.matches(record))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+        })
+    });
+
+    let apply = move |change: Change| {
+        spawn_local(async move {
+            let result = match PerformanceDb::open().await {
+                Ok(db) => match change {
+                    Change::Put(record) => db.put(&record).await.map(|_| ()),
+                    Change::Delete(id) => db.delete(id).await,
+                },
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => set_error.set(None),
+                Err(e) => set_error.set(Some(e)),
+            }
+            set_version.update(|v| *v += 1);
+        });
+    };
+    let save_edit = move |_| {
+        let Some(mut record) = editing.get_untracked() else {
+            return;
+        };
+        if let Err(e) = record.rescore(
+            providers.result_score_calculator,
+            providers.placement_score_calculator,
+        ) {
+            set_error.set(Some(e));
+            return;
+        }
+        set_editing.set(None);
+        apply(Change::Put(Box::new(record)));
+    };
+
+    view! {
+        <Title text="Stored Results - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-6xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Stored Results"</h2>
+                <p class="text-sm text-gray-500">
+                    "Results saved from the Import page are kept in this browser. Filter them, fix typos or delete them here."
+                </p>
+
+                <div class="grid grid-cols-1 md:grid-cols-5 gap-4">
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Event"
+                        <select class=INPUT_CLASS on:change=move |ev| set_event.set(event_target_value(&ev))>
+                            <option value="">"All events"</option>
+                            {Event::all_variants()
+                                .into_iter()
+                                .map(|e| view! { <option value=e.to_string()>{e.to_string()}</option> })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "From"
+                        <input type="date" class=INPUT_CLASS on:change=move |ev| set_from.set(event_target_value(&ev)) />
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "To"
+                        <input type="date" class=INPUT_CLASS on:change=move |ev| set_to.set(event_target_value(&ev)) />
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Min points"
+                        <input type="number" class=INPUT_CLASS on:input=move |ev| set_min_points.set(event_target_value(&ev)) />
+                    </label>
+                    <label class="text-sm text-gray-800 font-medium">
+                        "Venue"
+                        <input type="text" class=INPUT_CLASS on:input=move |ev| set_venue.set(event_target_value(&ev)) />
+                    </label>
+                </div>
+
+                {move || error.get().map(|e| view! { <p class="text-sm text-red-600">{e}</p> })}
+                <p class="text-sm text-gray-600">
+                    {move || format!("{} of {} results", matching.with(Vec::len), records.with(Vec::len))}
+                </p>
+
+                <div class="overflow-x-auto">
+                    <table class="min-w-full text-sm text-left">
+                        <thead class="border-b border-gray-200 text-gray-600">
+                            <tr>
+                                <th class="py-2 pr-4">"Date"</th>
+                                <th class="py-2 pr-4">"Athlete"</th>
+                                <th class="py-2 pr-4">"Event"</th>
+                                <th class="py-2 pr-4">"Mark"</th>
+                                <th class="py-2 pr-4">"Venue"</th>
+                                <th class="py-2 pr-4">"Points"</th>
+                                <th class="py-2"></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {move || {
+                                matching
+                                    .get()
+                                    .into_iter()
+                                    .map(|record| {
+                                        let id = record.id;
+                                        let is_editing = move || {
+                                            editing.with(|editing| editing.as_ref().is_some_and(|e| e.id == id))
+                                        };
+                                        let edit_field = move |get: fn(&StoredPerformance) -> String, set: fn(&mut StoredPerformance, String)| {
+                                            view! {
+                                                <input
+                                                    class=CELL_INPUT_CLASS
+                                                    prop:value=move || editing.with(|e| e.as_ref().map(get).unwrap_or_default())
+                                                    on:input=move |ev| {
+                                                        let value = event_target_value(&ev);
+                                                        set_editing.update(|e| {
+                                                            if let Some(e) = e {
+                                                                set(e, value);
+                                                            }
+                                                        });
+                                                    }
+                                                />
+                                            }
+                                        };
+                                        let editable = record.clone();
+                                        view! {
+                                            <tr class="border-b border-gray-100">
+                                                <Show when=is_editing fallback={
+                                                    let record = record.clone();
+                                                    move || {
+                                                        let record = record.clone();
+                                                        view! {
+                                                            <td class="py-2 pr-4">{record.date}</td>
+                                                            <td class="py-2 pr-4">{record.athlete}</td>
+                                                            <td class="py-2 pr-4">{record.event}</td>
+                                                            <td class="py-2 pr-4">{record.mark}</td>
+                                                            <td class="py-2 pr-4">{record.venue}</td>
+                                                        }
+                                                    }
+                                                }>
+                                                    <td class="py-2 pr-2">{edit_field(|r| r.date.clone(), |r, v| r.date = v)}</td>
+                                                    <td class="py-2 pr-2">{edit_field(|r| r.athlete.clone(), |r, v| r.athlete = v)}</td>
+                                                    <td class="py-2 pr-2">{record.event.clone()}</td>
+                                                    <td class="py-2 pr-2">{edit_field(|r| r.mark.clone(), |r, v| r.mark = v)}</td>
+                                                    <td class="py-2 pr-2">{edit_field(|r| r.venue.clone(), |r, v| r.venue = v)}</td>
+                                                </Show>
+                                                <td class="py-2 pr-4 font-medium">
+                                                    {record.points.map(|points| format!("{:.0}", points)).unwrap_or_else(|| "-".to_string())}
+                                                </td>
+                                                <td class="py-2 whitespace-nowrap space-x-2">
+                                                    <Show
+                                                        when=is_editing
+                                                        fallback=move || {
+                                                            let editable = editable.clone();
+                                                            view! {
+                                                                <button type="button" class=LINK_CLASS on:click=move |_| set_editing.set(Some(editable.clone()))>
+                                                                    "Edit"
+                                                                </button>
+                                                            }
+                                                        }
+                                                    >
+                                                        <button type="button" class=LINK_CLASS on:click=save_edit>"Save"</button>
+                                                        <button type="button" class=LINK_CLASS on:click=move |_| set_editing.set(None)>"Cancel"</button>
+                                                    </Show>
+                                                    <button
+                                                        type="button"
+                                                        class=LINK_CLASS
+                                                        on:click=move |_| {
+                                                            if let Some(id) = id {
+                                                                apply(Change::Delete(id));
+                                                            }
+                                                        }
+                                                    >
+                                                        "Delete"
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </tbody>
+                    </table>
+                </div>
+            </div>
+        </main>
+    }
+}
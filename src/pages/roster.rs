@@ -0,0 +1,244 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::*;
+use crate::roster::{score_roster, sorted_indices, use_roster, RosterAthlete, RosterSort};
+use crate::scoring_logic::calculator::is_wind_affected_event;
+use crate::settings::use_settings;
+use leptos::prelude::*;
+use leptos_meta::*;
+use strum::IntoEnumIterator;
+
+const INPUT_CLASS: &str = "w-full px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+const BUTTON_CLASS: &str = "px-3 py-2 text-sm bg-gray-900 text-white rounded-md hover:bg-gray-800";
+const REMOVE_CLASS: &str = "text-sm text-gray-500 underline hover:text-gray-900";
+
+/// A squad of athletes, each with an event and mark, scored together with a team total
+#[component]
+pub fn Roster() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let settings = use_settings();
+    let roster = use_roster();
+    let (sort, set_sort) = signal((RosterSort::Points, true));
+
+    let scores = Memo::new(move |_| {
+        roster.with(|roster| {
+            score_roster(
+                roster,
+                providers.result_score_calculator,
+                providers.placement_score_calculator,
+            )
+        })
+    });
+    let order = Memo::new(move |_| {
+        let (sort, descending) = sort.get();
+        roster.with(|roster| scores.with(|scores| sorted_indices(roster, scores, sort, descending)))
+    });
+    let totals = move || {
+        scores.with(|scores| {
+            let points: Vec<f64> = scores
+                .iter()
+                .filter_map(|s| s.as_ref().ok())
+                .map(|s| s.points)
+                .collect();
+            let total: f64 = points.iter().sum();
+            (points.len(), total)
+        })
+    };
+
+    // Clicking the sorted column reverses it; points start highest first
+    let sort_button = move |column: RosterSort, label: &'static str| {
+        view! {
+            <button
+                type="button"
+                class="font-medium underline-offset-2 hover:underline"
+                on:click=move |_| {
+                    set_sort.update(|(sort, descending)| {
+                        if *sort == column {
+                            *descending = !*descending;
+                        } else {
+                            *sort = column;
+                            *descending = column == RosterSort::Points;
+                        }
+                    })
+                }
+            >
+                {move || match sort.get() {
+                    (sorted, true) if sorted == column => format!("{} ▼", label),
+                    (sorted, false) if sorted == column => format!("{} ▲", label),
+                    _ => label.to_string(),
+                }}
+            </button>
+        }
+    };
+
+    view! {
+        <Title text="Roster - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-5xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Roster"</h2>
+                <p class="text-sm text-gray-500">
+                    "Keep a list of your athletes with an event and mark each to see everyone's points side by side. The roster is saved in this browser."
+                </p>
+
+                <table class="w-full text-sm">
+                    <thead>
+                        <tr class="text-left text-gray-600">
+                            <th class="py-1 pr-2">{sort_button(RosterSort::Name, "Athlete")}</th>
+                            <th class="py-1 pr-2">"Gender"</th>
+                            <th class="py-1 pr-2">{sort_button(RosterSort::Event, "Event")}</th>
+                            <th class="py-1 pr-2">"Mark"</th>
+                            <th class="py-1 pr-2">"Wind"</th>
+                            <th class="py-1 pr-2">{sort_button(RosterSort::Points, "Points")}</th>
+                            <th class="py-1"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {move || {
+                            let athletes = roster.get();
+                            order
+                                .get()
+                                .into_iter()
+                                .map(|index| {
+                                    let athlete = athletes[index].clone();
+                                    let wind_affected = Event::from_string(&athlete.event)
+                                        .is_some_and(|event| is_wind_affected_event(&event));
+                                    let has_mark = !athlete.mark.trim().is_empty();
+                                    let points = move || match scores.with(|scores| scores.get(index).cloned()) {
+                                        Some(Ok(score)) => settings.with(|s| s.format_points(score.points)),
+                                        Some(Err(e)) if has_mark => e,
+                                        _ => String::new(),
+                                    };
+                                    view! {
+                                        <tr class="border-t border-gray-200">
+                                            <td class="py-1 pr-2">
+                                                <input
+                                                    type="text"
+                                                    aria-label="Athlete"
+                                                    value=athlete.name.clone()
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        let name = event_target_value(&ev);
+                                                        roster.update(|r| r[index].name = name);
+                                                    }
+                                                />
+                                            </td>
+                                            <td class="py-1 pr-2">
+                                                <select
+                                                    aria-label="Gender"
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        if let Some(gender) = Gender::from_string(&event_target_value(&ev)) {
+                                                            roster.update(|r| r[index].gender = gender);
+                                                        }
+                                                    }
+                                                >
+                                                    {Gender::iter()
+                                                        .map(|g| {
+                                                            view! {
+                                                                <option value=g.to_string() selected=athlete.gender == g>
+                                                                    {g.to_string()}
+                                                                </option>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </select>
+                                            </td>
+                                            <td class="py-1 pr-2">
+                                                <select
+                                                    aria-label="Event"
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        let event = event_target_value(&ev);
+                                                        roster.update(|r| r[index].event = event);
+                                                    }
+                                                >
+                                                    {Event::all_variants()
+                                                        .into_iter()
+                                                        .filter(|event| event.is_available_for(athlete.gender, None))
+                                                        .map(|event| {
+                                                            let name = event.to_string();
+                                                            view! {
+                                                                <option value=name.clone() selected=athlete.event == name>
+                                                                    {name.clone()}
+                                                                </option>
+                                                            }
+                                                        })
+                                                        .collect_view()}
+                                                </select>
+                                            </td>
+                                            <td class="py-1 pr-2">
+                                                <input
+                                                    type="text"
+                                                    aria-label="Mark"
+                                                    value=athlete.mark.clone()
+                                                    class=INPUT_CLASS
+                                                    on:change=move |ev| {
+                                                        let mark = event_target_value(&ev);
+                                                        roster.update(|r| r[index].mark = mark);
+                                                    }
+                                                />
+                                            </td>
+                                            <td class="py-1 pr-2">
+                                                <Show when=move || wind_affected>
+                                                    <input
+                                                        type="text"
+                                                        aria-label="Wind"
+                                                        value=athlete.wind.clone()
+                                                        class=INPUT_CLASS
+                                                        on:change=move |ev| {
+                                                            let wind = event_target_value(&ev);
+                                                            roster.update(|r| r[index].wind = wind);
+                                                        }
+                                                    />
+                                                </Show>
+                                            </td>
+                                            <td class="py-1 pr-2 text-gray-700">{points}</td>
+                                            <td class="py-1">
+                                                <button
+                                                    type="button"
+                                                    class=REMOVE_CLASS
+                                                    on:click=move |_| roster.update(|roster| {
+                                                        roster.remove(index);
+                                                    })
+                                                >
+                                                    "Remove"
+                                                </button>
+                                            </td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()
+                        }}
+                    </tbody>
+                    <tfoot>
+                        <tr class="border-t-2 border-gray-400 font-semibold text-gray-900">
+                            <td class="py-1 pr-2" colspan="5">
+                                {move || format!("Total ({} scored)", totals().0)}
+                            </td>
+                            <td class="py-1 pr-2">
+                                {move || settings.with(|s| s.format_points(totals().1))}
+                            </td>
+                            <td></td>
+                        </tr>
+                        <tr class="text-gray-600">
+                            <td class="py-1 pr-2" colspan="5">"Average"</td>
+                            <td class="py-1 pr-2">
+                                {move || match totals() {
+                                    (0, _) => String::new(),
+                                    (count, total) => settings.with(|s| s.format_points(total / count as f64)),
+                                }}
+                            </td>
+                            <td></td>
+                        </tr>
+                    </tfoot>
+                </table>
+                <button
+                    type="button"
+                    class=BUTTON_CLASS
+                    on:click=move |_| roster.update(|roster| roster.push(RosterAthlete::default()))
+                >
+                    "Add athlete"
+                </button>
+            </div>
+        </main>
+    }
+}
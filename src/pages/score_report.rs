@@ -0,0 +1,117 @@
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::exporters::pdf::pdf_data_url;
+use crate::exporters::score_report::{score_report, score_report_pdf, ScoreReport};
+use crate::settings::use_settings;
+use crate::share_link::CalculatorState;
+use leptos::prelude::*;
+use leptos_meta::*;
+use leptos_router::hooks::use_query_map;
+
+const BUTTON_CLASS: &str = "px-4 py-2 text-sm border border-gray-300 rounded-md hover:bg-gray-50";
+
+/// A print-friendly report of the calculation in the URL (the calculator's share-link
+/// parameters), with a PDF download.
+#[component]
+pub fn ScoreReportPage() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let settings = use_settings();
+    let query = use_query_map();
+    let generated = js_sys::Date::new_0()
+        .to_iso_string()
+        .as_string()
+        .map(|date| date[..10].to_string())
+        .unwrap_or_default();
+
+    let report = Memo::new(move |_| {
+        let state = query
+            .with(|query| CalculatorState::from_query(|key| query.get(key)))
+            .ok_or("This link doesn't include a calculation. Calculate a score first, then open its printable report.")?;
+        // Scored against the edition the link names, or else the one in the settings
+        let mut input = state.score_input()?;
+        if input.table_edition.is_none() {
+            input.table_edition = Some(settings.with(|s| s.table_version.clone()));
+        }
+        let score = providers.score(input)?;
+        Ok::<_, String>(settings.with(|s| score_report(&state, &score, s)))
+    });
+
+    view! {
+        <Title text="Score Report - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4 print:shadow-none print:border-0">
+                <h2 class="text-xl font-semibold text-gray-800">"World Athletics Score Report"</h2>
+                {move || match report.get() {
+                    Ok(report) => {
+                        let pdf_href = pdf_data_url(&score_report_pdf(&report, &generated));
+                        view! {
+                            <ReportBody report=report generated=generated.clone() />
+                            <div class="flex gap-3 print:hidden">
+                                <button
+                                    type="button"
+                                    class=BUTTON_CLASS
+                                    on:click=move |_| {
+                                        if let Err(e) = window().print() {
+                                            log::error!("Failed to print: {:?}", e);
+                                        }
+                                    }
+                                >
+                                    "Print"
+                                </button>
+                                <a class=BUTTON_CLASS href=pdf_href download="score-report.pdf">
+                                    "Download PDF"
+                                </a>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    Err(e) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                }}
+            </div>
+        </main>
+    }
+}
+
+#[component]
+fn ReportBody(report: ScoreReport, generated: String) -> impl IntoView {
+    let rows = |rows: Vec<(String, String)>| {
+        rows.into_iter()
+            .map(|(label, value)| {
+                view! {
+                    <tr class="border-t border-gray-200">
+                        <th class="py-1 pr-4 text-left font-normal text-gray-600">{label}</th>
+                        <td class="py-1 text-gray-900">{value}</td>
+                    </tr>
+                }
+            })
+            .collect_view()
+    };
+
+    view! {
+        <p class="text-lg font-medium text-gray-900">{report.summary}</p>
+        <section>
+            <h3 class="text-sm font-semibold text-gray-800">"Inputs"</h3>
+            <table class="w-full mt-1 text-sm">
+                <tbody>{rows(report.inputs)}</tbody>
+            </table>
+        </section>
+        <section>
+            <h3 class="text-sm font-semibold text-gray-800">"Score"</h3>
+            <table class="w-full mt-1 text-sm">
+                <tbody>
+                    {rows(report.breakdown)}
+                    <tr class="border-t-2 border-gray-400 font-semibold">
+                        <th class="py-1 pr-4 text-left">"Total"</th>
+                        <td class="py-1">{report.total}</td>
+                    </tr>
+                </tbody>
+            </table>
+        </section>
+        <p class="text-xs text-gray-500">
+            {format!(
+                "Scoring tables: {} edition. Generated {}.",
+                report.table_edition,
+                generated,
+            )}
+        </p>
+    }
+}
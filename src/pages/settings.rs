@@ -0,0 +1,269 @@
+use crate::backup::{export_backup, import_backup, restore_performances};
+use crate::components::file_upload::FileUpload;
+use crate::components::ranking_calendar::today;
+use crate::history::{merge_history, use_history};
+use crate::models::{DistanceUnit, Event, Gender, PaceUnit, TemperatureUnit};
+use crate::performance_db::{use_performances_changed, PerformanceDb, StoredPerformance};
+use crate::scoring_logic::coefficients::table_editions;
+use crate::settings::{
+    export_settings, import_settings, use_settings, Density, Theme, MAX_POINTS_DECIMALS,
+    SUPPORTED_LOCALES,
+};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_meta::*;
+use std::fmt::Display;
+use strum::IntoEnumIterator;
+
+/// A labelled select bound to one setting
+#[component]
+fn SettingSelect<T>(
+    id: &'static str,
+    label: &'static str,
+    options: Vec<T>,
+    #[prop(into)] value: Signal<T>,
+    on_change: Callback<T>,
+) -> impl IntoView
+where
+    T: Display + PartialEq + Clone + Send + Sync + 'static,
+{
+    let options = StoredValue::new(options);
+    view! {
+        <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+            <label for=id class="text-gray-800 font-medium">
+                {label}
+            </label>
+            <select
+                id=id
+                class="md:col-span-2 w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black"
+                on:change=move |ev| {
+                    let selected = event_target_value(&ev);
+                    if let Some(option) = options
+                        .with_value(|options| options.iter().find(|o| o.to_string() == selected).cloned())
+                    {
+                        on_change.run(option);
+                    }
+                }
+            >
+                {options
+                    .get_value()
+                    .into_iter()
+                    .map(|option| {
+                        let name = option.to_string();
+                        view! {
+                            <option value=name.clone() selected=move || value.get() == option>
+                                {name.clone()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
+
+/// App settings, with JSON export/import for moving them between devices
+#[component]
+pub fn Settings() -> impl IntoView {
+    let settings = use_settings();
+    let (import_message, set_import_message) = signal(Option::<Result<String, String>>::None);
+
+    let export_href = move || {
+        format!(
+            "data:application/json;charset=utf-8,{}",
+            js_sys::encode_uri_component(&export_settings(&settings.get()))
+        )
+    };
+    let on_import = Callback::new(move |json: String| match import_settings(&json) {
+        Ok(imported) => {
+            settings.set(imported);
+            set_import_message.set(Some(Ok("Settings imported".to_string())));
+        }
+        Err(e) => set_import_message.set(Some(Err(e))),
+    });
+
+    // Stored results, kept loaded so the backup link is always up to date
+    let (performances, set_performances) = signal(Vec::<StoredPerformance>::new());
+    let changes = use_performances_changed();
+    Effect::new(move |_| {
+        changes.track();
+        spawn_local(async move {
+            match PerformanceDb::open().await {
+                Ok(db) => match db.all().await {
+                    Ok(all) => set_performances.set(all),
+                    Err(e) => log::error!("Failed to load results for the backup: {}", e),
+                },
+                Err(e) => log::error!("Failed to open the results database: {}", e),
+            }
+        });
+    });
+    let history = use_history();
+    let created = today().to_string();
+    let backup_filename = format!("wa-points-backup-{}.json", created);
+    let backup_href = move || {
+        let json = performances.with(|performances| {
+            settings.with(|settings| {
+                history.with(|history| export_backup(&created, settings, performances, history))
+            })
+        });
+        format!(
+            "data:application/json;charset=utf-8,{}",
+            js_sys::encode_uri_component(&json)
+        )
+    };
+    let (restore_message, set_restore_message) = signal(Option::<Result<String, String>>::None);
+    let on_restore = Callback::new(move |json: String| match import_backup(&json) {
+        Ok(backup) => {
+            settings.set(backup.settings.clone());
+            history.update(|history| *history = merge_history(history, &backup.history));
+            spawn_local(async move {
+                let message = restore_performances(&backup).await.map(|added| {
+                    format!(
+                        "Restored settings, history and {} of {} results ({} already stored)",
+                        added,
+                        backup.performances.len(),
+                        backup.performances.len() - added
+                    )
+                });
+                set_restore_message.set(Some(message));
+            });
+        }
+        Err(e) => set_restore_message.set(Some(Err(e))),
+    });
+
+    view! {
+        <Title text="Settings - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Settings"</h2>
+
+                <h3 class="text-lg font-medium text-gray-800">"Display"</h3>
+                <SettingSelect
+                    id="theme"
+                    label="Theme:"
+                    options=Theme::iter().collect()
+                    value=Signal::derive(move || settings.with(|s| s.theme))
+                    on_change=Callback::new(move |theme| settings.update(|s| s.theme = theme))
+                />
+                <SettingSelect
+                    id="density"
+                    label="Density:"
+                    options=Density::iter().collect()
+                    value=Signal::derive(move || settings.with(|s| s.density))
+                    on_change=Callback::new(move |density| settings.update(|s| s.density = density))
+                />
+                <SettingSelect
+                    id="locale"
+                    label="Language:"
+                    options=SUPPORTED_LOCALES.iter().map(|l| l.to_string()).collect()
+                    value=Signal::derive(move || settings.with(|s| s.locale.clone()))
+                    on_change=Callback::new(move |locale| settings.update(|s| s.locale = locale))
+                />
+                <SettingSelect
+                    id="points_decimals"
+                    label="Points Decimal Places:"
+                    options=(0..=MAX_POINTS_DECIMALS).collect()
+                    value=Signal::derive(move || settings.with(|s| s.points_decimals))
+                    on_change=Callback::new(move |decimals| {
+                        settings.update(|s| s.points_decimals = decimals)
+                    })
+                />
+
+                <h3 class="text-lg font-medium text-gray-800">"Units"</h3>
+                <SettingSelect
+                    id="distance_unit"
+                    label="Distances:"
+                    options=DistanceUnit::iter().collect()
+                    value=Signal::derive(move || settings.with(|s| s.units.distance))
+                    on_change=Callback::new(move |unit| settings.update(|s| s.units.distance = unit))
+                />
+                <SettingSelect
+                    id="pace_unit"
+                    label="Pace:"
+                    options=PaceUnit::iter().collect()
+                    value=Signal::derive(move || settings.with(|s| s.units.pace))
+                    on_change=Callback::new(move |unit| settings.update(|s| s.units.pace = unit))
+                />
+                <SettingSelect
+                    id="temperature_unit"
+                    label="Temperature:"
+                    options=TemperatureUnit::iter().collect()
+                    value=Signal::derive(move || settings.with(|s| s.units.temperature))
+                    on_change=Callback::new(move |unit| {
+                        settings.update(|s| s.units.temperature = unit)
+                    })
+                />
+
+                <h3 class="text-lg font-medium text-gray-800">"Calculator"</h3>
+                <SettingSelect
+                    id="default_gender"
+                    label="Default Gender:"
+                    options=Gender::iter().collect()
+                    value=Signal::derive(move || settings.with(|s| s.default_gender))
+                    on_change=Callback::new(move |gender| settings.update(|s| s.default_gender = gender))
+                />
+                <SettingSelect
+                    id="default_event"
+                    label="Default Event:"
+                    options=Event::all_variants()
+                    value=Signal::derive(move || settings.with(|s| s.default_event()))
+                    on_change=Callback::new(move |event: Event| {
+                        settings.update(|s| s.default_event = event.to_string())
+                    })
+                />
+                <SettingSelect
+                    id="table_version"
+                    label="Scoring Tables:"
+                    options=table_editions()
+                    value=Signal::derive(move || settings.with(|s| s.table_version.clone()))
+                    on_change=Callback::new(move |version| settings.update(|s| s.table_version = version))
+                />
+
+                <h3 class="text-lg font-medium text-gray-800">"Export / Import"</h3>
+                <p class="text-sm text-gray-500">
+                    "Save your settings to a file to move them to another device."
+                </p>
+                <div class="flex items-center gap-4">
+                    <a
+                        href=export_href
+                        download="wa-points-settings.json"
+                        class="px-3 py-2 bg-gray-900 text-white text-sm rounded-md hover:bg-gray-700"
+                    >
+                        "Export Settings"
+                    </a>
+                    <button
+                        type="button"
+                        class="px-3 py-2 border border-gray-300 text-sm rounded-md hover:bg-gray-100"
+                        on:click=move |_| settings.set(Default::default())
+                    >
+                        "Reset to Defaults"
+                    </button>
+                </div>
+                <FileUpload accept=".json,application/json" on_load=on_import />
+                {move || match import_message.get() {
+                    Some(Ok(message)) => view! { <p class="text-sm text-green-700">{message}</p> }.into_any(),
+                    Some(Err(e)) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    None => ().into_any(),
+                }}
+
+                <h3 class="text-lg font-medium text-gray-800">"Backup"</h3>
+                <p class="text-sm text-gray-500">
+                    "Download everything this browser stores (settings and stored results) as one file. Restoring it on another device adds any results that aren't already there."
+                </p>
+                <a
+                    href=backup_href
+                    download=backup_filename
+                    class="inline-block px-3 py-2 bg-gray-900 text-white text-sm rounded-md hover:bg-gray-700"
+                >
+                    {move || format!("Download Backup ({} results)", performances.with(Vec::len))}
+                </a>
+                <FileUpload accept=".json,application/json" on_load=on_restore />
+                {move || match restore_message.get() {
+                    Some(Ok(message)) => view! { <p class="text-sm text-green-700">{message}</p> }.into_any(),
+                    Some(Err(e)) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    None => ().into_any(),
+                }}
+            </div>
+        </main>
+    }
+}
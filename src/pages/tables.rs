@@ -0,0 +1,80 @@
+use crate::components::copy_button::CopyButton;
+use crate::components::full_points_table::FullPointsTable;
+use crate::components::inputs::EventSelectionInputs;
+use crate::components::points_curve_chart::PointsCurveChart;
+use crate::models::{Event, Gender};
+use crate::scoring_logic::coefficients::get_coefficients;
+use crate::scoring_logic::spreadsheet::result_score_formula;
+use leptos::prelude::*;
+use leptos_meta::*;
+
+/// The cell the spreadsheet formula reads the performance from
+const FORMULA_CELL: &str = "A1";
+
+/// Scoring tables for a single event
+#[component]
+pub fn Tables() -> impl IntoView {
+    let (gender, set_gender) = signal(Gender::Men);
+    let (event, set_event) = signal(Event::default());
+
+    let formula = Signal::derive(move || {
+        get_coefficients(gender.get(), &event.get())
+            .map(|coefficients| result_score_formula(&coefficients, FORMULA_CELL))
+    });
+
+    view! {
+        <Title text="Scoring Tables - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center justify-center p-4">
+            <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Scoring Tables"</h2>
+
+                <EventSelectionInputs
+                    gender=gender
+                    set_gender=set_gender
+                    event=event
+                    set_event=set_event
+                />
+
+                <div class="space-y-2">
+                    <h3 class="text-lg font-medium text-gray-800">"Points Curve"</h3>
+                    <p class="text-sm text-gray-500">
+                        "Drag along the curve to set a goal, or type the points or performance."
+                    </p>
+                    <PointsCurveChart gender=gender event=event />
+                </div>
+
+                <div class="space-y-2">
+                    <h3 class="text-lg font-medium text-gray-800">"Spreadsheet Formula"</h3>
+                    <p class="text-sm text-gray-500">
+                        {format!(
+                            "Paste into Excel or Google Sheets with the performance in cell {} (seconds for running events, meters for field events).",
+                            FORMULA_CELL,
+                        )}
+                    </p>
+                    {move || match formula.get() {
+                        Ok(formula_text) => {
+                            view! {
+                                <div class="flex items-start gap-2">
+                                    <code class="flex-grow block p-3 bg-gray-50 border border-gray-200 rounded-md text-sm break-all">
+                                        {formula_text.clone()}
+                                    </code>
+                                    <CopyButton text=Signal::derive(move || formula_text.clone()) />
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        Err(e) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    }}
+                </div>
+
+                <div class="space-y-2">
+                    <h3 class="text-lg font-medium text-gray-800">"Full Table"</h3>
+                    <p class="text-sm text-gray-500">
+                        "The mark needed for every score from 1400 points down to 1, rounded to the worst measurable mark that still scores it."
+                    </p>
+                    <FullPointsTable gender=gender event=event />
+                </div>
+            </div>
+        </main>
+    }
+}
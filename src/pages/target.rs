@@ -0,0 +1,136 @@
+use crate::components::inputs::{EventSelectionInputs, PlacementInfoSection};
+use crate::components::scoring_providers::use_scoring_providers;
+use crate::models::{CompetitionCategory, Event, Gender, PlacementInfo};
+use crate::scoring_logic::coefficients::MAX_RESULT_SCORE;
+use crate::scoring_logic::placement_score::RoundType;
+use crate::scoring_logic::target::required_performance;
+use leptos::prelude::*;
+use leptos_meta::*;
+
+const INPUT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black";
+
+/// The mark needed to reach a points target, optionally counting expected placing points
+#[component]
+pub fn Target() -> impl IntoView {
+    let providers = use_scoring_providers();
+    let (gender, set_gender) = signal(Gender::Men);
+    let (event, set_event) = signal(Event::default());
+    let (target_points, set_target_points) = signal(1200.0);
+    let (include_placement, set_include_placement) = signal(false);
+    let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
+    let (place, set_place) = signal(1);
+    let (tied_with, set_tied_with) = signal(0);
+    let (round, set_round) = signal(RoundType::Final);
+    let (size_of_final, set_size_of_final) = signal(8);
+    let (qualified_to_final, set_qualified_to_final) = signal(false);
+    let (main_event, set_main_event) = signal(false);
+
+    let target = Memo::new(move |_| {
+        let placement_info = include_placement.get().then(|| PlacementInfo {
+            competition_category: competition_category.get(),
+            place: place.get(),
+            round: round.get(),
+            size_of_final: size_of_final.get(),
+            qualified_to_final: qualified_to_final.get(),
+            tied_with: tied_with.get(),
+            main_event: main_event.get(),
+        });
+        required_performance(
+            gender.get(),
+            &event.get(),
+            target_points.get(),
+            placement_info.as_ref(),
+            providers.performance_calculator,
+            providers.placement_score_calculator,
+        )
+    });
+
+    view! {
+        <Title text="Target Performance - World Athletics Points Calculator" />
+        <main class="min-h-screen bg-white flex flex-col items-center p-4">
+            <div class="w-full max-w-2xl bg-white rounded-lg shadow-sm p-6 border border-gray-200 space-y-4">
+                <h2 class="text-xl font-semibold text-gray-800 mb-4">"Target Performance"</h2>
+                <p class="text-sm text-gray-500">
+                    "Pick an event and the points you're aiming for to see the mark you need. Include a placing to count the placing points you expect to earn."
+                </p>
+
+                <EventSelectionInputs
+                    gender=gender
+                    set_gender=set_gender
+                    event=event
+                    set_event=set_event
+                />
+
+                <div class="grid grid-cols-1 md:grid-cols-3 gap-4 items-center">
+                    <label for="target_points" class="text-gray-800 font-medium">
+                        "Target Points:"
+                    </label>
+                    <input
+                        id="target_points"
+                        type="number"
+                        min="1"
+                        step="1"
+                        value=move || target_points.get()
+                        class=format!("md:col-span-2 {}", INPUT_CLASS)
+                        on:input=move |ev| {
+                            if let Ok(points) = event_target_value(&ev).parse::<f64>() {
+                                set_target_points.set(points);
+                            }
+                        }
+                    />
+                </div>
+
+                <PlacementInfoSection
+                    include_placement=include_placement
+                    set_include_placement=set_include_placement
+                    competition_category=competition_category
+                    set_competition_category=set_competition_category
+                    place=place
+                    set_place=set_place
+                    tied_with=tied_with
+                    set_tied_with=set_tied_with
+                    round=round
+                    set_round=set_round
+                    size_of_final=size_of_final
+                    set_size_of_final=set_size_of_final
+                    qualified_to_final=qualified_to_final
+                    set_qualified_to_final=set_qualified_to_final
+                    main_event=main_event
+                    set_main_event=set_main_event
+                    event=event
+                />
+
+                <section class="p-4 bg-gray-50 border border-gray-200 rounded-md" aria-live="polite">
+                    {move || match target.get() {
+                        Ok(target) => {
+                            let required = match target.performance {
+                                Some(performance) => event.get().format_performance(performance),
+                                None => "Any mark".to_string(),
+                            };
+                            view! {
+                                <p class="text-sm text-gray-500">"Performance needed"</p>
+                                <p class="text-3xl font-bold text-gray-900">{required}</p>
+                                <p class="text-sm text-gray-600">
+                                    {format!(
+                                        "Result score {:.0} + placing score {} = {:.0} points",
+                                        target.result_score,
+                                        target.placement_score,
+                                        target.result_score + target.placement_score as f64,
+                                    )}
+                                </p>
+                            }
+                                .into_any()
+                        }
+                        Err(e) => view! { <p class="text-sm text-red-600">{e}</p> }.into_any(),
+                    }}
+                </section>
+                <p class="text-xs text-gray-500">
+                    {format!(
+                        "Wind and downhill adjustments aren't counted. A mark can score at most {:.0} points before placing points.",
+                        MAX_RESULT_SCORE,
+                    )}
+                </p>
+            </div>
+        </main>
+    }
+}
@@ -0,0 +1,698 @@
+//! Local performance database backed by IndexedDB.
+//!
+//! Results are stored as JSON strings under auto-incremented keys in a single object store.
+//! Queries load every record and filter in memory, which is plenty for a club's results.
+//!
+//! Every write is announced on a `BroadcastChannel` so pages open in other tabs reload, and
+//! updates carry the record's revision so an edit can't silently overwrite a change made
+//! in another tab since the record was loaded.
+use crate::importers::open_track::ScoredResult;
+use crate::importers::result_status::ResultStatus;
+use crate::models::*;
+use crate::scoring_logic::calculator::{calculate_world_athletics_score, is_placement_only_event};
+use crate::scoring_logic::placement_score::{PlacementScoreCalcInput, RoundType};
+use crate::share_link::{parse_mark, CalculatorState};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    IdbDatabase, IdbObjectStoreParameters, IdbOpenDbRequest, IdbRequest, IdbTransactionMode,
+};
+
+const DB_NAME: &str = "wa_points";
+const DB_VERSION: u32 = 1;
+const PERFORMANCES_STORE: &str = "performances";
+/// Channel on which writes are announced to the app's other tabs
+const CHANGES_CHANNEL: &str = "wa_points_performances";
+
+/// A result kept in the local database
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StoredPerformance {
+    /// The database key; `None` until the record has been saved
+    #[serde(skip)]
+    pub id: Option<u32>,
+    pub athlete: String,
+    pub gender: Gender,
+    /// The event's display name, see `Event::from_string`
+    pub event: String,
+    pub mark: String,
+    /// Set for results without a mark, such as DNF, which are kept but never scored
+    pub status: Option<ResultStatus>,
+    pub wind_speed: Option<f64>,
+    /// Net elevation drop of a road course in m/km
+    pub net_downhill: Option<f64>,
+    /// Venue altitude in meters above sea level
+    pub venue_altitude: Option<f64>,
+    /// The event the mark was scored as when it differs from `event`, e.g. the outdoor event
+    /// for a mark set on an oversized indoor track
+    pub scored_as: Option<String>,
+    pub competition_category: Option<CompetitionCategory>,
+    pub round: RoundType,
+    pub place: Option<i32>,
+    pub size_of_final: i32,
+    pub qualified_to_final: bool,
+    /// Number of other athletes sharing the place
+    pub tied_with: i32,
+    /// See `PlacementInfo::main_event`
+    pub main_event: bool,
+    /// ISO 8601 date (YYYY-MM-DD)
+    pub date: String,
+    pub venue: String,
+    /// World Athletics points when the result was stored, if it could be scored
+    pub points: Option<f64>,
+    /// The placing points included in `points`
+    pub placement_points: Option<i32>,
+    /// Incremented on every update, to detect concurrent edits from other tabs
+    pub revision: u32,
+}
+
+impl Default for StoredPerformance {
+    fn default() -> Self {
+        StoredPerformance {
+            id: None,
+            athlete: String::new(),
+            gender: Gender::Men,
+            event: Event::default().to_string(),
+            mark: String::new(),
+            status: None,
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: None,
+            scored_as: None,
+            competition_category: None,
+            round: RoundType::Final,
+            place: None,
+            size_of_final: 8,
+            qualified_to_final: false,
+            tied_with: 0,
+            main_event: false,
+            date: String::new(),
+            venue: String::new(),
+            points: None,
+            placement_points: None,
+            revision: 0,
+        }
+    }
+}
+
+impl StoredPerformance {
+    /// A record for an imported result scored at the given competition category
+    pub fn from_scored(scored: &ScoredResult, competition_category: CompetitionCategory) -> Self {
+        let result = &scored.result;
+        StoredPerformance {
+            id: None,
+            athlete: result.athlete.clone(),
+            gender: result.gender,
+            event: result.event.to_string(),
+            mark: result.mark.clone(),
+            status: result.status,
+            wind_speed: result.wind_speed,
+            net_downhill: None,
+            venue_altitude: None,
+            scored_as: None,
+            competition_category: result.place.map(|_| competition_category),
+            round: result.round,
+            place: result.place,
+            size_of_final: result.size_of_final,
+            qualified_to_final: result.qualified_to_final,
+            tied_with: 0,
+            main_event: false,
+            date: result.date.clone().unwrap_or_default(),
+            venue: result.venue.clone().unwrap_or_default(),
+            points: scored.score.as_ref().ok().map(|score| score.points),
+            placement_points: scored
+                .score
+                .as_ref()
+                .ok()
+                .map(|score| score.placement_score),
+            revision: 0,
+        }
+    }
+
+    /// A record for a result scored in the calculator, saved under an athlete and date. The
+    /// scored input is kept in full, so rescoring the record gives the points shown.
+    pub fn from_calculation(
+        athlete: &str,
+        date: &str,
+        venue: &str,
+        state: &CalculatorState,
+        input: &WorldAthleticsScoreInput,
+        score: &WorldAthleticsScoreOutput,
+    ) -> Self {
+        let placement = input.placement_info.as_ref();
+        StoredPerformance {
+            id: None,
+            athlete: athlete.trim().to_string(),
+            gender: input.gender,
+            event: state.event.to_string(),
+            mark: state.mark.clone(),
+            status: None,
+            wind_speed: input.wind_speed,
+            net_downhill: input.net_downhill,
+            venue_altitude: input.venue_altitude,
+            scored_as: (input.event != state.event).then(|| input.event.to_string()),
+            competition_category: placement.map(|p| p.competition_category),
+            round: placement.map_or(RoundType::Final, |p| p.round),
+            place: placement.map(|p| p.place),
+            size_of_final: placement.map_or(8, |p| p.size_of_final),
+            qualified_to_final: placement.is_some_and(|p| p.qualified_to_final),
+            tied_with: placement.map_or(0, |p| p.tied_with),
+            main_event: placement.is_some_and(|p| p.main_event),
+            date: date.to_string(),
+            venue: venue.trim().to_string(),
+            points: Some(score.points),
+            placement_points: Some(score.placement_score),
+            revision: 0,
+        }
+    }
+
+    /// Recalculates `points` from the stored mark, e.g. after an edit
+    pub fn rescore(
+        &mut self,
+        result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+        placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+    ) -> Result<f64, String> {
+        let event_name = self.scored_as.as_ref().unwrap_or(&self.event);
+        let event = Event::from_string(event_name)
+            .ok_or_else(|| format!("Unknown event: {}", event_name))?;
+        // Re-read the marker, since an edit may have replaced it with a mark or vice versa.
+        // Events scored on placing alone are saved without a mark.
+        self.status = if is_placement_only_event(&event) && self.mark.trim().is_empty() {
+            None
+        } else {
+            ResultStatus::from_mark(&self.mark)
+        };
+        if let Some(status) = self.status {
+            self.points = None;
+            self.placement_points = None;
+            return Err(format!("{} ({}), not scored", status, status.description()));
+        }
+        let performance = parse_mark(&event, &self.mark)?;
+        let placement_info = match (self.competition_category, self.place) {
+            (Some(competition_category), Some(place)) => Some(PlacementInfo {
+                competition_category,
+                place,
+                round: self.round,
+                size_of_final: self.size_of_final,
+                qualified_to_final: self.qualified_to_final,
+                tied_with: self.tied_with,
+                main_event: self.main_event,
+            }),
+            _ => None,
+        };
+        let score = calculate_world_athletics_score(
+            WorldAthleticsScoreInput {
+                gender: self.gender,
+                event,
+                performance,
+                wind_speed: self.wind_speed,
+                net_downhill: self.net_downhill,
+                venue_altitude: self.venue_altitude,
+                placement_info,
+                table_edition: None,
+            },
+            result_score_calculator,
+            placement_score_calculator,
+        );
+        self.points = score.as_ref().ok().map(|score| score.points);
+        self.placement_points = score.as_ref().ok().map(|score| score.placement_score);
+        score.map(|score| score.points)
+    }
+}
+
+/// Filters for stored performances. Empty fields match everything.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PerformanceQuery {
+    pub athlete: Option<String>,
+    pub event: Option<String>,
+    /// Inclusive ISO 8601 date bounds
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub min_points: Option<f64>,
+    /// Case-insensitive substring of the venue
+    pub venue: Option<String>,
+}
+
+impl PerformanceQuery {
+    pub fn matches(&self, performance: &StoredPerformance) -> bool {
+        self.athlete
+            .as_ref()
+            .is_none_or(|athlete| &performance.athlete == athlete)
+            && self
+                .event
+                .as_ref()
+                .is_none_or(|event| &performance.event == event)
+            && self
+                .from
+                .as_ref()
+                .is_none_or(|from| &performance.date >= from)
+            && self.to.as_ref().is_none_or(|to| &performance.date <= to)
+            && self
+                .min_points
+                .is_none_or(|min| performance.points.is_some_and(|points| points >= min))
+            && self.venue.as_ref().is_none_or(|venue| {
+                performance
+                    .venue
+                    .to_lowercase()
+                    .contains(&venue.to_lowercase())
+            })
+    }
+}
+
+fn js_error(e: JsValue) -> String {
+    e.as_string()
+        .or_else(|| e.dyn_ref::<js_sys::Error>().map(|e| e.message().into()))
+        .unwrap_or_else(|| "IndexedDB error".to_string())
+}
+
+/// Waits for an IndexedDB request to finish and returns its result. The handlers are
+/// attached immediately, so the request can't complete unobserved before the future is polled.
+fn request_result(request: &IdbRequest) -> impl Future<Output = Result<JsValue, String>> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let succeeded = request.clone();
+        let onsuccess = Closure::once_into_js(move || {
+            let _ = resolve.call1(
+                &JsValue::NULL,
+                &succeeded.result().unwrap_or(JsValue::UNDEFINED),
+            );
+        });
+        let failed = request.clone();
+        let onerror = Closure::once_into_js(move || {
+            let message = failed
+                .error()
+                .ok()
+                .flatten()
+                .map(|e| e.message())
+                .unwrap_or_else(|| "IndexedDB request failed".to_string());
+            let _ = reject.call1(&JsValue::NULL, &JsValue::from_str(&message));
+        });
+        request.set_onsuccess(Some(onsuccess.unchecked_ref()));
+        request.set_onerror(Some(onerror.unchecked_ref()));
+    });
+    let future = JsFuture::from(promise);
+    async move { future.await.map_err(js_error) }
+}
+
+/// A connection to the local performance database
+#[derive(Clone)]
+pub struct PerformanceDb {
+    db: IdbDatabase,
+}
+
+impl PerformanceDb {
+    /// Opens the database, creating the object store on first use
+    pub async fn open() -> Result<Self, String> {
+        let factory = web_sys::window()
+            .ok_or("No window")?
+            .indexed_db()
+            .map_err(js_error)?
+            .ok_or("IndexedDB is not available in this browser")?;
+        let request: IdbOpenDbRequest = factory
+            .open_with_u32(DB_NAME, DB_VERSION)
+            .map_err(js_error)?;
+        let upgrading = request.clone();
+        let onupgradeneeded = Closure::once_into_js(move || {
+            if let Ok(db) = upgrading
+                .result()
+                .and_then(|db| db.dyn_into::<IdbDatabase>())
+            {
+                if !db.object_store_names().contains(PERFORMANCES_STORE) {
+                    let parameters = IdbObjectStoreParameters::new();
+                    parameters.set_auto_increment(true);
+                    if let Err(e) = db.create_object_store_with_optional_parameters(
+                        PERFORMANCES_STORE,
+                        &parameters,
+                    ) {
+                        log::error!("Failed to create the performances store: {}", js_error(e));
+                    }
+                }
+            }
+        });
+        request.set_onupgradeneeded(Some(onupgradeneeded.unchecked_ref()));
+        let db = request_result(&request)
+            .await?
+            .dyn_into::<IdbDatabase>()
+            .map_err(js_error)?;
+        Ok(PerformanceDb { db })
+    }
+
+    fn store(&self, mode: IdbTransactionMode) -> Result<web_sys::IdbObjectStore, String> {
+        self.db
+            .transaction_with_str_and_mode(PERFORMANCES_STORE, mode)
+            .and_then(|transaction| transaction.object_store(PERFORMANCES_STORE))
+            .map_err(js_error)
+    }
+
+    /// Every stored performance, in the order they were added
+    pub async fn all(&self) -> Result<Vec<StoredPerformance>, String> {
+        let store = self.store(IdbTransactionMode::Readonly)?;
+        // Both requests are issued before awaiting, since the transaction commits once idle
+        let keys_request = store.get_all_keys().map_err(js_error)?;
+        let values_request = store.get_all().map_err(js_error)?;
+        let (keys, values) = (
+            request_result(&keys_request),
+            request_result(&values_request),
+        );
+        let (keys, values) = (keys.await?, values.await?);
+        let keys = js_sys::Array::from(&keys);
+        let values = js_sys::Array::from(&values);
+        Ok(keys
+            .iter()
+            .zip(values.iter())
+            .filter_map(|(key, value)| {
+                let mut performance: StoredPerformance =
+                    serde_json::from_str(&value.as_string()?).ok()?;
+                performance.id = key.as_f64().map(|id| id as u32);
+                Some(performance)
+            })
+            .collect())
+    }
+
+    pub async fn query(&self, query: &PerformanceQuery) -> Result<Vec<StoredPerformance>, String> {
+        Ok(self
+            .all()
+            .await?
+            .into_iter()
+            .filter(|performance| query.matches(performance))
+            .collect())
+    }
+
+    /// Adds a new performance or updates a stored one, returning its id. An update fails if
+    /// the record was changed or deleted (e.g. in another tab) since `performance` was loaded.
+    pub async fn put(&self, performance: &StoredPerformance) -> Result<u32, String> {
+        let id = self.write(performance).await?;
+        notify_changed();
+        Ok(id)
+    }
+
+    async fn write(&self, performance: &StoredPerformance) -> Result<u32, String> {
+        let store = self.store(IdbTransactionMode::Readwrite)?;
+        let Some(id) = performance.id else {
+            let value =
+                JsValue::from_str(&serde_json::to_string(performance).map_err(|e| e.to_string())?);
+            let key = request_result(&store.add(&value).map_err(js_error)?).await?;
+            return key
+                .as_f64()
+                .map(|id| id as u32)
+                .ok_or_else(|| "Unexpected key from IndexedDB".to_string());
+        };
+
+        let updated = StoredPerformance {
+            revision: performance.revision + 1,
+            ..performance.clone()
+        };
+        let value = JsValue::from_str(&serde_json::to_string(&updated).map_err(|e| e.to_string())?);
+        let key = JsValue::from(id);
+        let current = store.get(&key).map_err(js_error)?;
+        // The check and the write happen in the success handler of the read, so no other
+        // transaction can write the record in between
+        let expected = performance.revision;
+        let promise = js_sys::Promise::new(&mut |resolve, reject| {
+            let read = current.clone();
+            let store = store.clone();
+            let key = key.clone();
+            let value = value.clone();
+            let failed = reject.clone();
+            let onsuccess = Closure::once_into_js(move || {
+                let stored = read
+                    .result()
+                    .ok()
+                    .and_then(|stored| stored.as_string())
+                    .and_then(|json| serde_json::from_str::<StoredPerformance>(&json).ok());
+                let conflict = match stored {
+                    None => Some("This result was deleted in another tab"),
+                    Some(stored) if stored.revision != expected => {
+                        Some("This result was changed in another tab. Reload and try again")
+                    }
+                    Some(_) => None,
+                };
+                if let Some(conflict) = conflict {
+                    let _ = reject.call1(&JsValue::NULL, &JsValue::from_str(conflict));
+                    return;
+                }
+                match store.put_with_key(&value, &key) {
+                    Ok(written) => {
+                        let written = request_result(&written);
+                        wasm_bindgen_futures::spawn_local(async move {
+                            let _ = match written.await {
+                                Ok(key) => resolve.call1(&JsValue::NULL, &key),
+                                Err(e) => reject.call1(&JsValue::NULL, &JsValue::from_str(&e)),
+                            };
+                        });
+                    }
+                    Err(e) => {
+                        let _ = reject.call1(&JsValue::NULL, &e);
+                    }
+                }
+            });
+            let onerror = Closure::once_into_js(move || {
+                let _ = failed.call1(
+                    &JsValue::NULL,
+                    &JsValue::from_str("IndexedDB request failed"),
+                );
+            });
+            current.set_onsuccess(Some(onsuccess.unchecked_ref()));
+            current.set_onerror(Some(onerror.unchecked_ref()));
+        });
+        JsFuture::from(promise).await.map_err(js_error)?;
+        Ok(id)
+    }
+
+    pub async fn put_all(&self, performances: &[StoredPerformance]) -> Result<(), String> {
+        let written = async {
+            for performance in performances {
+                self.write(performance).await?;
+            }
+            Ok(())
+        }
+        .await;
+        notify_changed();
+        written
+    }
+
+    pub async fn delete(&self, id: u32) -> Result<(), String> {
+        let store = self.store(IdbTransactionMode::Readwrite)?;
+        request_result(&store.delete(&JsValue::from(id)).map_err(js_error)?).await?;
+        notify_changed();
+        Ok(())
+    }
+}
+
+/// Tells the app's other tabs that the stored performances changed
+fn notify_changed() {
+    match web_sys::BroadcastChannel::new(CHANGES_CHANNEL) {
+        Ok(channel) => {
+            let _ = channel.post_message(&JsValue::NULL);
+            channel.close();
+        }
+        Err(e) => log::warn!("Failed to announce the change: {}", js_error(e)),
+    }
+}
+
+/// A counter bumped whenever the stored performances are changed, in this tab or another.
+/// Pages track it to reload their records.
+pub fn use_performances_changed() -> ReadSignal<u32> {
+    let (changes, set_changes) = signal(0u32);
+    let Ok(channel) = web_sys::BroadcastChannel::new(CHANGES_CHANNEL) else {
+        return changes;
+    };
+    let onmessage = Closure::<dyn Fn()>::new(move || set_changes.update(|n| *n += 1));
+    channel.set_onmessage(Some(onmessage.as_ref().unchecked_ref()));
+    // Keeps the handler alive for as long as the component using it
+    let channel = StoredValue::new_local((channel, onmessage));
+    on_cleanup(move || channel.with_value(|(channel, _)| channel.close()));
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scoring_logic::track_size::{scoring_event, TrackSize};
+
+    fn mock_result_score(performance: f64, _: Gender, _: &Event) -> Result<f64, String> {
+        Ok(2000.0 - performance * 100.0)
+    }
+
+    fn mock_placement_score(_: PlacementScoreCalcInput) -> Option<i32> {
+        Some(50)
+    }
+
+    fn mock_placing_by_place(input: PlacementScoreCalcInput) -> Option<i32> {
+        Some(100 - 10 * input.place + i32::from(input.main_event) * 5)
+    }
+
+    // The outdoor 200m scores differently from every other event
+    fn mock_by_event(performance: f64, _: Gender, event: &Event) -> Result<f64, String> {
+        match event {
+            Event::TrackAndField(TrackAndFieldEvent::M200) => Ok(1300.0 - performance * 10.0),
+            _ => Ok(2000.0 - performance / 10.0),
+        }
+    }
+
+    #[test]
+    fn test_query_matches() {
+        let performance = StoredPerformance {
+            athlete: "Ana".to_string(),
+            event: "100m".to_string(),
+            date: "2025-06-14".to_string(),
+            venue: "Olympic Stadium".to_string(),
+            points: Some(1050.0),
+            ..Default::default()
+        };
+        assert!(PerformanceQuery::default().matches(&performance));
+        assert!(PerformanceQuery {
+            event: Some("100m".to_string()),
+            from: Some("2025-06-01".to_string()),
+            to: Some("2025-06-14".to_string()),
+            min_points: Some(1000.0),
+            venue: Some("olympic".to_string()),
+            ..Default::default()
+        }
+        .matches(&performance));
+        assert!(!PerformanceQuery {
+            min_points: Some(1100.0),
+            ..Default::default()
+        }
+        .matches(&performance));
+        assert!(!PerformanceQuery {
+            to: Some("2025-05-31".to_string()),
+            ..Default::default()
+        }
+        .matches(&performance));
+    }
+
+    #[test]
+    fn test_rescore_and_serde() {
+        let mut performance = StoredPerformance {
+            mark: "10.00".to_string(),
+            wind_speed: Some(0.0),
+            competition_category: Some(CompetitionCategory::A),
+            place: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            performance.rescore(mock_result_score, mock_placement_score),
+            Ok(1050.0)
+        );
+        assert_eq!(performance.points, Some(1050.0));
+        assert_eq!(performance.placement_points, Some(50));
+
+        performance.id = Some(7);
+        let json = serde_json::to_string(&performance).unwrap();
+        let restored: StoredPerformance = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            restored,
+            StoredPerformance {
+                id: None,
+                ..performance
+            }
+        );
+    }
+
+    #[test]
+    fn test_rescore_from_calculation() {
+        let state = CalculatorState {
+            gender: Gender::Women,
+            event: Event::RoadRunning(RoadRunningEvent::Road10km),
+            mark: "31:00".to_string(),
+            wind_speed: None,
+            net_downhill: Some(2.0),
+            venue_altitude: None,
+            track_size: TrackSize::Banked,
+            athlete_category: AthleteCategory::Senior,
+            placement: Some(PlacementInfo {
+                competition_category: CompetitionCategory::A,
+                place: 2,
+                round: RoundType::Final,
+                size_of_final: 8,
+                qualified_to_final: false,
+                tied_with: 1,
+                main_event: true,
+            }),
+            table_edition: None,
+        };
+        let input = WorldAthleticsScoreInput {
+            gender: state.gender,
+            event: state.event.clone(),
+            performance: Performance::Seconds(1860.0),
+            wind_speed: None,
+            net_downhill: state.net_downhill,
+            venue_altitude: None,
+            placement_info: state.placement.clone(),
+            table_edition: None,
+        };
+        let score =
+            calculate_world_athletics_score(input.clone(), mock_by_event, mock_placing_by_place)
+                .unwrap();
+        let mut saved =
+            StoredPerformance::from_calculation("Ana", "2025-06-14", "", &state, &input, &score);
+        assert_eq!(
+            saved.rescore(mock_by_event, mock_placing_by_place),
+            Ok(score.points)
+        );
+
+        // A short track mark on an oversized track is rescored as the outdoor event
+        let (outdoor, _) = scoring_event(
+            &Event::TrackAndField(TrackAndFieldEvent::M200mSh),
+            TrackSize::Oversized,
+        );
+        let state = CalculatorState {
+            event: Event::TrackAndField(TrackAndFieldEvent::M200mSh),
+            mark: "23.00".to_string(),
+            net_downhill: None,
+            track_size: TrackSize::Oversized,
+            placement: None,
+            ..state
+        };
+        let input = WorldAthleticsScoreInput {
+            event: outdoor,
+            performance: Performance::Seconds(23.0),
+            wind_speed: Some(0.0),
+            net_downhill: None,
+            placement_info: None,
+            ..input
+        };
+        let score =
+            calculate_world_athletics_score(input.clone(), mock_by_event, mock_placing_by_place)
+                .unwrap();
+        let mut saved =
+            StoredPerformance::from_calculation("Ana", "2025-02-01", "", &state, &input, &score);
+        assert_eq!(saved.event, state.event.to_string());
+        assert_eq!(saved.scored_as, Some(input.event.to_string()));
+        assert_eq!(
+            saved.rescore(mock_by_event, mock_placing_by_place),
+            Ok(score.points)
+        );
+    }
+
+    #[test]
+    fn test_rescore_status() {
+        let mut performance = StoredPerformance {
+            mark: "DNF".to_string(),
+            points: Some(1000.0),
+            ..Default::default()
+        };
+        assert!(performance
+            .rescore(mock_result_score, mock_placement_score)
+            .is_err());
+        assert_eq!(performance.status, Some(ResultStatus::DidNotFinish));
+        assert_eq!(performance.points, None);
+
+        performance.mark = "10.00".to_string();
+        assert!(performance
+            .rescore(mock_result_score, mock_placement_score)
+            .is_ok());
+        assert_eq!(performance.status, None);
+    }
+
+    #[test]
+    fn test_records_without_revision_start_at_zero() {
+        let stored: StoredPerformance =
+            serde_json::from_str(r#"{"athlete":"Ana","event":"100m","mark":"11.20"}"#).unwrap();
+        assert_eq!(stored.revision, 0);
+    }
+}
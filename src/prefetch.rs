@@ -0,0 +1,62 @@
+// src/prefetch.rs
+//! Background parsing of the secondary datasets (season lists, ranking calendar) once the
+//! app has painted, so pages that need them open instantly without adding to the initial
+//! load. The placement tables aren't prefetched: they're indexed when the placement section
+//! is first enabled.
+//!
+//! Each dataset is parsed in its own idle callback, so a long parse never blocks input.
+//! Browsers without `requestIdleCallback` fall back to a short timeout.
+use crate::scoring_logic::ranking_calendar::ranking_calendar;
+use crate::scoring_logic::season_lists::season_lists;
+use crate::startup_timing::measure;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Delay between tasks where `requestIdleCallback` isn't supported
+const FALLBACK_DELAY_MS: i32 = 50;
+
+type PrefetchTask = (&'static str, fn());
+
+const TASKS: &[PrefetchTask] = &[
+    ("prefetch_season_lists", || {
+        season_lists();
+    }),
+    ("prefetch_ranking_calendar", || {
+        ranking_calendar();
+    }),
+];
+
+/// Schedules the prefetch tasks one per idle period, in order
+pub fn prefetch_when_idle() {
+    schedule(TASKS);
+}
+
+fn schedule(tasks: &'static [PrefetchTask]) {
+    let Some(((name, task), rest)) = tasks.split_first() else {
+        return;
+    };
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    let callback = Closure::once_into_js(move || {
+        measure(name, task);
+        schedule(rest);
+    });
+    let supports_idle_callback =
+        js_sys::Reflect::has(&window, &"requestIdleCallback".into()).unwrap_or(false);
+    let scheduled = if supports_idle_callback {
+        window
+            .request_idle_callback(callback.unchecked_ref())
+            .map(|_| ())
+    } else {
+        window
+            .set_timeout_with_callback_and_timeout_and_arguments_0(
+                callback.unchecked_ref(),
+                FALLBACK_DELAY_MS,
+            )
+            .map(|_| ())
+    };
+    if let Err(e) = scheduled {
+        log::warn!("Failed to schedule {}: {:?}", name, e);
+    }
+}
@@ -0,0 +1,199 @@
+//! A coach's roster of athletes, each with an event and mark, kept in localStorage so it
+//! survives a refresh. The roster is scored with the same code as imported results files.
+use crate::importers::open_track::{score_result, ImportedResult};
+use crate::models::*;
+use crate::scoring_logic::placement_score::{PlacementScoreCalcInput, RoundType};
+use crate::settings::{load_from_storage, save_to_storage};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+const ROSTER_KEY: &str = "wa_points_roster";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RosterAthlete {
+    pub name: String,
+    pub gender: Gender,
+    /// The event's display name, see `Event::from_string`
+    pub event: String,
+    pub mark: String,
+    /// As typed; blank for no wind reading
+    pub wind: String,
+}
+
+impl Default for RosterAthlete {
+    fn default() -> Self {
+        RosterAthlete {
+            name: String::new(),
+            gender: Gender::Men,
+            event: Event::default().to_string(),
+            mark: String::new(),
+            wind: String::new(),
+        }
+    }
+}
+
+impl RosterAthlete {
+    /// The athlete's mark as a result without a placing
+    pub fn to_result(&self) -> Result<ImportedResult, String> {
+        let event = Event::from_string(&self.event)
+            .ok_or_else(|| format!("Unknown event: {}", self.event))?;
+        let wind_speed = match self.wind.trim() {
+            "" => None,
+            wind => Some(
+                wind.trim_start_matches('+')
+                    .parse::<f64>()
+                    .map_err(|_| format!("Invalid wind: {}", wind))?,
+            ),
+        };
+        Ok(ImportedResult {
+            athlete: self.name.clone(),
+            gender: self.gender,
+            event,
+            round: RoundType::Final,
+            place: None,
+            mark: self.mark.trim().to_string(),
+            wind_speed,
+            size_of_final: 8,
+            qualified_to_final: false,
+            date: None,
+            venue: None,
+            status: None,
+        })
+    }
+}
+
+/// Scores every athlete on the roster, in roster order
+pub fn score_roster(
+    roster: &[RosterAthlete],
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> Vec<Result<WorldAthleticsScoreOutput, String>> {
+    roster
+        .iter()
+        .map(|athlete| {
+            // Without a placing the competition category has no effect
+            score_result(
+                &athlete.to_result()?,
+                CompetitionCategory::F,
+                result_score_calculator,
+                placement_score_calculator,
+            )
+        })
+        .collect()
+}
+
+/// Column the roster table is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterSort {
+    Name,
+    Event,
+    Points,
+}
+
+/// The roster's indices in display order. Athletes who couldn't be scored always come last
+/// when sorting by points.
+pub fn sorted_indices(
+    roster: &[RosterAthlete],
+    scores: &[Result<WorldAthleticsScoreOutput, String>],
+    sort: RosterSort,
+    descending: bool,
+) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..roster.len()).collect();
+    let points = |i: usize| {
+        scores
+            .get(i)
+            .and_then(|s| s.as_ref().ok())
+            .map(|s| s.points)
+    };
+    indices.sort_by(|&a, &b| {
+        let order = match sort {
+            RosterSort::Name => roster[a]
+                .name
+                .to_lowercase()
+                .cmp(&roster[b].name.to_lowercase()),
+            RosterSort::Event => roster[a].event.cmp(&roster[b].event),
+            RosterSort::Points => match (points(a), points(b)) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (Some(_), None) if descending => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) if descending => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+        };
+        if descending {
+            order.reverse()
+        } else {
+            order
+        }
+    });
+    indices
+}
+
+/// The roster saved in the browser, saved again whenever it changes
+pub fn use_roster() -> RwSignal<Vec<RosterAthlete>> {
+    let roster = RwSignal::new(
+        load_from_storage::<Vec<RosterAthlete>>(ROSTER_KEY)
+            .unwrap_or_else(|| vec![RosterAthlete::default()]),
+    );
+    Effect::new(move |_| save_to_storage(ROSTER_KEY, &roster.get()));
+    roster
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_result_score_calculator(performance: f64, _: Gender, _: &Event) -> Result<f64, String> {
+        Ok(2000.0 - performance * 100.0)
+    }
+
+    fn mock_placement_score_calculator(_: PlacementScoreCalcInput) -> Option<i32> {
+        Some(100)
+    }
+
+    fn athlete(name: &str, mark: &str) -> RosterAthlete {
+        RosterAthlete {
+            name: name.to_string(),
+            event: "100m".to_string(),
+            mark: mark.to_string(),
+            wind: "+0.5".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_score_and_sort_roster() {
+        let roster = vec![
+            athlete("Cole", "10.50"),
+            athlete("ada", "10.20"),
+            RosterAthlete {
+                wind: "gusty".to_string(),
+                ..athlete("Bea", "10.30")
+            },
+        ];
+        let scores = score_roster(
+            &roster,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        );
+        assert_eq!(scores[0].as_ref().unwrap().points, 950.0);
+        assert_eq!(scores[1].as_ref().unwrap().placement_score, 0);
+        assert_eq!(scores[2], Err("Invalid wind: gusty".to_string()));
+
+        assert_eq!(
+            sorted_indices(&roster, &scores, RosterSort::Points, true),
+            [1, 0, 2]
+        );
+        assert_eq!(
+            sorted_indices(&roster, &scores, RosterSort::Points, false),
+            [0, 1, 2]
+        );
+        assert_eq!(
+            sorted_indices(&roster, &scores, RosterSort::Name, false),
+            [1, 2, 0]
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! An athlete's profile from the stored results: their personal best in every event, each
+//! scored on the mark alone so that events can be compared, and their strongest event.
+use crate::models::*;
+use crate::performance_db::StoredPerformance;
+use crate::scoring_logic::calculator::calculate_world_athletics_score;
+use crate::scoring_logic::placement_score::PlacementScoreCalcInput;
+use crate::scoring_logic::selection::MAX_LEGAL_WIND;
+
+/// The best legal mark an athlete has in one event
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalBest {
+    pub event: Event,
+    pub mark: String,
+    /// The mark in the event's standard unit (seconds, meters or points)
+    pub performance: f64,
+    pub wind_speed: Option<f64>,
+    pub date: String,
+    pub venue: String,
+    /// Result score plus wind adjustment, without placing points
+    pub points: Result<f64, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AthleteProfile {
+    pub athlete: String,
+    /// Highest points first, then the bests that couldn't be scored
+    pub personal_bests: Vec<PersonalBest>,
+}
+
+impl AthleteProfile {
+    /// The event the athlete scores most points in
+    pub fn strongest(&self) -> Option<&PersonalBest> {
+        self.personal_bests.first().filter(|pb| pb.points.is_ok())
+    }
+}
+
+fn is_better(event: &Event, performance: f64, than: f64) -> bool {
+    match event.performance_type() {
+        PerformanceType::Time => performance < than,
+        PerformanceType::Distance | PerformanceType::Points => performance > than,
+    }
+}
+
+/// Builds an athlete's profile from their stored results. Marks that are wind-assisted or
+/// can't be read are passed over, and the personal bests are scored as one batch.
+pub fn athlete_profile(
+    athlete: &str,
+    records: &[StoredPerformance],
+    result_score_calculator: fn(f64, Gender, &Event) -> Result<f64, String>,
+    placement_score_calculator: fn(PlacementScoreCalcInput) -> Option<i32>,
+) -> AthleteProfile {
+    let mut bests: Vec<(Gender, PersonalBest)> = Vec::new();
+    for record in records.iter().filter(|record| record.athlete == athlete) {
+        if record.status.is_some() || record.wind_speed.is_some_and(|wind| wind > MAX_LEGAL_WIND) {
+            continue;
+        }
+        let Some(event) = Event::from_string(&record.event) else {
+            continue;
+        };
+        let Ok(performance) = Performance::parse(&event, &record.mark) else {
+            continue;
+        };
+        let performance = performance.value();
+        let best = PersonalBest {
+            event,
+            mark: record.mark.clone(),
+            performance,
+            wind_speed: record.wind_speed,
+            date: record.date.clone(),
+            venue: record.venue.clone(),
+            points: Err("Not scored".to_string()),
+        };
+        match bests.iter_mut().find(|(_, pb)| pb.event == best.event) {
+            Some((gender, pb)) => {
+                if is_better(&pb.event, performance, pb.performance) {
+                    *gender = record.gender;
+                    *pb = best;
+                }
+            }
+            None => bests.push((record.gender, best)),
+        }
+    }
+
+    let inputs: Vec<WorldAthleticsScoreInput> = bests
+        .iter()
+        .map(|(gender, pb)| WorldAthleticsScoreInput {
+            gender: *gender,
+            event: pb.event.clone(),
+            performance: Performance::for_event(&pb.event, pb.performance),
+            wind_speed: pb.wind_speed,
+            net_downhill: None,
+            venue_altitude: None,
+            placement_info: None,
+            table_edition: None,
+        })
+        .collect();
+    let mut personal_bests: Vec<PersonalBest> = bests
+        .into_iter()
+        .zip(inputs)
+        .map(|((_, pb), input)| PersonalBest {
+            points: calculate_world_athletics_score(
+                input,
+                result_score_calculator,
+                placement_score_calculator,
+            )
+            .map(|score| score.points),
+            ..pb
+        })
+        .collect();
+    personal_bests.sort_by(|a, b| match (&a.points, &b.points) {
+        (Ok(a), Ok(b)) => b.total_cmp(a),
+        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+        (Err(_), Err(_)) => std::cmp::Ordering::Equal,
+    });
+    AthleteProfile {
+        athlete: athlete.to_string(),
+        personal_bests,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::importers::result_status::ResultStatus;
+
+    // 1 point per hundredth under 20 seconds, 100 points per meter, no table for the marathon
+    fn mock_result_score_calculator(
+        performance: f64,
+        _gender: Gender,
+        event: &Event,
+    ) -> Result<f64, String> {
+        match event {
+            Event::TrackAndField(TrackAndFieldEvent::M100) => Ok((20.0 - performance) * 100.0),
+            Event::TrackAndField(TrackAndFieldEvent::LJ) => Ok(performance * 100.0),
+            _ => Err(format!("No table for {}", event)),
+        }
+    }
+
+    fn mock_placement_score_calculator(_input: PlacementScoreCalcInput) -> Option<i32> {
+        None
+    }
+
+    fn record(event: &str, mark: &str, wind: Option<f64>) -> StoredPerformance {
+        StoredPerformance {
+            athlete: "Ana".to_string(),
+            event: event.to_string(),
+            mark: mark.to_string(),
+            wind_speed: wind,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_athlete_profile() {
+        let records = vec![
+            record("100m", "10.50", Some(1.0)),
+            record("100m", "10.20", Some(1.5)),
+            record("100m", "10.00", Some(3.1)),
+            StoredPerformance {
+                status: Some(ResultStatus::DidNotFinish),
+                ..record("100m", "", None)
+            },
+            record("Long Jump", "7.10", Some(0.0)),
+            record("Long Jump", "7.40", None),
+            record("Road Marathon", "2:10:00", None),
+            StoredPerformance {
+                athlete: "Bea".to_string(),
+                ..record("100m", "9.90", Some(0.0))
+            },
+        ];
+        let profile = athlete_profile(
+            "Ana",
+            &records,
+            mock_result_score_calculator,
+            mock_placement_score_calculator,
+        );
+        let marks: Vec<String> = profile
+            .personal_bests
+            .iter()
+            .map(|pb| pb.mark.clone())
+            .collect();
+        assert_eq!(marks, vec!["10.20", "7.40", "2:10:00"]);
+        let strongest = profile.strongest().unwrap();
+        assert_eq!(strongest.event.to_string(), "100m");
+        assert!(profile.personal_bests[2].points.is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::importers::open_track::ScoredResult;
+use crate::models::{EventGroup, Gender};
+
+/// Which results count towards a leaderboard
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LeaderboardFilter {
+    pub gender: Option<Gender>,
+    pub event_group: Option<EventGroup>,
+    /// Inclusive ISO 8601 date bounds. Results without a date are left out when either is set.
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+impl LeaderboardFilter {
+    fn matches(&self, scored: &ScoredResult) -> bool {
+        let result = &scored.result;
+        let in_date_range = match (&self.from, &self.to) {
+            (None, None) => true,
+            (from, to) => result.date.as_ref().is_some_and(|date| {
+                from.as_ref().is_none_or(|from| date >= from)
+                    && to.as_ref().is_none_or(|to| date <= to)
+            }),
+        };
+        self.gender.is_none_or(|gender| result.gender == gender)
+            && self
+                .event_group
+                .is_none_or(|group| result.event.event_group() == group)
+            && in_date_range
+    }
+}
+
+/// An athlete's best scoring result on the leaderboard
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub points: f64,
+    pub best: ScoredResult,
+}
+
+/// Ranks athletes by their single best World Athletics score among the results
+/// that match the filter. Unscored results are ignored; equal points share a rank.
+pub fn leaderboard(results: &[ScoredResult], filter: &LeaderboardFilter) -> Vec<LeaderboardEntry> {
+    let mut best: Vec<(f64, &ScoredResult)> = Vec::new();
+    for scored in results.iter().filter(|scored| filter.matches(scored)) {
+        let Ok(score) = &scored.score else {
+            continue;
+        };
+        let athlete = (&scored.result.athlete, scored.result.gender);
+        match best
+            .iter_mut()
+            .find(|(_, other)| (&other.result.athlete, other.result.gender) == athlete)
+        {
+            Some(entry) if entry.0 < score.points => *entry = (score.points, scored),
+            Some(_) => {}
+            None => best.push((score.points, scored)),
+        }
+    }
+    best.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut entries: Vec<LeaderboardEntry> = Vec::with_capacity(best.len());
+    for (index, (points, scored)) in best.into_iter().enumerate() {
+        let rank = match entries.last() {
+            Some(previous) if previous.points == points => previous.rank,
+            _ => index + 1,
+        };
+        entries.push(LeaderboardEntry {
+            rank,
+            points,
+            best: scored.clone(),
+        });
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::importers::open_track::ImportedResult;
+    use crate::models::{Event, TrackAndFieldEvent, WorldAthleticsScoreOutput};
+    use crate::scoring_logic::placement_score::RoundType;
+
+    fn scored(athlete: &str, event: TrackAndFieldEvent, date: &str, points: f64) -> ScoredResult {
+        ScoredResult {
+            result: ImportedResult {
+                athlete: athlete.to_string(),
+                gender: Gender::Women,
+                event: Event::TrackAndField(event),
+                round: RoundType::Final,
+                place: None,
+                mark: String::new(),
+                wind_speed: None,
+                size_of_final: 8,
+                qualified_to_final: false,
+                date: Some(date.to_string()),
+                venue: None,
+                status: None,
+            },
+            score: Ok(WorldAthleticsScoreOutput {
+                points,
+                result_score: points,
+                wind_adjustment: 0.0,
+                downhill_adjustment: 0.0,
+                placement_score: 0,
+                beyond_table_range: false,
+                estimated: false,
+                result_estimated: false,
+                placement_estimated: false,
+                altitude_assisted: false,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_leaderboard_ranks_best_result_per_athlete() {
+        let results = vec![
+            scored("Ana", TrackAndFieldEvent::M100, "2025-05-01", 1000.0),
+            scored("Ana", TrackAndFieldEvent::LJ, "2025-06-01", 1100.0),
+            scored("Bea", TrackAndFieldEvent::M200, "2025-06-10", 1100.0),
+            scored("Cat", TrackAndFieldEvent::HJ, "2025-07-01", 900.0),
+        ];
+
+        let overall = leaderboard(&results, &LeaderboardFilter::default());
+        let ranks: Vec<(usize, &str)> = overall
+            .iter()
+            .map(|entry| (entry.rank, entry.best.result.athlete.as_str()))
+            .collect();
+        assert_eq!(ranks, vec![(1, "Ana"), (1, "Bea"), (3, "Cat")]);
+
+        let track_in_may = leaderboard(
+            &results,
+            &LeaderboardFilter {
+                event_group: Some(EventGroup::Track),
+                from: Some("2025-05-01".to_string()),
+                to: Some("2025-05-31".to_string()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(track_in_may.len(), 1);
+        assert_eq!(track_in_may[0].points, 1000.0);
+
+        let men = LeaderboardFilter {
+            gender: Some(Gender::Men),
+            ..Default::default()
+        };
+        assert!(leaderboard(&results, &men).is_empty());
+    }
+}
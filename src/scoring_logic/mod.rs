@@ -1,3 +1,9 @@
-pub mod calculator;
-pub mod coefficients;
-pub mod placement_score;
+//! The scoring logic from `wa_points_core`, plus the parts that need the app: ranking stored
+//! and imported performances, and fetching updated tables.
+pub use wa_points_core::scoring_logic::*;
+
+pub mod athlete_profile;
+pub mod leaderboard;
+pub mod progression;
+pub mod remote_tables;
+pub mod season_report;
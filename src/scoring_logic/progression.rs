@@ -0,0 +1,98 @@
+//! An athlete's progression in one event from the stored results: every scored result's
+//! mark and points in date order, for charting across seasons.
+use crate::models::{Event, Performance};
+use crate::performance_db::StoredPerformance;
+use crate::scoring_logic::ranking_calendar::CalendarDate;
+
+/// One result on a progression chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProgressionPoint {
+    /// ISO 8601 date (YYYY-MM-DD)
+    pub date: String,
+    /// Days since 1970-01-01, to space the results by date
+    pub day: i64,
+    pub mark: String,
+    /// The mark in the event's standard unit (seconds, meters or points)
+    pub performance: f64,
+    pub points: f64,
+}
+
+/// The events an athlete has scored results in, sorted by name
+pub fn athlete_events(athlete: &str, records: &[StoredPerformance]) -> Vec<String> {
+    let mut events: Vec<String> = records
+        .iter()
+        .filter(|record| record.athlete == athlete && record.points.is_some())
+        .map(|record| record.event.clone())
+        .collect();
+    events.sort();
+    events.dedup();
+    events
+}
+
+/// An athlete's scored results in an event with a valid date and mark, in date order
+pub fn event_progression(
+    athlete: &str,
+    event: &str,
+    records: &[StoredPerformance],
+) -> Vec<ProgressionPoint> {
+    let Some(parsed_event) = Event::from_string(event) else {
+        return Vec::new();
+    };
+    let mut points: Vec<ProgressionPoint> = records
+        .iter()
+        .filter(|record| record.athlete == athlete && record.event == event)
+        .filter_map(|record| {
+            Some(ProgressionPoint {
+                day: CalendarDate::parse(&record.date).ok()?.days_since_epoch(),
+                performance: Performance::parse(&parsed_event, &record.mark)
+                    .ok()?
+                    .value(),
+                points: record.points?,
+                date: record.date.clone(),
+                mark: record.mark.clone(),
+            })
+        })
+        .collect();
+    points.sort_by(|a, b| a.date.cmp(&b.date));
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(event: &str, date: &str, mark: &str, points: Option<f64>) -> StoredPerformance {
+        StoredPerformance {
+            athlete: "Ana".to_string(),
+            event: event.to_string(),
+            date: date.to_string(),
+            mark: mark.to_string(),
+            points,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_event_progression() {
+        let records = vec![
+            record("800m", "2025-06-01", "1:46.10", Some(1100.0)),
+            record("800m", "2024-07-01", "1:48.00", Some(1030.0)),
+            record("800m", "2025-07-01", "DNF", None),
+            record("800m", "", "1:47.00", Some(1060.0)),
+            record("1500m", "2025-05-01", "3:40.00", Some(1080.0)),
+            StoredPerformance {
+                athlete: "Bea".to_string(),
+                ..record("800m", "2025-06-01", "2:01.00", Some(1000.0))
+            },
+        ];
+        let progression = event_progression("Ana", "800m", &records);
+        assert_eq!(progression.len(), 2);
+        assert_eq!(progression[0].date, "2024-07-01");
+        assert_eq!(progression[1].day - progression[0].day, 335);
+        assert!((progression[1].performance - 106.1).abs() < 1e-9);
+        assert_eq!(progression[1].points, 1100.0);
+
+        assert_eq!(athlete_events("Ana", &records), vec!["1500m", "800m"]);
+        assert!(event_progression("Ana", "Egg and spoon", &records).is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+//! Scoring tables fetched at runtime from the published static API (`/api/static/`), so a
+//! table update only needs the JSON redeployed rather than a new WASM bundle.
+//!
+//! The embedded tables are loaded at startup as before, so scoring works straight away; the
+//! fetched tables replace them once they arrive and validate. If the fetch fails the
+//! embedded copy stays in use.
+use super::coefficients::TABLE_VERSION;
+use super::engine::{replace_scoring_engine, ScoringEngine};
+use crate::fetch_cache::{fetch_cached, DEFAULT_MAX_AGE_MS};
+use crate::models::{Event, Gender, TrackAndFieldEvent};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+pub const COEFFICIENTS_PATH: &str = "/api/static/coefficients.json";
+pub const PLACEMENT_PATH: &str = "/api/static/placement.json";
+
+/// Which scoring tables are in use
+#[derive(Debug, Clone, PartialEq)]
+pub enum TablesStatus {
+    /// The embedded tables are in use while the published ones are fetched
+    Loading,
+    /// The published tables, fetched at `fetched_at` (ms since the epoch)
+    Remote { fetched_at: f64, offline: bool },
+    /// The embedded tables, because the published ones couldn't be used
+    Embedded { reason: String },
+}
+
+/// Parses and sanity-checks fetched tables before they replace the embedded ones. The
+/// published tables are the current edition's.
+pub fn parse_remote_tables(
+    coefficients_json: &str,
+    placement_json: &str,
+) -> Result<ScoringEngine, String> {
+    let engine = ScoringEngine::from_json(coefficients_json, placement_json)?
+        .with_estimates()
+        .with_edition(TABLE_VERSION);
+    let coefficients = engine.coefficients();
+    let hundred = Event::TrackAndField(TrackAndFieldEvent::M100);
+    if coefficients
+        .get_coefficients(Gender::Men, &hundred)
+        .is_none()
+        || coefficients
+            .get_coefficients(Gender::Women, &hundred)
+            .is_none()
+    {
+        return Err("Coefficients are missing the 100m".to_string());
+    }
+    if !coefficients.unmatched_keys().is_empty() {
+        log::warn!(
+            "Coefficients for events the calculator doesn't have: {}",
+            coefficients.unmatched_keys().join(", ")
+        );
+    }
+    Ok(engine)
+}
+
+/// Fetches the published tables from under `base` (the app's path prefix, or "") and swaps
+/// them in, returning the resulting status
+pub async fn load_remote_tables(base: &str) -> TablesStatus {
+    let coefficients_url = format!("{}{}", base, COEFFICIENTS_PATH);
+    let placement_url = format!("{}{}", base, PLACEMENT_PATH);
+    let fetched = async {
+        let coefficients = fetch_cached(&coefficients_url, DEFAULT_MAX_AGE_MS).await?;
+        let placement = fetch_cached(&placement_url, DEFAULT_MAX_AGE_MS).await?;
+        replace_scoring_engine(parse_remote_tables(&coefficients.body, &placement.body)?)?;
+        Ok::<_, String>(TablesStatus::Remote {
+            fetched_at: coefficients.fetched_at.min(placement.fetched_at),
+            offline: coefficients.offline || placement.offline,
+        })
+    };
+    fetched.await.unwrap_or_else(|reason| {
+        log::warn!("Using the embedded scoring tables: {}", reason);
+        TablesStatus::Embedded { reason }
+    })
+}
+
+/// Starts fetching the published tables and provides their status to the app
+pub fn provide_remote_tables(base: &'static str) {
+    let status = RwSignal::new(TablesStatus::Loading);
+    spawn_local(async move { status.set(load_remote_tables(base).await) });
+    provide_context(status);
+}
+
+/// The status of the scoring tables, `Loading` outside the app (e.g. in tests)
+pub fn use_tables_status() -> RwSignal<TablesStatus> {
+    use_context::<RwSignal<TablesStatus>>().unwrap_or_else(|| RwSignal::new(TablesStatus::Loading))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COEFFICIENTS: &str = include_str!("../../core/data/world_athletics_constants_2025.json");
+    const PLACEMENT: &str = include_str!("../../core/data/track_and_field_placement_scores.json");
+
+    #[test]
+    fn test_parse_remote_tables() {
+        let engine = parse_remote_tables(COEFFICIENTS, PLACEMENT).unwrap();
+        assert_eq!(engine.edition(), Some(TABLE_VERSION));
+        assert!(parse_remote_tables("<html>Not found</html>", PLACEMENT).is_err());
+        assert!(parse_remote_tables(r#"{"men": {}, "women": {}}"#, PLACEMENT).is_err());
+        assert!(parse_remote_tables(COEFFICIENTS, "{}").is_err());
+    }
+}
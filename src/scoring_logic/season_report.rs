@@ -0,0 +1,140 @@
+use crate::performance_db::StoredPerformance;
+
+/// World Athletics points counted as a standout performance in the report
+pub const STANDOUT_POINTS: f64 = 1000.0;
+/// Number of best results averaged for the report
+pub const TOP_RESULTS: usize = 5;
+
+/// An athlete's end-of-season summary
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeasonReport {
+    pub athlete: String,
+    /// The season's year, matched against the start of each result's date
+    pub season: String,
+    pub results: usize,
+    pub best: Option<StoredPerformance>,
+    /// Average of the best `TOP_RESULTS` scores, or of all of them if there are fewer
+    pub top_average: Option<f64>,
+    pub placement_points: i32,
+    /// Results worth at least `STANDOUT_POINTS`
+    pub standout_results: usize,
+    /// `(date, points)` for every scored result, in date order
+    pub progression: Vec<(String, f64)>,
+}
+
+/// Points between the targets suggested after a season best
+pub const TARGET_STEP: f64 = 50.0;
+
+/// The next round-number target above a season best, e.g. 1050 after 1023 points
+pub fn next_target(season_best: f64) -> f64 {
+    ((season_best / TARGET_STEP).floor() + 1.0) * TARGET_STEP
+}
+
+/// Every athlete with stored results, sorted by name
+pub fn athletes(records: &[StoredPerformance]) -> Vec<String> {
+    let mut athletes: Vec<String> = records
+        .iter()
+        .map(|record| record.athlete.clone())
+        .collect();
+    athletes.sort();
+    athletes.dedup();
+    athletes
+}
+
+/// The seasons (years) the stored results cover, most recent first
+pub fn seasons(records: &[StoredPerformance]) -> Vec<String> {
+    let mut seasons: Vec<String> = records
+        .iter()
+        .filter_map(|record| record.date.get(..4).map(str::to_string))
+        .collect();
+    seasons.sort_unstable_by(|a, b| b.cmp(a));
+    seasons.dedup();
+    seasons
+}
+
+/// Summarises an athlete's scored results for the season
+pub fn season_report(athlete: &str, season: &str, records: &[StoredPerformance]) -> SeasonReport {
+    let mut scored: Vec<&StoredPerformance> = records
+        .iter()
+        .filter(|record| record.athlete == athlete && record.date.starts_with(season))
+        .filter(|record| record.points.is_some())
+        .collect();
+    scored.sort_by(|a, b| a.date.cmp(&b.date));
+    let points = |record: &StoredPerformance| record.points.unwrap_or_default();
+
+    let progression = scored
+        .iter()
+        .map(|record| (record.date.clone(), points(record)))
+        .collect();
+    let mut by_points = scored.clone();
+    by_points.sort_by(|a, b| points(b).total_cmp(&points(a)));
+    let top: Vec<f64> = by_points
+        .iter()
+        .take(TOP_RESULTS)
+        .map(|record| points(record))
+        .collect();
+
+    SeasonReport {
+        athlete: athlete.to_string(),
+        season: season.to_string(),
+        results: scored.len(),
+        best: by_points.first().map(|record| (*record).clone()),
+        top_average: (!top.is_empty()).then(|| top.iter().sum::<f64>() / top.len() as f64),
+        placement_points: scored
+            .iter()
+            .filter_map(|record| record.placement_points)
+            .sum(),
+        standout_results: scored
+            .iter()
+            .filter(|record| points(record) >= STANDOUT_POINTS)
+            .count(),
+        progression,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(athlete: &str, date: &str, points: f64, placement_points: i32) -> StoredPerformance {
+        StoredPerformance {
+            athlete: athlete.to_string(),
+            date: date.to_string(),
+            points: Some(points),
+            placement_points: Some(placement_points),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_season_report() {
+        let records = vec![
+            record("Ana", "2025-07-01", 1050.0, 40),
+            record("Ana", "2025-05-01", 950.0, 0),
+            record("Ana", "2025-06-01", 1000.0, 20),
+            record("Ana", "2024-06-01", 1200.0, 0),
+            record("Bea", "2025-06-01", 1300.0, 0),
+            StoredPerformance {
+                points: None,
+                ..record("Ana", "2025-08-01", 0.0, 0)
+            },
+        ];
+        let report = season_report("Ana", "2025", &records);
+        assert_eq!(report.results, 3);
+        assert_eq!(report.best.unwrap().date, "2025-07-01");
+        assert_eq!(report.top_average, Some(1000.0));
+        assert_eq!(report.placement_points, 60);
+        assert_eq!(report.standout_results, 2);
+        assert_eq!(report.progression[0], ("2025-05-01".to_string(), 950.0));
+
+        assert_eq!(seasons(&records), vec!["2025", "2024"]);
+        assert_eq!(athletes(&records), vec!["Ana", "Bea"]);
+        assert_eq!(season_report("Cat", "2025", &records).top_average, None);
+    }
+
+    #[test]
+    fn test_next_target() {
+        assert_eq!(next_target(1023.0), 1050.0);
+        assert_eq!(next_target(1050.0), 1100.0);
+    }
+}
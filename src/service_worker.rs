@@ -0,0 +1,53 @@
+// src/service_worker.rs
+//! Registers the service worker (`public/sw.js`) that caches the app for offline use.
+//!
+//! Browsers without service workers, or pages not served from a secure context, simply run
+//! online-only.
+use crate::components::nav_bar::PAGES_BASE;
+use leptos::task::spawn_local;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+
+/// Where the service worker is served, next to `index.html`
+pub fn service_worker_url(pathname: &str) -> String {
+    if pathname.starts_with(PAGES_BASE) {
+        format!("{}/sw.js", PAGES_BASE)
+    } else {
+        "/sw.js".to_string()
+    }
+}
+
+/// Registers the service worker in the background
+pub fn register_service_worker() {
+    let Some(window) = web_sys::window() else {
+        return;
+    };
+    // `navigator.serviceWorker` is undefined outside secure contexts
+    let navigator = window.navigator();
+    if !js_sys::Reflect::has(&navigator, &JsValue::from_str("serviceWorker")).unwrap_or(false) {
+        log::debug!("Service workers are unavailable; running online-only");
+        return;
+    }
+    let url = service_worker_url(&window.location().pathname().unwrap_or_default());
+    let registration = navigator.service_worker().register(&url);
+    spawn_local(async move {
+        match JsFuture::from(registration).await {
+            Ok(_) => log::debug!("Registered service worker {}", url),
+            Err(e) => log::warn!("Failed to register service worker {}: {:?}", url, e),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_worker_url() {
+        assert_eq!(service_worker_url("/tables"), "/sw.js");
+        assert_eq!(
+            service_worker_url("/world_athletics_points_calculator/tables"),
+            "/world_athletics_points_calculator/sw.js"
+        );
+    }
+}
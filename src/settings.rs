@@ -0,0 +1,355 @@
+// src/settings.rs
+//! User settings shared through Leptos context and persisted to localStorage, with JSON
+//! export/import for moving them between devices.
+use crate::models::{Event, Gender, UnitPreferences};
+use crate::scoring_logic::coefficients::{is_bundled_edition, TABLE_VERSION};
+use leptos::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt;
+use strum_macros::EnumIter;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+const SETTINGS_KEY: &str = "wa_points.settings";
+/// Where unit preferences were saved before they moved into `Settings`
+const LEGACY_UNIT_PREFERENCES_KEY: &str = "wa_points.units";
+
+/// Bumped when the export format changes incompatibly
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Locales the app can be displayed in
+pub const SUPPORTED_LOCALES: &[&str] = &["en"];
+
+/// The most decimal places points can be shown with
+pub const MAX_POINTS_DECIMALS: usize = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Theme {
+    Light,
+    Dark,
+    /// Follow the operating system's preference
+    #[default]
+    System,
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Theme::Light => write!(f, "Light"),
+            Theme::Dark => write!(f, "Dark"),
+            Theme::System => write!(f, "System"),
+        }
+    }
+}
+
+impl Theme {
+    /// Whether the dark palette is shown, given the operating system's `prefers-color-scheme`
+    pub fn is_dark(self, system_prefers_dark: bool) -> bool {
+        match self {
+            Theme::Light => false,
+            Theme::Dark => true,
+            Theme::System => system_prefers_dark,
+        }
+    }
+}
+
+/// How tightly the calculator is laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter, Serialize, Deserialize)]
+pub enum Density {
+    #[default]
+    Comfortable,
+    /// Smaller paddings and no helper text, so the whole form and score fit on a laptop screen
+    Compact,
+}
+
+impl fmt::Display for Density {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Density::Comfortable => write!(f, "Comfortable"),
+            Density::Compact => write!(f, "Compact"),
+        }
+    }
+}
+
+impl Density {
+    /// A label + control row whose items are vertically centred
+    pub fn row_class(self) -> &'static str {
+        match self {
+            Density::Comfortable => "grid grid-cols-1 md:grid-cols-3 gap-4 items-center",
+            Density::Compact => "grid grid-cols-1 md:grid-cols-3 gap-2 items-center",
+        }
+    }
+
+    /// A label + control row with helper text below the control
+    pub fn row_start_class(self) -> &'static str {
+        match self {
+            Density::Comfortable => "grid grid-cols-1 md:grid-cols-3 gap-4 items-start",
+            Density::Compact => "grid grid-cols-1 md:grid-cols-3 gap-2 items-start",
+        }
+    }
+
+    /// Inputs and selects
+    pub fn control_class(self) -> &'static str {
+        match self {
+            Density::Comfortable => "w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black",
+            Density::Compact => "w-full px-2 py-1 text-sm border border-gray-300 rounded-md focus:outline-none focus:ring-1 focus:ring-black",
+        }
+    }
+
+    /// Vertical spacing between the form's rows
+    pub fn stack_class(self) -> &'static str {
+        match self {
+            Density::Comfortable => "space-y-4",
+            Density::Compact => "space-y-2",
+        }
+    }
+
+    pub fn show_helper_text(self) -> bool {
+        self == Density::Comfortable
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub theme: Theme,
+    pub density: Density,
+    pub locale: String,
+    pub units: UnitPreferences,
+    /// Gender selected when the calculator opens
+    pub default_gender: Gender,
+    /// Event selected when the calculator opens, by its display name
+    pub default_event: String,
+    /// Decimal places shown for points
+    pub points_decimals: usize,
+    /// Edition of the scoring tables to use
+    pub table_version: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            theme: Theme::default(),
+            density: Density::default(),
+            locale: SUPPORTED_LOCALES[0].to_string(),
+            units: UnitPreferences::default(),
+            default_gender: Gender::Men,
+            default_event: Event::default().to_string(),
+            points_decimals: MAX_POINTS_DECIMALS,
+            table_version: TABLE_VERSION.to_string(),
+        }
+    }
+}
+
+impl Settings {
+    /// The default event, falling back to the app default if the saved name is unknown
+    pub fn default_event(&self) -> Event {
+        Event::from_string(&self.default_event).unwrap_or_default()
+    }
+
+    /// Formats points with the configured number of decimal places
+    pub fn format_points(&self, points: f64) -> String {
+        format!("{:.*}", self.points_decimals, points)
+    }
+
+    /// Replaces values that this version of the app can't honour with their defaults.
+    pub(crate) fn sanitized(mut self) -> Self {
+        let defaults = Settings::default();
+        if !SUPPORTED_LOCALES.contains(&self.locale.as_str()) {
+            self.locale = defaults.locale;
+        }
+        if Event::from_string(&self.default_event).is_none() {
+            self.default_event = defaults.default_event;
+        }
+        self.points_decimals = self.points_decimals.min(MAX_POINTS_DECIMALS);
+        if !is_bundled_edition(&self.table_version) {
+            self.table_version = defaults.table_version;
+        }
+        self
+    }
+}
+
+/// The file written by `export_settings`
+#[derive(Debug, Serialize, Deserialize)]
+struct SettingsExport {
+    version: u32,
+    settings: Settings,
+}
+
+/// Serializes settings for saving to a file.
+pub fn export_settings(settings: &Settings) -> String {
+    serde_json::to_string_pretty(&SettingsExport {
+        version: SETTINGS_EXPORT_VERSION,
+        settings: settings.clone(),
+    })
+    .unwrap_or_default()
+}
+
+/// Reads settings from an exported file.
+pub fn import_settings(json: &str) -> Result<Settings, String> {
+    let export: SettingsExport =
+        serde_json::from_str(json).map_err(|e| format!("Invalid settings file: {}", e))?;
+    if export.version > SETTINGS_EXPORT_VERSION {
+        return Err(format!(
+            "Settings file version {} is newer than this app supports ({})",
+            export.version, SETTINGS_EXPORT_VERSION
+        ));
+    }
+    Ok(export.settings.sanitized())
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    window().local_storage().ok().flatten()
+}
+
+/// Reads a JSON value from localStorage, or `None` if it is missing or unreadable.
+pub fn load_from_storage<T: DeserializeOwned>(key: &str) -> Option<T> {
+    let json = local_storage()?.get_item(key).ok()??;
+    serde_json::from_str(&json).ok()
+}
+
+/// Writes a value to localStorage as JSON. Storage can be unavailable (e.g. private browsing),
+/// in which case settings only last for the session.
+pub fn save_to_storage<T: Serialize>(key: &str, value: &T) {
+    if let (Some(storage), Ok(json)) = (local_storage(), serde_json::to_string(value)) {
+        let _ = storage.set_item(key, &json);
+    }
+}
+
+fn load_settings() -> Settings {
+    if let Some(settings) = load_from_storage::<Settings>(SETTINGS_KEY) {
+        return settings.sanitized();
+    }
+    Settings {
+        units: load_from_storage(LEGACY_UNIT_PREFERENCES_KEY).unwrap_or_default(),
+        ..Settings::default()
+    }
+}
+
+/// Provides the saved settings to the app and saves them whenever they change. Changes
+/// saved by other tabs are picked up from the `storage` event, so every open tab agrees.
+pub fn provide_settings() {
+    let settings = RwSignal::new(load_settings());
+    Effect::new(move |_| save_to_storage(SETTINGS_KEY, &settings.get()));
+    let listener = window_event_listener(leptos::ev::storage, move |ev| {
+        if ev.key().as_deref() != Some(SETTINGS_KEY) {
+            return;
+        }
+        let Some(changed) = ev
+            .new_value()
+            .and_then(|json| serde_json::from_str::<Settings>(&json).ok())
+            .map(Settings::sanitized)
+        else {
+            return;
+        };
+        // Only set differing values, so saving them doesn't echo back to the other tab
+        if settings.with_untracked(|current| current != &changed) {
+            settings.set(changed);
+        }
+    });
+    on_cleanup(move || listener.remove());
+    provide_context(settings);
+}
+
+/// The current settings, falling back to the defaults outside the app (e.g. in tests).
+pub fn use_settings() -> RwSignal<Settings> {
+    use_context::<RwSignal<Settings>>().unwrap_or_else(|| RwSignal::new(Settings::default()))
+}
+
+/// Whether the operating system prefers a dark color scheme, updated when it changes.
+/// False where `matchMedia` is unavailable.
+pub fn use_system_prefers_dark() -> Signal<bool> {
+    let Some(query) = window()
+        .match_media("(prefers-color-scheme: dark)")
+        .ok()
+        .flatten()
+    else {
+        return Signal::derive(|| false);
+    };
+    let prefers_dark = RwSignal::new(query.matches());
+    let on_change = Closure::<dyn Fn(web_sys::MediaQueryListEvent)>::new(
+        move |ev: web_sys::MediaQueryListEvent| prefers_dark.set(ev.matches()),
+    );
+    let _ = query.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref());
+    // Lives as long as the app
+    on_change.forget();
+    prefers_dark.into()
+}
+
+/// Whether the dark theme is shown, from the theme setting and the system preference.
+pub fn use_dark_theme() -> Signal<bool> {
+    let settings = use_settings();
+    let system_prefers_dark = use_system_prefers_dark();
+    Signal::derive(move || {
+        settings
+            .with(|s| s.theme)
+            .is_dark(system_prefers_dark.get())
+    })
+}
+
+/// The layout density consumed by the input components.
+pub fn use_density() -> Signal<Density> {
+    let settings = use_settings();
+    Signal::derive(move || settings.with(|s| s.density))
+}
+
+/// The current unit preferences.
+pub fn use_unit_preferences() -> Signal<UnitPreferences> {
+    let settings = use_settings();
+    Signal::derive(move || settings.with(|s| s.units))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::DistanceUnit;
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let settings = Settings {
+            theme: Theme::Dark,
+            default_gender: Gender::Women,
+            default_event: "Long Jump".to_string(),
+            points_decimals: 0,
+            units: UnitPreferences {
+                distance: DistanceUnit::Imperial,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let imported = import_settings(&export_settings(&settings)).unwrap();
+        assert_eq!(imported, settings);
+        assert_eq!(imported.format_points(1234.56), "1235");
+    }
+
+    #[test]
+    fn test_import_sanitizes_unknown_values() {
+        let json = r#"{"version": 1, "settings": {"default_event": "Egg and Spoon", "points_decimals": 7, "locale": "xx"}}"#;
+        let settings = import_settings(json).unwrap();
+        assert_eq!(settings.default_event(), Event::default());
+        assert_eq!(settings.points_decimals, MAX_POINTS_DECIMALS);
+        assert_eq!(settings.locale, "en");
+
+        assert!(import_settings(r#"{"version": 99, "settings": {}}"#).is_err());
+        assert!(import_settings("{}").is_err());
+    }
+
+    #[test]
+    fn test_theme_is_dark() {
+        assert!(Theme::Dark.is_dark(false));
+        assert!(!Theme::Light.is_dark(true));
+        assert!(Theme::System.is_dark(true));
+        assert!(!Theme::System.is_dark(false));
+        assert_eq!(Theme::default(), Theme::System);
+    }
+
+    #[test]
+    fn test_compact_density() {
+        let json = r#"{"version": 1, "settings": {"density": "Compact"}}"#;
+        let density = import_settings(json).unwrap().density;
+        assert_eq!(density, Density::Compact);
+        assert!(!density.show_helper_text());
+        assert!(density.control_class().contains("py-1"));
+        assert!(Density::default().show_helper_text());
+    }
+}
@@ -0,0 +1,377 @@
+//! The calculator's inputs as URL query parameters, so a link reproduces a calculation:
+//!
+//! `?gender=men&event=100m&mark=10.12&wind=0.5&category=A&place=1&round=Final&final=8`
+//!
+//! `wind=nwi` records a wind-affected event without a wind reading; a missing `wind` means
+//! 0.0. `downhill` and `altitude` are only present when entered, `group` only for a
+//! non-senior category (`u18`, `u20` or a masters age group such as `45`), `track` only for
+//! short-track events off a standard banked track, `tables` only when the scoring-table
+//! edition was chosen, and the placement parameters are omitted when placement isn't
+//! included.
+use crate::importers::open_track::round_from_code;
+use crate::models::{
+    AthleteCategory, CompetitionCategory, Event, Gender, Performance, PlacementInfo,
+    WorldAthleticsScoreInput,
+};
+use crate::scoring_logic::altitude::is_altitude_affected_event;
+use crate::scoring_logic::calculator::{
+    is_placement_only_event, is_road_running_event, is_wind_affected_event,
+};
+use crate::scoring_logic::coefficients::is_bundled_edition;
+use crate::scoring_logic::placement_score::{RoundType, MAX_TABLE_PLACE};
+use crate::scoring_logic::track_size::{is_short_track_event, scoring_event, TrackSize};
+
+/// Percent-encodes a query parameter value (RFC 3986 unreserved characters are kept)
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Parses a mark typed for `event`. Events scored on placing alone have no mark to enter, so
+/// theirs may be left blank.
+pub fn parse_mark(event: &Event, mark: &str) -> Result<Performance, String> {
+    if is_placement_only_event(event) && mark.trim().is_empty() {
+        return Ok(Performance::Seconds(0.0));
+    }
+    Performance::parse(event, mark)
+}
+
+/// The calculator inputs carried in a share link
+#[derive(Debug, Clone)]
+pub struct CalculatorState {
+    pub gender: Gender,
+    pub event: Event,
+    /// The mark as typed, e.g. "1:45.20"; blank for events scored on placing alone
+    pub mark: String,
+    pub wind_speed: Option<f64>,
+    pub net_downhill: Option<f64>,
+    pub venue_altitude: Option<f64>,
+    pub track_size: TrackSize,
+    pub athlete_category: AthleteCategory,
+    pub placement: Option<PlacementInfo>,
+    /// The edition of the scoring tables, e.g. "2025"; `None` scores against the default
+    pub table_edition: Option<String>,
+}
+
+impl CalculatorState {
+    /// The state as query parameter names and (unencoded) values
+    pub fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("gender", self.gender.to_string()),
+            ("event", self.event.to_string()),
+            ("mark", self.mark.clone()),
+        ];
+        if is_wind_affected_event(&self.event) {
+            match self.wind_speed {
+                Some(wind) => params.push(("wind", wind.to_string())),
+                None => params.push(("wind", "nwi".to_string())),
+            }
+        }
+        if let Some(drop) = self.net_downhill {
+            params.push(("downhill", drop.to_string()));
+        }
+        if let Some(altitude) = self.venue_altitude {
+            params.push(("altitude", altitude.to_string()));
+        }
+        if is_short_track_event(&self.event) && self.track_size != TrackSize::Banked {
+            params.push(("track", self.track_size.code().to_string()));
+        }
+        if self.athlete_category != AthleteCategory::Senior {
+            params.push(("group", self.athlete_category.code()));
+        }
+        if let Some(edition) = &self.table_edition {
+            params.push(("tables", edition.clone()));
+        }
+        if let Some(placement) = &self.placement {
+            params.extend([
+                ("category", placement.competition_category.to_string()),
+                ("place", placement.place.to_string()),
+                ("round", placement.round.to_string()),
+                ("final", placement.size_of_final.to_string()),
+            ]);
+            if placement.qualified_to_final {
+                params.push(("qualified", "1".to_string()));
+            }
+            if placement.main_event {
+                params.push(("main", "1".to_string()));
+            }
+            if placement.tied_with > 0 {
+                params.push(("tied", placement.tied_with.to_string()));
+            }
+        }
+        params
+    }
+
+    /// The state as a query string, without the leading `?`
+    pub fn to_query(&self) -> String {
+        self.to_params()
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, encode(&value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// The scoring input for the state, as the calculator builds it: oversized indoor tracks
+    /// are scored as outdoor marks, and conditions only apply to the events they affect.
+    pub fn score_input(&self) -> Result<WorldAthleticsScoreInput, String> {
+        let (scored_event, _) = scoring_event(&self.event, self.track_size);
+        Ok(WorldAthleticsScoreInput {
+            gender: self.gender,
+            performance: parse_mark(&self.event, &self.mark)?,
+            wind_speed: if is_wind_affected_event(&self.event) {
+                self.wind_speed
+            } else if is_wind_affected_event(&scored_event) {
+                Some(0.0) // An indoor mark scored as outdoor had no wind
+            } else {
+                None
+            },
+            net_downhill: self
+                .net_downhill
+                .filter(|_| is_road_running_event(&self.event)),
+            venue_altitude: self
+                .venue_altitude
+                .filter(|_| is_altitude_affected_event(&self.event)),
+            placement_info: self.placement.clone(),
+            event: scored_event,
+            table_edition: self.table_edition.clone(),
+        })
+    }
+
+    /// Reads the state from decoded query parameters. `None` unless the gender, event and
+    /// mark are all present and valid (the mark may be missing for events scored on placing
+    /// alone); other parameters fall back to their defaults, as do a place before 1st, a
+    /// number of tied athletes the tables couldn't score and an edition that isn't bundled.
+    pub fn from_query(get: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let gender = Gender::from_string(&get("gender")?)?;
+        let event = get("event")?.parse::<Event>().ok()?;
+        let mark = get("mark")
+            .filter(|mark| !mark.trim().is_empty())
+            .or_else(|| is_placement_only_event(&event).then(String::new))?;
+        let number = |key: &str| get(key).and_then(|value| value.trim().parse::<f64>().ok());
+        let placement = get("category")
+            .and_then(|category| CompetitionCategory::from_string(&category))
+            .map(|competition_category| PlacementInfo {
+                competition_category,
+                place: number("place")
+                    .filter(|place| *place >= 1.0)
+                    .map(|place| place as i32)
+                    .unwrap_or(1),
+                round: get("round")
+                    .map(|round| round_from_code(&round))
+                    .unwrap_or(RoundType::Final),
+                size_of_final: number("final").map(|size| size as i32).unwrap_or(8),
+                qualified_to_final: get("qualified").is_some_and(|q| q == "1"),
+                tied_with: number("tied")
+                    .filter(|tied| (0.0..MAX_TABLE_PLACE as f64).contains(tied))
+                    .map(|tied| tied as i32)
+                    .unwrap_or(0),
+                main_event: get("main").is_some_and(|main| main == "1"),
+            });
+        Some(CalculatorState {
+            gender,
+            event,
+            mark,
+            wind_speed: match get("wind") {
+                Some(wind) if wind == "nwi" => None,
+                Some(_) => number("wind"),
+                None => Some(0.0),
+            },
+            net_downhill: number("downhill"),
+            venue_altitude: number("altitude"),
+            track_size: get("track")
+                .and_then(|track| TrackSize::from_code(&track))
+                .unwrap_or_default(),
+            athlete_category: get("group")
+                .and_then(|group| AthleteCategory::from_code(&group))
+                .unwrap_or_default(),
+            placement,
+            table_edition: get("tables").filter(|edition| is_bundled_edition(edition)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CrossCountryEvent, TrackAndFieldEvent};
+    use crate::scoring_logic::coefficients::TABLE_VERSION;
+    use std::collections::HashMap;
+
+    fn decode(value: &str) -> String {
+        let bytes = value.as_bytes();
+        let mut decoded = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'%' {
+                decoded.push(u8::from_str_radix(&value[i + 1..i + 3], 16).unwrap());
+                i += 3;
+            } else {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+        String::from_utf8(decoded).unwrap()
+    }
+
+    fn round_trip(state: &CalculatorState) -> Option<CalculatorState> {
+        let params: HashMap<String, String> = state
+            .to_query()
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| (key.to_string(), decode(value)))
+            .collect();
+        CalculatorState::from_query(|key| params.get(key).cloned())
+    }
+
+    #[test]
+    fn test_share_link_round_trip() {
+        let state = CalculatorState {
+            gender: Gender::Women,
+            event: Event::TrackAndField(TrackAndFieldEvent::M800),
+            mark: "1:58.20".to_string(),
+            wind_speed: None,
+            net_downhill: None,
+            venue_altitude: Some(1850.0),
+            track_size: TrackSize::Oversized,
+            athlete_category: AthleteCategory::U20,
+            placement: Some(PlacementInfo {
+                competition_category: CompetitionCategory::GL,
+                place: 3,
+                round: RoundType::SemiFinal,
+                size_of_final: 9,
+                qualified_to_final: true,
+                tied_with: 1,
+                main_event: false,
+            }),
+            table_edition: Some(TABLE_VERSION.to_string()),
+        };
+        let query = state.to_query();
+        assert!(query.contains("mark=1%3A58.20"));
+        assert!(!query.contains("wind="));
+        assert!(!query.contains("track="));
+        let restored = round_trip(&state).unwrap();
+        assert_eq!(restored.mark, state.mark);
+        assert_eq!(restored.event, state.event);
+        assert_eq!(restored.venue_altitude, Some(1850.0));
+        assert_eq!(restored.athlete_category, AthleteCategory::U20);
+        assert_eq!(restored.table_edition.as_deref(), Some(TABLE_VERSION));
+        assert_eq!(
+            restored.score_input().unwrap().table_edition.as_deref(),
+            Some(TABLE_VERSION)
+        );
+        let placement = restored.placement.unwrap();
+        assert_eq!(placement.round, RoundType::SemiFinal);
+        assert_eq!(placement.size_of_final, 9);
+        assert!(placement.qualified_to_final);
+        assert_eq!(placement.tied_with, 1);
+
+        let windy = CalculatorState {
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            wind_speed: Some(-1.2),
+            placement: None,
+            table_edition: Some("1985".to_string()),
+            ..state
+        };
+        let restored = round_trip(&windy).unwrap();
+        assert_eq!(restored.wind_speed, Some(-1.2));
+        assert!(restored.placement.is_none());
+        // Editions that aren't bundled fall back to the default
+        assert!(restored.table_edition.is_none());
+        let no_reading = CalculatorState {
+            wind_speed: None,
+            ..windy
+        };
+        assert!(no_reading.to_query().contains("wind=nwi"));
+        assert_eq!(round_trip(&no_reading).unwrap().wind_speed, None);
+
+        let oversized = CalculatorState {
+            event: Event::TrackAndField(TrackAndFieldEvent::M800mSh),
+            ..no_reading
+        };
+        assert!(oversized.to_query().contains("track=oversized"));
+        assert_eq!(
+            round_trip(&oversized).unwrap().track_size,
+            TrackSize::Oversized
+        );
+
+        assert!(
+            CalculatorState::from_query(|key| (key == "gender").then(|| "men".to_string()))
+                .is_none()
+        );
+        // Out-of-range places and ties fall back to the defaults
+        let huge_tie = CalculatorState::from_query(|key| match key {
+            "gender" => Some("men".to_string()),
+            "event" => Some("100m".to_string()),
+            "mark" => Some("10.12".to_string()),
+            "category" => Some("A".to_string()),
+            "place" => Some("-3".to_string()),
+            "tied" => Some("1e12".to_string()),
+            _ => None,
+        })
+        .unwrap()
+        .placement
+        .unwrap();
+        assert_eq!(huge_tie.place, 1);
+        assert_eq!(huge_tie.tied_with, 0);
+    }
+
+    #[test]
+    fn test_score_input() {
+        let state = CalculatorState {
+            gender: Gender::Men,
+            event: Event::TrackAndField(TrackAndFieldEvent::M800mSh),
+            mark: "1:46.10".to_string(),
+            wind_speed: Some(1.0),
+            net_downhill: Some(2.0),
+            venue_altitude: Some(1200.0),
+            track_size: TrackSize::Oversized,
+            athlete_category: AthleteCategory::Senior,
+            placement: None,
+            table_edition: None,
+        };
+        let input = state.score_input().unwrap();
+        assert_eq!(input.event, Event::TrackAndField(TrackAndFieldEvent::M800));
+        assert_eq!(input.performance, Performance::Seconds(106.1));
+        assert_eq!(input.wind_speed, None);
+        assert_eq!(input.net_downhill, None);
+        assert_eq!(input.venue_altitude, None);
+
+        let sprint = CalculatorState {
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            mark: "10.12".to_string(),
+            ..state
+        };
+        let input = sprint.score_input().unwrap();
+        assert_eq!(input.wind_speed, Some(1.0));
+        assert_eq!(input.venue_altitude, Some(1200.0));
+
+        let invalid = CalculatorState {
+            mark: "fast".to_string(),
+            ..sprint
+        };
+        assert!(invalid.score_input().is_err());
+
+        // Cross country is scored on placing alone, so it needs no mark
+        let cross_country = CalculatorState {
+            event: Event::CrossCountry(CrossCountryEvent::Xc10km),
+            mark: String::new(),
+            ..invalid
+        };
+        let input = cross_country.score_input().unwrap();
+        assert_eq!(input.performance, Performance::Seconds(0.0));
+        assert!(round_trip(&cross_country).is_some());
+        let blank = CalculatorState {
+            event: Event::TrackAndField(TrackAndFieldEvent::M100),
+            ..cross_country
+        };
+        assert!(blank.score_input().is_err());
+        assert!(round_trip(&blank).is_none());
+    }
+}
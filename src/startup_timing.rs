@@ -0,0 +1,77 @@
+// src/startup_timing.rs
+//! Timing of the startup phases (table parsing, mount and first render).
+//!
+//! Each phase is recorded here for the debug panel and, when the browser supports it, as a
+//! User Timing measure so it shows up in the devtools Performance tab.
+use leptos::prelude::request_animation_frame;
+use std::cell::RefCell;
+
+/// Startup time, from navigation to first render, that the app should stay within
+pub const STARTUP_BUDGET_MS: f64 = 500.0;
+
+/// The name of the first-render timing, measured from navigation start
+pub const FIRST_RENDER: &str = "first_render";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StartupTiming {
+    pub name: &'static str,
+    pub duration_ms: f64,
+}
+
+thread_local! {
+    static TIMINGS: RefCell<Vec<StartupTiming>> = const { RefCell::new(Vec::new()) };
+}
+
+fn performance() -> Option<web_sys::Performance> {
+    web_sys::window().and_then(|window| window.performance())
+}
+
+fn record(name: &'static str, duration_ms: f64) {
+    log::debug!("{} took {:.1}ms", name, duration_ms);
+    TIMINGS.with(|timings| {
+        timings
+            .borrow_mut()
+            .push(StartupTiming { name, duration_ms });
+    });
+}
+
+/// Runs `f`, recording how long it took under `name`.
+pub fn measure<T>(name: &'static str, f: impl FnOnce() -> T) -> T {
+    let performance = performance();
+    let start = performance.as_ref().map(|p| p.now());
+    let start_mark = format!("{}:start", name);
+    if let Some(p) = &performance {
+        let _ = p.mark(&start_mark);
+    }
+
+    let result = f();
+
+    if let (Some(p), Some(start)) = (&performance, start) {
+        let _ = p.measure_with_start_mark(name, &start_mark);
+        record(name, p.now() - start);
+    }
+    result
+}
+
+/// Records the first render once the browser has painted the mounted app.
+pub fn record_first_render() {
+    request_animation_frame(|| {
+        if let Some(p) = performance() {
+            let _ = p.mark(FIRST_RENDER);
+            let since_navigation = p.now();
+            record(FIRST_RENDER, since_navigation);
+            if since_navigation > STARTUP_BUDGET_MS {
+                log::warn!(
+                    "Startup took {:.0}ms, over the {:.0}ms budget",
+                    since_navigation,
+                    STARTUP_BUDGET_MS
+                );
+            }
+        }
+    });
+}
+
+/// Every phase recorded so far, in the order they finished.
+pub fn startup_timings() -> Vec<StartupTiming> {
+    TIMINGS.with(|timings| timings.borrow().clone())
+}
@@ -1,6 +1,8 @@
 /** @type {import('tailwindcss').Config} */
 module.exports = {
     content: ["./src/**/*.rs", "./index.html"],
+    // Toggled by the theme setting rather than only following the OS
+    darkMode: "class",
     theme: {
         extend: {},
     },
@@ -64,13 +64,15 @@ mod performance_input_integration_tests {
         let input = WorldAthleticsScoreInput {
             gender: Gender::Men,
             event,
-            performance: parsed_performance,
+            performance: Performance::Seconds(parsed_performance),
             wind_speed: Some(1.5),
             net_downhill: None,
+            venue_altitude: None,
             placement_info: None,
+            table_edition: None,
         };
 
-        assert!((input.performance - 10.50).abs() < 0.001);
+        assert!((input.performance.value_for(&input.event).unwrap() - 10.50).abs() < 0.001);
         assert_eq!(input.wind_speed, Some(1.5));
     }
 
@@ -83,13 +85,15 @@ mod performance_input_integration_tests {
         let input = WorldAthleticsScoreInput {
             gender: Gender::Men,
             event,
-            performance: distance_meters,
+            performance: Performance::Meters(distance_meters),
             wind_speed: Some(0.5), // Wind still matters for long jump
             net_downhill: None,
+            venue_altitude: None,
             placement_info: None,
+            table_edition: None,
         };
 
-        assert!((input.performance - 8.95).abs() < 0.001);
+        assert!((input.performance.value_for(&input.event).unwrap() - 8.95).abs() < 0.001);
         assert_eq!(input.wind_speed, Some(0.5));
     }
 
@@ -143,14 +147,14 @@ mod performance_input_integration_tests {
             PerformanceType::Time
         );
 
-        // Verify combined events are time-based (individual events within would vary, but the overall scoring is points)
+        // Verify combined events are measured by their points total
         assert_eq!(
             Event::CombinedEvents(CombinedEvent::Dec).performance_type(),
-            PerformanceType::Time
+            PerformanceType::Points
         );
         assert_eq!(
             Event::CombinedEvents(CombinedEvent::Hept).performance_type(),
-            PerformanceType::Time
+            PerformanceType::Points
         );
     }
 
@@ -228,7 +232,7 @@ mod performance_input_integration_tests {
     fn test_placement_info_toggle() {
         // Test creating WorldAthleticsScoreInput with placement info
         let event = Event::TrackAndField(TrackAndFieldEvent::M100);
-        let performance = 10.50;
+        let performance = Performance::Seconds(10.50);
 
         let input_with_placement = WorldAthleticsScoreInput {
             gender: Gender::Men,
@@ -236,13 +240,17 @@ mod performance_input_integration_tests {
             performance,
             wind_speed: Some(1.5),
             net_downhill: None,
+            venue_altitude: None,
             placement_info: Some(PlacementInfo {
                 competition_category: CompetitionCategory::A,
                 place: 1,
                 round: RoundType::Final,
                 size_of_final: 8,
                 qualified_to_final: true,
+                tied_with: 0,
+                main_event: false,
             }),
+            table_edition: None,
         };
 
         // Test creating WorldAthleticsScoreInput without placement info
@@ -252,7 +260,9 @@ mod performance_input_integration_tests {
             performance,
             wind_speed: Some(1.5),
             net_downhill: None,
+            venue_altitude: None,
             placement_info: None,
+            table_edition: None,
         };
 
         // Verify placement info is present/absent as expected
@@ -262,8 +272,9 @@ mod performance_input_integration_tests {
         // Verify other fields are the same
         assert_eq!(input_with_placement.gender, input_without_placement.gender);
         assert_eq!(input_with_placement.event, input_without_placement.event);
-        assert!(
-            (input_with_placement.performance - input_without_placement.performance).abs() < 0.001
+        assert_eq!(
+            input_with_placement.performance,
+            input_without_placement.performance
         );
         assert_eq!(
             input_with_placement.wind_speed,
@@ -0,0 +1,73 @@
+//! Snapshot of the generated points tables.
+//!
+//! Every event/gender pair is rendered at fixed checkpoints (the performance needed for every
+//! 100 points, and the score that performance earns once rounded to hundredths) and compared
+//! against `tests/snapshots/score_tables.snap`. Any change to the coefficients data or the
+//! rounding logic shows up as a reviewable diff of that file.
+//!
+//! After an intentional change, regenerate the snapshot with:
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --test table_snapshots
+//! ```
+use std::fmt::Write;
+use strum::IntoEnumIterator;
+use world_athletics_points_calulator::models::*;
+use world_athletics_points_calulator::scoring_logic::coefficients::{
+    calculate_result_score, score_table,
+};
+
+const SNAPSHOT_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/snapshots/score_tables.snap"
+);
+const CHECKPOINT_STEP: usize = 100;
+
+fn render_tables() -> String {
+    let mut snapshot = String::new();
+    for gender in Gender::iter() {
+        for event in Event::all_variants() {
+            let event_name = event.to_string();
+            // Not every event is contested by both genders
+            let Ok(rows) = score_table(gender, &event, CHECKPOINT_STEP) else {
+                continue;
+            };
+            writeln!(snapshot, "[{} {}]", gender, event_name).unwrap();
+            for row in rows {
+                let rounded = (row.performance * 100.0).round() / 100.0;
+                let score = calculate_result_score(rounded, gender, &event).unwrap();
+                writeln!(snapshot, "{:>4} {:>10.2} {:>6}", row.points, rounded, score).unwrap();
+            }
+        }
+    }
+    snapshot
+}
+
+#[test]
+fn test_score_tables_match_snapshot() {
+    let actual = render_tables();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(SNAPSHOT_PATH, &actual).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(SNAPSHOT_PATH).unwrap_or_default();
+    if actual != expected {
+        let changes: Vec<String> = expected
+            .lines()
+            .zip(actual.lines())
+            .filter(|(e, a)| e != a)
+            .take(20)
+            .map(|(e, a)| format!("- {}\n+ {}", e, a))
+            .collect();
+        panic!(
+            "Points tables differ from {} ({} vs {} lines). First changes:\n{}\n\
+             Run `UPDATE_SNAPSHOTS=1 cargo test --test table_snapshots` if this is intended.",
+            SNAPSHOT_PATH,
+            expected.lines().count(),
+            actual.lines().count(),
+            changes.join("\n")
+        );
+    }
+}
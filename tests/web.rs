@@ -0,0 +1,256 @@
+//! Browser tests for the interactive components.
+//!
+//! These only build for wasm32 and run in a headless browser:
+//!
+//! ```sh
+//! wasm-pack test --headless --firefox
+//! ```
+#![cfg(target_arch = "wasm32")]
+
+use leptos::prelude::*;
+use leptos::task::tick;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use world_athletics_points_calulator::components::elite_histogram::EliteHistogram;
+use world_athletics_points_calulator::components::inputs::{
+    PerformanceInput, PlacementInfoSection,
+};
+use world_athletics_points_calulator::components::scoring_providers::{
+    provide_scoring_providers, ScoringProviders,
+};
+use world_athletics_points_calulator::components::world_athletics_score_form::WorldAthleticsScoreForm;
+use world_athletics_points_calulator::models::*;
+use world_athletics_points_calulator::scoring_logic::coefficients::PerformanceRange;
+use world_athletics_points_calulator::scoring_logic::placement_score::{
+    PlacementScoreCalcInput, RoundType,
+};
+use world_athletics_points_calulator::scoring_logic::season_lists::SeasonLists;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// A fresh container in the document body for each test to mount into
+fn container() -> web_sys::HtmlElement {
+    let div = document().create_element("div").unwrap();
+    document().body().unwrap().append_child(&div).unwrap();
+    div.unchecked_into()
+}
+
+fn find<T: JsCast>(root: &web_sys::HtmlElement, selector: &str) -> Option<T> {
+    root.query_selector(selector)
+        .unwrap()
+        .map(|element| element.unchecked_into())
+}
+
+fn set_input(root: &web_sys::HtmlElement, selector: &str, value: &str) {
+    let input: web_sys::HtmlInputElement = find(root, selector).unwrap();
+    input.set_value(value);
+    input
+        .dispatch_event(&web_sys::Event::new("input").unwrap())
+        .unwrap();
+}
+
+fn set_select(root: &web_sys::HtmlElement, selector: &str, value: &str) {
+    let select: web_sys::HtmlSelectElement = find(root, selector).unwrap();
+    select.set_value(value);
+    select
+        .dispatch_event(&web_sys::Event::new("change").unwrap())
+        .unwrap();
+}
+
+fn mock_result_score_calculator(
+    performance: f64,
+    _gender: Gender,
+    _event: &Event,
+) -> Result<f64, String> {
+    Ok(performance * 100.0)
+}
+
+fn mock_performance_calculator(
+    points: f64,
+    _gender: Gender,
+    _event: &Event,
+) -> Result<f64, String> {
+    Ok(points / 100.0)
+}
+
+fn mock_range_calculator(_gender: Gender, _event: &Event) -> Result<PerformanceRange, String> {
+    Ok(PerformanceRange {
+        best: 1.0,
+        worst: 100.0,
+    })
+}
+
+fn mock_placement_score_calculator(_input: PlacementScoreCalcInput) -> Option<i32> {
+    Some(50)
+}
+
+#[wasm_bindgen_test]
+async fn performance_input_shows_and_clears_parse_errors() {
+    let root = container();
+    let (event, _set_event) = signal(Event::default());
+    let (performance_input, set_performance_input) = signal(String::new());
+    let (performance, set_performance) = signal(0.0);
+    let (parse_error, set_parse_error) = signal(Option::<String>::None);
+    let _handle = mount_to(root.clone(), move || {
+        view! {
+            <PerformanceInput
+                event=event
+                performance_input=performance_input
+                set_performance_input=set_performance_input
+                set_performance=set_performance
+                parse_error=parse_error
+                set_parse_error=set_parse_error
+            />
+        }
+    });
+
+    set_input(&root, "#performance", "ten seconds");
+    tick().await;
+    assert!(parse_error.get_untracked().is_some());
+    assert!(root.inner_text().contains("Invalid time format"));
+    let input: web_sys::HtmlInputElement = find(&root, "#performance").unwrap();
+    assert!(input.class_name().contains("bg-red-50"));
+
+    set_input(&root, "#performance", "1:30.25");
+    tick().await;
+    assert!(parse_error.get_untracked().is_none());
+    assert!((performance.get_untracked() - 90.25).abs() < 0.001);
+    assert!(!root.inner_text().contains("Invalid time format"));
+}
+
+#[wasm_bindgen_test]
+async fn placement_section_renders_conditionally() {
+    let root = container();
+    let (include_placement, set_include_placement) = signal(true);
+    let (competition_category, set_competition_category) = signal(CompetitionCategory::A);
+    let (place, set_place) = signal(1);
+    let (tied_with, set_tied_with) = signal(0);
+    let (round, set_round) = signal(RoundType::Final);
+    let (size_of_final, set_size_of_final) = signal(8);
+    let (qualified_to_final, set_qualified_to_final) = signal(false);
+    let (main_event, set_main_event) = signal(false);
+    let _handle = mount_to(root.clone(), move || {
+        view! {
+            <PlacementInfoSection
+                include_placement=include_placement
+                set_include_placement=set_include_placement
+                competition_category=competition_category
+                set_competition_category=set_competition_category
+                place=place
+                set_place=set_place
+                tied_with=tied_with
+                set_tied_with=set_tied_with
+                round=round
+                set_round=set_round
+                size_of_final=size_of_final
+                set_size_of_final=set_size_of_final
+                qualified_to_final=qualified_to_final
+                set_qualified_to_final=set_qualified_to_final
+                main_event=main_event
+                set_main_event=set_main_event
+            />
+        }
+    });
+
+    assert!(find::<web_sys::Element>(&root, "#competition_category").is_some());
+    // The semifinal-only inputs are hidden for finals
+    assert!(find::<web_sys::Element>(&root, "#size_of_final").is_none());
+
+    set_select(&root, "#round", "Semifinal");
+    tick().await;
+    assert_eq!(round.get_untracked(), RoundType::SemiFinal);
+    assert!(find::<web_sys::Element>(&root, "#size_of_final").is_some());
+    assert!(find::<web_sys::Element>(&root, "#qualified_to_final").is_some());
+
+    set_include_placement.set(false);
+    tick().await;
+    assert!(find::<web_sys::Element>(&root, "#competition_category").is_none());
+    assert!(find::<web_sys::Element>(&root, "#round").is_none());
+}
+
+#[wasm_bindgen_test]
+async fn form_submit_shows_points() {
+    let root = container();
+    let _handle = mount_to(root.clone(), || {
+        provide_scoring_providers(ScoringProviders {
+            result_score_calculator: mock_result_score_calculator,
+            placement_score_calculator: mock_placement_score_calculator,
+            performance_calculator: mock_performance_calculator,
+            range_calculator: mock_range_calculator,
+        });
+        view! { <WorldAthleticsScoreForm /> }
+    });
+    assert!(root
+        .inner_text()
+        .contains("Enter a mark to calculate points"));
+    assert!(find::<web_sys::HtmlSelectElement>(&root, "#table_edition").is_some());
+
+    set_input(&root, "#performance", "10.00");
+    tick().await;
+    let submit: web_sys::HtmlButtonElement = find(&root, "button[type=submit]").unwrap();
+    submit.click();
+    tick().await;
+
+    // 10.00 * 100 from the result score, plus 50 placing points
+    assert!(root.inner_text().contains("Points: 1050.00"));
+}
+
+#[wasm_bindgen_test]
+async fn form_recalculates_as_inputs_change() {
+    let root = container();
+    let _handle = mount_to(root.clone(), || {
+        provide_scoring_providers(ScoringProviders {
+            result_score_calculator: mock_result_score_calculator,
+            placement_score_calculator: mock_placement_score_calculator,
+            performance_calculator: mock_performance_calculator,
+            range_calculator: mock_range_calculator,
+        });
+        view! { <WorldAthleticsScoreForm /> }
+    });
+
+    // No submit needed
+    set_input(&root, "#performance", "10.00");
+    tick().await;
+    assert!(root.inner_text().contains("Points: 1050.00"));
+
+    set_input(&root, "#performance", "11.00");
+    tick().await;
+    assert!(root.inner_text().contains("Points: 1150.00"));
+
+    // An invalid mark keeps the last score rather than scoring it
+    set_input(&root, "#performance", "fast");
+    tick().await;
+    assert!(root.inner_text().contains("Points: 1150.00"));
+}
+
+#[wasm_bindgen_test]
+async fn elite_histogram_renders_from_season_lists() {
+    let lists: &'static SeasonLists = Box::leak(Box::new(
+        SeasonLists::from_json(
+            r#"{"season": 2024, "source": "Test list", "men": {"100m": [9.79, 9.85, 9.9, 9.95, 10.0]}, "women": {}}"#,
+        )
+        .unwrap(),
+    ));
+    let root = container();
+    let (gender, _) = signal(Gender::Men);
+    let (event, _) = signal(Event::TrackAndField(TrackAndFieldEvent::M100));
+    let (performance, _) = signal(9.9);
+    let _handle = mount_to(root.clone(), move || {
+        view! { <EliteHistogram gender=gender event=event performance=performance lists=lists /> }
+    });
+    tick().await;
+
+    assert_eq!(
+        root.query_selector_all(".histogram-bar").unwrap().length(),
+        20
+    );
+    assert!(root.inner_text().contains("of the 5 marks"));
+
+    // Without a list the histogram is hidden
+    let empty = container();
+    let _handle = mount_to(empty.clone(), move || {
+        view! { <EliteHistogram gender=gender event=event performance=performance /> }
+    });
+    tick().await;
+    assert!(find::<web_sys::Element>(&empty, "figure").is_none());
+}